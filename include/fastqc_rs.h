@@ -0,0 +1,85 @@
+#ifndef FASTQC_RS_H
+#define FASTQC_RS_H
+
+/* Generated with cbindgen from src/ffi.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Options of [`fqc_process_file`], of which zero values mean the defaults of `fqc`.
+ */
+typedef struct FqcOptions {
+  /**
+   * Length of the counted k-mers, from 2 to 10, or 0 for the default of 5.
+   */
+  uint8_t k;
+  /**
+   * Number of threads processing the records.
+   */
+  uint32_t threads;
+  /**
+   * Number of reads randomly sampled instead of using all reads.
+   */
+  uint64_t sample;
+  /**
+   * Number of records read from the start of the file instead of all records.
+   */
+  uint64_t max_reads;
+  /**
+   * Whether positions of long reads are grouped into bins.
+   */
+  bool long_reads;
+  /**
+   * Path of a FastQC limits file, or null.
+   */
+  const char *limits;
+} FqcOptions;
+
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Returns the default options.
+ */
+struct FqcOptions fqc_default_options(void);
+
+/**
+ * Runs all quality control modules on the given FASTQ, FASTA or SAM/BAM/CRAM file and
+ * returns the report as written by `fqc --format json`, which must be released with
+ * [`fqc_string_free`]. Returns null on errors, whose message is returned by
+ * [`fqc_last_error`].
+ *
+ * # Safety
+ *
+ * `path` must be a valid null-terminated string and `options` null or a valid pointer
+ * to options whose `limits` is null or a valid null-terminated string.
+ */
+char *fqc_process_file(const char *path, const struct FqcOptions *options);
+
+/**
+ * Returns the message of the last error on the calling thread, or null. The message
+ * is valid until the next failing call on that thread.
+ */
+const char *fqc_last_error(void);
+
+/**
+ * Releases a string returned by [`fqc_process_file`].
+ *
+ * # Safety
+ *
+ * `string` must be null or returned by [`fqc_process_file`] and not released before.
+ */
+void fqc_string_free(char *string);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* FASTQC_RS_H */
@@ -0,0 +1,272 @@
+use crate::bins::{group_means, Binning};
+use crate::contaminants::parse_sequence_list;
+use crate::input::Record;
+use crate::modules::{Module, ModuleResult, QcModule, Threshold};
+use crate::process::most_severe;
+use crate::qc::Status;
+
+/// An adapter sequence searched for in every read.
+#[derive(Clone)]
+pub(crate) struct Adapter {
+    pub(crate) name: String,
+    sequence: Vec<u8>,
+}
+
+impl Adapter {
+    pub(crate) fn new(name: &str, sequence: &[u8]) -> Self {
+        Adapter {
+            name: name.to_string(),
+            sequence: sequence.to_ascii_uppercase(),
+        }
+    }
+
+    /// Parses an adapter list in FastQC's format.
+    pub(crate) fn parse_list(list: &str) -> Vec<Adapter> {
+        parse_sequence_list(list)
+            .map(|(name, sequence)| Adapter::new(name, sequence.as_bytes()))
+            .collect()
+    }
+
+    /// Parses an adapter file either as FASTA, taking the header lines as names, or in
+    /// FastQC's format, returning the names and sequences.
+    pub(crate) fn parse_file(text: &str) -> Vec<(String, String)> {
+        let fasta = text
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with('#'))
+            .is_some_and(|line| line.starts_with('>'));
+        if !fasta {
+            return parse_sequence_list(text)
+                .map(|(name, sequence)| (name.to_string(), sequence.to_string()))
+                .collect();
+        }
+        let mut adapters: Vec<(String, String)> = Vec::new();
+        for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            if let Some(name) = line.strip_prefix('>') {
+                adapters.push((name.trim().to_string(), String::new()));
+            } else if let Some((_, sequence)) = adapters.last_mut() {
+                sequence.push_str(line);
+            }
+        }
+        adapters.retain(|(_, sequence)| !sequence.is_empty());
+        adapters
+    }
+
+    /// The adapter list shipped with fastqc-rs.
+    pub(crate) fn default_list() -> Vec<Adapter> {
+        Adapter::parse_list(include_str!("resources/adapter_list.txt"))
+    }
+
+    /// Returns the first position of the adapter within the given sequence.
+    pub(crate) fn find(&self, seq: &[u8]) -> Option<usize> {
+        if self.sequence.is_empty() || seq.len() < self.sequence.len() {
+            return None;
+        }
+        seq.windows(self.sequence.len())
+            .position(|window| window.eq_ignore_ascii_case(&self.sequence))
+    }
+}
+
+/// Counts for every adapter and position bin how many reads contain the adapter at or
+/// before that position.
+pub(crate) struct AdapterCounter {
+    adapters: Vec<Adapter>,
+    binning: Binning,
+    /// Per adapter the change in the number of reads with adapter at each position bin.
+    deltas: Vec<Vec<isize>>,
+    /// Per adapter the number of reads in which it starts at each position bin.
+    starts: Vec<Vec<usize>>,
+    /// Per adapter the number of bases from its start to the end of the reads.
+    bases: Vec<usize>,
+    total: usize,
+    /// Percentage of reads with any adapter at any position above which the module
+    /// warns or fails.
+    threshold: Threshold,
+}
+
+impl AdapterCounter {
+    pub(crate) fn new(adapters: Vec<Adapter>, binning: Binning, threshold: Threshold) -> Self {
+        let deltas = adapters.iter().map(|_| Vec::new()).collect();
+        let starts = adapters.iter().map(|_| Vec::new()).collect();
+        let bases = vec![0; adapters.len()];
+        AdapterCounter {
+            adapters,
+            binning,
+            deltas,
+            starts,
+            bases,
+            total: 0,
+            threshold,
+        }
+    }
+
+    pub(crate) fn adapters(&self) -> &[Adapter] {
+        &self.adapters
+    }
+
+    pub(crate) fn observe(&mut self, seq: &[u8]) {
+        let bins = self.binning.bins(seq.len());
+        for (((adapter, deltas), starts), bases) in self
+            .adapters
+            .iter()
+            .zip(self.deltas.iter_mut())
+            .zip(self.starts.iter_mut())
+            .zip(self.bases.iter_mut())
+        {
+            if deltas.len() <= bins {
+                deltas.resize(bins + 1, 0);
+            }
+            if let Some(start) = adapter.find(seq) {
+                let bin = self.binning.bin(start);
+                deltas[bin] += 1;
+                deltas[bins] -= 1;
+                if starts.len() <= bin {
+                    starts.resize(bin + 1, 0);
+                }
+                starts[bin] += 1;
+                *bases += seq.len() - start;
+            }
+        }
+        self.total += 1;
+    }
+
+    /// Adds the counts of another counter for the same adapters, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &AdapterCounter) {
+        for (deltas, other) in self.deltas.iter_mut().zip(&other.deltas) {
+            if deltas.len() < other.len() {
+                deltas.resize(other.len(), 0);
+            }
+            for (delta, other) in deltas.iter_mut().zip(other) {
+                *delta += other;
+            }
+        }
+        for (starts, other) in self.starts.iter_mut().zip(&other.starts) {
+            if starts.len() < other.len() {
+                starts.resize(other.len(), 0);
+            }
+            for (count, other) in starts.iter_mut().zip(other) {
+                *count += other;
+            }
+        }
+        for (bases, other) in self.bases.iter_mut().zip(&other.bases) {
+            *bases += other;
+        }
+        self.total += other.total;
+    }
+
+    /// Returns per adapter the cumulative percentage of reads containing it for
+    /// every position bin.
+    pub(crate) fn percentages(&self) -> Vec<Vec<f64>> {
+        self.deltas
+            .iter()
+            .map(|deltas| {
+                let length = deltas.len().saturating_sub(1);
+                deltas[..length]
+                    .iter()
+                    .scan(0_isize, |count, delta| {
+                        *count += delta;
+                        Some(*count as f64 / self.total as f64 * 100.0)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns per adapter the number of reads in which it starts at every position
+    /// bin, which for reads shorter than the insert mostly is the insert size.
+    pub(crate) fn starts(&self) -> &[Vec<usize>] {
+        &self.starts
+    }
+
+    /// Returns the number of bases from the start of an adapter to the end of the
+    /// reads for the adapter with the most of them, as reads rarely contain several.
+    pub(crate) fn bases(&self) -> usize {
+        self.bases.iter().copied().max().unwrap_or(0)
+    }
+}
+
+impl QcModule for AdapterCounter {
+    fn observe(&mut self, record: &Record) {
+        self.observe(&record.seq());
+    }
+
+    fn is_mergeable() -> bool {
+        true
+    }
+
+    fn merge(&mut self, other: &dyn QcModule) {
+        if let Some(other) = other.downcast_ref::<AdapterCounter>() {
+            self.merge(other);
+        }
+    }
+
+    fn finish(&self) -> ModuleResult {
+        let mut status = "pass";
+        let mut result = ModuleResult::new(Module::AdapterContent.name(), Status::Pass)
+            .with_columns(&["Adapter", "Maximum percentage"]);
+        for (adapter, percentages) in self.adapters.iter().zip(self.percentages()) {
+            // Like in the reports, positions are averaged over the same groups
+            let groups = self.binning.groups_of(percentages.len());
+            let max = group_means(&percentages, &groups)
+                .into_iter()
+                .map(|(_, pct)| pct)
+                .fold(0.0, f64::max);
+            status = most_severe([status, self.threshold.above(max)]);
+            result = result.with_row(&[adapter.name.clone(), format!("{:.2}", max)]);
+        }
+        result.status = Status::parse(status);
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Adapter, AdapterCounter};
+    use crate::bins::Binning;
+    use crate::modules::{QcModule, Thresholds};
+    use crate::qc::Status;
+    #[test]
+    fn test_percentages() {
+        let mut counter = AdapterCounter::new(
+            vec![Adapter::new("adapter", b"AGATCGGAAGAG")],
+            Binning::Exact,
+            Thresholds::default().adapter_content,
+        );
+        counter.observe(b"TTTTAGATCGGAAGAGTTTT");
+        counter.observe(b"TTTTTTTTAGATCGGAAGAG");
+        counter.observe(b"TTTTTTTTTTTTTTTTTTTT");
+        counter.observe(b"TTTTTTTTTTTTTTTTTTTT");
+        let percentages = &counter.percentages()[0];
+        assert_eq!(percentages.len(), 20);
+        assert_eq!(percentages[3], 0.0);
+        assert_eq!(percentages[4], 25.0);
+        assert_eq!(percentages[8], 50.0);
+        assert_eq!(percentages[19], 50.0);
+        let starts = &counter.starts()[0];
+        assert_eq!(starts.len(), 9);
+        assert_eq!((starts[4], starts[8]), (1, 1));
+        assert_eq!(counter.bases(), 16 + 12);
+        let result = counter.finish();
+        assert_eq!(result.status, Status::Fail);
+        assert_eq!(result.rows, [["adapter", "50.00"]]);
+    }
+    #[test]
+    fn test_parse_file() {
+        let fasta = ">Kit adapter 1\nAGATCGGA\nAGAG\n\n>Empty\n>Kit adapter 2\nCTGTCTCTTATA\n";
+        assert_eq!(
+            Adapter::parse_file(fasta),
+            [
+                ("Kit adapter 1".to_string(), "AGATCGGAAGAG".to_string()),
+                ("Kit adapter 2".to_string(), "CTGTCTCTTATA".to_string()),
+            ]
+        );
+        let tsv = "# Kit adapters\nKit adapter 1\tAGATCGGAAGAG\n";
+        assert_eq!(Adapter::parse_file(tsv).len(), 1);
+    }
+    #[test]
+    fn test_default_list() {
+        assert!(Adapter::default_list()
+            .iter()
+            .any(|adapter| adapter.name == "Nextera Transposase Sequence"));
+    }
+}
@@ -0,0 +1,166 @@
+use crate::casava;
+use rustc_hash::FxHashMap as HashMap;
+
+/// Number of distinct index sequences counted, afterwards only those already seen are
+/// counted.
+const TRACKING_LIMIT: usize = 1 << 16;
+/// Number of most frequent index sequences reported.
+const TOP_BARCODES: usize = 20;
+/// Number of mismatches to the expected index from which an index is not taken for a
+/// sequencing error of it, but for one of another sample.
+pub(crate) const UNEXPECTED_MISMATCHES: usize = 2;
+
+/// Counts the index sequences in the read headers, written by bcl2fastq and
+/// BCL Convert for the barcodes the reads were demultiplexed by.
+#[derive(Debug, Default)]
+pub(crate) struct BarcodeCounter {
+    counts: HashMap<Vec<u8>, usize>,
+    /// Number of reads with an index.
+    indexed: usize,
+}
+
+/// Frequencies of the index sequences of the reads, compared to the most frequent one.
+pub(crate) struct Barcodes {
+    /// Number of reads with an index.
+    pub(crate) reads: usize,
+    /// Most frequent index, taken for the one the reads were demultiplexed by.
+    pub(crate) expected: String,
+    /// Number of reads with each number of mismatches to the expected index.
+    pub(crate) mismatches: Vec<usize>,
+    /// Shannon entropy in bits of the indices other than the expected one, which is
+    /// high for sequencing errors and low for few barcodes of other samples.
+    pub(crate) entropy: f64,
+    /// Number and percentage of reads with an unexpected index, i.e. at least
+    /// `UNEXPECTED_MISMATCHES` away from the expected one.
+    pub(crate) unexpected: usize,
+    pub(crate) unexpected_percentage: f64,
+    /// Most frequent indices with their number of reads, percentage and mismatches.
+    pub(crate) top: Vec<(String, usize, f64, usize)>,
+    /// Number of reads of every dual index, split into the i7 and i5 index.
+    pub(crate) dual: Vec<(String, String, usize)>,
+}
+
+impl BarcodeCounter {
+    pub(crate) fn observe(&mut self, id: &[u8]) {
+        let Some(index) = casava::index(id) else {
+            return;
+        };
+        self.indexed += 1;
+        if self.counts.len() < TRACKING_LIMIT {
+            *self.counts.entry(index.to_vec()).or_insert(0) += 1;
+        } else if let Some(count) = self.counts.get_mut(index) {
+            *count += 1;
+        }
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &BarcodeCounter) {
+        for (index, &count) in &other.counts {
+            if self.counts.len() < TRACKING_LIMIT || self.counts.contains_key(index) {
+                *self.counts.entry(index.clone()).or_insert(0) += count;
+            }
+        }
+        self.indexed += other.indexed;
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.indexed == 0
+    }
+
+    pub(crate) fn barcodes(&self) -> Barcodes {
+        let mut counts = self.counts.iter().collect::<Vec<_>>();
+        counts.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+        let expected = counts
+            .first()
+            .map_or(&[][..], |(index, _)| index.as_slice());
+        let mut mismatches = Vec::new();
+        let mut entropy = 0.0;
+        let others = self.indexed - counts.first().map_or(0, |(_, &count)| count);
+        for (index, &count) in &counts {
+            let distance = distance(index, expected);
+            if mismatches.len() <= distance {
+                mismatches.resize(distance + 1, 0);
+            }
+            mismatches[distance] += count;
+            if distance > 0 {
+                let p = count as f64 / others as f64;
+                entropy -= p * p.log2();
+            }
+        }
+        let pct = |count: usize| match self.indexed {
+            0 => 0.0,
+            total => count as f64 * 100.0 / total as f64,
+        };
+        let unexpected = mismatches.iter().skip(UNEXPECTED_MISMATCHES).sum();
+        Barcodes {
+            reads: self.indexed,
+            expected: String::from_utf8_lossy(expected).to_string(),
+            mismatches,
+            entropy,
+            unexpected,
+            unexpected_percentage: pct(unexpected),
+            top: counts
+                .iter()
+                .take(TOP_BARCODES)
+                .map(|(index, &count)| {
+                    let distance = distance(index, expected);
+                    (
+                        String::from_utf8_lossy(index).to_string(),
+                        count,
+                        pct(count),
+                        distance,
+                    )
+                })
+                .collect(),
+            dual: counts
+                .iter()
+                .filter_map(|(index, &count)| {
+                    let index = std::str::from_utf8(index).ok()?;
+                    let (i7, i5) = index.split_once('+')?;
+                    Some((i7.to_string(), i5.to_string(), count))
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Returns the number of mismatches between two indices, counting missing bases of the
+/// shorter one as mismatches.
+fn distance(a: &[u8], b: &[u8]) -> usize {
+    let mismatches = a.iter().zip(b).filter(|(a, b)| a != b).count();
+    mismatches + a.len().abs_diff(b.len())
+}
+
+#[cfg(test)]
+mod test {
+    use super::BarcodeCounter;
+    #[test]
+    fn test_barcodes() {
+        let mut counter = BarcodeCounter::default();
+        for _ in 0..6 {
+            counter.observe(b"M1:7:FC:1:1101:1:2 1:N:0:ATCACG");
+        }
+        counter.observe(b"M1:7:FC:1:1101:1:3 1:N:0:ATCACC");
+        let mut other = BarcodeCounter::default();
+        other.observe(b"M1:7:FC:1:1101:1:4 1:N:0:CGATGT");
+        other.observe(b"M1:7:FC:1:1101:1:5 1:N:0:1");
+        counter.merge(&other);
+        let barcodes = counter.barcodes();
+        assert_eq!(barcodes.reads, 8);
+        assert_eq!(barcodes.expected, "ATCACG");
+        assert_eq!(barcodes.mismatches, [6, 1, 0, 0, 0, 0, 1]);
+        assert_eq!(barcodes.entropy, 1.0);
+        assert_eq!(
+            (barcodes.unexpected, barcodes.unexpected_percentage),
+            (1, 12.5)
+        );
+        assert_eq!(barcodes.top[1], ("ATCACC".to_string(), 1, 12.5, 1));
+        assert_eq!(barcodes.top[2].3, 6);
+        assert!(barcodes.dual.is_empty());
+        assert!(BarcodeCounter::default().is_empty());
+        let mut dual = BarcodeCounter::default();
+        dual.observe(b"M1:7:FC:1:1101:1:2 1:N:0:ATCACG+GTCAAG");
+        let dual = dual.barcodes().dual;
+        assert_eq!(dual, [("ATCACG".to_string(), "GTCAAG".to_string(), 1)]);
+    }
+}
@@ -0,0 +1,184 @@
+use flate2::read::GzDecoder;
+use std::io::{self, Read};
+use std::thread;
+
+/// Length of the header of a BGZF block up to and including its size.
+pub(crate) const HEADER_LEN: usize = 18;
+/// Number of blocks of up to 64 KiB decompressed by every thread at once.
+const BLOCKS_PER_THREAD: usize = 16;
+
+/// Returns whether the input starting with the given bytes is BGZF compressed, i.e.
+/// gzip with the block size in the extra field as written by `bgzip` and htslib.
+pub(crate) fn is_bgzf(header: &[u8]) -> bool {
+    header.len() >= HEADER_LEN
+        && header[..4] == [0x1F, 0x8B, 0x08, 0x04]
+        && header[12..16] == [b'B', b'C', 0x02, 0x00]
+}
+
+/// Decompresses BGZF input, whose blocks are independent gzip members, on several
+/// threads, reading enough blocks to keep all of them busy and returning their data
+/// in order.
+pub(crate) struct BgzfReader<R> {
+    inner: R,
+    threads: usize,
+    decompressed: Vec<u8>,
+    position: usize,
+}
+
+impl<R: Read> BgzfReader<R> {
+    pub(crate) fn new(inner: R, threads: usize) -> Self {
+        BgzfReader {
+            inner,
+            threads: threads.max(1),
+            decompressed: Vec::new(),
+            position: 0,
+        }
+    }
+
+    /// Reads the next compressed block, or `None` at the end of the input.
+    fn read_block(&mut self) -> io::Result<Option<Vec<u8>>> {
+        let mut block = vec![0; HEADER_LEN];
+        let mut read = 0;
+        while read < HEADER_LEN {
+            match self.inner.read(&mut block[read..])? {
+                0 if read == 0 => return Ok(None),
+                0 => return Err(io::ErrorKind::UnexpectedEof.into()),
+                n => read += n,
+            }
+        }
+        if !is_bgzf(&block) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Invalid BGZF block, the input is not compressed with bgzip throughout.",
+            ));
+        }
+        let size = u16::from_le_bytes([block[16], block[17]]) as usize + 1;
+        if size <= HEADER_LEN {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Invalid BGZF block size.",
+            ));
+        }
+        block.resize(size, 0);
+        self.inner.read_exact(&mut block[HEADER_LEN..])?;
+        Ok(Some(block))
+    }
+
+    /// Decompresses the next blocks, returning whether there were any.
+    fn fill(&mut self) -> io::Result<bool> {
+        let mut blocks = Vec::new();
+        while blocks.len() < self.threads * BLOCKS_PER_THREAD {
+            match self.read_block()? {
+                Some(block) => blocks.push(block),
+                None => break,
+            }
+        }
+        if blocks.is_empty() {
+            return Ok(false);
+        }
+        let chunk = blocks.len().div_ceil(self.threads);
+        let chunks = if self.threads == 1 {
+            vec![decompress(&blocks)?]
+        } else {
+            thread::scope(|scope| {
+                let handles = blocks
+                    .chunks(chunk)
+                    .map(|blocks| scope.spawn(move || decompress(blocks)))
+                    .collect::<Vec<_>>();
+                handles
+                    .into_iter()
+                    .map(|handle| handle.join().expect("BGZF decompression panicked"))
+                    .collect::<io::Result<Vec<_>>>()
+            })?
+        };
+        self.decompressed = chunks.concat();
+        self.position = 0;
+        Ok(true)
+    }
+}
+
+/// Decompresses the given blocks one after another, checking their checksums.
+fn decompress(blocks: &[Vec<u8>]) -> io::Result<Vec<u8>> {
+    let mut data = Vec::new();
+    for block in blocks {
+        // The uncompressed size is given by the last four bytes
+        let end = block.len();
+        let size = u32::from_le_bytes([
+            block[end - 4],
+            block[end - 3],
+            block[end - 2],
+            block[end - 1],
+        ]);
+        data.reserve(size as usize);
+        GzDecoder::new(&block[..]).read_to_end(&mut data)?;
+    }
+    Ok(data)
+}
+
+impl<R: Read> Read for BgzfReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        // Blocks like the empty one at the end of the file may hold no data
+        while self.position == self.decompressed.len() {
+            if !self.fill()? {
+                return Ok(0);
+            }
+        }
+        let n = buf.len().min(self.decompressed.len() - self.position);
+        buf[..n].copy_from_slice(&self.decompressed[self.position..self.position + n]);
+        self.position += n;
+        Ok(n)
+    }
+}
+
+/// Compresses the data as BGZF blocks of at most the given size, ending with the empty
+/// block marking the end of the file.
+#[cfg(test)]
+pub(crate) fn compress(data: &[u8], block_size: usize) -> Vec<u8> {
+    use std::io::Write;
+    let mut compressed = Vec::new();
+    for chunk in data.chunks(block_size).chain([&[][..]]) {
+        let mut encoder = flate2::write::DeflateEncoder::new(Vec::new(), Default::default());
+        encoder.write_all(chunk).unwrap();
+        let deflated = encoder.finish().unwrap();
+        let mut crc = flate2::Crc::new();
+        crc.update(chunk);
+        let size = (HEADER_LEN + deflated.len() + 8 - 1) as u16;
+        compressed.extend([0x1F, 0x8B, 0x08, 0x04, 0, 0, 0, 0, 0, 0xFF, 0x06, 0x00]);
+        compressed.extend([b'B', b'C', 0x02, 0x00]);
+        compressed.extend(size.to_le_bytes());
+        compressed.extend(deflated);
+        compressed.extend(crc.sum().to_le_bytes());
+        compressed.extend((chunk.len() as u32).to_le_bytes());
+    }
+    compressed
+}
+
+#[cfg(test)]
+mod test {
+    use super::{compress, is_bgzf, BgzfReader};
+    use std::io::Read;
+    #[test]
+    fn test_bgzf() {
+        let data = (0..5000)
+            .map(|i| format!("@read{}\nACGT\n+\nIIII\n", i))
+            .collect::<String>();
+        let compressed = compress(data.as_bytes(), 1000);
+        assert!(is_bgzf(&compressed));
+        for threads in [1, 3] {
+            let mut decompressed = String::new();
+            BgzfReader::new(&compressed[..], threads)
+                .read_to_string(&mut decompressed)
+                .unwrap();
+            assert_eq!(decompressed, data);
+        }
+        let mut gzip = flate2::write::GzEncoder::new(Vec::new(), Default::default());
+        std::io::Write::write_all(&mut gzip, data.as_bytes()).unwrap();
+        let gzip = gzip.finish().unwrap();
+        assert!(!is_bgzf(&gzip));
+        let mut corrupted = compressed.clone();
+        corrupted[30] ^= 0xFF;
+        assert!(BgzfReader::new(&corrupted[..], 2)
+            .read_to_end(&mut Vec::new())
+            .is_err());
+    }
+}
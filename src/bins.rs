@@ -0,0 +1,216 @@
+use std::ops::Range;
+
+/// Positions below which every position gets its own bin in adaptive binning.
+const EXACT_POSITIONS: usize = 16;
+/// Reads up to this length are reported position by position when grouping.
+const MAX_UNGROUPED_LENGTH: usize = 75;
+/// Number of leading positions reported individually when grouping.
+const UNGROUPED_POSITIONS: usize = 9;
+/// Groups of reads are made this small that there are fewer groups than this.
+const MAX_GROUPS: usize = 75;
+/// Number of bins between consecutive powers of two in adaptive binning, as log2.
+const SUB_BINS_LOG2: u32 = 3;
+
+/// How read positions are grouped into bins for per-position statistics.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Binning {
+    /// Every position is its own bin.
+    Exact,
+    /// Every position is its own bin, but the positions of reads longer than 75 bp are
+    /// reported in windows like in FastQC.
+    #[default]
+    Grouped,
+    /// Bins grow exponentially with the position, so that the number of bins only
+    /// grows logarithmically with the read length. Used for long reads.
+    Adaptive,
+}
+
+impl Binning {
+    /// Returns the bin of the given position.
+    pub(crate) fn bin(self, pos: usize) -> usize {
+        match self {
+            Binning::Exact | Binning::Grouped => pos,
+            Binning::Adaptive if pos < EXACT_POSITIONS => pos,
+            Binning::Adaptive => {
+                let exponent = usize::BITS - 1 - pos.leading_zeros();
+                let offset = (exponent - EXACT_POSITIONS.trailing_zeros()) as usize;
+                let sub_bin = (pos - (1 << exponent)) >> (exponent - SUB_BINS_LOG2);
+                EXACT_POSITIONS + (offset << SUB_BINS_LOG2) + sub_bin
+            }
+        }
+    }
+
+    /// Returns the first position of the given bin.
+    pub(crate) fn start(self, bin: usize) -> usize {
+        match self {
+            Binning::Exact | Binning::Grouped => bin,
+            Binning::Adaptive if bin < EXACT_POSITIONS => bin,
+            Binning::Adaptive => {
+                let bin = bin - EXACT_POSITIONS;
+                let exponent = (bin >> SUB_BINS_LOG2) as u32 + EXACT_POSITIONS.trailing_zeros();
+                let sub_bin = bin & ((1 << SUB_BINS_LOG2) - 1);
+                (1 << exponent) + (sub_bin << (exponent - SUB_BINS_LOG2))
+            }
+        }
+    }
+
+    /// Returns the number of bins needed for a read of the given length.
+    pub(crate) fn bins(self, length: usize) -> usize {
+        match length {
+            0 => 0,
+            length => self.bin(length - 1) + 1,
+        }
+    }
+
+    /// Returns the ranges of bins reported together for reads up to the given length.
+    /// Like in FastQC, grouped reads have the first 9 positions reported individually
+    /// and the following ones in windows of 2, 5 or 10 times a power of ten positions.
+    pub(crate) fn groups(self, length: usize) -> Vec<Range<usize>> {
+        if self != Binning::Grouped || length <= MAX_UNGROUPED_LENGTH {
+            return (0..self.bins(length)).map(|bin| bin..bin + 1).collect();
+        }
+        let interval = (0..)
+            .flat_map(|exponent| [2, 5, 10].map(|base| base * 10_usize.pow(exponent)))
+            .find(|&interval| {
+                UNGROUPED_POSITIONS + (length - UNGROUPED_POSITIONS).div_ceil(interval) < MAX_GROUPS
+            })
+            .unwrap();
+        let mut groups = (0..UNGROUPED_POSITIONS)
+            .map(|pos| pos..pos + 1)
+            .collect::<Vec<_>>();
+        // Windows after the first start at multiples of the interval in 1-based positions
+        let mut start = UNGROUPED_POSITIONS;
+        while start < length {
+            let end = if start == UNGROUPED_POSITIONS && interval > UNGROUPED_POSITIONS + 1 {
+                interval - 1
+            } else {
+                start + interval
+            };
+            groups.push(start..end.min(length));
+            start = end;
+        }
+        groups
+    }
+
+    /// Returns the ranges of bins reported together for counts of the given number of
+    /// bins, like [`Binning::groups`] for the longest read covering all of them.
+    pub(crate) fn groups_of(self, bins: usize) -> Vec<Range<usize>> {
+        self.groups(self.start(bins))
+    }
+
+    /// Returns the first and last position of a range of bins.
+    pub(crate) fn positions(self, bins: &Range<usize>) -> (usize, usize) {
+        (self.start(bins.start), self.start(bins.end) - 1)
+    }
+
+    /// Returns the 1-based positions of a range of bins like in the reports of FastQC,
+    /// e.g. `10-14`.
+    pub(crate) fn label(self, bins: &Range<usize>) -> String {
+        match self.positions(bins) {
+            (pos, end) if pos == end => (pos + 1).to_string(),
+            (pos, end) => format!("{}-{}", pos + 1, end + 1),
+        }
+    }
+}
+
+/// Sums the counts of the bins in each group, leaving out groups without bins.
+pub(crate) fn group_counts<const N: usize>(
+    counts: &[[usize; N]],
+    groups: &[Range<usize>],
+) -> Vec<(Range<usize>, [usize; N])> {
+    groups
+        .iter()
+        .filter(|group| group.start < counts.len())
+        .map(|group| {
+            let mut sum = [0; N];
+            for counts in &counts[group.start..group.end.min(counts.len())] {
+                for (sum, count) in sum.iter_mut().zip(counts) {
+                    *sum += count;
+                }
+            }
+            (group.clone(), sum)
+        })
+        .collect()
+}
+
+/// Averages the values of the bins in each group, leaving out groups without bins.
+pub(crate) fn group_means(values: &[f64], groups: &[Range<usize>]) -> Vec<(Range<usize>, f64)> {
+    groups
+        .iter()
+        .filter(|group| group.start < values.len())
+        .map(|group| {
+            let values = &values[group.start..group.end.min(values.len())];
+            (
+                group.clone(),
+                values.iter().sum::<f64>() / values.len() as f64,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{group_counts, group_means, Binning};
+    #[test]
+    fn test_adaptive() {
+        let binning = Binning::Adaptive;
+        assert_eq!(binning.bin(15), 15);
+        assert_eq!(binning.bin(16), 16);
+        assert_eq!(binning.bin(17), 16);
+        assert_eq!(binning.bin(18), 17);
+        assert_eq!(binning.bin(32), 24);
+        assert_eq!(binning.start(17), 18);
+        assert_eq!(binning.start(24), 32);
+        for pos in 0..100_000 {
+            let bin = binning.bin(pos);
+            assert!(binning.start(bin) <= pos && pos < binning.start(bin + 1));
+        }
+        assert!(binning.bins(1_000_000) < 150);
+    }
+    #[test]
+    fn test_exact() {
+        assert_eq!(Binning::Exact.bin(12345), 12345);
+        assert_eq!(Binning::Exact.start(12345), 12345);
+        assert_eq!(Binning::Exact.bins(100), 100);
+        assert_eq!(Binning::Exact.groups(150).len(), 150);
+    }
+    #[test]
+    fn test_groups() {
+        let binning = Binning::Grouped;
+        assert_eq!(binning.groups(75), Binning::Exact.groups(75));
+        let groups = binning.groups(101);
+        assert_eq!(groups.len(), 55);
+        assert_eq!(groups[8], 8..9);
+        assert_eq!(groups[9], 9..11);
+        assert_eq!(groups[54], 99..101);
+        let groups = binning.groups(300);
+        assert_eq!(groups.len(), 68);
+        assert_eq!(groups[..2], [0..1, 1..2]);
+        assert_eq!(groups[9..11], [9..14, 14..19]);
+        assert_eq!(groups.last(), Some(&(299..300)));
+        let groups = binning.groups(1000);
+        assert_eq!(groups[9..11], [9..19, 19..39]);
+        let groups = binning.groups(5000);
+        assert_eq!(groups[9..11], [9..99, 99..199]);
+        assert_eq!(binning.positions(&groups[9]), (9, 98));
+        assert_eq!(binning.label(&groups[9]), "10-99");
+        assert_eq!(binning.label(&groups[0]), "1");
+        assert_eq!(binning.groups_of(5000), groups);
+        assert_eq!(
+            Binning::Adaptive.groups_of(17),
+            Binning::Adaptive.groups(18)
+        );
+    }
+    #[test]
+    fn test_group_counts() {
+        let counts = [[1, 0], [2, 1], [3, 2]];
+        assert_eq!(
+            group_counts(&counts, &[0..1, 1..3, 3..5]),
+            [(0..1, [1, 0]), (1..3, [5, 3])]
+        );
+        assert_eq!(
+            group_means(&[1.0, 2.0, 4.0], &[0..2, 2..4]),
+            [(0..2, 1.5), (2..4, 4.0)]
+        );
+    }
+}
@@ -0,0 +1,65 @@
+/// Returns whether a read is flagged as filtered in a Casava 1.8+ header like
+/// `@name 1:Y:0:ATCACG`, or `None` for other headers.
+pub(crate) fn is_filtered(id: &[u8]) -> Option<bool> {
+    let start = id.iter().position(|byte| byte.is_ascii_whitespace())?;
+    let mut fields = id[start + 1..].split(|&byte| byte == b':');
+    let read = fields.next()?;
+    if read.is_empty() || !read.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    match fields.next()? {
+        b"Y" => Some(true),
+        b"N" => Some(false),
+        _ => None,
+    }
+}
+
+/// Returns the index sequence of a read, from a Casava 1.8+ header like
+/// `@name 1:N:0:ATCACG+GTCAAG`, or an older header like `@name#ATCACG/1`.
+pub(crate) fn index(id: &[u8]) -> Option<&[u8]> {
+    let index = match id.iter().position(|byte| byte.is_ascii_whitespace()) {
+        Some(start) => id[start + 1..].split(|&byte| byte == b':').nth(3)?,
+        None => {
+            let start = id.iter().rposition(|&byte| byte == b'#')? + 1;
+            id[start..].split(|&byte| byte == b'/').next()?
+        }
+    };
+    let index = index.split(|byte| byte.is_ascii_whitespace()).next()?;
+    let bases = |part: &[u8]| !part.is_empty() && part.iter().all(|base| b"ACGTN".contains(base));
+    index
+        .split(|&byte| byte == b'+')
+        .all(bases)
+        .then_some(index)
+}
+
+#[cfg(test)]
+mod test {
+    use super::is_filtered;
+    #[test]
+    fn test_is_filtered() {
+        assert_eq!(is_filtered(b"M1:7:FC:1:1101:1:2 1:Y:0:ATCACG"), Some(true));
+        assert_eq!(is_filtered(b"M1:7:FC:1:1101:1:2 2:N:0:1"), Some(false));
+        assert_eq!(is_filtered(b"M1:7:FC:1:1101:1:2"), None);
+        assert_eq!(is_filtered(b"read1/1"), None);
+        assert_eq!(is_filtered(b"read1 length=100"), None);
+    }
+    #[test]
+    fn test_index() {
+        let index = |id: &[u8]| super::index(id).map(|index| index.to_vec());
+        assert_eq!(
+            index(b"M1:7:FC:1:1101:1:2 1:N:0:ATCACG"),
+            Some(b"ATCACG".to_vec())
+        );
+        assert_eq!(
+            index(b"M1:7:FC:1:1101:1:2 2:N:0:ATCACG+GTCAAN"),
+            Some(b"ATCACG+GTCAAN".to_vec())
+        );
+        assert_eq!(
+            index(b"HWUSI-EAS100R:6:73:941:1973#ATCACG/1"),
+            Some(b"ATCACG".to_vec())
+        );
+        assert_eq!(index(b"M1:7:FC:1:1101:1:2 1:N:0:1"), None);
+        assert_eq!(index(b"M1:7:FC:1:1101:1:2 1:N:0:ATCACG+"), None);
+        assert_eq!(index(b"read1"), None);
+    }
+}
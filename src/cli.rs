@@ -0,0 +1,1677 @@
+use crate::error::FastqcError;
+use crate::modules::Module;
+use crate::trim_simulation::{parse_cutoffs, TrimCutoffs};
+use clap::builder::{PossibleValuesParser, TypedValueParser};
+use clap::parser::ValueSource;
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use env_logger::Builder;
+use itertools::Itertools;
+use log::LevelFilter;
+use std::ffi::OsString;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Logs warnings and errors to standard error, or with the given number of `-v` also
+/// what was read and written and the time it took, and with two or more the time per
+/// module, or only errors if `quiet`. `RUST_LOG` takes precedence, e.g. `RUST_LOG=trace`.
+pub fn init_log(verbosity: u8, quiet: bool) {
+    let level = match verbosity {
+        _ if quiet => LevelFilter::Error,
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        _ => LevelFilter::Debug,
+    };
+    // The logger may already be set up by a program embedding the command line
+    let _ = Builder::new()
+        .filter_level(level)
+        .parse_default_env()
+        .try_init();
+}
+
+/// Names of the subcommands.
+const SUBCOMMANDS: [&str; 9] = [
+    "report",
+    "aggregate",
+    "cohort",
+    "validate",
+    "compare",
+    "screen",
+    "index",
+    "completions",
+    "man",
+];
+
+/// Parses the command line arguments and runs the given subcommand.
+pub fn run() -> Result<(), FastqcError> {
+    let args = with_subcommand(std::env::args_os().collect());
+    let mut matches = command().get_matches_from(&args);
+    let config = matches.get_one::<PathBuf>("config").map(PathBuf::as_path);
+    if let Some(path) = crate::settings::find(config) {
+        let text = read_to_string(&path)?;
+        let settings = crate::settings::parse(&text)
+            .and_then(|settings| with_settings(args, &settings, &matches))
+            .map_err(|message| FastqcError::parse(message).with_path(&path))?;
+        matches = command().get_matches_from(settings);
+    }
+    init_log(matches.get_count("verbose"), matches.get_flag("quiet"));
+    match matches.subcommand() {
+        Some(("report", matches)) => report(matches, false),
+        Some(("aggregate", matches)) => report(matches, true),
+        Some(("cohort", matches)) => cohort(matches),
+        Some(("validate", matches)) => validate(matches),
+        Some(("compare", matches)) => compare(matches),
+        Some(("screen", matches)) => screen(matches),
+        Some(("index", matches)) => index(matches),
+        Some(("completions", matches)) => completions(matches),
+        Some(("man", matches)) => man(matches),
+        _ => unreachable!("a subcommand is required"),
+    }
+}
+
+/// Reads a file given in the arguments, naming it if it cannot be read.
+fn read_to_string(path: &Path) -> Result<String, FastqcError> {
+    fs::read_to_string(path).map_err(|e| FastqcError::from(e).with_path(path))
+}
+
+/// Inserts the `report` subcommand into the arguments unless the first argument
+/// besides global options names a subcommand or asks for help, so that reports are
+/// still written like `fqc -q reads.fastq`, even if e.g. an output is named `report`.
+fn with_subcommand(mut args: Vec<OsString>) -> Vec<OsString> {
+    let command = command();
+    let globals = command
+        .get_arguments()
+        .filter(|arg| arg.is_global_set())
+        .collect::<Vec<_>>();
+    let mut rest = args.iter().skip(1);
+    let named = loop {
+        let Some(arg) = rest.next().and_then(|arg| arg.to_str()) else {
+            break false;
+        };
+        // Global options and the next argument if it is their value
+        let value = if let Some(long) = arg.strip_prefix("--") {
+            let (name, value) = long.split_once('=').unwrap_or((long, ""));
+            globals
+                .iter()
+                .find(|global| global.get_long() == Some(name))
+                .map(|global| global.get_action().takes_values() && value.is_empty())
+        } else if let Some(short) = arg.strip_prefix('-').filter(|short| !short.is_empty()) {
+            let flag = short.chars().next();
+            globals
+                .iter()
+                .find(|global| global.get_short() == flag)
+                .filter(|global| {
+                    // Values may follow the flag like -t2, and flags may be repeated like -vv
+                    global.get_action().takes_values() || short.chars().all(|c| Some(c) == flag)
+                })
+                .map(|global| global.get_action().takes_values() && short.len() == 1)
+        } else {
+            None
+        };
+        match value {
+            Some(true) => {
+                rest.next();
+            }
+            Some(false) => {}
+            None => {
+                break SUBCOMMANDS.contains(&arg)
+                    || ["help", "-h", "--help", "-V", "--version"].contains(&arg)
+            }
+        }
+    };
+    if !named {
+        args.insert(args.len().min(1), "report".into());
+    }
+    args
+}
+
+/// Appends the settings of a configuration file to the arguments as options, except
+/// for options already given in the arguments. Every setting is named by the long
+/// form of its option and applies to all subcommands having it unless it is in the
+/// section of a subcommand, which takes precedence. `modules` lists the enabled modules
+/// and disables all others.
+fn with_settings(
+    mut args: Vec<OsString>,
+    settings: &[crate::settings::Setting],
+    matches: &ArgMatches,
+) -> Result<Vec<OsString>, String> {
+    use crate::settings::Value;
+    let mut command = command();
+    command.build();
+    let (name, submatches) = matches.subcommand().unwrap();
+    let subcommand = command.find_subcommand(name).unwrap();
+    let find = |command: &Command, key: &str| {
+        command
+            .get_arguments()
+            .find(|arg| arg.get_long() == Some(key) && key != "config")
+            .cloned()
+    };
+    let mut options: Vec<(String, Option<Arg>, &Value)> = Vec::new();
+    for setting in settings {
+        let unknown = || match &setting.subcommand {
+            Some(section) => format!("Unknown option {} in [{}].", setting.key, section),
+            None => format!("Unknown option {}.", setting.key),
+        };
+        let arg = match &setting.subcommand {
+            Some(section) => {
+                let command = command
+                    .find_subcommand(section)
+                    .ok_or_else(|| format!("Unknown subcommand [{}].", section))?;
+                if setting.key != "modules" && find(command, &setting.key).is_none() {
+                    return Err(unknown());
+                }
+                if section != name {
+                    continue;
+                }
+                find(subcommand, &setting.key)
+            }
+            None => {
+                let known = setting.key == "modules"
+                    || command
+                        .get_subcommands()
+                        .any(|command| find(command, &setting.key).is_some());
+                if !known {
+                    return Err(unknown());
+                }
+                find(subcommand, &setting.key)
+            }
+        };
+        if setting.key != "modules" && arg.is_none() {
+            continue;
+        }
+        // Settings of the section of the subcommand replace those of all subcommands
+        options.retain(|(key, _, _)| *key != setting.key || setting.subcommand.is_none());
+        options.push((setting.key.clone(), arg, &setting.value));
+    }
+    let given = |id: &str| submatches.value_source(id) == Some(ValueSource::CommandLine);
+    let mut extra: Vec<OsString> = Vec::new();
+    for (key, arg, value) in options {
+        let Some(arg) = arg else {
+            // The enabled modules are given as all other modules disabled
+            let Value::Values(enabled) = value else {
+                return Err("modules expects a list of modules.".to_string());
+            };
+            if let Some(module) = enabled.iter().find(|key| Module::from_key(key).is_none()) {
+                return Err(format!("Unknown module {} in modules.", module));
+            }
+            if find(subcommand, "disable").is_some() && !given("disable") {
+                for module in Module::ALL.map(Module::key) {
+                    if !enabled.iter().any(|key| key == module) {
+                        extra.push(format!("--disable={}", module).into());
+                    }
+                }
+            }
+            continue;
+        };
+        if given(arg.get_id().as_str()) {
+            continue;
+        }
+        match (value, arg.get_action().takes_values()) {
+            (Value::Flag(true), false) => extra.push(format!("--{}", key).into()),
+            (Value::Flag(false), false) => {}
+            (Value::Values(values), true) => extra.extend(
+                values
+                    .iter()
+                    .map(|value| format!("--{}={}", key, value).into()),
+            ),
+            (_, false) => return Err(format!("{} expects true or false.", key)),
+            (_, true) => return Err(format!("{} expects a value.", key)),
+        }
+    }
+    // Options are added before the end of the options of the arguments, if marked
+    let end = args
+        .iter()
+        .position(|arg| arg == "--")
+        .unwrap_or(args.len());
+    args.splice(end..end, extra);
+    Ok(args)
+}
+
+/// Returns the command with its subcommands and the options shared by all of them.
+fn command() -> Command {
+    Command::new("fastqc-rs")
+        .about("A FASTQ quality control tool inspired by fastQC")
+        .version("0.3.3")
+        .author("Felix W. <fxwiegand@wgdnet.de>")
+        .subcommand_required(true)
+        .arg(
+            Arg::new("threads")
+                .short('t')
+                .long("threads")
+                .value_name("N")
+                .global(true)
+                .help("The number of threads processing the records of each input file, also decompressing BGZF input. Several input files are processed at the same time on an equal share of the threads.")
+                .default_value("1")
+                .value_parser(clap::value_parser!(u16).range(1..)),
+        )
+        .arg(
+            Arg::new("outdir")
+                .long("outdir")
+                .value_name("DIR")
+                .global(true)
+                .help("Writes the outputs into the given directory instead of printing them, e.g. one report per input file named after the input.")
+                .value_parser(clap::value_parser!(PathBuf)),
+        )
+        .arg(
+            Arg::new("config")
+                .long("config")
+                .value_name("FILE")
+                .global(true)
+                .help("A configuration file with defaults of the options, used instead of fastqc-rs.toml in the working directory or in fastqc-rs/ of the XDG configuration directory like ~/.config.")
+                .value_parser(clap::value_parser!(PathBuf)),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .value_name("FORMAT")
+                .help("The format of the reports, either an HTML report, all computed data as JSON or a Markdown report with summary tables. Defaults to HTML, or a TSV table for screen.")
+                .global(true)
+                .value_parser(PossibleValuesParser::new(["html", "json", "markdown"]).map(|s| {
+                    match s.as_str() {
+                        "json" => crate::process::Format::Json,
+                        "markdown" => crate::process::Format::Markdown,
+                        _ => crate::process::Format::Html,
+                    }
+                })),
+        )
+        .arg(
+            Arg::new("verbose")
+                .short('v')
+                .long("verbose")
+                .global(true)
+                .action(ArgAction::Count)
+                .help("Logs the records read from every input file, the statuses of the modules and the written reports with the time taken to standard error. Given twice, also logs the time per module."),
+        )
+        .arg(
+            Arg::new("quiet")
+                .long("quiet")
+                .global(true)
+                .action(ArgAction::SetTrue)
+                .conflicts_with("verbose")
+                .help("Hides the progress of reading the input files, which is shown on standard error if it is a terminal, and only logs errors instead of warnings about e.g. invalid records."),
+        )
+        .subcommand(
+            Command::new("report")
+                .about("Writes a report of the quality of the input files, one per file or pair of files. Used if no subcommand is given.")
+                .args(report_args())
+                .arg(input_arg())
+                .args(analysis_args())
+                .args(output_args())
+                .args(html_args()),
+        )
+        .subcommand(
+            Command::new("aggregate")
+                .about("Creates a single report comparing all input files, additionally reporting each file into --outdir if given.")
+                .arg(input_arg())
+                .args(analysis_args())
+                .args(output_args())
+                .args(html_args()),
+        )
+        .subcommand(
+            Command::new("cohort")
+                .about("Builds a matrix of the metrics and module statuses of many samples from their previous JSON reports or fastqc_data.txt files, written as TSV table with a row per sample, and an overview in HTML.")
+                .arg(
+                    Arg::new("input")
+                        .value_name("FILE")
+                        .help("The JSON reports written with --format json and fastqc_data.txt files of fastqc-rs or FastQC, or glob patterns like 'qc/*/fastqc_data.txt'.")
+                        .required(true)
+                        .num_args(1..)
+                        .value_parser(clap::value_parser!(String)),
+                )
+                .arg(
+                    Arg::new("output")
+                        .short('o')
+                        .long("output")
+                        .value_name("FILE")
+                        .help("Writes the matrix to the given file instead of printing it.")
+                        .conflicts_with("outdir")
+                        .value_parser(clap::value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("html")
+                        .long("html")
+                        .value_name("FILE")
+                        .help("Writes the HTML overview of the samples to the given file.")
+                        .conflicts_with("outdir")
+                        .value_parser(clap::value_parser!(PathBuf)),
+                )
+                .args(html_args()),
+        )
+        .subcommand(
+            Command::new("validate")
+                .about("Checks FASTQ files strictly record by record, printing the byte offset and record number of every violation and their number by kind. Exits with status 3 if a file is invalid.")
+                .arg(
+                    Arg::new("input")
+                        .value_name("FILE")
+                        .help("The FASTQ files, directories or glob patterns to check, or `-` for standard input.")
+                        .required(true)
+                        .num_args(1..)
+                        .value_parser(clap::value_parser!(String)),
+                ),
+        )
+        .subcommand(
+            Command::new("index")
+                .about("Writes an index of the k-mers of a reference genome for --screen.")
+                .arg(
+                    Arg::new("fasta")
+                        .value_name("FASTA")
+                        .help("The FASTA files of the genome, which may be compressed.")
+                        .required(true)
+                        .num_args(1..)
+                        .value_parser(clap::value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("output")
+                        .long("output")
+                        .short('o')
+                        .value_name("FILE")
+                        .help("The path of the index.")
+                        .required(true)
+                        .value_parser(clap::value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("name")
+                        .long("name")
+                        .value_name("NAME")
+                        .help("The name of the genome in the reports. Defaults to the name of the first FASTA file.")
+                        .value_parser(clap::value_parser!(String)),
+                )
+                .arg(
+                    Arg::new("scale")
+                        .long("scale")
+                        .value_name("SCALE")
+                        .help("Keeps one in about this many k-mers to keep indices of large genomes small, e.g. 100 for a human genome.")
+                        .default_value("1")
+                        .value_parser(clap::value_parser!(u64).range(1..)),
+                ),
+        )
+        .subcommand(
+            Command::new("compare")
+                .about("Compares two samples, e.g. before and after trimming, in a report of the changes of their metrics and module statuses with regressions highlighted and their plots overlaid.")
+                .arg(
+                    Arg::new("before")
+                        .value_name("BEFORE")
+                        .help("The input file or JSON report of a single file written with --format json to compare against.")
+                        .required(true)
+                        .value_parser(clap::value_parser!(String)),
+                )
+                .arg(
+                    Arg::new("after")
+                        .value_name("AFTER")
+                        .help("The input file or JSON report of a single file compared to BEFORE.")
+                        .required(true)
+                        .value_parser(clap::value_parser!(String)),
+                )
+                .arg(
+                    Arg::new("output")
+                        .short('o')
+                        .long("output")
+                        .value_name("FILE")
+                        .help("Writes the comparison to the given file instead of printing it.")
+                        .conflicts_with("outdir")
+                        .value_parser(clap::value_parser!(PathBuf)),
+                )
+                .arg(tolerance_arg())
+                .args(analysis_args())
+                .args(html_args()),
+        )
+        .subcommand(
+            Command::new("screen")
+                .about("Prints the percentage of the reads of the input files matching each of the given reference genomes and no other, like FastQ Screen.")
+                .arg(input_arg())
+                .arg(
+                    Arg::new("index")
+                        .short('x')
+                        .long("index")
+                        .value_name("INDEX")
+                        .help("An index of a reference genome written by fqc index. Can be given multiple times.")
+                        .required(true)
+                        .action(ArgAction::Append)
+                        .value_parser(clap::value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("output")
+                        .short('o')
+                        .long("output")
+                        .value_name("FILE")
+                        .help("Writes the table to the given file instead of printing it.")
+                        .conflicts_with("outdir")
+                        .value_parser(clap::value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("max-reads")
+                        .long("max-reads")
+                        .value_name("N")
+                        .help("Only screens the first N records of each input file.")
+                        .value_parser(clap::value_parser!(u64)),
+                ),
+        )
+        .subcommand(
+            Command::new("completions")
+                .about("Prints the tab completions of fqc for the given shell, e.g. for bash with `source <(fqc completions bash)` in ~/.bashrc.")
+                .arg(
+                    Arg::new("shell")
+                        .value_name("SHELL")
+                        .help("The shell to complete fqc in.")
+                        .required(true)
+                        .value_parser(clap::value_parser!(clap_complete::Shell)),
+                ),
+        )
+        .subcommand(
+            Command::new("man")
+                .about("Prints the man page of fqc in roff format, or writes the man pages of fqc and of every subcommand into --outdir, e.g. to be read with `man ./fqc.1`."),
+        )
+}
+
+/// Returns the input argument of the subcommands analyzing reads.
+fn input_arg() -> Arg {
+    Arg::new("input")
+        .value_name("FILE")
+        .help("The input FASTQ or SAM/BAM/CRAM files, directories or glob patterns like 'fastq_pass/*.fastq.gz'. Reads from standard input if omitted or `-`.")
+        .num_args(1..)
+        .value_parser(clap::value_parser!(String))
+}
+
+/// Returns the arguments of the `report` subcommand choosing how the input files are
+/// reported.
+fn report_args() -> Vec<Arg> {
+    vec![
+        Arg::new("fastq")
+            .short('q')
+            .long("fastq")
+            .value_name("FILE")
+            .help("The input FASTQ or SAM/BAM/CRAM file to use. Reads from standard input if omitted or `-`.")
+            .required(false)
+            .conflicts_with("input")
+            .value_parser(clap::value_parser!(String)),
+        Arg::new("read2")
+            .long("read2")
+            .value_name("FILE")
+            .required(false)
+            .help("The read 2 file of paired-end reads given as single input file, reported together with read 1.")
+            .conflicts_with("paired")
+            .value_parser(clap::value_parser!(String)),
+        Arg::new("paired")
+            .long("paired")
+            .action(ArgAction::SetTrue)
+            .help("Pairs up the input files by _R1 and _R2 in their names and creates one report per pair."),
+        Arg::new("interleaved")
+            .long("interleaved")
+            .action(ArgAction::SetTrue)
+            .help("Treats the input files as interleaved paired-end reads and reports read 1 and read 2 separately.")
+            .conflicts_with_all(["read2", "paired", "sample"]),
+        Arg::new("merge")
+            .long("merge")
+            .action(ArgAction::SetTrue)
+            .help("Combines all input files into a single report.")
+            .conflicts_with_all(["read2", "paired", "interleaved"]),
+        Arg::new("watch")
+            .long("watch")
+            .value_name("DIR")
+            .help("Watches the given directory of a running sequencer, e.g. fastq_pass/, adding new FASTQ files to a single report rewritten after every scan until the run finished. Requires --output or --outdir.")
+            .conflicts_with_all(["fastq", "input", "read2", "paired", "interleaved", "merge"])
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("watch-interval")
+            .long("watch-interval")
+            .value_name("SECONDS")
+            .help("The time between two scans of the directory given with --watch.")
+            .default_value("60")
+            .requires("watch")
+            .value_parser(clap::value_parser!(u64).range(1..)),
+        Arg::new("watch-idle")
+            .long("watch-idle")
+            .value_name("SECONDS")
+            .help("Stops watching if no new files appeared for the given time, instead of waiting for the final summary of MinKNOW.")
+            .requires("watch")
+            .value_parser(clap::value_parser!(u64).range(1..)),
+    ]
+}
+
+/// Returns the arguments setting up the analyses of the input files.
+fn analysis_args() -> Vec<Arg> {
+    vec![
+        Arg::new("k")
+            .short('k')
+            .long("kmer")
+            .value_name("K")
+            .help("The length k of k-mers for k-mer counting.")
+            .default_value("5")
+            .value_parser(clap::value_parser!(u8).range(2..=10)),
+        Arg::new("duplication-memory")
+            .long("duplication-memory")
+            .value_name("MIB")
+            .help("The memory in MiB for counting duplicate sequences exactly, shared by all threads, above which the duplication levels are estimated with sketches.")
+            .default_value("1024")
+            .value_parser(clap::value_parser!(u64).range(1..)),
+        Arg::new("fastqc-duplication")
+            .long("fastqc-duplication")
+            .action(ArgAction::SetTrue)
+            .help("Estimates the duplication levels from the first 100,000 distinct sequences like FastQC, so that they are comparable with those of FastQC, instead of counting all sequences."),
+        Arg::new("read-id-memory")
+            .long("read-id-memory")
+            .value_name("MIB")
+            .help("The memory in MiB per thread for detecting duplicate read IDs exactly, above which their number is estimated.")
+            .default_value("256")
+            .value_parser(clap::value_parser!(u64).range(1..)),
+        Arg::new("genome-size")
+            .long("genome-size")
+            .value_name("BASES")
+            .help("Reports the mean coverage of a genome of the given size, e.g. 3.1g or 4.6m, also corrected for duplicates and adapters.")
+            .value_parser(crate::coverage::parse_bases),
+        Arg::new("casava")
+            .long("casava")
+            .action(ArgAction::SetTrue)
+            .help("Leaves reads flagged as filtered in their Casava 1.8+ header, e.g. `1:Y:0:ATCACG`, out of the statistics like FastQC. Filtered reads are counted either way."),
+        Arg::new("nofilter")
+            .long("nofilter")
+            .action(ArgAction::SetTrue)
+            .requires("casava")
+            .help("Keeps the reads flagged as filtered in the statistics with --casava, like in FastQC."),
+        Arg::new("umi-len")
+            .long("umi-len")
+            .value_name("BASES")
+            .help("Takes the UMIs from the given number of bases at the start of the reads instead of the end of the read names.")
+            .value_parser(clap::value_parser!(u16).range(1..)),
+        Arg::new("live-json")
+            .long("live-json")
+            .value_name("N")
+            .requires("live-json-out")
+            .help("Writes a JSON report of the records read so far as a line to --live-json-out after every N records of an input file, instead of showing the progress. The records are then processed on one thread.")
+            .value_parser(clap::value_parser!(u64).range(1..)),
+        Arg::new("live-json-out")
+            .long("live-json-out")
+            .value_name("FILE")
+            .requires("live-json")
+            .help("The file the reports of --live-json are written to, e.g. a named pipe read by a dashboard, keeping them apart from the log on standard error.")
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("max-reads")
+            .long("max-reads")
+            .value_name("N")
+            .help("Only uses the first N records of each input file, e.g. for a quick look at new data.")
+            .value_parser(clap::value_parser!(u64)),
+        Arg::new("sample")
+            .long("sample")
+            .value_name("N")
+            .help("Estimates all metrics from N reads sampled uniformly at random from each input file.")
+            .value_parser(clap::value_parser!(u64).range(1..)),
+        Arg::new("seed")
+            .long("seed")
+            .value_name("SEED")
+            .help("The seed of the random sample of --sample. Runs with the same seed sample the same reads of a file.")
+            .default_value("0")
+            .value_parser(clap::value_parser!(u64)),
+        Arg::new("adapter")
+            .short('a')
+            .long("adapter")
+            .value_name("SEQUENCE")
+            .required(false)
+            .action(ArgAction::Append)
+            .help("An additional adapter sequence to search for. Can be given multiple times.")
+            .value_parser(clap::value_parser!(String)),
+        Arg::new("adapters")
+            .long("adapters")
+            .value_name("FILE")
+            .help("A FASTA file or adapter list in the format of FastQC with the adapters to search for instead of the bundled adapters.")
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("contaminants")
+            .long("contaminants")
+            .value_name("FILE")
+            .help("A contaminant list in the format of FastQC to annotate overrepresented sequences with instead of the bundled list.")
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("phix")
+            .long("phix")
+            .value_name("FASTA")
+            .help("The PhiX genome as FASTA to estimate the percentage of reads from a PhiX spike-in by their shared k-mers.")
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("screen")
+            .long("screen")
+            .value_name("INDEX")
+            .help("Indices of reference genomes written by fqc index to report the percentage of reads matching each of them, like FastQ Screen.")
+            .num_args(1..)
+            .action(ArgAction::Append)
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("spectrum")
+            .long("spectrum")
+            .action(ArgAction::SetTrue)
+            .help("Counts the 21-mers of the reads for their spectrum and estimates the genome size, heterozygosity and error rate from it like GenomeScope, e.g. before a de novo assembly. Needs memory for all distinct 21-mers."),
+        Arg::new("spectrum-scale")
+            .long("spectrum-scale")
+            .value_name("N")
+            .requires("spectrum")
+            .help("Only counts about one in N of the 21-mers for --spectrum, chosen by their hash, so that large genomes fit into memory.")
+            .default_value("1")
+            .value_parser(clap::value_parser!(u64).range(1..)),
+        Arg::new("optical-duplicates")
+            .long("optical-duplicates")
+            .action(ArgAction::SetTrue)
+            .help("Estimates optical and ExAmp duplicates, i.e. reads starting like another read whose cluster lies close by on the same tile, from the positions in Illumina read names, and reports them apart from the other duplicates, e.g. from PCR. Keeps up to 1024 positions of each of up to 2 million distinct sequences and 4 million positions in total, and of a sample of the sequences beyond."),
+        Arg::new("optical-distance")
+            .long("optical-distance")
+            .value_name("PIXELS")
+            .requires("optical-duplicates")
+            .help("The largest distance in pixels between the clusters of optical duplicates, e.g. 2500 for patterned flowcells like the NovaSeq.")
+            .default_value("100")
+            .value_parser(clap::value_parser!(u32)),
+        Arg::new("simulate-trim")
+            .long("simulate-trim")
+            .value_name("CUTOFFS")
+            .help("Simulates BWA-style and sliding-window quality trimming with each of the comma-separated phred cutoffs, e.g. Q20,Q30, and reports the reads and bases kept of the reads at least as long as --survival-min-length, and the lengths of the trimmed reads.")
+            .value_parser(parse_cutoffs),
+        Arg::new("quality-heatmap")
+            .long("quality-heatmap")
+            .action(ArgAction::SetTrue)
+            .help("Also plots the number of bases of every quality score per position as a heatmap, which shows populations of reads of different quality that the box plots hide."),
+        Arg::new("split-by-lane")
+            .long("split-by-lane")
+            .action(ArgAction::SetTrue)
+            .help("Also reports the yield, Q30 bases and mean quality per position of every flowcell lane in the Illumina read names, e.g. of files merged from several lanes."),
+        Arg::new("platform-modules")
+            .long("platform-modules")
+            .action(ArgAction::SetTrue)
+            .help("Only reports the poly-G tails for two-color sequencers like the NovaSeq and the per tile qualities for patterned flowcells, if the platform is detected from the read names or qualities."),
+        Arg::new("survival-min-length")
+            .long("survival-min-length")
+            .value_name("LENGTH")
+            .help("The shortest read length passing the filters of which the passing reads and usable bases are reported.")
+            .default_value("36")
+            .value_parser(clap::value_parser!(u32)),
+        Arg::new("survival-min-quality")
+            .long("survival-min-quality")
+            .value_name("Q")
+            .help("The lowest mean quality of a read passing the filters.")
+            .default_value("20")
+            .value_parser(clap::value_parser!(f64)),
+        Arg::new("survival-max-n")
+            .long("survival-max-n")
+            .value_name("N")
+            .help("The highest number of N bases of a read passing the filters.")
+            .default_value("5")
+            .value_parser(clap::value_parser!(u32)),
+        Arg::new("survival-max-ee")
+            .long("survival-max-ee")
+            .value_name("EE")
+            .help("The highest expected number of errors of a read passing the filters, i.e. the sum of the error probabilities of its bases like maxEE of DADA2.")
+            .default_value("2")
+            .value_parser(clap::value_parser!(f64)),
+        Arg::new("disable")
+            .long("disable")
+            .value_name("MODULE")
+            .action(ArgAction::Append)
+            .help("Leaves the given module out of all reports, skipping its counting. Can be given multiple times.")
+            .value_parser(
+                PossibleValuesParser::new(Module::ALL.map(Module::key))
+                    .map(|s| Module::from_key(&s).unwrap()),
+            ),
+        Arg::new("limits")
+            .long("limits")
+            .value_name("FILE")
+            .help("A FastQC limits file with the thresholds at which modules warn or fail and the modules to ignore.")
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("phred-offset")
+            .long("phred-offset")
+            .value_name("OFFSET")
+            .required(false)
+            .help("Forces the phred offset of quality scores instead of detecting it.")
+            .value_parser(
+                PossibleValuesParser::new(["33", "64"]).map(|s| s.parse::<u8>().unwrap()),
+            ),
+        Arg::new("fetch-assets")
+            .long("fetch-assets")
+            .action(ArgAction::SetTrue)
+            .help("Downloads JavaScript and CSS assets of the report that are not bundled with fastqc-rs."),
+        Arg::new("long-reads")
+            .long("long-reads")
+            .action(ArgAction::SetTrue)
+            .help("Bins read positions and uses log-scaled length axes for long reads, e.g. from ONT or PacBio."),
+        Arg::new("nogroup")
+            .long("nogroup")
+            .action(ArgAction::SetTrue)
+            .conflicts_with("long-reads")
+            .help("Reports every position of the reads individually instead of grouping them into windows for reads longer than 75 bp, like in FastQC."),
+        Arg::new("unmapped-only")
+            .long("unmapped-only")
+            .action(ArgAction::SetTrue)
+            .help("Only uses unmapped reads of SAM/BAM/CRAM input."),
+        Arg::new("primary-only")
+            .long("primary-only")
+            .action(ArgAction::SetTrue)
+            .help("Skips secondary and supplementary alignments of SAM/BAM/CRAM input."),
+    ]
+}
+
+/// Returns the arguments of the outputs of the reports and the QC gates.
+fn output_args() -> Vec<Arg> {
+    vec![
+        Arg::new("output")
+            .short('o')
+            .long("output")
+            .value_name("FILE")
+            .required(false)
+            .help("Writes the report to the given file instead of printing it.")
+            .conflicts_with("outdir")
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("summary")
+            .short('s')
+            .long("summary")
+            .value_name("FILE")
+            .required(false)
+            .help("Creates the fastqc_data.txt and summary.txt files of FastQC for usage with MultiQC under the given path.")
+            .value_parser(clap::value_parser!(String)),
+        Arg::new("data-dir")
+            .long("data-dir")
+            .value_name("DIR")
+            .required(false)
+            .help("Writes the data of every module as TSV files into the given directory, in a subdirectory per file for several input files.")
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("data-format")
+            .long("data-format")
+            .value_name("FORMAT")
+            .help("The format of the files written with --data-dir, either TSV or Parquet, which requires the parquet feature.")
+            .default_value("tsv")
+            .requires("data-dir")
+            .value_parser(PossibleValuesParser::new(["tsv", "parquet"]).map(|s| {
+                match s.as_str() {
+                    "parquet" => crate::process::DataFormat::Parquet,
+                    _ => crate::process::DataFormat::Tsv,
+                }
+            })),
+        Arg::new("plot-dir")
+            .long("plot-dir")
+            .value_name("DIR")
+            .required(false)
+            .help("Writes every plot as a standalone SVG file into the given directory, in a subdirectory per file for several input files.")
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("db")
+            .long("db")
+            .value_name("FILE")
+            .required(false)
+            .help("Appends the metrics and module statuses of every input file to the given SQLite database, requires the sqlite feature.")
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("metrics-out")
+            .long("metrics-out")
+            .value_name("FILE")
+            .required(false)
+            .help("Writes the key metrics and module statuses of every input file as OpenMetrics gauges labeled with the sample to the given file, e.g. metrics.prom in the directory of the textfile collector of the Prometheus node exporter.")
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("zip")
+            .long("zip")
+            .action(ArgAction::SetTrue)
+            .help("Writes a FastQC-style <name>_fastqc.zip archive with the report and summaries per input file next to the report."),
+        Arg::new("qc-status")
+            .long("qc-status")
+            .action(ArgAction::SetTrue)
+            .help("Writes <name>.qc_status.yaml next to every report with the version of fastqc-rs, the given options, the MD5 checksums of the input files and the module statuses, for provenance in pipelines."),
+        Arg::new("fail-on")
+            .long("fail-on")
+            .value_name("STATUS")
+            .help("Exits with code 3 after writing all reports if any module of an input file has the given status or a worse one.")
+            .value_parser(PossibleValuesParser::new(["warn", "fail"])),
+        Arg::new("baseline")
+            .long("baseline")
+            .value_name("FILE")
+            .help("A JSON report of a previous run written with --format json to compare every input file to, matched by file name unless the report has only one file. Exits with code 3 after writing all reports if a metric got worse by more than its tolerance or a module status got more severe.")
+            .value_parser(clap::value_parser!(String)),
+        tolerance_arg().requires("baseline"),
+        Arg::new("print-schema")
+            .long("print-schema")
+            .action(ArgAction::SetTrue)
+            .help("Prints the JSON Schema of the reports written with --format json, whose schema_version it gives, and exits."),
+    ]
+}
+
+/// Returns the arguments setting the appearance of the HTML reports.
+fn html_args() -> Vec<Arg> {
+    vec![
+        Arg::new("title")
+            .long("title")
+            .value_name("TITLE")
+            .help("The title of the reports, e.g. the name of the facility or a required header, instead of fastqc-rs report.")
+            .value_parser(clap::value_parser!(String)),
+        Arg::new("logo")
+            .long("logo")
+            .value_name("FILE")
+            .help("A PNG, JPEG, GIF or SVG image embedded as logo next to the title of HTML reports.")
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("theme")
+            .long("theme")
+            .value_name("THEME")
+            .help("The color theme of HTML reports.")
+            .default_value("light")
+            .value_parser(PossibleValuesParser::new(["light", "dark"]).map(|s| {
+                match s.as_str() {
+                    "dark" => crate::process::Theme::Dark,
+                    _ => crate::process::Theme::Light,
+                }
+            })),
+        Arg::new("template")
+            .long("template")
+            .value_name("FILE")
+            .help("A Tera template replacing the bundled template of the HTML report of single files.")
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("template-dir")
+            .long("template-dir")
+            .value_name("DIR")
+            .help("A directory of Tera templates replacing the bundled templates of the same name, e.g. aggregate.html.tera. Other *.html.tera templates not starting with _ are added as sections of the HTML report of single files.")
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("plot-config")
+            .long("plot-config")
+            .value_name("FILE")
+            .help("A JSON object with patches merged into the Vega-Lite specs of the plots by their short name, or * for all plots, e.g. {\"base\": {\"encoding\": {\"x\": {\"scale\": {\"domain\": [0, 41]}}}}}.")
+            .value_parser(clap::value_parser!(PathBuf)),
+        Arg::new("max-plot-points")
+            .long("max-plot-points")
+            .value_name("N")
+            .help("The number of data points of a plot above which its positions or lengths are thinned out evenly, keeping HTML reports of long reads small. 0 keeps all points.")
+            .default_value("10000")
+            .value_parser(clap::value_parser!(u64)),
+    ]
+}
+
+/// Applies the title, logo, theme, templates and plot configuration of the arguments to
+/// the reports.
+fn html_setup(
+    mut setup: crate::process::Setup,
+    matches: &ArgMatches,
+) -> Result<crate::process::Setup, FastqcError> {
+    if let Some(title) = matches.get_one::<String>("title") {
+        setup = setup.with_title(title);
+    }
+    if let Some(logo) = matches.get_one::<PathBuf>("logo") {
+        setup = setup.with_logo(logo)?;
+    }
+    if let Some(dir) = matches.get_one::<PathBuf>("template-dir") {
+        setup = setup.with_template_dir(dir)?;
+    }
+    if let Some(template) = matches.get_one::<PathBuf>("template") {
+        setup = setup.with_template(template)?;
+    }
+    if let Some(path) = matches.get_one::<PathBuf>("plot-config") {
+        setup = setup
+            .with_plot_config(&read_to_string(path)?)
+            .map_err(|e| e.with_path(path))?;
+    }
+    Ok(setup.with_theme(*matches.get_one::<crate::process::Theme>("theme").unwrap()))
+}
+
+/// Writes the reports of the `report` and, if `aggregate`, the `aggregate` subcommand.
+fn report(matches: &ArgMatches, aggregate: bool) -> Result<(), FastqcError> {
+    if matches.get_flag("print-schema") {
+        print!("{}", crate::process::REPORT_SCHEMA);
+        return Ok(());
+    }
+    // Reports of single files may also be given their input with --fastq
+    let fastq = (!aggregate)
+        .then(|| matches.get_one::<String>("fastq"))
+        .flatten();
+    let inputs = match fastq {
+        Some(fastq) => vec![fastq.clone()],
+        None => matches
+            .get_many::<String>("input")
+            .map(|inputs| inputs.cloned().collect())
+            .unwrap_or_else(|| vec!["-".to_string()]),
+    };
+    let outdir = matches.get_one::<PathBuf>("outdir");
+    let output = matches.get_one::<PathBuf>("output");
+    let summary = matches.get_one::<String>("summary").map(Path::new);
+    let zip = matches.get_flag("zip");
+    let qc_status = matches.get_flag("qc-status");
+    let data_dir = matches.get_one::<PathBuf>("data-dir");
+    let plot_dir = matches.get_one::<PathBuf>("plot-dir");
+    let database = matches.get_one::<PathBuf>("db");
+    let metrics = matches.get_one::<PathBuf>("metrics-out");
+    let mut config = config(matches)?;
+    config.options.data_format = *matches
+        .get_one::<crate::process::DataFormat>("data-format")
+        .unwrap();
+    let mut options = config.options;
+
+    let jobs = if aggregate {
+        let inputs = crate::input::expand_inputs(&inputs)?;
+        let names = inputs.iter().map(crate::input::report_name);
+        if let Some(name) = names.duplicates().next() {
+            return Err(format!("Several input files would be reported as {}.", name).into());
+        }
+        vec![Job::Aggregated(inputs)]
+    } else if let Some(dir) = matches.get_one::<PathBuf>("watch") {
+        let name = crate::input::file_name(dir).unwrap_or("watched");
+        vec![Job::Watched(name.to_string(), dir.clone())]
+    } else if let Some(read2) = matches.get_one::<String>("read2") {
+        if inputs.len() > 1 {
+            return Err("--read2 requires a single input file.".into());
+        }
+        vec![Job::Paired([inputs[0].clone(), read2.clone()])]
+    } else if matches.get_flag("merge") {
+        // A single scanned directory names the report, e.g. fastq_pass
+        let name = match inputs.as_slice() {
+            [dir] if Path::new(dir).is_dir() => crate::input::file_name(Path::new(dir))
+                .unwrap_or("merged")
+                .to_string(),
+            _ => "merged".to_string(),
+        };
+        vec![Job::Merged(name, crate::input::expand_inputs(&inputs)?)]
+    } else {
+        let inputs = crate::input::expand_inputs(&inputs)?;
+        if matches.get_flag("paired") {
+            crate::input::pair_files(&inputs)?
+                .into_iter()
+                .map(Job::Paired)
+                .collect()
+        } else if matches.get_flag("interleaved") {
+            inputs.into_iter().map(Job::Interleaved).collect()
+        } else {
+            inputs.into_iter().map(Job::Single).collect::<Vec<_>>()
+        }
+    };
+    let names = jobs.iter().map(Job::name).collect::<Vec<_>>();
+    if jobs.len() > 1 {
+        if output.is_some() {
+            return Err(
+                "--output requires a single report, use --outdir for several input files.".into(),
+            );
+        }
+        if outdir.is_none() {
+            return Err(
+                "Several input files require an output directory given with --outdir.".into(),
+            );
+        }
+        if let Some(name) = names.iter().duplicates().next() {
+            return Err(format!("Several input files would be reported as {}.", name).into());
+        }
+    }
+    if let Some(dir) = outdir {
+        fs::create_dir_all(dir)?;
+    }
+    // Archives are written next to the report
+    let bundle_dir = match (outdir, output) {
+        (Some(dir), _) => Some(dir.as_path()),
+        (None, Some(output)) => output.parent(),
+        (None, None) => None,
+    };
+    if zip && bundle_dir.is_none() {
+        return Err("--zip requires --output or --outdir.".into());
+    }
+    if qc_status && bundle_dir.is_none() {
+        return Err("--qc-status requires --output or --outdir.".into());
+    }
+    // Options given on the command line, recorded with --qc-status besides the inputs
+    let parameters = matches
+        .ids()
+        .map(|id| id.as_str())
+        .filter(|&id| id != "input" && matches.value_source(id) == Some(ValueSource::CommandLine))
+        .filter_map(|id| {
+            let values = matches.get_raw(id)?;
+            Some((
+                id.to_string(),
+                values
+                    .map(|value| value.to_string_lossy().into_owned())
+                    .collect(),
+            ))
+        })
+        .collect::<Vec<_>>();
+    if options.data_format == crate::process::DataFormat::Parquet && !cfg!(feature = "parquet") {
+        return Err(
+            "--data-format parquet requires fastqc-rs to be built with the `parquet` feature."
+                .into(),
+        );
+    }
+    if database.is_some() && !cfg!(feature = "sqlite") {
+        return Err("--db requires fastqc-rs to be built with the `sqlite` feature.".into());
+    }
+    // Several files share the threads, each processed on an equal share of them
+    let files = match jobs.as_slice() {
+        [Job::Aggregated(files)] => files.len(),
+        jobs => jobs.len(),
+    };
+    let workers = crate::pool::workers(options.threads, files);
+    options.parallel_files = workers;
+    options.threads /= workers;
+
+    let setup = crate::process::Setup::new(options, &config.adapter_list, &config.adapters)?
+        .with_contaminants(&config.contaminants)
+        .with_references(&config.references);
+    let mut setup = html_setup(setup, matches)?;
+    if let Some(path) = matches.get_one::<PathBuf>("live-json-out") {
+        setup = setup.with_live_json(path)?;
+    }
+    if let Some(baseline) = matches.get_one::<String>("baseline") {
+        setup = setup.with_baseline(baseline, &tolerances(matches))?;
+    }
+    let fail_on = matches.get_one::<String>("fail-on").map(String::as_str);
+    let severity = |status: &str| {
+        crate::process::STATUSES
+            .iter()
+            .position(|&level| level == status)
+    };
+    let reports = jobs.iter().zip(&names).collect::<Vec<_>>();
+    let run_job = |&(job, name): &(&Job, &String)| -> Result<_, FastqcError> {
+        let output = match outdir {
+            Some(dir) => Some(dir.join(format!("{}.{}", name, options.format.extension()))),
+            None => output.cloned(),
+        };
+        // Like FastQC, each file gets its own summary directory if there are several.
+        let paths = |name: &str, several: bool| -> io::Result<OutputPaths> {
+            let dir = |dir: &Path, subdir: String| -> io::Result<PathBuf> {
+                let dir = if several {
+                    dir.join(subdir)
+                } else {
+                    dir.to_path_buf()
+                };
+                fs::create_dir_all(&dir)?;
+                Ok(dir)
+            };
+            Ok(OutputPaths {
+                summary: summary
+                    .map(|summary| dir(summary, format!("{}_fastqc", name)))
+                    .transpose()?,
+                bundle: bundle_dir
+                    .filter(|_| zip)
+                    .map(|dir| dir.join(format!("{}_fastqc.zip", name))),
+                tables: data_dir
+                    .map(|data_dir| dir(data_dir, name.to_string()))
+                    .transpose()?,
+                plots: plot_dir
+                    .map(|plot_dir| dir(plot_dir, name.to_string()))
+                    .transpose()?,
+                database: database.cloned(),
+            })
+        };
+        let outcome = match job {
+            Job::Single(file) => {
+                let paths = paths(name, jobs.len() > 1)?;
+                crate::process::process(file, &setup, output.as_deref(), paths.outputs())?
+            }
+            Job::Paired([read1, read2]) => {
+                let paths = [
+                    paths(&crate::input::report_name(read1), true)?,
+                    paths(&crate::input::report_name(read2), true)?,
+                ];
+                crate::process::process_paired(
+                    [read1, read2],
+                    &setup,
+                    output.as_deref(),
+                    [paths[0].outputs(), paths[1].outputs()],
+                )?
+            }
+            Job::Interleaved(file) => {
+                let paths = [
+                    paths(&format!("{}_R1", name), true)?,
+                    paths(&format!("{}_R2", name), true)?,
+                ];
+                crate::process::process_interleaved(
+                    file,
+                    &setup,
+                    output.as_deref(),
+                    [paths[0].outputs(), paths[1].outputs()],
+                )?
+            }
+            Job::Aggregated(files) => {
+                let reports = files
+                    .iter()
+                    .map(|file| {
+                        let name = crate::input::report_name(file);
+                        let report = outdir.map(|dir| {
+                            dir.join(format!("{}.{}", name, options.format.extension()))
+                        });
+                        Ok((report, paths(&name, true)?))
+                    })
+                    .collect::<io::Result<Vec<_>>>()?;
+                crate::process::process_aggregated(
+                    files,
+                    &setup,
+                    output.as_deref(),
+                    &reports
+                        .iter()
+                        .map(|(report, paths)| (report.as_deref(), paths.outputs()))
+                        .collect::<Vec<_>>(),
+                )?
+            }
+            Job::Merged(name, files) => {
+                let paths = paths(name, false)?;
+                crate::process::process_merged(
+                    files,
+                    name,
+                    &setup,
+                    output.as_deref(),
+                    paths.outputs(),
+                )?
+            }
+            Job::Watched(name, dir) => {
+                let paths = paths(name, false)?;
+                let output = output.ok_or("--watch requires --output or --outdir.")?;
+                let watch = crate::process::Watch {
+                    interval: Duration::from_secs(
+                        *matches.get_one::<u64>("watch-interval").unwrap(),
+                    ),
+                    idle: matches
+                        .get_one::<u64>("watch-idle")
+                        .map(|&idle| Duration::from_secs(idle)),
+                };
+                crate::process::process_watched(dir, name, &setup, &output, paths.outputs(), watch)?
+            }
+        };
+        if let Some(dir) = bundle_dir.filter(|_| qc_status) {
+            let inputs = job
+                .inputs()
+                .into_iter()
+                .map(|input| Ok((input.to_string(), crate::qc_status::md5(input)?)))
+                .collect::<io::Result<Vec<_>>>()?;
+            let status = crate::qc_status::QcStatus {
+                parameters: parameters.clone(),
+                inputs,
+                status: outcome.status,
+                files: &outcome.metrics,
+            };
+            fs::write(
+                dir.join(format!("{}.qc_status.yaml", name)),
+                status.render(),
+            )?;
+        }
+        Ok(outcome)
+    };
+    let outcomes = crate::pool::process_files(&reports, workers, options.progress, run_job)?;
+    if let Some(path) = metrics {
+        let samples = outcomes
+            .iter()
+            .flat_map(|outcome| outcome.metrics.iter().cloned())
+            .collect::<Vec<_>>();
+        crate::metrics::write(path, &samples).map_err(|e| FastqcError::from(e).with_path(path))?;
+    }
+    let mut failed = Vec::new();
+    let mut regressed = Vec::new();
+    for (outcome, name) in outcomes.iter().zip(&names) {
+        if fail_on.is_some_and(|level| severity(outcome.status) >= severity(level)) {
+            failed.push(name);
+        }
+        if outcome.regressions > 0 {
+            regressed.push(name);
+        }
+    }
+    if !failed.is_empty() || !regressed.is_empty() {
+        io::stdout().flush()?;
+        if !failed.is_empty() {
+            log::warn!(
+                "Modules with status {} or worse in {}.",
+                fail_on.unwrap(),
+                failed.iter().join(", ")
+            );
+        }
+        if !regressed.is_empty() {
+            log::warn!(
+                "Regressions from the baseline in {}.",
+                regressed.iter().join(", ")
+            );
+        }
+        std::process::exit(3);
+    }
+    Ok(())
+}
+
+/// Returns the configuration of the analyses and reports given by the arguments.
+fn config(matches: &ArgMatches) -> Result<crate::qc::QcConfig, FastqcError> {
+    let mut config = crate::qc::QcConfig::new()
+        .k(*matches.get_one::<u8>("k").unwrap())
+        .long_reads(matches.get_flag("long-reads"))
+        .nogroup(matches.get_flag("nogroup"))
+        .threads(*matches.get_one::<u16>("threads").unwrap() as usize)
+        .duplication_memory((*matches.get_one::<u64>("duplication-memory").unwrap() << 20) as usize)
+        .fastqc_duplication(matches.get_flag("fastqc-duplication"))
+        .read_id_memory((*matches.get_one::<u64>("read-id-memory").unwrap() << 20) as usize)
+        .casava(matches.get_flag("casava") && !matches.get_flag("nofilter"))
+        .quality_heatmap(matches.get_flag("quality-heatmap"))
+        .split_by_lane(matches.get_flag("split-by-lane"))
+        .platform_modules(matches.get_flag("platform-modules"))
+        .survival_filters(crate::SurvivalFilters {
+            min_length: *matches.get_one::<u32>("survival-min-length").unwrap() as usize,
+            min_mean_quality: *matches.get_one::<f64>("survival-min-quality").unwrap(),
+            max_n: *matches.get_one::<u32>("survival-max-n").unwrap() as usize,
+            max_expected_errors: *matches.get_one::<f64>("survival-max-ee").unwrap(),
+        })
+        .unmapped_only(matches.get_flag("unmapped-only"))
+        .primary_only(matches.get_flag("primary-only"));
+    if let Some(&offset) = matches.get_one::<u8>("phred-offset") {
+        config = config.phred_offset(offset);
+    }
+    if let Some(&reads) = matches.get_one::<u64>("sample") {
+        config = config
+            .sample(reads as usize)
+            .seed(*matches.get_one::<u64>("seed").unwrap());
+    }
+    if let Some(&bases) = matches.get_one::<u64>("genome-size") {
+        config = config.genome_size(bases);
+    }
+    if let Some(&length) = matches.get_one::<u16>("umi-len") {
+        config = config.umi_length(length as usize);
+    }
+    if matches.get_flag("spectrum") {
+        config = config.kmer_spectrum(*matches.get_one::<u64>("spectrum-scale").unwrap());
+    }
+    if matches.get_flag("optical-duplicates") {
+        config = config.optical_duplicates(*matches.get_one::<u32>("optical-distance").unwrap());
+    }
+    if let Some(cutoffs) = matches.get_one::<TrimCutoffs>("simulate-trim") {
+        config = config.simulate_trimming(&cutoffs.cutoffs());
+    }
+    if let Some(&reads) = matches.get_one::<u64>("max-reads") {
+        config = config.max_reads(reads as usize);
+    }
+    if let Some(path) = matches.get_one::<PathBuf>("adapters") {
+        config = config
+            .adapters(&read_to_string(path)?)
+            .map_err(|e| e.with_path(path))?;
+    }
+    for adapter in matches.get_many::<String>("adapter").unwrap_or_default() {
+        config = config.adapter(adapter);
+    }
+    if let Some(path) = matches.get_one::<PathBuf>("contaminants") {
+        config = config
+            .contaminants(&read_to_string(path)?)
+            .map_err(|e| e.with_path(path))?;
+    }
+    if let Some(path) = matches.get_one::<PathBuf>("phix") {
+        config = config
+            .phix(&read_to_string(path)?)
+            .map_err(|e| e.with_path(path))?;
+    }
+    for path in matches.get_many::<PathBuf>("screen").unwrap_or_default() {
+        config = config
+            .screen(&fs::read(path).map_err(|e| FastqcError::from(e).with_path(path))?)
+            .map_err(|e| e.with_path(path))?;
+    }
+    if let Some(path) = matches.get_one::<PathBuf>("limits") {
+        config = config
+            .limits(&read_to_string(path)?)
+            .map_err(|e| e.with_path(path))?;
+    }
+    for &module in matches.get_many::<Module>("disable").unwrap_or_default() {
+        config = config.disable(module);
+    }
+    // Settings of the written outputs are not part of the library configuration
+    config.options.format = matches
+        .get_one::<crate::process::Format>("format")
+        .copied()
+        .unwrap_or_default();
+    config.options.fetch_assets = matches.get_flag("fetch-assets");
+    config.options.live_json = matches
+        .get_one::<u64>("live-json")
+        .map(|&every| every as usize);
+    config.options.progress = !matches.get_flag("quiet") && config.options.live_json.is_none();
+    config.options.max_plot_points = matches
+        .get_one::<u64>("max-plot-points")
+        .filter(|&&points| points > 0)
+        .map(|&points| points as usize);
+    Ok(config)
+}
+
+/// Checks the input files of the `validate` subcommand, printing every violation
+/// followed by a summary per file.
+fn validate(matches: &ArgMatches) -> Result<(), FastqcError> {
+    let inputs = matches
+        .get_many::<String>("input")
+        .unwrap()
+        .cloned()
+        .collect::<Vec<_>>();
+    let mut stdout = io::stdout().lock();
+    let mut invalid = Vec::new();
+    for file in crate::input::expand_inputs(&inputs)? {
+        let summary =
+            crate::validate::validate(crate::input::open_bytes(&file)?, &mut |violation| {
+                writeln!(stdout, "{}: {}", file, violation)
+            })?;
+        let violations = summary.violations.values().sum::<usize>();
+        writeln!(
+            stdout,
+            "{}: {} records, {} violations",
+            file, summary.records, violations
+        )?;
+        for (kind, count) in &summary.violations {
+            writeln!(stdout, "    {}: {}", kind, count)?;
+        }
+        if !summary.is_valid() {
+            invalid.push(file);
+        }
+    }
+    if !invalid.is_empty() {
+        stdout.flush()?;
+        log::warn!("Invalid FASTQ in {}.", invalid.iter().join(", "));
+        std::process::exit(3);
+    }
+    Ok(())
+}
+
+/// Writes the screen index of the k-mers of the given FASTA files.
+fn index(matches: &ArgMatches) -> Result<(), FastqcError> {
+    let files = matches
+        .get_many::<PathBuf>("fasta")
+        .unwrap()
+        .collect::<Vec<_>>();
+    let name = match matches.get_one::<String>("name") {
+        Some(name) => name.clone(),
+        None => crate::input::report_name(files[0].display().to_string()),
+    };
+    let scale = *matches.get_one::<u64>("scale").unwrap();
+    let mut reference = crate::screen::Reference::new(&name, scale);
+    for file in &files {
+        let mut reader = needletail::parse_fastx_file(file)?;
+        while let Some(record) = reader.next() {
+            reference.add(&record?.seq());
+        }
+    }
+    let reference = reference.check()?;
+    let output = matches.get_one::<PathBuf>("output").unwrap();
+    fs::write(output, reference.to_bytes())?;
+    log::info!(
+        "Wrote {} k-mers of {} to {}.",
+        reference.len(),
+        name,
+        output.display()
+    );
+    Ok(())
+}
+
+/// Prints the completions of the `completions` subcommand for the chosen shell.
+fn completions(matches: &ArgMatches) -> Result<(), FastqcError> {
+    let shell = *matches.get_one::<clap_complete::Shell>("shell").unwrap();
+    clap_complete::generate(shell, &mut command(), "fqc", &mut io::stdout());
+    Ok(())
+}
+
+/// Prints the man page of fqc or writes the pages of fqc and its subcommands, named
+/// like `fqc-report.1`, into the output directory.
+fn man(matches: &ArgMatches) -> Result<(), FastqcError> {
+    let command = command().name("fqc");
+    match matches.get_one::<PathBuf>("outdir") {
+        Some(dir) => {
+            fs::create_dir_all(dir)?;
+            clap_mangen::generate_to(command, dir)?;
+            log::info!("Wrote the man pages to {}.", dir.display());
+        }
+        None => clap_mangen::Man::new(command).render(&mut io::stdout())?,
+    }
+    Ok(())
+}
+
+/// Writes the comparison of the two samples of the `compare` subcommand, which are
+/// analyzed with the default settings unless given as JSON reports.
+fn compare(matches: &ArgMatches) -> Result<(), FastqcError> {
+    let config = config(matches)?;
+    if config.options.format == crate::process::Format::Markdown {
+        return Err("compare writes HTML or JSON, not Markdown.".into());
+    }
+    let setup = crate::process::Setup::new(config.options, &config.adapter_list, &config.adapters)?
+        .with_contaminants(&config.contaminants)
+        .with_references(&config.references);
+    let mut setup = html_setup(setup, matches)?;
+    if let Some(path) = matches.get_one::<PathBuf>("live-json-out") {
+        setup = setup.with_live_json(path)?;
+    }
+    let samples = ["before", "after"].map(|arg| matches.get_one::<String>(arg).unwrap().as_str());
+    let output = match matches.get_one::<PathBuf>("outdir") {
+        Some(dir) => {
+            fs::create_dir_all(dir)?;
+            Some(dir.join(format!("comparison.{}", config.options.format.extension())))
+        }
+        None => matches.get_one::<PathBuf>("output").cloned(),
+    };
+    let regressions = crate::process::process_comparison(
+        samples,
+        &setup,
+        &tolerances(matches),
+        output.as_deref(),
+    )?;
+    log::info!(
+        "Regressions from {} to {}: {}.",
+        samples[0],
+        samples[1],
+        regressions
+    );
+    Ok(())
+}
+
+/// Writes the matrix and overview of the samples of the `cohort` subcommand, both into
+/// --outdir if given.
+fn cohort(matches: &ArgMatches) -> Result<(), FastqcError> {
+    let inputs = matches
+        .get_many::<String>("input")
+        .unwrap()
+        .cloned()
+        .collect::<Vec<_>>();
+    let mut options = crate::qc::QcConfig::new().options;
+    options.progress = false;
+    let setup = html_setup(crate::process::Setup::new(options, &[], &[])?, matches)?;
+    let (output, html) = match matches.get_one::<PathBuf>("outdir") {
+        Some(dir) => {
+            fs::create_dir_all(dir)?;
+            (Some(dir.join("cohort.tsv")), Some(dir.join("cohort.html")))
+        }
+        None => (
+            matches.get_one::<PathBuf>("output").cloned(),
+            matches.get_one::<PathBuf>("html").cloned(),
+        ),
+    };
+    crate::process::process_cohort(
+        &crate::input::expand_inputs(&inputs)?,
+        &setup,
+        output.as_deref(),
+        html.as_deref(),
+    )
+}
+
+/// Prints the percentages of the reads of the input files of the `screen` subcommand
+/// matching each reference genome.
+fn screen(matches: &ArgMatches) -> Result<(), FastqcError> {
+    let inputs = matches
+        .get_many::<String>("input")
+        .map(|inputs| inputs.cloned().collect())
+        .unwrap_or_else(|| vec!["-".to_string()]);
+    // Only the screen is needed, which is not a module that can be disabled
+    let mut config = crate::qc::QcConfig::new()
+        .only(&[])
+        .threads(*matches.get_one::<u16>("threads").unwrap() as usize);
+    if let Some(&reads) = matches.get_one::<u64>("max-reads") {
+        config = config.max_reads(reads as usize);
+    }
+    for path in matches.get_many::<PathBuf>("index").unwrap() {
+        config = config
+            .screen(&fs::read(path).map_err(|e| FastqcError::from(e).with_path(path))?)
+            .map_err(|e| e.with_path(path))?;
+    }
+    config.options.format = matches
+        .get_one::<crate::process::Format>("format")
+        .copied()
+        .unwrap_or_default();
+    config.options.progress = !matches.get_flag("quiet");
+    let json = config.options.format == crate::process::Format::Json;
+    let setup =
+        crate::process::Setup::new(config.options, &[], &[])?.with_references(&config.references);
+    let output = match matches.get_one::<PathBuf>("outdir") {
+        Some(dir) => {
+            fs::create_dir_all(dir)?;
+            Some(dir.join(if json { "screen.json" } else { "screen.tsv" }))
+        }
+        None => matches.get_one::<PathBuf>("output").cloned(),
+    };
+    crate::process::process_screen(
+        &crate::input::expand_inputs(&inputs)?,
+        &setup,
+        output.as_deref(),
+    )
+}
+
+/// Returns the argument setting the tolerance of a compared metric.
+fn tolerance_arg() -> Arg {
+    Arg::new("tolerance")
+        .long("tolerance")
+        .value_name("METRIC=TOLERANCE")
+        .action(ArgAction::Append)
+        .help("Tolerates a change of the given metric in the worse direction up to the given value, in points for percentages, e.g. q30=2. Can be given multiple times.")
+        .value_parser(crate::compare::parse_tolerance)
+}
+
+/// Returns the metrics with tolerances given with --tolerance.
+fn tolerances(matches: &ArgMatches) -> Vec<(&'static str, f64)> {
+    matches
+        .get_many::<(&'static str, f64)>("tolerance")
+        .unwrap_or_default()
+        .copied()
+        .collect()
+}
+
+/// Paths of the outputs for a single input file besides the report.
+struct OutputPaths {
+    summary: Option<PathBuf>,
+    bundle: Option<PathBuf>,
+    tables: Option<PathBuf>,
+    plots: Option<PathBuf>,
+    database: Option<PathBuf>,
+}
+
+impl OutputPaths {
+    fn outputs(&self) -> crate::process::FileOutputs<'_> {
+        crate::process::FileOutputs {
+            summary: self.summary.as_deref(),
+            bundle: self.bundle.as_deref(),
+            tables: self.tables.as_deref(),
+            plots: self.plots.as_deref(),
+            database: self.database.as_deref(),
+        }
+    }
+}
+
+/// The input files of one report.
+enum Job {
+    Single(String),
+    Paired([String; 2]),
+    Interleaved(String),
+    /// Files combined into a single report with the given name.
+    Merged(String, Vec<String>),
+    /// Files compared in an aggregated report.
+    Aggregated(Vec<String>),
+    /// Files appearing in a watched directory combined into a report with the given
+    /// name.
+    Watched(String, PathBuf),
+}
+
+impl Job {
+    /// Returns the name of the report.
+    fn name(&self) -> String {
+        match self {
+            Job::Single(file) | Job::Interleaved(file) => crate::input::report_name(file),
+            Job::Paired([read1, _]) => crate::input::pair_name(read1),
+            Job::Merged(name, _) | Job::Watched(name, _) => name.clone(),
+            Job::Aggregated(_) => "aggregate".to_string(),
+        }
+    }
+
+    /// Returns the input files, or the watched directory.
+    fn inputs(&self) -> Vec<&str> {
+        match self {
+            Job::Single(file) | Job::Interleaved(file) => vec![file],
+            Job::Paired(files) => files.iter().map(String::as_str).collect(),
+            Job::Merged(_, files) | Job::Aggregated(files) => {
+                files.iter().map(String::as_str).collect()
+            }
+            Job::Watched(_, dir) => vec![dir.to_str().unwrap_or_default()],
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{command, with_settings, with_subcommand};
+    use std::ffi::OsString;
+    #[test]
+    fn test_command() {
+        command().debug_assert();
+        let args = |args: &[&str]| args.iter().map(OsString::from).collect::<Vec<_>>();
+        assert_eq!(
+            with_subcommand(args(&["fqc", "-q", "reads.fastq"])),
+            args(&["fqc", "report", "-q", "reads.fastq"])
+        );
+        assert_eq!(with_subcommand(args(&["fqc"])), args(&["fqc", "report"]));
+        // Only the first argument besides global options can name a subcommand
+        for unnamed in [
+            &["fqc", "-q", "reads.fastq", "-o", "report"][..],
+            &["fqc", "--title", "index", "reads.fastq"],
+            &["fqc", "-vv", "--threads=2", "reads.fastq", "index"],
+        ] {
+            let mut expected = args(unnamed);
+            expected.insert(1, "report".into());
+            assert_eq!(with_subcommand(args(unnamed)), expected);
+        }
+        for named in [
+            &["fqc", "-t", "4", "compare", "a", "b"][..],
+            &["fqc", "-t2", "-vv", "--outdir", "out", "index", "a"],
+            &["fqc", "--help"],
+            &["fqc", "completions", "bash"],
+        ] {
+            assert_eq!(with_subcommand(args(named)), args(named));
+        }
+        let mut completions = Vec::new();
+        clap_complete::generate(
+            clap_complete::Shell::Bash,
+            &mut command(),
+            "fqc",
+            &mut completions,
+        );
+        let completions = String::from_utf8(completions).unwrap();
+        assert!(completions.contains("--max-plot-points"));
+        assert!(completions.contains("aggregate"));
+    }
+
+    #[test]
+    fn test_with_settings() {
+        let args = |args: &[&str]| args.iter().map(OsString::from).collect::<Vec<_>>();
+        let settings = crate::settings::parse(
+            "threads = 4\nquiet = true\nadapter = [\"ACGT\", \"TTTT\"]\nmodules = [\"per_base_quality\"]\n\
+             [aggregate]\nthreads = 8\n[validate]\nthreads = 2\n",
+        )
+        .unwrap();
+        let with = |given: &[&str]| {
+            let given = args(given);
+            let matches = command().get_matches_from(&given);
+            with_settings(given, &settings, &matches).unwrap()
+        };
+        // Options given as arguments are kept and the others added before `--`
+        let report = with(&[
+            "fqc",
+            "report",
+            "-t",
+            "2",
+            "-a",
+            "CCCC",
+            "--",
+            "reads.fastq",
+        ]);
+        // The settings are added in the order of their keys
+        assert_eq!(
+            report[..6],
+            args(&["fqc", "report", "-t", "2", "-a", "CCCC"])
+        );
+        assert_eq!(
+            report[..report.len() - 3].last(),
+            Some(&OsString::from("--disable=low_complexity"))
+        );
+        assert_eq!(
+            report[report.len() - 3..],
+            args(&["--quiet", "--", "reads.fastq"])[..]
+        );
+        let aggregate = with(&["fqc", "aggregate", "reads.fastq"]);
+        // Settings of the section of the subcommand replace the others
+        assert_eq!(aggregate[3..5], args(&["--adapter=ACGT", "--adapter=TTTT"]));
+        assert_eq!(
+            aggregate[aggregate.len() - 2..],
+            args(&["--quiet", "--threads=8"])[..]
+        );
+        assert!(!aggregate.contains(&OsString::from("--threads=4")));
+        assert_eq!(
+            with(&["fqc", "validate", "reads.fastq"])[3..],
+            args(&["--quiet", "--threads=2"])
+        );
+        let matches = command().get_matches_from(args(&["fqc", "report"]));
+        for invalid in ["quiet = 1\n", "foo = 1\n", "[screen]\nadapter = \"A\"\n"] {
+            let settings = crate::settings::parse(invalid).unwrap();
+            assert!(with_settings(args(&["fqc", "report"]), &settings, &matches).is_err());
+        }
+    }
+}
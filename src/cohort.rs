@@ -0,0 +1,244 @@
+use crate::compare::METRICS;
+use crate::modules::Module;
+use crate::process::{most_severe, STATUSES};
+use serde_json::Value;
+
+/// Measures of the basic statistics of `fastqc_data.txt` with the key of the compared
+/// metric they give.
+const MEASURES: [(&str, &str); 5] = [
+    ("Total Sequences", "total_sequences"),
+    ("%GC", "gc_content"),
+    ("%Q20 bases", "q20"),
+    ("%Q30 bases", "q30"),
+    ("%Q30 reads", "q30_reads"),
+];
+
+/// Metrics and module statuses of a sample read from a previous output.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Sample {
+    pub(crate) name: String,
+    /// Values of the compared metrics by their key, if the output has them.
+    pub(crate) metrics: Vec<(&'static str, f64)>,
+    /// Statuses of the modules by their key in JSON reports.
+    pub(crate) statuses: Vec<(String, String)>,
+}
+
+impl Sample {
+    /// Reads the sample from the results of a file of a JSON report.
+    pub(crate) fn from_json(results: &Value) -> Self {
+        let metrics = METRICS
+            .iter()
+            .filter_map(|metric| Some((metric.key, results.pointer(metric.pointer)?.as_f64()?)))
+            .collect();
+        let statuses = results["modules"]
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter_map(|(module, data)| {
+                Some((module.clone(), data["status"].as_str()?.to_string()))
+            })
+            .collect();
+        Sample {
+            name: results["filename"].as_str().unwrap_or_default().to_string(),
+            metrics,
+            statuses,
+        }
+    }
+
+    /// Reads the sample from a `fastqc_data.txt` written by fastqc-rs or FastQC.
+    pub(crate) fn from_fastqc_data(text: &str) -> Result<Self, String> {
+        if !text.starts_with("##") {
+            return Err("Not a fastqc_data.txt file.".to_string());
+        }
+        let mut sample = Sample {
+            name: String::new(),
+            metrics: Vec::new(),
+            statuses: Vec::new(),
+        };
+        for line in text.lines() {
+            let (key, value) = line.split_once('\t').unwrap_or((line, ""));
+            let value = value.trim();
+            if let Some(name) = key.strip_prefix(">>") {
+                if name != "END_MODULE" && name != "Basic Statistics" {
+                    sample
+                        .statuses
+                        .push((module_key(name), value.to_lowercase()));
+                }
+            } else if key == "Filename" {
+                sample.name = value.to_string();
+            } else if key == "Total Bases" {
+                sample
+                    .metrics
+                    .extend(parse_bases(value).map(|bases| ("total_bases", bases)));
+            } else if key == "#Total Deduplicated Percentage" {
+                sample
+                    .metrics
+                    .extend(value.parse().ok().map(|pct| ("remaining", pct)));
+            } else if let Some(&(_, metric)) = MEASURES.iter().find(|(measure, _)| *measure == key)
+            {
+                sample
+                    .metrics
+                    .extend(value.parse().ok().map(|value| (metric, value)));
+            }
+        }
+        // Keep the order of the metrics in comparisons
+        sample
+            .metrics
+            .sort_by_key(|(key, _)| METRICS.iter().position(|metric| metric.key == *key));
+        Ok(sample)
+    }
+
+    /// Returns the value of the metric with the given key.
+    pub(crate) fn metric(&self, key: &str) -> Option<f64> {
+        self.metrics
+            .iter()
+            .find(|(metric, _)| *metric == key)
+            .map(|&(_, value)| value)
+    }
+
+    /// Returns the status of the module with the given key, or an empty string if it
+    /// was not run.
+    pub(crate) fn status(&self, module: &str) -> &str {
+        self.statuses
+            .iter()
+            .find(|(key, _)| key == module)
+            .map_or("", |(_, status)| status)
+    }
+
+    /// Returns the most severe status of all modules.
+    pub(crate) fn overall_status(&self) -> &'static str {
+        most_severe(
+            self.statuses
+                .iter()
+                .filter_map(|(_, status)| STATUSES.iter().copied().find(|s| s == status)),
+        )
+    }
+}
+
+/// Returns the key of a module named like in `fastqc_data.txt`, e.g.
+/// `per_base_quality` for `Per base sequence quality`. Modules unknown to fastqc-rs,
+/// e.g. custom modules, are named in snake case.
+fn module_key(name: &str) -> String {
+    match Module::ALL.iter().find(|module| module.name() == name) {
+        Some(module) => module.key().to_string(),
+        None => name.to_lowercase().replace([' ', '-'], "_"),
+    }
+}
+
+/// Parses a number of bases like `1.2 Mbp` or `150 bp`.
+fn parse_bases(text: &str) -> Option<f64> {
+    let (value, unit) = text.split_once(' ')?;
+    let factor = match unit {
+        "bp" => 1.0,
+        "kbp" => 1e3,
+        "Mbp" => 1e6,
+        "Gbp" => 1e9,
+        "Tbp" => 1e12,
+        _ => return None,
+    };
+    Some(value.parse::<f64>().ok()? * factor)
+}
+
+/// Samples of a cohort with the metrics and modules any of them has, as wide matrix.
+pub(crate) struct Cohort {
+    pub(crate) samples: Vec<Sample>,
+    /// Keys of the metrics in the order of comparisons.
+    pub(crate) metrics: Vec<&'static str>,
+    /// Keys of the modules in the order of their first appearance.
+    pub(crate) modules: Vec<String>,
+}
+
+impl Cohort {
+    pub(crate) fn new(samples: Vec<Sample>) -> Self {
+        let metrics = METRICS
+            .iter()
+            .map(|metric| metric.key)
+            .filter(|key| samples.iter().any(|sample| sample.metric(key).is_some()))
+            .collect();
+        let mut modules: Vec<String> = Vec::new();
+        for sample in &samples {
+            for (module, _) in &sample.statuses {
+                if !modules.contains(module) {
+                    modules.push(module.clone());
+                }
+            }
+        }
+        Cohort {
+            samples,
+            metrics,
+            modules,
+        }
+    }
+
+    /// Renders the matrix as TSV table with a row per sample and a column per metric
+    /// and module, leaving out values a sample does not have.
+    pub(crate) fn to_tsv(&self) -> String {
+        let mut header = vec!["sample", "status"];
+        header.extend(&self.metrics);
+        header.extend(self.modules.iter().map(String::as_str));
+        let mut table = header.join("\t") + "\n";
+        for sample in &self.samples {
+            let mut row = vec![sample.name.clone(), sample.overall_status().to_string()];
+            row.extend(self.metrics.iter().map(|key| {
+                sample
+                    .metric(key)
+                    .map_or(String::new(), |value| format!("{}", value))
+            }));
+            row.extend(
+                self.modules
+                    .iter()
+                    .map(|module| sample.status(module).to_string()),
+            );
+            table.push_str(&row.join("\t"));
+            table.push('\n');
+        }
+        table
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Cohort, Sample};
+    use serde_json::json;
+    #[test]
+    fn test_cohort() {
+        let json = Sample::from_json(&json!({
+            "filename": "a.fastq",
+            "basic_statistics": {"total_sequences": 1000, "gc_content": 45.5, "q30": 92.0},
+            "modules": {
+                "per_base_quality": {"status": "pass"},
+                "adapter_content": {"status": "warn"},
+                "quality_heatmap": {"data": []},
+            },
+        }));
+        assert_eq!(json.metric("q30"), Some(92.0));
+        assert_eq!(json.overall_status(), "warn");
+        let text = "##FastQC\t0.12.1\n>>Basic Statistics\tpass\n#Measure\tValue\n\
+            Filename\tb.fastq.gz\nTotal Sequences\t2000\nTotal Bases\t1.5 Mbp\n%GC\t41\n\
+            >>END_MODULE\n>>Per base sequence quality\tfail\n>>END_MODULE\n\
+            >>Sequence Duplication Levels\tpass\n#Total Deduplicated Percentage\t80.5\n\
+            >>END_MODULE\n";
+        let data = Sample::from_fastqc_data(text).unwrap();
+        assert_eq!(data.name, "b.fastq.gz");
+        assert_eq!(
+            data.metrics,
+            [
+                ("total_sequences", 2000.0),
+                ("total_bases", 1.5e6),
+                ("gc_content", 41.0),
+                ("remaining", 80.5),
+            ]
+        );
+        assert_eq!(data.status("per_base_quality"), "fail");
+        assert_eq!(data.status("duplication_levels"), "pass");
+        assert!(Sample::from_fastqc_data("{}").is_err());
+        let cohort = Cohort::new(vec![json, data]);
+        assert_eq!(
+            cohort.to_tsv(),
+            "sample\tstatus\ttotal_sequences\ttotal_bases\tgc_content\tq30\tremaining\t\
+             adapter_content\tper_base_quality\tduplication_levels\n\
+             a.fastq\twarn\t1000\t\t45.5\t92\t\twarn\tpass\t\n\
+             b.fastq.gz\tfail\t2000\t1500000\t41\t\t80.5\t\tfail\tpass\n"
+        );
+    }
+}
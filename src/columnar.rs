@@ -0,0 +1,164 @@
+use crate::error::FastqcError;
+use serde_json::Value;
+use std::path::Path;
+
+#[cfg(not(feature = "parquet"))]
+pub(crate) fn write_parquet(_: &Path, _: &str, _: &[&str], _: &[Value]) -> Result<(), FastqcError> {
+    Err("--data-format parquet requires fastqc-rs to be built with the `parquet` feature.".into())
+}
+
+/// Writes the rows of a module table as Parquet file with one optional column per
+/// given column. Columns holding only integers or only numbers are stored as such,
+/// all others as strings.
+#[cfg(feature = "parquet")]
+pub(crate) fn write_parquet(
+    path: &Path,
+    name: &str,
+    columns: &[&str],
+    rows: &[Value],
+) -> Result<(), FastqcError> {
+    use parquet::basic::Compression;
+    use parquet::data_type::{ByteArray, ByteArrayType, DoubleType, Int64Type};
+    use parquet::file::properties::WriterProperties;
+    use parquet::file::writer::SerializedFileWriter;
+    use parquet::schema::parser::parse_message_type;
+    use std::sync::Arc;
+
+    let kinds = columns
+        .iter()
+        .map(|&column| kind(rows.iter().map(|row| &row[column])))
+        .collect::<Vec<_>>();
+    let fields = columns
+        .iter()
+        .zip(&kinds)
+        .map(|(column, kind)| kind.field(column))
+        .collect::<Vec<_>>();
+    let schema = parse_message_type(&format!("message {} {{ {} }}", name, fields.join(" ")))?;
+    let properties = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+    let mut writer = SerializedFileWriter::new(
+        std::fs::File::create(path)?,
+        Arc::new(schema),
+        Arc::new(properties),
+    )?;
+    let mut row_group = writer.next_row_group()?;
+    for (&column, kind) in columns.iter().zip(&kinds) {
+        let values = rows.iter().map(|row| &row[column]).collect::<Vec<_>>();
+        let levels = values
+            .iter()
+            .map(|value| i16::from(!value.is_null()))
+            .collect::<Vec<_>>();
+        let values = values.into_iter().filter(|value| !value.is_null());
+        let mut writer = row_group.next_column()?.ok_or("Missing Parquet column")?;
+        match kind {
+            Kind::Integer => {
+                let values = values.filter_map(Value::as_i64).collect::<Vec<_>>();
+                writer
+                    .typed::<Int64Type>()
+                    .write_batch(&values, Some(&levels), None)?;
+            }
+            Kind::Number => {
+                let values = values.filter_map(Value::as_f64).collect::<Vec<_>>();
+                writer
+                    .typed::<DoubleType>()
+                    .write_batch(&values, Some(&levels), None)?;
+            }
+            Kind::String => {
+                let values = values
+                    .map(|value| match value {
+                        Value::String(value) => ByteArray::from(value.as_str()),
+                        value => ByteArray::from(value.to_string().as_str()),
+                    })
+                    .collect::<Vec<_>>();
+                writer
+                    .typed::<ByteArrayType>()
+                    .write_batch(&values, Some(&levels), None)?;
+            }
+        }
+        writer.close()?;
+    }
+    row_group.close()?;
+    writer.close()?;
+    Ok(())
+}
+
+/// Type of the values of a column.
+#[cfg(feature = "parquet")]
+#[derive(Debug, PartialEq, Eq)]
+enum Kind {
+    Integer,
+    Number,
+    String,
+}
+
+#[cfg(feature = "parquet")]
+impl Kind {
+    /// Returns the definition of an optional column of this kind in a Parquet schema.
+    fn field(&self, column: &str) -> String {
+        match self {
+            Kind::Integer => format!("optional int64 {};", column),
+            Kind::Number => format!("optional double {};", column),
+            Kind::String => format!("optional binary {} (UTF8);", column),
+        }
+    }
+}
+
+/// Returns the narrowest kind holding all given values, ignoring missing ones.
+#[cfg(feature = "parquet")]
+fn kind<'a>(values: impl Iterator<Item = &'a Value>) -> Kind {
+    let mut kind = Kind::Integer;
+    for value in values {
+        match value {
+            Value::Null => {}
+            Value::Number(number) if number.is_i64() => {}
+            Value::Number(_) => kind = Kind::Number,
+            _ => return Kind::String,
+        }
+    }
+    kind
+}
+
+#[cfg(all(test, feature = "parquet"))]
+mod test {
+    use super::{kind, write_parquet, Kind};
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use serde_json::json;
+    #[test]
+    fn test_kind() {
+        let values = [json!(1), json!(null), json!(3)];
+        assert_eq!(kind(values.iter()), Kind::Integer);
+        let values = [json!(1), json!(2.5)];
+        assert_eq!(kind(values.iter()), Kind::Number);
+        let values = [json!(1.5), json!("no hit")];
+        assert_eq!(kind(values.iter()), Kind::String);
+    }
+    #[test]
+    fn test_write_parquet() {
+        let path = std::env::temp_dir().join(format!("fqc-test-{}.parquet", std::process::id()));
+        let rows = [
+            json!({"sequence": "ACGT", "count": 3, "pct": 1.5, "source": null}),
+            json!({"sequence": "TTTT", "count": 2, "pct": 1.0, "source": "Adapter"}),
+        ];
+        write_parquet(
+            &path,
+            "overrepresented",
+            &["sequence", "count", "pct", "source"],
+            &rows,
+        )
+        .unwrap();
+        let reader = SerializedFileReader::new(std::fs::File::open(&path).unwrap()).unwrap();
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 2);
+        let rows = reader
+            .get_row_iter(None)
+            .unwrap()
+            .map(|row| row.unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows[1],
+            r#"{sequence: "TTTT", count: 2, pct: 1.0, source: "Adapter"}"#
+        );
+        assert!(rows[0].contains("source: null"));
+        std::fs::remove_file(path).unwrap();
+    }
+}
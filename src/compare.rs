@@ -0,0 +1,330 @@
+use crate::process::STATUSES;
+use itertools::Itertools;
+use serde_json::{json, Value};
+
+/// Direction in which a metric gets worse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Worse {
+    /// Lower values are worse, e.g. the percentage of Q30 bases.
+    Lower,
+    /// Higher values are worse, e.g. the number of invalid records.
+    Higher,
+    /// Any change is worse, e.g. a drift of the GC content.
+    Either,
+}
+
+/// A metric of the JSON report of a file compared between two samples.
+pub(crate) struct Metric {
+    /// Key of the metric in the JSON comparison.
+    pub(crate) key: &'static str,
+    pub(crate) name: &'static str,
+    /// JSON pointer to the metric in the results of a file.
+    pub(crate) pointer: &'static str,
+    worse: Worse,
+    /// Change in the worse direction tolerated before it is a regression, or `None`
+    /// for metrics which only describe the samples, like their size.
+    tolerance: Option<f64>,
+}
+
+/// The compared metrics. Tolerances of percentages are in percentage points.
+pub(crate) const METRICS: [Metric; 11] = [
+    Metric {
+        key: "total_sequences",
+        name: "Total sequences",
+        pointer: "/basic_statistics/total_sequences",
+        worse: Worse::Lower,
+        tolerance: None,
+    },
+    Metric {
+        key: "total_bases",
+        name: "Total bases",
+        pointer: "/basic_statistics/total_bases",
+        worse: Worse::Lower,
+        tolerance: None,
+    },
+    Metric {
+        key: "mean_length",
+        name: "Mean length",
+        pointer: "/basic_statistics/mean_length",
+        worse: Worse::Lower,
+        tolerance: None,
+    },
+    Metric {
+        key: "gc_content",
+        name: "%GC",
+        pointer: "/basic_statistics/gc_content",
+        worse: Worse::Either,
+        tolerance: Some(2.0),
+    },
+    Metric {
+        key: "q20",
+        name: "%Q20 bases",
+        pointer: "/basic_statistics/q20",
+        worse: Worse::Lower,
+        tolerance: Some(1.0),
+    },
+    Metric {
+        key: "q30",
+        name: "%Q30 bases",
+        pointer: "/basic_statistics/q30",
+        worse: Worse::Lower,
+        tolerance: Some(1.0),
+    },
+    Metric {
+        key: "q30_reads",
+        name: "%Q30 reads",
+        pointer: "/basic_statistics/q30_reads",
+        worse: Worse::Lower,
+        tolerance: Some(1.0),
+    },
+    Metric {
+        key: "remaining",
+        name: "% remaining if deduplicated",
+        pointer: "/modules/duplication_levels/remaining",
+        worse: Worse::Lower,
+        tolerance: Some(5.0),
+    },
+    Metric {
+        key: "bases_passing_filters",
+        name: "% bases passing filters",
+        pointer: "/modules/filter_survival/bases_pct",
+        worse: Worse::Lower,
+        tolerance: Some(2.0),
+    },
+    Metric {
+        key: "mean_expected_errors",
+        name: "Mean expected errors",
+        pointer: "/modules/expected_errors/mean",
+        worse: Worse::Higher,
+        tolerance: Some(0.1),
+    },
+    Metric {
+        key: "invalid_reads",
+        name: "Invalid records",
+        pointer: "/invalid_reads/count",
+        worse: Worse::Higher,
+        tolerance: Some(0.0),
+    },
+];
+
+/// Returns the metric with the given key and a tolerance, parsed from `key=tolerance`,
+/// e.g. `q30=2` to tolerate a drop of the %Q30 bases by 2 points.
+pub(crate) fn parse_tolerance(text: &str) -> Result<(&'static str, f64), String> {
+    let (key, tolerance) = text
+        .split_once('=')
+        .ok_or_else(|| format!("{} is not given as METRIC=TOLERANCE.", text))?;
+    let metric = METRICS
+        .iter()
+        .find(|metric| metric.key == key)
+        .ok_or_else(|| {
+            format!(
+                "Unknown metric {}, expected one of {}.",
+                key,
+                METRICS.iter().map(|metric| metric.key).join(", ")
+            )
+        })?;
+    match tolerance.parse::<f64>() {
+        Ok(tolerance) if tolerance >= 0.0 => Ok((metric.key, tolerance)),
+        _ => Err(format!("Invalid tolerance {} of {}.", tolerance, key)),
+    }
+}
+
+/// The values of a metric in both samples.
+pub(crate) struct Delta {
+    pub(crate) metric: &'static Metric,
+    pub(crate) before: f64,
+    pub(crate) after: f64,
+    /// The tolerance of the metric, unless replaced by a given one.
+    pub(crate) tolerance: Option<f64>,
+}
+
+impl Delta {
+    pub(crate) fn change(&self) -> f64 {
+        self.after - self.before
+    }
+
+    /// Returns whether the metric got worse by more than its tolerance.
+    pub(crate) fn is_regression(&self) -> bool {
+        let worsening = match self.metric.worse {
+            Worse::Lower => -self.change(),
+            Worse::Higher => self.change(),
+            Worse::Either => self.change().abs(),
+        };
+        self.tolerance
+            .is_some_and(|tolerance| worsening > tolerance)
+    }
+}
+
+/// The statuses of a module in both samples, empty if it is not reported for one.
+pub(crate) struct StatusChange {
+    pub(crate) module: String,
+    pub(crate) before: String,
+    pub(crate) after: String,
+}
+
+impl StatusChange {
+    /// Returns whether the status got more severe.
+    pub(crate) fn is_regression(&self) -> bool {
+        let severity = |status: &str| STATUSES.iter().position(|&s| s == status);
+        severity(&self.after) > severity(&self.before)
+    }
+}
+
+/// The differences between the JSON results of two files, e.g. before and after
+/// trimming.
+pub(crate) struct Comparison {
+    /// Metrics reported for both samples.
+    pub(crate) metrics: Vec<Delta>,
+    /// Modules with a status in either sample.
+    pub(crate) statuses: Vec<StatusChange>,
+}
+
+impl Comparison {
+    /// Compares the results with the given tolerances of metrics by their key, which
+    /// replace the tolerances of these metrics.
+    pub(crate) fn new(before: &Value, after: &Value, tolerances: &[(&str, f64)]) -> Self {
+        let metrics = METRICS
+            .iter()
+            .filter_map(|metric| {
+                Some(Delta {
+                    metric,
+                    before: before.pointer(metric.pointer)?.as_f64()?,
+                    after: after.pointer(metric.pointer)?.as_f64()?,
+                    tolerance: tolerances
+                        .iter()
+                        .rev()
+                        .find(|(key, _)| *key == metric.key)
+                        .map_or(metric.tolerance, |&(_, tolerance)| Some(tolerance)),
+                })
+            })
+            .collect();
+        let status = |results: &Value, module: &str| {
+            results["modules"][module]["status"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string()
+        };
+        let mut modules = Vec::new();
+        for results in [before, after] {
+            for (module, data) in results["modules"].as_object().into_iter().flatten() {
+                if data["status"].is_string() && !modules.contains(module) {
+                    modules.push(module.clone());
+                }
+            }
+        }
+        let statuses = modules
+            .into_iter()
+            .map(|module| StatusChange {
+                before: status(before, &module),
+                after: status(after, &module),
+                module,
+            })
+            .collect();
+        Comparison { metrics, statuses }
+    }
+
+    /// Returns the number of regressed metrics and module statuses.
+    pub(crate) fn regressions(&self) -> usize {
+        self.metrics
+            .iter()
+            .filter(|delta| delta.is_regression())
+            .count()
+            + self
+                .statuses
+                .iter()
+                .filter(|change| change.is_regression())
+                .count()
+    }
+
+    pub(crate) fn to_json(&self) -> Value {
+        json!({
+            "regressions": self.regressions(),
+            "metrics": self
+                .metrics
+                .iter()
+                .map(|delta| json!({
+                    "key": delta.metric.key,
+                    "name": delta.metric.name,
+                    "before": delta.before,
+                    "after": delta.after,
+                    "change": delta.change(),
+                    "tolerance": delta.tolerance,
+                    "regression": delta.is_regression(),
+                }))
+                .collect::<Vec<_>>(),
+            "statuses": self
+                .statuses
+                .iter()
+                .map(|change| json!({
+                    "module": change.module,
+                    "before": change.before,
+                    "after": change.after,
+                    "regression": change.is_regression(),
+                }))
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_tolerance, Comparison};
+    use serde_json::json;
+    #[test]
+    fn test_comparison() {
+        let before = json!({
+            "invalid_reads": {"count": 0},
+            "basic_statistics": {"total_sequences": 1000, "gc_content": 45.0, "q30": 92.0},
+            "modules": {
+                "per_base_quality": {"status": "pass"},
+                "adapter_content": {"status": "warn"},
+                "quality_heatmap": {"data": []},
+            },
+        });
+        let after = json!({
+            "invalid_reads": {"count": 0},
+            "basic_statistics": {"total_sequences": 800, "gc_content": 48.0, "q30": 91.5},
+            "modules": {
+                "per_base_quality": {"status": "fail"},
+                "adapter_content": {"status": "pass"},
+            },
+        });
+        let comparison = Comparison::new(&before, &after, &[]);
+        let regressions = comparison
+            .metrics
+            .iter()
+            .map(|delta| (delta.metric.key, delta.is_regression()))
+            .collect::<Vec<_>>();
+        // Fewer reads are no regression and a Q30 drop of half a point is tolerated
+        assert_eq!(
+            regressions,
+            [
+                ("total_sequences", false),
+                ("gc_content", true),
+                ("q30", false),
+                ("invalid_reads", false),
+            ]
+        );
+        let modules = comparison
+            .statuses
+            .iter()
+            .map(|change| (change.module.as_str(), change.is_regression()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            modules,
+            [("adapter_content", false), ("per_base_quality", true)]
+        );
+        assert_eq!(comparison.regressions(), 2);
+        // Given tolerances also apply to metrics which only describe the samples
+        let tolerances = [
+            parse_tolerance("total_sequences=100").unwrap(),
+            ("gc_content", 5.0),
+        ];
+        let comparison = Comparison::new(&before, &after, &tolerances);
+        assert!(comparison.metrics[0].is_regression());
+        assert!(!comparison.metrics[1].is_regression());
+        assert!(parse_tolerance("q30").is_err());
+        assert!(parse_tolerance("q40=1").is_err());
+        assert!(parse_tolerance("q30=-1").is_err());
+    }
+}
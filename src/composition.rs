@@ -0,0 +1,526 @@
+use crate::bins::{group_counts, Binning};
+use crate::input::Record;
+use crate::modules::{Module, ModuleResult, QcModule, Threshold, Thresholds};
+use crate::process::most_severe;
+use crate::qc::Status;
+use crate::simd::count_bases;
+use rustc_hash::FxHashMap as HashMap;
+
+#[allow(unused)]
+pub(crate) const BASES: [char; 5] = ['A', 'C', 'G', 'T', 'N'];
+pub(crate) const A: usize = 0;
+pub(crate) const C: usize = 1;
+pub(crate) const G: usize = 2;
+pub(crate) const T: usize = 3;
+pub(crate) const N: usize = 4;
+
+/// Reads with more ACGT bases are counted by their rounded GC content.
+const MAX_MODELED_LENGTH: usize = 1000;
+
+/// Counts bases per position bin.
+pub(crate) struct CompositionCounter {
+    binning: Binning,
+    /// Counts of each base in `BASES` at each position bin, except for the pending counts.
+    base_content: Vec<[usize; 5]>,
+    /// Counts of A, C, G and T at each position of the reads counted with vectorized
+    /// instructions since the last flush, only used without adaptive binning.
+    pending: [Vec<u32>; 4],
+    /// Number of pending reads per read length, giving the number of pending N bases.
+    pending_lengths: Vec<usize>,
+    pending_reads: u32,
+    /// Difference in percent between A and T or G and C at any position at which the
+    /// module warns or fails.
+    threshold: Threshold,
+}
+
+impl Default for CompositionCounter {
+    fn default() -> Self {
+        CompositionCounter::new(
+            Binning::default(),
+            Thresholds::default().base_content_difference,
+        )
+    }
+}
+
+impl CompositionCounter {
+    pub(crate) fn new(binning: Binning, threshold: Threshold) -> Self {
+        CompositionCounter {
+            binning,
+            base_content: Vec::new(),
+            pending: [Vec::new(), Vec::new(), Vec::new(), Vec::new()],
+            pending_lengths: Vec::new(),
+            pending_reads: 0,
+            threshold,
+        }
+    }
+
+    pub(crate) fn observe(&mut self, seq: &[u8]) {
+        if self.binning != Binning::Adaptive {
+            if self.pending_lengths.len() <= seq.len() {
+                for pending in self.pending.iter_mut() {
+                    pending.resize(seq.len(), 0);
+                }
+                self.pending_lengths.resize(seq.len() + 1, 0);
+            }
+            self.pending_lengths[seq.len()] += 1;
+            count_bases(seq, &mut self.pending);
+            // Flushed before any of the 32-bit counts can overflow
+            self.pending_reads += 1;
+            if self.pending_reads == u32::MAX {
+                self.flush();
+            }
+        } else {
+            let bins = self.binning.bins(seq.len());
+            if self.base_content.len() < bins {
+                self.base_content.resize(bins, [0; 5]);
+            }
+            for (pos, &base) in seq.iter().enumerate() {
+                self.base_content[self.binning.bin(pos)][base_index(base)] += 1;
+            }
+        }
+    }
+
+    /// Moves the pending counts to the base content.
+    fn flush(&mut self) {
+        let pending = self.pending_content();
+        add_content(&mut self.base_content, &pending);
+        for pending in self.pending.iter_mut() {
+            pending.iter_mut().for_each(|count| *count = 0);
+        }
+        self.pending_lengths.iter_mut().for_each(|count| *count = 0);
+        self.pending_reads = 0;
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &CompositionCounter) {
+        add_content(&mut self.base_content, &other.base_content());
+    }
+
+    /// Returns the counts of each base in `BASES` at each position bin.
+    pub(crate) fn base_content(&self) -> Vec<[usize; 5]> {
+        let mut content = self.base_content.clone();
+        add_content(&mut content, &self.pending_content());
+        content
+    }
+
+    /// Returns the pending counts of each base in `BASES` at each position.
+    fn pending_content(&self) -> Vec<[usize; 5]> {
+        let mut content = vec![[0; 5]; self.pending[0].len()];
+        // Bases other than ACGT make up the rest of the reads covering a position
+        let mut coverage = 0;
+        for pos in (0..content.len()).rev() {
+            coverage += self.pending_lengths[pos + 1];
+            let mut acgt = 0;
+            for (base, pending) in self.pending.iter().enumerate() {
+                content[pos][base] = pending[pos] as usize;
+                acgt += pending[pos] as usize;
+            }
+            content[pos][N] = coverage - acgt;
+        }
+        content
+    }
+
+    /// Returns the counts of each base in `BASES` over all positions.
+    pub(crate) fn totals(&self) -> [usize; 5] {
+        let mut totals = [0; 5];
+        for counts in &self.base_content() {
+            for (total, count) in totals.iter_mut().zip(counts) {
+                *total += count;
+            }
+        }
+        totals
+    }
+}
+
+impl QcModule for CompositionCounter {
+    fn observe(&mut self, record: &Record) {
+        self.observe(&record.seq());
+    }
+
+    fn is_mergeable() -> bool {
+        true
+    }
+
+    fn merge(&mut self, other: &dyn QcModule) {
+        if let Some(other) = other.downcast_ref::<CompositionCounter>() {
+            self.merge(other);
+        }
+    }
+
+    fn finish(&self) -> ModuleResult {
+        let content = self.base_content();
+        let groups = self.binning.groups_of(content.len());
+        let mut status = "pass";
+        let mut rows = Vec::new();
+        for (bins, counts) in group_counts(&content, &groups) {
+            let pct = acgt_percentages(&counts);
+            let difference = f64::max((pct[A] - pct[T]).abs(), (pct[G] - pct[C]).abs());
+            status = most_severe([status, self.threshold.above(difference)]);
+            rows.push([
+                self.binning.label(&bins),
+                format!("{:.2}", pct[G]),
+                format!("{:.2}", pct[A]),
+                format!("{:.2}", pct[T]),
+                format!("{:.2}", pct[C]),
+            ]);
+        }
+        let mut result = ModuleResult::new(Module::PerBaseContent.name(), Status::parse(status))
+            .with_columns(&["Base", "G", "A", "T", "C"]);
+        for row in &rows {
+            result = result.with_row(row);
+        }
+        result
+    }
+}
+
+/// Returns the percentage of A, C, G and T among these bases, in the order of `BASES`.
+pub(crate) fn acgt_percentages(counts: &[usize; 5]) -> [f64; 4] {
+    let acgt = counts[A] + counts[C] + counts[G] + counts[T];
+    [A, C, G, T].map(|base| match acgt {
+        0 => 0.0,
+        acgt => counts[base] as f64 / acgt as f64 * 100.0,
+    })
+}
+
+/// Counts reads per GC content.
+pub(crate) struct GcContentCounter {
+    /// Number of reads per percentage of G and C among their ACGT bases.
+    gc_content: Vec<f64>,
+    /// Percentages and their shares for each GC count, per number of ACGT bases.
+    gc_models: HashMap<usize, Vec<Vec<(usize, f64)>>>,
+    /// Percentage of reads deviating from a normal distribution at which the module
+    /// warns or fails.
+    threshold: Threshold,
+}
+
+impl Default for GcContentCounter {
+    fn default() -> Self {
+        GcContentCounter::new(Thresholds::default().gc_deviation)
+    }
+}
+
+impl GcContentCounter {
+    pub(crate) fn new(threshold: Threshold) -> Self {
+        GcContentCounter {
+            gc_content: vec![0.0; 101],
+            gc_models: HashMap::default(),
+            threshold,
+        }
+    }
+
+    pub(crate) fn observe(&mut self, seq: &[u8]) {
+        let mut counts = [0_usize; 5];
+        for &base in seq {
+            counts[base_index(base)] += 1;
+        }
+        let acgt = counts[A] + counts[C] + counts[G] + counts[T];
+        let gc = counts[G] + counts[C];
+        if acgt > MAX_MODELED_LENGTH {
+            // Several GC counts round to each percentage, so there are no gaps to avoid
+            self.gc_content[(gc * 100 + acgt / 2) / acgt] += 1.0;
+        } else if acgt > 0 {
+            let model = self.gc_models.entry(acgt).or_insert_with(|| gc_model(acgt));
+            for &(pct, share) in &model[gc] {
+                self.gc_content[pct] += share;
+            }
+        }
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &GcContentCounter) {
+        for (count, other) in self.gc_content.iter_mut().zip(&other.gc_content) {
+            *count += other;
+        }
+    }
+
+    /// Returns the number of reads for each GC content from 0 to 100%.
+    pub(crate) fn gc_content(&self) -> &[f64] {
+        &self.gc_content
+    }
+}
+
+impl QcModule for GcContentCounter {
+    fn observe(&mut self, record: &Record) {
+        self.observe(&record.seq());
+    }
+
+    fn is_mergeable() -> bool {
+        true
+    }
+
+    fn merge(&mut self, other: &dyn QcModule) {
+        if let Some(other) = other.downcast_ref::<GcContentCounter>() {
+            self.merge(other);
+        }
+    }
+
+    fn finish(&self) -> ModuleResult {
+        let status = self.threshold.above(gc_deviation(&self.gc_content));
+        let mut result =
+            ModuleResult::new(Module::PerSequenceGcContent.name(), Status::parse(status))
+                .with_columns(&["GC Content", "Count"]);
+        for (gc, count) in self.gc_content.iter().enumerate() {
+            result = result.with_row(&[gc.to_string(), format!("{:.1}", count)]);
+        }
+        result
+    }
+}
+
+/// Counts the N bases at each position bin, which are all bases but A, C, G and T.
+pub(crate) struct NContentCounter {
+    binning: Binning,
+    /// Number of N bases at each position bin.
+    n: Vec<usize>,
+    /// Number of reads ending in each position bin and of their bases in it.
+    ends: Vec<(usize, usize)>,
+    /// Percentage of N at any position at which the module warns or fails.
+    threshold: Threshold,
+}
+
+impl Default for NContentCounter {
+    fn default() -> Self {
+        NContentCounter::new(Binning::default(), Thresholds::default().n_content)
+    }
+}
+
+impl NContentCounter {
+    pub(crate) fn new(binning: Binning, threshold: Threshold) -> Self {
+        NContentCounter {
+            binning,
+            n: Vec::new(),
+            ends: Vec::new(),
+            threshold,
+        }
+    }
+
+    pub(crate) fn observe(&mut self, seq: &[u8]) {
+        let bins = self.binning.bins(seq.len());
+        if bins == 0 {
+            return;
+        }
+        if self.ends.len() < bins {
+            self.ends.resize(bins, (0, 0));
+            self.n.resize(bins, 0);
+        }
+        let last = &mut self.ends[bins - 1];
+        last.0 += 1;
+        last.1 += seq.len() - self.binning.start(bins - 1);
+        // Most reads have no N, so only the positions of the others are looked at
+        if seq.iter().any(|&base| base_index(base) == N) {
+            for (pos, &base) in seq.iter().enumerate() {
+                if base_index(base) == N {
+                    self.n[self.binning.bin(pos)] += 1;
+                }
+            }
+        }
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &NContentCounter) {
+        if self.ends.len() < other.ends.len() {
+            self.ends.resize(other.ends.len(), (0, 0));
+            self.n.resize(other.n.len(), 0);
+        }
+        for (end, other) in self.ends.iter_mut().zip(&other.ends) {
+            *end = (end.0 + other.0, end.1 + other.1);
+        }
+        for (n, other) in self.n.iter_mut().zip(&other.n) {
+            *n += other;
+        }
+    }
+
+    /// Returns the number of N bases and of all bases at each position bin.
+    pub(crate) fn content(&self) -> Vec<[usize; 2]> {
+        let mut content = vec![[0; 2]; self.ends.len()];
+        // Reads ending after a bin cover all of its positions
+        let mut longer = 0;
+        for bin in (0..self.ends.len()).rev() {
+            let width = self.binning.start(bin + 1) - self.binning.start(bin);
+            content[bin] = [self.n[bin], longer * width + self.ends[bin].1];
+            longer += self.ends[bin].0;
+        }
+        content
+    }
+}
+
+impl QcModule for NContentCounter {
+    fn observe(&mut self, record: &Record) {
+        self.observe(&record.seq());
+    }
+
+    fn is_mergeable() -> bool {
+        true
+    }
+
+    fn merge(&mut self, other: &dyn QcModule) {
+        if let Some(other) = other.downcast_ref::<NContentCounter>() {
+            self.merge(other);
+        }
+    }
+
+    fn finish(&self) -> ModuleResult {
+        let content = self.content();
+        let groups = self.binning.groups_of(content.len());
+        let mut status = "pass";
+        let mut rows = Vec::new();
+        for (bins, [n, total]) in group_counts(&content, &groups) {
+            let pct = n as f64 / total as f64 * 100.0;
+            status = most_severe([status, self.threshold.above(pct)]);
+            rows.push([self.binning.label(&bins), format!("{:.2}", pct)]);
+        }
+        let mut result = ModuleResult::new(Module::PerBaseNContent.name(), Status::parse(status))
+            .with_columns(&["Base", "N-Count"]);
+        for row in &rows {
+            result = result.with_row(row);
+        }
+        result
+    }
+}
+
+/// Adds the base counts elementwise, extending `content` to the positions of `other`.
+fn add_content(content: &mut Vec<[usize; 5]>, other: &[[usize; 5]]) {
+    if content.len() < other.len() {
+        content.resize(other.len(), [0; 5]);
+    }
+    for (counts, other) in content.iter_mut().zip(other) {
+        for (count, other) in counts.iter_mut().zip(other) {
+            *count += other;
+        }
+    }
+}
+
+/// Returns for every GC count of a read with the given number of ACGT bases the
+/// percentages it is spread over. Like in FastQC, each percentage is shared by all GC
+/// counts that could be rounded to it, so that short reads leave no gaps in the histogram.
+fn gc_model(length: usize) -> Vec<Vec<(usize, f64)>> {
+    let range = |gc: usize| {
+        let pct = |gc: f64| (gc.clamp(0.0, length as f64) * 100.0 / length as f64).round() as usize;
+        pct(gc as f64 - 0.5)..=pct(gc as f64 + 0.5)
+    };
+    let mut claims = [0_usize; 101];
+    for gc in 0..=length {
+        for pct in range(gc) {
+            claims[pct] += 1;
+        }
+    }
+    (0..=length)
+        .map(|gc| {
+            range(gc)
+                .map(|pct| (pct, 1.0 / claims[pct] as f64))
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns the index of a base in `BASES`, with every non-ACGT base counted as N.
+pub(crate) fn base_index(base: u8) -> usize {
+    match base {
+        b'A' | b'a' => A,
+        b'C' | b'c' => C,
+        b'G' | b'g' => G,
+        b'T' | b't' => T,
+        _ => N,
+    }
+}
+
+/// Returns the percentage of reads deviating from a normal distribution fitted to the
+/// GC content histogram, which FastQC uses to flag contaminated libraries.
+pub(crate) fn gc_deviation(hist: &[f64]) -> f64 {
+    let total = hist.iter().sum::<f64>();
+    if total == 0.0 {
+        return 0.0;
+    }
+    let mean = hist
+        .iter()
+        .enumerate()
+        .map(|(gc, &count)| gc as f64 * count)
+        .sum::<f64>()
+        / total;
+    let variance = hist
+        .iter()
+        .enumerate()
+        .map(|(gc, &count)| (gc as f64 - mean).powi(2) * count)
+        .sum::<f64>()
+        / total;
+    let sd = variance.sqrt().max(f64::EPSILON);
+    // Normalized over the histogram bins, so that a very narrow fit still sums up to total
+    let density = (0..hist.len())
+        .map(|gc| (-(gc as f64 - mean).powi(2) / (2.0 * sd * sd)).exp())
+        .collect::<Vec<_>>();
+    let norm = density.iter().sum::<f64>();
+    hist.iter()
+        .zip(&density)
+        .map(|(&count, &density)| (count - total * density / norm).abs())
+        .sum::<f64>()
+        / total
+        * 100.0
+}
+
+#[cfg(test)]
+mod test {
+    use super::{gc_deviation, CompositionCounter, GcContentCounter, NContentCounter, N};
+    use crate::bins::Binning;
+    use crate::modules::{QcModule, Thresholds};
+    use crate::qc::Status;
+    #[test]
+    fn test_composition() {
+        let threshold = Thresholds::default().base_content_difference;
+        let mut counter = CompositionCounter::new(Binning::Exact, threshold);
+        counter.observe(b"ACGN");
+        counter.observe(b"GG");
+        assert_eq!(counter.base_content()[0], [1, 0, 1, 0, 0]);
+        assert_eq!(counter.base_content()[3][N], 1);
+        assert_eq!(counter.totals(), [1, 1, 3, 0, 1]);
+        counter.observe(&[b'G'; 2000]);
+        counter.flush();
+        counter.observe(b"T");
+        assert_eq!(counter.base_content()[0], [1, 0, 2, 1, 0]);
+        assert_eq!(counter.totals(), [1, 1, 2003, 1, 1]);
+        let result = counter.finish();
+        assert_eq!(result.status, Status::Fail);
+        assert_eq!(result.rows[0], ["1", "50.00", "25.00", "25.00", "0.00"]);
+    }
+    #[test]
+    fn test_gc_content() {
+        let mut counter = GcContentCounter::default();
+        counter.observe(b"ACGN");
+        // ACG has a GC content of 67%, which is the only GC count rounded to 67%
+        assert_eq!(counter.gc_content()[49], 0.0);
+        assert_eq!(counter.gc_content()[67], 1.0);
+        let mut other = GcContentCounter::default();
+        other.observe(b"GG");
+        other.observe(&[b'G'; 2000]);
+        counter.merge(&other);
+        assert_eq!(counter.gc_content()[50], 0.5);
+        assert_eq!(counter.gc_content()[100], 2.0);
+        assert_eq!(counter.finish().status, Status::Fail);
+    }
+    #[test]
+    fn test_n_content() {
+        let mut counter = NContentCounter::new(Binning::Adaptive, Thresholds::default().n_content);
+        counter.observe(&[b'A'; 20]);
+        counter.observe(b"");
+        let mut other = NContentCounter::new(Binning::Adaptive, Thresholds::default().n_content);
+        let mut seq = vec![b'C'; 17];
+        seq[16] = b'N';
+        other.observe(&seq);
+        counter.merge(&other);
+        // The bin of positions 16 and 17 has 2 bases of the first read and 1 of the other
+        assert_eq!(counter.content()[15], [0, 2]);
+        assert_eq!(counter.content()[16], [1, 3]);
+        assert_eq!(counter.content()[17], [0, 2]);
+        assert_eq!(counter.content().len(), 18);
+        assert_eq!(counter.finish().status, Status::Fail);
+    }
+    #[test]
+    fn test_gc_deviation() {
+        let normal = (0..=100)
+            .map(|gc: i32| 1000.0 * (-((gc - 50) as f64).powi(2) / 50.0).exp())
+            .collect::<Vec<_>>();
+        assert!(gc_deviation(&normal) < 1.0);
+        let mut bimodal = vec![0.0; 101];
+        bimodal[30] = 100.0;
+        bimodal[70] = 100.0;
+        assert!(gc_deviation(&bimodal) > 30.0);
+    }
+}
@@ -0,0 +1,165 @@
+use needletail::Sequence;
+
+/// Minimal length of a partial alignment to be reported as a hit.
+const MIN_MATCH_LENGTH: usize = 20;
+
+/// A known contaminant such as an adapter or primer sequence.
+pub(crate) struct Contaminant {
+    pub(crate) name: String,
+    forward: Vec<u8>,
+    reverse: Vec<u8>,
+}
+
+/// The best alignment of a sequence against a contaminant.
+#[derive(Debug, PartialEq)]
+pub(crate) struct ContaminantHit<'a> {
+    pub(crate) name: &'a str,
+    pub(crate) length: usize,
+    pub(crate) identity: f64,
+}
+
+impl std::fmt::Display for ContaminantHit<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({:.0}% over {}bp)",
+            self.name, self.identity, self.length
+        )
+    }
+}
+
+impl Contaminant {
+    pub(crate) fn new(name: &str, sequence: &[u8]) -> Self {
+        let forward = sequence.to_ascii_uppercase();
+        let reverse = forward.reverse_complement();
+        Contaminant {
+            name: name.to_string(),
+            forward,
+            reverse,
+        }
+    }
+
+    /// Parses a contaminant list in FastQC's format.
+    pub(crate) fn parse_list(list: &str) -> Vec<Contaminant> {
+        parse_sequence_list(list)
+            .map(|(name, sequence)| Contaminant::new(name, sequence.as_bytes()))
+            .collect()
+    }
+
+    /// The contaminant list shipped with fastqc-rs.
+    pub(crate) fn default_list() -> Vec<Contaminant> {
+        Contaminant::parse_list(include_str!("resources/contaminant_list.txt"))
+    }
+
+    /// Finds the longest ungapped alignment with at most one mismatch between the
+    /// query and either strand of the contaminant.
+    pub(crate) fn find_match(&self, query: &[u8]) -> Option<ContaminantHit<'_>> {
+        [&self.forward, &self.reverse]
+            .iter()
+            .filter_map(|subject| longest_match(query, subject))
+            .max_by_key(|&(length, mismatches)| (length, std::cmp::Reverse(mismatches)))
+            .map(|(length, mismatches)| ContaminantHit {
+                name: &self.name,
+                length,
+                identity: (length - mismatches) as f64 / length as f64 * 100.0,
+            })
+    }
+}
+
+/// Parses lists of named sequences in FastQC's format, i.e. one name and sequence
+/// separated by tabs per line. Empty lines and lines starting with `#` are skipped.
+pub(crate) fn parse_sequence_list(list: &str) -> impl Iterator<Item = (&str, &str)> {
+    list.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let (name, sequence) = line.split_once('\t')?;
+            Some((name.trim(), sequence.trim()))
+        })
+}
+
+/// Returns the contaminant that aligns best to the given sequence.
+pub(crate) fn best_hit<'a>(
+    contaminants: &'a [Contaminant],
+    query: &[u8],
+) -> Option<ContaminantHit<'a>> {
+    let query = query.to_ascii_uppercase();
+    // Iterate in reverse so that ties resolve to the first entry of the list
+    contaminants
+        .iter()
+        .rev()
+        .filter_map(|contaminant| contaminant.find_match(&query))
+        .max_by(|a, b| {
+            (a.length, a.identity)
+                .partial_cmp(&(b.length, b.identity))
+                .unwrap()
+        })
+}
+
+/// Returns length and mismatches of the longest alignment with at most one mismatch.
+fn longest_match(query: &[u8], subject: &[u8]) -> Option<(usize, usize)> {
+    if query.is_empty() || subject.is_empty() {
+        return None;
+    }
+    let contained = |a: &[u8], b: &[u8]| a.len() <= b.len() && b.windows(a.len()).any(|w| w == a);
+    if contained(query, subject) {
+        return Some((query.len(), 0));
+    }
+    if contained(subject, query) {
+        return Some((subject.len(), 0));
+    }
+    let mut best: Option<(usize, usize)> = None;
+    let offsets = -(query.len() as isize - 1)..subject.len() as isize;
+    for offset in offsets {
+        let mut start = 0;
+        let mut mismatch = None;
+        let pairs = (0..query.len())
+            .map(|i| (i, i as isize + offset))
+            .filter(|&(_, j)| j >= 0 && (j as usize) < subject.len());
+        for (step, (i, j)) in pairs.enumerate() {
+            if query[i] != subject[j as usize] {
+                if let Some(previous) = mismatch {
+                    start = previous + 1;
+                }
+                mismatch = Some(step);
+            }
+            let length = step + 1 - start;
+            let mismatches = mismatch.map_or(0, |m| (m >= start) as usize);
+            if length >= MIN_MATCH_LENGTH && best.is_none_or(|(l, _)| length > l) {
+                best = Some((length, mismatches));
+            }
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod test {
+    use super::{best_hit, longest_match, Contaminant};
+    #[test]
+    fn test_default_list() {
+        let contaminants = Contaminant::default_list();
+        assert!(contaminants.len() > 50);
+        assert!(contaminants
+            .iter()
+            .any(|c| c.name == "TruSeq Universal Adapter"));
+    }
+    #[test]
+    fn test_longest_match() {
+        assert_eq!(longest_match(b"ACGT", b"TTACGTTT"), Some((4, 0)));
+        let subject = b"GATCGGAAGAGCACACGTCTGAACTCCAGTCAC";
+        let query = b"TTTTTGATCGGAAGAGCACACCTCTGAACT";
+        assert_eq!(longest_match(query, subject), Some((25, 1)));
+        assert_eq!(longest_match(b"ACGTACGTACGT", subject), None);
+    }
+    #[test]
+    fn test_best_hit() {
+        let contaminants = Contaminant::default_list();
+        let read = b"GATCGGAAGAGCACACGTCTGAACTCCAGTCACATCACGATCTCGTATGCCG";
+        let hit = best_hit(&contaminants, read).unwrap();
+        assert_eq!(hit.name, "TruSeq Adapter, Index 1");
+        assert_eq!(hit.to_string(), "TruSeq Adapter, Index 1 (100% over 52bp)");
+        let reverse = needletail::Sequence::reverse_complement(&read[..]);
+        assert_eq!(best_hit(&contaminants, &reverse).unwrap().length, 52);
+    }
+}
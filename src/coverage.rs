@@ -0,0 +1,72 @@
+/// Mean coverage of a genome of a given size estimated from the bases of the reads.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Coverage {
+    pub(crate) genome_size: u64,
+    /// Bases of all reads per base of the genome.
+    pub(crate) mean: f64,
+    /// Bases of the reads per base of the genome without those of duplicates and from
+    /// the start of adapters.
+    pub(crate) corrected: f64,
+}
+
+impl Coverage {
+    /// Estimates the coverage from the bases of the reads, of which `adapter_bases`
+    /// are adapters and `remaining` percent would remain after deduplication. The
+    /// bases of a sample are multiplied by `scale` to those of all reads.
+    pub(crate) fn new(
+        genome_size: u64,
+        bases: usize,
+        adapter_bases: usize,
+        remaining: f64,
+        scale: f64,
+    ) -> Self {
+        let genome_size = genome_size.max(1);
+        let unique = bases.saturating_sub(adapter_bases) as f64 * remaining / 100.0;
+        Coverage {
+            genome_size,
+            mean: bases as f64 * scale / genome_size as f64,
+            corrected: unique * scale / genome_size as f64,
+        }
+    }
+}
+
+/// Parses a number of bases with an optional suffix `k`, `m` or `g` for thousands,
+/// millions or billions, e.g. `3.1g` for the human genome.
+pub(crate) fn parse_bases(text: &str) -> Result<u64, String> {
+    let invalid = || {
+        format!(
+            "Invalid size {}, expected bases like 4600000, 4.6m or 3.1g.",
+            text
+        )
+    };
+    let (number, factor) = match text.trim().to_ascii_lowercase() {
+        text if text.ends_with('k') => (text[..text.len() - 1].to_string(), 1e3),
+        text if text.ends_with('m') => (text[..text.len() - 1].to_string(), 1e6),
+        text if text.ends_with('g') => (text[..text.len() - 1].to_string(), 1e9),
+        text => (text, 1.0),
+    };
+    match number.parse::<f64>() {
+        Ok(number) if number.is_finite() && number * factor >= 1.0 => {
+            Ok((number * factor).round() as u64)
+        }
+        _ => Err(invalid()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_bases, Coverage};
+    #[test]
+    fn test_coverage() {
+        // A tenth of the bases are adapters and half of the rest duplicates
+        let coverage = Coverage::new(1000, 20_000, 2000, 50.0, 2.0);
+        assert_eq!(coverage.mean, 40.0);
+        assert_eq!(coverage.corrected, 18.0);
+        assert_eq!(parse_bases("3.1g"), Ok(3_100_000_000));
+        assert_eq!(parse_bases("4.6M"), Ok(4_600_000));
+        assert_eq!(parse_bases("12k"), Ok(12_000));
+        assert_eq!(parse_bases("5000"), Ok(5000));
+        assert!(parse_bases("0").is_err());
+        assert!(parse_bases("3.1gb").is_err());
+    }
+}
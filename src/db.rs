@@ -0,0 +1,97 @@
+use crate::error::FastqcError;
+use std::path::Path;
+
+/// Metrics of a single file appended to the QC database.
+#[cfg_attr(not(feature = "sqlite"), allow(dead_code))]
+pub(crate) struct Sample<'a> {
+    pub(crate) file: &'a str,
+    pub(crate) total_reads: usize,
+    pub(crate) total_bases: usize,
+    pub(crate) q30: f64,
+    pub(crate) gc_content: f64,
+    pub(crate) statuses: &'a [(&'a str, &'static str)],
+}
+
+#[cfg(not(feature = "sqlite"))]
+pub(crate) fn append(_: &Path, _: &Sample) -> Result<(), FastqcError> {
+    Err("--db requires fastqc-rs to be built with the `sqlite` feature.".into())
+}
+
+/// Appends the sample with the current time as run date to the SQLite database at
+/// `path`, creating it if needed. Module statuses are kept in a separate table, so that
+/// samples with and without e.g. tile information can be queried alike.
+#[cfg(feature = "sqlite")]
+pub(crate) fn append(path: &Path, sample: &Sample) -> Result<(), FastqcError> {
+    use rusqlite::{params, Connection};
+    let mut connection = Connection::open(path)?;
+    connection.execute_batch(
+        "CREATE TABLE IF NOT EXISTS samples (
+            id INTEGER PRIMARY KEY,
+            run_date TEXT NOT NULL,
+            file TEXT NOT NULL,
+            version TEXT NOT NULL,
+            total_reads INTEGER NOT NULL,
+            total_bases INTEGER NOT NULL,
+            q30 REAL NOT NULL,
+            gc_content REAL NOT NULL
+        );
+        CREATE TABLE IF NOT EXISTS module_statuses (
+            sample_id INTEGER NOT NULL REFERENCES samples (id),
+            module TEXT NOT NULL,
+            status TEXT NOT NULL
+        );",
+    )?;
+    let transaction = connection.transaction()?;
+    transaction.execute(
+        "INSERT INTO samples (run_date, file, version, total_reads, total_bases, q30, gc_content)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            chrono::Local::now().to_rfc3339(),
+            sample.file,
+            env!("CARGO_PKG_VERSION"),
+            sample.total_reads as i64,
+            sample.total_bases as i64,
+            sample.q30,
+            sample.gc_content,
+        ],
+    )?;
+    let id = transaction.last_insert_rowid();
+    for (module, status) in sample.statuses {
+        transaction.execute(
+            "INSERT INTO module_statuses (sample_id, module, status) VALUES (?1, ?2, ?3)",
+            params![id, module, status],
+        )?;
+    }
+    transaction.commit()?;
+    Ok(())
+}
+
+#[cfg(all(test, feature = "sqlite"))]
+mod test {
+    use super::{append, Sample};
+    #[test]
+    fn test_append() {
+        let path = std::env::temp_dir().join(format!("fqc-test-{}.sqlite", std::process::id()));
+        let sample = Sample {
+            file: "a.fastq",
+            total_reads: 2,
+            total_bases: 100,
+            q30: 90.0,
+            gc_content: 50.0,
+            statuses: &[("Basic Statistics", "pass"), ("Kmer Content", "warn")],
+        };
+        append(&path, &sample).unwrap();
+        append(&path, &sample).unwrap();
+        let connection = rusqlite::Connection::open(&path).unwrap();
+        let count = |table: &str| -> i64 {
+            connection
+                .query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| {
+                    row.get(0)
+                })
+                .unwrap()
+        };
+        assert_eq!(count("samples"), 2);
+        assert_eq!(count("module_statuses"), 4);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,594 @@
+use crate::input::Record;
+use crate::modules::{Module, ModuleResult, QcModule, Threshold, Thresholds};
+use crate::qc::Status;
+use crate::sketch::{hash, CountMinSketch, HyperLogLog};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+/// Sequences longer than this are truncated before counting duplicates.
+const TRUNCATE_ABOVE: usize = 75;
+/// Length the long sequences are truncated to.
+const TRUNCATED_LENGTH: usize = 50;
+
+/// Labels of the duplication level bins, following FastQC.
+pub(crate) const LEVELS: [&str; 16] = [
+    "1", "2", "3", "4", "5", "6", "7", "8", "9", ">10", ">50", ">100", ">500", ">1k", ">5k", ">10k",
+];
+
+/// Default memory for counting duplicates, in bytes.
+pub(crate) const DEFAULT_MEMORY: usize = 1 << 30;
+/// Approximate memory of a counted sequence besides its bases, in bytes.
+const ENTRY_OVERHEAD: usize = 48;
+/// Maximal number of distinct sequences sampled for estimating the duplication levels.
+const SAMPLE_SIZE: usize = 1 << 16;
+/// Number of increasing fractions of the reads subsampled for the saturation curve.
+const SATURATION_STEPS: usize = 20;
+/// Multiple of the sequenced reads up to which the saturation model is extrapolated.
+const EXTRAPOLATION: usize = 4;
+/// Number of distinct sequences FastQC tracks, after which only the counts of the
+/// tracked sequences grow.
+const OBSERVATION_CUTOFF: usize = 100_000;
+
+/// Counts how often every (truncated) sequence has been seen. Once the counts exceed
+/// the memory budget, they are replaced by sketches estimating the duplication levels.
+pub(crate) struct DuplicationCounter {
+    counts: HashMap<Vec<u8>, usize>,
+    total: usize,
+    /// Memory budget in bytes.
+    memory: usize,
+    /// Approximate memory of the exact counts in bytes.
+    used: usize,
+    sketch: Option<Sketch>,
+    /// Whether only the first `OBSERVATION_CUTOFF` distinct sequences are counted and
+    /// the levels extrapolated from them like FastQC.
+    fastqc: bool,
+    /// Number of sequences seen until the last one counted while fewer than
+    /// `OBSERVATION_CUTOFF` distinct sequences were tracked, in the FastQC mode.
+    count_at_limit: usize,
+    /// Percentage of reads remaining after deduplication below which the module warns
+    /// or fails.
+    threshold: Threshold,
+}
+
+/// Estimates of the number of distinct sequences and of the counts of a uniform sample
+/// of them.
+struct Sketch {
+    distinct: HyperLogLog,
+    counts: CountMinSketch,
+    /// Hashes of the sampled distinct sequences, which are all hashes below the threshold.
+    sample: HashSet<u64>,
+    threshold: u64,
+}
+
+/// The binned duplication levels of a library.
+pub(crate) struct DuplicationLevels {
+    /// Percentage of distinct sequences per level.
+    pub(crate) deduplicated: [f64; 16],
+    /// Percentage of all sequences per level.
+    pub(crate) total: [f64; 16],
+    /// Estimated percentage of the library remaining after deduplication.
+    pub(crate) remaining: f64,
+}
+
+/// Number of distinct sequences at increasing sequencing depths of the library.
+pub(crate) struct Saturation {
+    /// Expected number of distinct sequences in random subsamples of the reads, per
+    /// number of subsampled reads.
+    pub(crate) observed: Vec<(usize, f64)>,
+    /// Number of distinct sequences in the library estimated by the Lander-Waterman
+    /// model like Picard, or `None` without duplicates.
+    pub(crate) library_size: Option<f64>,
+    /// Number of distinct sequences of the model up to `EXTRAPOLATION` times the reads.
+    pub(crate) model: Vec<(usize, f64)>,
+}
+
+impl Default for DuplicationCounter {
+    fn default() -> Self {
+        DuplicationCounter::new(DEFAULT_MEMORY, Thresholds::default().duplication_remaining)
+    }
+}
+
+impl DuplicationCounter {
+    /// Creates a counter using about the given number of bytes.
+    pub(crate) fn new(memory: usize, threshold: Threshold) -> Self {
+        DuplicationCounter {
+            counts: HashMap::default(),
+            total: 0,
+            memory,
+            used: 0,
+            sketch: None,
+            fastqc: false,
+            count_at_limit: 0,
+            threshold,
+        }
+    }
+
+    /// Creates a counter following the algorithm of FastQC, which tracks the first
+    /// 100,000 distinct sequences and corrects their levels for the sequences missed
+    /// afterwards. Its levels are comparable with those of FastQC, but depend on the
+    /// order of the sequences.
+    pub(crate) fn fastqc(threshold: Threshold) -> Self {
+        DuplicationCounter {
+            fastqc: true,
+            ..DuplicationCounter::new(DEFAULT_MEMORY, threshold)
+        }
+    }
+
+    /// Returns the memory budget in bytes.
+    #[cfg(test)]
+    pub(crate) fn memory(&self) -> usize {
+        self.memory
+    }
+
+    /// Returns whether the sequences have to be observed in the order of the file.
+    pub(crate) fn is_ordered(&self) -> bool {
+        self.fastqc
+    }
+
+    pub(crate) fn observe(&mut self, seq: &[u8]) {
+        self.total += 1;
+        if !self.fastqc {
+            self.add(truncate(seq), 1);
+            return;
+        }
+        // Repeats of the tracked sequences are still counted once the cutoff is reached,
+        // but the extrapolation only corrects for the sequences up to it
+        let frozen = self.counts.len() >= OBSERVATION_CUTOFF;
+        let seq = truncate(seq);
+        match self.counts.get_mut(seq) {
+            Some(count) => *count += 1,
+            None if !frozen => {
+                self.counts.insert(seq.to_vec(), 1);
+            }
+            None => {}
+        }
+        if !frozen {
+            self.count_at_limit = self.total;
+        }
+    }
+
+    fn add(&mut self, seq: &[u8], count: usize) {
+        if let Some(sketch) = self.sketch.as_mut() {
+            sketch.add(hash(seq), count);
+            return;
+        }
+        match self.counts.get_mut(seq) {
+            Some(total) => *total += count,
+            None => {
+                self.counts.insert(seq.to_vec(), count);
+                self.used += seq.len() + ENTRY_OVERHEAD;
+                if self.used > self.memory {
+                    self.start_sketch();
+                }
+            }
+        }
+    }
+
+    /// Replaces the exact counts by sketches using the memory budget.
+    fn start_sketch(&mut self) {
+        let mut sketch = Sketch {
+            distinct: HyperLogLog::new(),
+            counts: CountMinSketch::with_memory(self.memory),
+            sample: HashSet::default(),
+            threshold: u64::MAX,
+        };
+        for (seq, count) in self.counts.drain() {
+            sketch.add(hash(&seq), count);
+        }
+        self.counts.shrink_to_fit();
+        self.used = 0;
+        self.sketch = Some(sketch);
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &DuplicationCounter) {
+        // Counters of the FastQC mode see all sequences in order on one thread
+        if self.fastqc {
+            debug_assert_eq!(other.total, 0);
+            return;
+        }
+        if other.sketch.is_some() && self.sketch.is_none() {
+            self.start_sketch();
+        }
+        for (seq, &count) in &other.counts {
+            self.add(seq, count);
+        }
+        if let (Some(sketch), Some(other)) = (self.sketch.as_mut(), other.sketch.as_ref()) {
+            sketch.merge(other);
+        }
+        self.total += other.total;
+    }
+
+    pub(crate) fn levels(&self) -> DuplicationLevels {
+        let mut levels = DuplicationLevels {
+            deduplicated: [0.0; 16],
+            total: [0.0; 16],
+            remaining: 100.0,
+        };
+        if self.total == 0 {
+            return levels;
+        }
+        if self.fastqc {
+            return self.fastqc_levels();
+        }
+        let sketch = match &self.sketch {
+            Some(sketch) => sketch,
+            None => {
+                let counts = self.counts.values().copied().collect::<Vec<_>>();
+                let (deduplicated, total) = level_shares(&counts);
+                levels.deduplicated = deduplicated;
+                levels.total = total;
+                levels.remaining = self.counts.len() as f64 / self.total as f64 * 100.0;
+                return levels;
+            }
+        };
+        let counts = sketch
+            .sample
+            .iter()
+            .map(|&hash| sketch.counts.estimate(hash))
+            .collect::<Vec<_>>();
+        let distinct = sketch.distinct.estimate().min(self.total as f64);
+        let (deduplicated, sampled_total) = level_shares(&counts);
+        levels.deduplicated = deduplicated;
+        // The reads of the levels with exact counts follow from the number of distinct
+        // sequences, the rest is split by the sample, which rarely contains the few
+        // very frequent sequences
+        let mut rest = 100.0;
+        for (i, share) in deduplicated.iter().enumerate().take(9) {
+            levels.total[i] =
+                (share / 100.0 * distinct * (i + 1) as f64 / self.total as f64 * 100.0).min(rest);
+            rest -= levels.total[i];
+        }
+        let sampled_rest = sampled_total[9..].iter().sum::<f64>();
+        if sampled_rest > 0.0 {
+            for (total, sampled) in levels.total.iter_mut().zip(&sampled_total).skip(9) {
+                *total = sampled / sampled_rest * rest;
+            }
+        }
+        levels.remaining = distinct / self.total as f64 * 100.0;
+        levels
+    }
+
+    /// Returns the levels of the tracked sequences like FastQC, extrapolating the number
+    /// of distinct sequences of every count to all sequences.
+    fn fastqc_levels(&self) -> DuplicationLevels {
+        let mut distinct_per_count = HashMap::<usize, usize>::default();
+        for &count in self.counts.values() {
+            *distinct_per_count.entry(count).or_insert(0) += 1;
+        }
+        let mut deduplicated = [0.0; 16];
+        let mut total = [0.0; 16];
+        for (&count, &distinct) in &distinct_per_count {
+            let corrected = corrected_count(self.count_at_limit, self.total, count, distinct);
+            deduplicated[level_index(count)] += corrected;
+            total[level_index(count)] += corrected * count as f64;
+        }
+        let deduplicated_total = deduplicated.iter().sum::<f64>();
+        let raw_total = total.iter().sum::<f64>();
+        for share in &mut deduplicated {
+            *share = *share / deduplicated_total * 100.0;
+        }
+        for share in &mut total {
+            *share = *share / raw_total * 100.0;
+        }
+        DuplicationLevels {
+            deduplicated,
+            total,
+            remaining: deduplicated_total / raw_total * 100.0,
+        }
+    }
+
+    /// Returns the saturation curve of the library, subsampling the counted or sampled
+    /// sequences which occur `c` times by the expected share `1 - (1 - p)^c` of them kept in
+    /// a subsample of a fraction `p` of the reads. The FastQC mode only counts the
+    /// first sequences, so it gives no curve.
+    pub(crate) fn saturation(&self) -> Option<Saturation> {
+        if self.fastqc {
+            return None;
+        }
+        let (counts, scale) = match &self.sketch {
+            None => (self.counts.values().copied().collect::<Vec<_>>(), 1.0),
+            Some(sketch) => {
+                let counts = sketch
+                    .sample
+                    .iter()
+                    .map(|&hash| sketch.counts.estimate(hash))
+                    .collect::<Vec<_>>();
+                let distinct = sketch.distinct.estimate().min(self.total as f64);
+                let scale = distinct / counts.len().max(1) as f64;
+                (counts, scale)
+            }
+        };
+        let mut frequencies = HashMap::<usize, usize>::default();
+        for count in counts {
+            *frequencies.entry(count).or_insert(0) += 1;
+        }
+        let observed = (1..=SATURATION_STEPS)
+            .map(|step| {
+                let fraction = step as f64 / SATURATION_STEPS as f64;
+                let distinct = frequencies
+                    .iter()
+                    .map(|(&count, &sequences)| {
+                        sequences as f64 * (1.0 - (1.0 - fraction).powi(count as i32))
+                    })
+                    .sum::<f64>();
+                (self.total * step / SATURATION_STEPS, distinct * scale)
+            })
+            .collect::<Vec<_>>();
+        let distinct = observed.last().map_or(0.0, |&(_, distinct)| distinct);
+        let library_size = library_size(self.total as f64, distinct);
+        let model = library_size.map_or_else(Vec::new, |size| {
+            (1..=SATURATION_STEPS * EXTRAPOLATION)
+                .map(|step| {
+                    let reads = self.total * step / SATURATION_STEPS;
+                    (reads, lander_waterman(size, reads as f64))
+                })
+                .collect()
+        });
+        Some(Saturation {
+            observed,
+            library_size,
+            model,
+        })
+    }
+}
+
+/// Returns the number of distinct sequences occurring `count` times among all `total`
+/// sequences, of which `observed` were seen among the first `at_limit` sequences, like
+/// FastQC. It divides them by the probability of a sequence with that count occurring
+/// among the first sequences.
+fn corrected_count(at_limit: usize, total: usize, count: usize, observed: usize) -> f64 {
+    if at_limit == total || total - observed < at_limit {
+        return observed as f64;
+    }
+    // FastQC stops once the chance of missing a sequence is too small to matter
+    let limit_of_caring = 1.0 - observed as f64 / (observed as f64 + 0.01);
+    let mut not_seeing = 1.0;
+    for i in 0..at_limit {
+        let remaining = (total - i) as f64;
+        not_seeing *= (remaining - count as f64) / remaining;
+        if not_seeing < limit_of_caring {
+            not_seeing = 0.0;
+            break;
+        }
+    }
+    observed as f64 / (1.0 - not_seeing)
+}
+
+/// Returns the number of distinct sequences expected among the given number of reads
+/// sampled from a library of the given size.
+fn lander_waterman(size: f64, reads: f64) -> f64 {
+    size * -(-reads / size).exp_m1()
+}
+
+/// Returns the library size for which the given number of reads are expected to contain
+/// the given number of distinct sequences, or `None` if they are all distinct.
+fn library_size(reads: f64, distinct: f64) -> Option<f64> {
+    if distinct <= 0.0 || distinct >= reads - 0.5 {
+        return None;
+    }
+    let mut lower = distinct;
+    let mut upper = distinct * 2.0;
+    while lander_waterman(upper, reads) < distinct {
+        lower = upper;
+        upper *= 2.0;
+    }
+    for _ in 0..100 {
+        let size = (lower + upper) / 2.0;
+        if lander_waterman(size, reads) < distinct {
+            lower = size;
+        } else {
+            upper = size;
+        }
+    }
+    Some((lower + upper) / 2.0)
+}
+
+/// Returns the percentage of distinct sequences and of all reads per level for the
+/// given sequence counts.
+fn level_shares(counts: &[usize]) -> ([f64; 16], [f64; 16]) {
+    let mut distinct_per_level = [0_usize; 16];
+    let mut total_per_level = [0_usize; 16];
+    for &count in counts {
+        let level = level_index(count);
+        distinct_per_level[level] += 1;
+        total_per_level[level] += count;
+    }
+    let distinct = counts.len() as f64;
+    let total = counts.iter().sum::<usize>() as f64;
+    let mut shares = ([0.0; 16], [0.0; 16]);
+    for i in 0..LEVELS.len() {
+        shares.0[i] = distinct_per_level[i] as f64 / distinct * 100.0;
+        shares.1[i] = total_per_level[i] as f64 / total * 100.0;
+    }
+    shares
+}
+
+impl Sketch {
+    fn add(&mut self, hash: u64, count: usize) {
+        self.distinct.add(hash);
+        self.counts.add(hash, count);
+        if hash < self.threshold && self.sample.insert(hash) && self.sample.len() > SAMPLE_SIZE {
+            // Halving the threshold keeps a uniform sample of about half the size
+            self.threshold /= 2;
+            let threshold = self.threshold;
+            self.sample.retain(|&hash| hash < threshold);
+        }
+    }
+
+    fn merge(&mut self, other: &Sketch) {
+        self.distinct.merge(&other.distinct);
+        self.counts.merge(&other.counts);
+        self.threshold = self.threshold.min(other.threshold);
+        let threshold = self.threshold;
+        self.sample
+            .extend(other.sample.iter().filter(|&&hash| hash < threshold));
+        self.sample.retain(|&hash| hash < threshold);
+        while self.sample.len() > SAMPLE_SIZE {
+            self.threshold /= 2;
+            let threshold = self.threshold;
+            self.sample.retain(|&hash| hash < threshold);
+        }
+    }
+}
+
+impl QcModule for DuplicationCounter {
+    fn observe(&mut self, record: &Record) {
+        self.observe(&record.seq());
+    }
+
+    /// Counters of the FastQC mode see all sequences in order on one thread, and are
+    /// not merged.
+    fn is_mergeable() -> bool {
+        true
+    }
+
+    fn merge(&mut self, other: &dyn QcModule) {
+        if let Some(other) = other.downcast_ref::<DuplicationCounter>() {
+            self.merge(other);
+        }
+    }
+
+    fn finish(&self) -> ModuleResult {
+        let levels = self.levels();
+        let status = Status::parse(self.threshold.below(levels.remaining));
+        let mut result =
+            ModuleResult::new(Module::DuplicationLevels.name(), status).with_columns(&[
+                "Duplication Level",
+                "Percentage of deduplicated",
+                "Percentage of total",
+            ]);
+        for (i, level) in LEVELS.iter().enumerate() {
+            result = result.with_row(&[
+                level.to_string(),
+                format!("{:.2}", levels.deduplicated[i]),
+                format!("{:.2}", levels.total[i]),
+            ]);
+        }
+        result
+    }
+}
+
+/// Truncates long sequences the way FastQC does before comparing them, so that
+/// sequencing errors towards the end of long reads do not hide duplicates.
+pub(crate) fn truncate(seq: &[u8]) -> &[u8] {
+    if seq.len() > TRUNCATE_ABOVE {
+        &seq[..TRUNCATED_LENGTH]
+    } else {
+        seq
+    }
+}
+
+/// Returns the index of the bin in `LEVELS` a sequence seen `count` times falls into.
+fn level_index(count: usize) -> usize {
+    match count {
+        0..=9 => count.saturating_sub(1),
+        10..=49 => 9,
+        50..=99 => 10,
+        100..=499 => 11,
+        500..=999 => 12,
+        1000..=4999 => 13,
+        5000..=9999 => 14,
+        _ => 15,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{lander_waterman, level_index, DuplicationCounter, OBSERVATION_CUTOFF};
+    use crate::modules::{QcModule, Thresholds};
+    use crate::qc::Status;
+    #[test]
+    fn test_level_index() {
+        assert_eq!(level_index(1), 0);
+        assert_eq!(level_index(9), 8);
+        assert_eq!(level_index(10), 9);
+        assert_eq!(level_index(499), 11);
+        assert_eq!(level_index(10_000), 15);
+    }
+    #[test]
+    fn test_levels() {
+        let mut counter = DuplicationCounter::default();
+        for seq in [&b"ACGT"[..], b"ACGT", b"ACGT", b"TTTT"] {
+            counter.observe(seq);
+        }
+        let levels = counter.levels();
+        assert_eq!(levels.remaining, 50.0);
+        assert_eq!(levels.deduplicated[0], 50.0);
+        assert_eq!(levels.deduplicated[2], 50.0);
+        assert_eq!(levels.total[0], 25.0);
+        assert_eq!(levels.total[2], 75.0);
+        let result = counter.finish();
+        assert_eq!(result.status, Status::Warn);
+        assert_eq!(result.rows[2], ["3", "50.00", "75.00"]);
+    }
+    #[test]
+    fn test_sketch() {
+        let threshold = Thresholds::default().duplication_remaining;
+        let mut counter = DuplicationCounter::new(1 << 20, threshold);
+        for i in 0_u32..40_000 {
+            counter.observe(format!("{:x}", i).as_bytes());
+        }
+        for _ in 0..40 {
+            for i in 0_u32..1000 {
+                counter.observe(format!("{:x}", i + 1_000_000).as_bytes());
+            }
+        }
+        assert!(counter.sketch.is_some());
+        let levels = counter.levels();
+        // 41,000 of 80,000 reads are distinct, 40,000 of them singletons
+        assert!((levels.remaining - 51.25).abs() < 2.5);
+        assert!((levels.deduplicated[0] - 97.56).abs() < 2.5);
+        assert!((levels.total[0] - 50.0).abs() < 2.5);
+        assert!((levels.total[9] - 50.0).abs() < 2.5);
+    }
+    #[test]
+    fn test_saturation() {
+        let mut counter = DuplicationCounter::default();
+        for seq in [&b"ACGT"[..], b"ACGT", b"TTTT", b"GGGG"] {
+            counter.observe(seq);
+        }
+        let saturation = counter.saturation().unwrap();
+        assert_eq!(saturation.observed.len(), 20);
+        // Half of the reads keep ACGT with a probability of 3/4, the others with 1/2
+        assert_eq!(saturation.observed[9], (2, 1.75));
+        assert_eq!(saturation.observed[19], (4, 3.0));
+        let size = saturation.library_size.unwrap();
+        assert!((lander_waterman(size, 4.0) - 3.0).abs() < 1e-9);
+        assert_eq!(saturation.model.len(), 80);
+        assert!(saturation.model[79].1 > 3.0 && saturation.model[79].1 < size);
+        let mut distinct = DuplicationCounter::default();
+        distinct.observe(b"ACGT");
+        assert!(distinct.saturation().unwrap().library_size.is_none());
+    }
+    #[test]
+    fn test_fastqc_levels() {
+        let mut counter = DuplicationCounter::fastqc(Thresholds::default().duplication_remaining);
+        let seq = |i: usize| format!("{:x}", i).into_bytes();
+        for i in 0..OBSERVATION_CUTOFF {
+            counter.observe(&seq(i));
+        }
+        // Only the repeats of the tracked sequences are counted after the cutoff
+        for i in 0..OBSERVATION_CUTOFF {
+            counter.observe(&seq(i));
+            counter.observe(&seq(i + OBSERVATION_CUTOFF));
+        }
+        assert_eq!(counter.counts.len(), OBSERVATION_CUTOFF);
+        assert_eq!(counter.count_at_limit, OBSERVATION_CUTOFF);
+        assert!(counter.saturation().is_none());
+        // A sequence with two of the 300,000 reads is among the first 100,000 reads
+        // with a probability of 5/9, so 180,000 of them are extrapolated
+        let levels = counter.levels();
+        assert!((levels.remaining - 50.0).abs() < 0.01);
+        assert_eq!(levels.deduplicated[1], 100.0);
+        assert_eq!(levels.total[1], 100.0);
+    }
+    #[test]
+    fn test_truncation() {
+        let mut counter = DuplicationCounter::default();
+        let mut long = vec![b'A'; 100];
+        counter.observe(&long);
+        long[80] = b'C';
+        counter.observe(&long);
+        assert_eq!(counter.levels().remaining, 50.0);
+    }
+}
@@ -0,0 +1,112 @@
+/// Lowest printable quality character.
+pub(crate) const LOWEST_CHAR: u8 = b'!';
+/// Number of printable quality characters from `!` to `~`, the size of quality histograms.
+pub(crate) const QUALITY_CHARS: usize = 94;
+
+/// Returns the index of a quality character in a quality histogram, counting characters
+/// outside of the printable range as the nearest printable one.
+pub(crate) fn char_index(q: u8) -> usize {
+    (q.saturating_sub(LOWEST_CHAR) as usize).min(QUALITY_CHARS - 1)
+}
+
+/// Returns the quality character counted at the given index of a quality histogram.
+pub(crate) fn index_char(index: usize) -> u8 {
+    LOWEST_CHAR + index as u8
+}
+
+/// Most distinct quality scores of binned qualities, e.g. 4 of NovaSeq or 8 of HiSeq
+/// X and NextSeq.
+const MAX_QUALITY_BINS: usize = 8;
+/// Number of bases below which few distinct quality scores are expected by chance.
+const MIN_BINNED_BASES: usize = 1000;
+
+/// Returns the distinct phred scores of a histogram over phred scores if the qualities
+/// are binned, i.e. only two to `MAX_QUALITY_BINS` scores occur among enough bases.
+pub(crate) fn quality_bins(scores: &[usize]) -> Option<Vec<usize>> {
+    let bins = (0..scores.len())
+        .filter(|&score| scores[score] > 0)
+        .collect::<Vec<_>>();
+    let binned = (2..=MAX_QUALITY_BINS).contains(&bins.len())
+        && scores.iter().sum::<usize>() >= MIN_BINNED_BASES;
+    binned.then_some(bins)
+}
+
+/// The encoding of quality scores in a FASTQ file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PhredEncoding {
+    pub(crate) name: &'static str,
+    pub(crate) offset: u8,
+}
+
+impl PhredEncoding {
+    /// Guesses the encoding from the lowest quality character in a file, following FastQC.
+    pub(crate) fn detect(lowest: u8) -> Self {
+        match lowest {
+            0..=63 => PhredEncoding::from_offset(33),
+            65 => PhredEncoding {
+                name: "Illumina 1.3",
+                offset: 64,
+            },
+            _ => PhredEncoding::from_offset(64),
+        }
+    }
+
+    /// Returns the encoding with the given offset.
+    pub(crate) fn from_offset(offset: u8) -> Self {
+        let name = match offset {
+            64 => "Illumina 1.5",
+            _ => "Sanger / Illumina 1.9",
+        };
+        PhredEncoding { name, offset }
+    }
+
+    /// Converts a histogram over quality characters to a histogram over phred scores.
+    /// Characters below the offset are counted as phred score 0.
+    pub(crate) fn scores(&self, hist: &[usize]) -> Vec<usize> {
+        let offset = char_index(self.offset);
+        let mut scores = hist[offset..].to_vec();
+        scores[0] += hist[..offset].iter().sum::<usize>();
+        scores
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{char_index, index_char, quality_bins, PhredEncoding, QUALITY_CHARS};
+    #[test]
+    fn test_detect() {
+        assert_eq!(PhredEncoding::detect(b'#').offset, 33);
+        assert_eq!(PhredEncoding::detect(b'A').name, "Illumina 1.3");
+        assert_eq!(PhredEncoding::detect(b'B'), PhredEncoding::from_offset(64));
+    }
+    #[test]
+    fn test_scores() {
+        let mut hist = vec![0; QUALITY_CHARS];
+        hist[char_index(b'!')] = 1;
+        hist[char_index(b'I')] = 2;
+        let scores = PhredEncoding::from_offset(64).scores(&hist);
+        assert_eq!(scores[0], 1);
+        assert_eq!(scores[b'I' as usize - 64], 2);
+        assert_eq!(PhredEncoding::from_offset(33).scores(&hist)[40], 2);
+    }
+    #[test]
+    fn test_quality_bins() {
+        let mut scores = vec![0; 42];
+        for (score, count) in [(2, 100), (12, 200), (23, 300), (37, 1000)] {
+            scores[score] = count;
+        }
+        assert_eq!(quality_bins(&scores), Some(vec![2, 12, 23, 37]));
+        // Few bases or continuous scores are not binned
+        assert_eq!(quality_bins(&scores[..20]), None);
+        scores.iter_mut().for_each(|count| *count += 1);
+        assert_eq!(quality_bins(&scores), None);
+    }
+    #[test]
+    fn test_char_index() {
+        assert_eq!(char_index(b'!'), 0);
+        assert_eq!(char_index(b'~'), QUALITY_CHARS - 1);
+        assert_eq!(char_index(0), 0);
+        assert_eq!(char_index(200), QUALITY_CHARS - 1);
+        assert_eq!(index_char(char_index(b'I')), b'I');
+    }
+}
@@ -0,0 +1,269 @@
+use needletail::errors::{ParseError, ParseErrorKind};
+use needletail::parser::Format;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// An error of reading the inputs or settings, or of writing the reports, which names
+/// the offending file where known.
+#[derive(Debug)]
+pub enum FastqcError {
+    /// A file or standard input could not be read or an output could not be written.
+    Io {
+        path: Option<PathBuf>,
+        source: io::Error,
+    },
+    /// An input or settings file is not in the expected format.
+    Parse {
+        path: Option<PathBuf>,
+        /// Number of the record which could not be parsed, starting at 1.
+        record: Option<u64>,
+        /// How the record is malformed, if a single record could not be parsed.
+        invalid: Option<InvalidRecord>,
+        message: String,
+    },
+    /// A report template is invalid or could not be rendered.
+    Template {
+        path: Option<PathBuf>,
+        source: tera::Error,
+    },
+    /// A URL could not be downloaded.
+    Network { url: String, message: String },
+    /// The given options or inputs cannot be used, e.g. a list without sequences.
+    Invalid(String),
+}
+
+/// The way a FASTA/FASTQ record is malformed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidRecord {
+    /// The header line does not start with `@` or `>`.
+    MissingHeader,
+    /// The separator line does not start with `+`.
+    MissingSeparator,
+    /// The quality line is not as long as the sequence line.
+    LengthMismatch,
+    /// The input ends within the record.
+    Truncated,
+    /// The input is empty or neither FASTA nor FASTQ.
+    UnknownFormat,
+}
+
+impl FastqcError {
+    /// Returns a parse error of the given message without the file and record.
+    pub(crate) fn parse<M: Into<String>>(message: M) -> Self {
+        FastqcError::Parse {
+            path: None,
+            record: None,
+            invalid: None,
+            message: message.into(),
+        }
+    }
+
+    /// Names the file of the error, unless it already names one.
+    pub fn with_path<P: AsRef<Path>>(mut self, file: P) -> Self {
+        match &mut self {
+            FastqcError::Io { path, .. }
+            | FastqcError::Parse { path, .. }
+            | FastqcError::Template { path, .. } => {
+                path.get_or_insert_with(|| file.as_ref().to_path_buf());
+            }
+            FastqcError::Network { .. } | FastqcError::Invalid(_) => {}
+        }
+        self
+    }
+
+    /// Returns the file of the error, if known.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            FastqcError::Io { path, .. }
+            | FastqcError::Parse { path, .. }
+            | FastqcError::Template { path, .. } => path.as_deref(),
+            FastqcError::Network { .. } | FastqcError::Invalid(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for FastqcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(path) = self.path() {
+            write!(f, "{}: ", path.display())?;
+        }
+        match self {
+            FastqcError::Io { source, .. } => write!(f, "{}", source),
+            FastqcError::Parse {
+                record: Some(record),
+                message,
+                ..
+            } => write!(f, "Invalid record {}: {}", record, message),
+            FastqcError::Parse { message, .. } => write!(f, "{}", message),
+            // The causes of template errors hold the line of a syntax error or the
+            // missing variable
+            FastqcError::Template { source, .. } => {
+                write!(f, "{}", source)?;
+                let mut cause = source.source();
+                while let Some(error) = cause {
+                    write!(f, ": {}", error)?;
+                    cause = error.source();
+                }
+                Ok(())
+            }
+            FastqcError::Network { url, message } => {
+                write!(f, "Cannot download {}: {}", url, message)
+            }
+            FastqcError::Invalid(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for FastqcError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FastqcError::Io { source, .. } => Some(source),
+            FastqcError::Template { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for FastqcError {
+    fn from(source: io::Error) -> Self {
+        FastqcError::Io { path: None, source }
+    }
+}
+
+impl From<ParseError> for FastqcError {
+    fn from(error: ParseError) -> Self {
+        // Records of FASTQ files span four lines, those of FASTA files any number
+        let line = error.position.line;
+        let record = (error.format == Some(Format::Fastq) && line > 0).then(|| (line - 1) / 4 + 1);
+        let invalid = match error.kind {
+            ParseErrorKind::Io => return io::Error::other(error.msg).into(),
+            ParseErrorKind::InvalidStart => InvalidRecord::MissingHeader,
+            ParseErrorKind::InvalidSeparator => InvalidRecord::MissingSeparator,
+            ParseErrorKind::UnequalLengths => InvalidRecord::LengthMismatch,
+            ParseErrorKind::UnexpectedEnd => InvalidRecord::Truncated,
+            ParseErrorKind::UnknownFormat | ParseErrorKind::EmptyFile => {
+                InvalidRecord::UnknownFormat
+            }
+        };
+        FastqcError::Parse {
+            path: None,
+            record,
+            invalid: Some(invalid),
+            message: error.to_string(),
+        }
+    }
+}
+
+impl From<tera::Error> for FastqcError {
+    fn from(source: tera::Error) -> Self {
+        FastqcError::Template { path: None, source }
+    }
+}
+
+impl From<serde_json::Error> for FastqcError {
+    fn from(error: serde_json::Error) -> Self {
+        match error.classify() {
+            serde_json::error::Category::Io => io::Error::from(error).into(),
+            _ => FastqcError::parse(error.to_string()),
+        }
+    }
+}
+
+impl From<zip::result::ZipError> for FastqcError {
+    fn from(error: zip::result::ZipError) -> Self {
+        match error {
+            zip::result::ZipError::Io(error) => error.into(),
+            error => io::Error::other(error).into(),
+        }
+    }
+}
+
+impl From<glob::PatternError> for FastqcError {
+    fn from(error: glob::PatternError) -> Self {
+        FastqcError::Invalid(format!("Invalid pattern: {}", error))
+    }
+}
+
+impl From<glob::GlobError> for FastqcError {
+    fn from(error: glob::GlobError) -> Self {
+        let path = error.path().to_path_buf();
+        FastqcError::from(io::Error::from(error)).with_path(path)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<rusqlite::Error> for FastqcError {
+    fn from(error: rusqlite::Error) -> Self {
+        io::Error::other(error).into()
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl From<parquet::errors::ParquetError> for FastqcError {
+    fn from(error: parquet::errors::ParquetError) -> Self {
+        io::Error::other(error).into()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl From<reqwest::Error> for FastqcError {
+    fn from(error: reqwest::Error) -> Self {
+        FastqcError::Network {
+            url: error.url().map(|url| url.to_string()).unwrap_or_default(),
+            message: error.to_string(),
+        }
+    }
+}
+
+impl From<String> for FastqcError {
+    fn from(message: String) -> Self {
+        FastqcError::Invalid(message)
+    }
+}
+
+impl From<&str> for FastqcError {
+    fn from(message: &str) -> Self {
+        FastqcError::Invalid(message.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{FastqcError, InvalidRecord};
+    use needletail::errors::{ErrorPosition, ParseError};
+    use std::error::Error;
+    use std::io;
+    use std::path::Path;
+    #[test]
+    fn test_fastqc_error() {
+        let position = ErrorPosition {
+            line: 9,
+            id: Some("read3".to_string()),
+        };
+        let error = ParseError::new_unequal_length(4, 3, position);
+        let error = FastqcError::from(error).with_path("reads.fastq");
+        match &error {
+            FastqcError::Parse {
+                record, invalid, ..
+            } => {
+                assert_eq!(*record, Some(3));
+                assert_eq!(*invalid, Some(InvalidRecord::LengthMismatch));
+            }
+            _ => panic!("Expected a parse error, got {:?}", error),
+        }
+        assert_eq!(
+            error.to_string(),
+            "reads.fastq: Invalid record 3: Sequence length is 4 but quality length is 3 (record 'read3' at line 9)"
+        );
+        let missing = io::Error::new(io::ErrorKind::NotFound, "No such file");
+        let error = FastqcError::from(missing)
+            .with_path("a.fastq")
+            .with_path("b.fastq");
+        assert_eq!(error.path(), Some(Path::new("a.fastq")));
+        assert!(error.source().is_some());
+        let error = FastqcError::from("The adapter list contains no sequences.");
+        assert!(matches!(error, FastqcError::Invalid(_)));
+    }
+}
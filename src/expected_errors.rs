@@ -0,0 +1,138 @@
+use rustc_hash::FxHashMap as HashMap;
+
+/// Expected errors of reads are counted in bins of a tenth of a decade.
+const BINS_PER_DECADE: i32 = 10;
+
+/// Counts reads by their expected number of errors, i.e. the sum of the error
+/// probabilities of their bases, like used for filtering by DADA2 or usearch.
+pub(crate) struct ExpectedErrorCounter {
+    /// Error probability of every quality character with a phred offset of 0.
+    probabilities: [f64; 256],
+    /// Number of reads per bin of the expected errors with a phred offset of 0.
+    counts: HashMap<i32, usize>,
+    /// Sum of the expected errors of all reads with a phred offset of 0.
+    total: f64,
+    reads: usize,
+    bases: usize,
+}
+
+/// Number of reads with expected errors in the same bin.
+pub(crate) struct ExpectedErrors {
+    /// Lowest expected errors of the bin.
+    pub(crate) errors: f64,
+    pub(crate) count: usize,
+    /// Percentage of reads with fewer expected errors than the end of the bin.
+    pub(crate) cumulative_percentage: f64,
+}
+
+impl Default for ExpectedErrorCounter {
+    fn default() -> Self {
+        let mut probabilities = [0.0; 256];
+        for (q, probability) in probabilities.iter_mut().enumerate() {
+            *probability = 10_f64.powf(-(q as f64) / 10.0);
+        }
+        ExpectedErrorCounter {
+            probabilities,
+            counts: HashMap::default(),
+            total: 0.0,
+            reads: 0,
+            bases: 0,
+        }
+    }
+}
+
+impl ExpectedErrorCounter {
+    /// Adds a read and returns its expected errors with a phred offset of 0.
+    pub(crate) fn observe(&mut self, qualities: &[u8]) -> f64 {
+        if qualities.is_empty() {
+            return 0.0;
+        }
+        let errors = qualities
+            .iter()
+            .map(|&q| self.probabilities[q as usize])
+            .sum::<f64>();
+        // Sums at bin boundaries must not be rounded down into the previous bin
+        let bin = (errors.log10() * BINS_PER_DECADE as f64 + 1e-9).floor() as i32;
+        *self.counts.entry(bin).or_insert(0) += 1;
+        self.total += errors;
+        self.reads += 1;
+        self.bases += qualities.len();
+        errors
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &ExpectedErrorCounter) {
+        for (&bin, &count) in &other.counts {
+            *self.counts.entry(bin).or_insert(0) += count;
+        }
+        self.total += other.total;
+        self.reads += other.reads;
+        self.bases += other.bases;
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.reads == 0
+    }
+
+    /// Returns the mean expected errors of the reads. As the phred offset scales the
+    /// error probabilities of all bases by the same factor, it is applied afterwards.
+    pub(crate) fn mean(&self, offset: u8) -> f64 {
+        match self.reads {
+            0 => 0.0,
+            reads => self.total * 10_f64.powf(offset as f64 / 10.0) / reads as f64,
+        }
+    }
+
+    /// Returns the percentage of erroneous bases expected from their qualities.
+    pub(crate) fn error_rate(&self, offset: u8) -> f64 {
+        match self.bases {
+            0 => 0.0,
+            bases => self.total * 10_f64.powf(offset as f64 / 10.0) / bases as f64 * 100.0,
+        }
+    }
+
+    /// Returns the number of reads per bin of expected errors, ordered by errors. The
+    /// phred offset shifts the bins by a tenth of a decade per quality.
+    pub(crate) fn distribution(&self, offset: u8) -> Vec<ExpectedErrors> {
+        let shift = offset as i32 * BINS_PER_DECADE / 10;
+        let mut bins = self.counts.iter().collect::<Vec<_>>();
+        bins.sort_by_key(|(&bin, _)| bin);
+        let mut reads = 0;
+        bins.into_iter()
+            .map(|(&bin, &count)| {
+                reads += count;
+                ExpectedErrors {
+                    errors: 10_f64.powf((bin + shift) as f64 / BINS_PER_DECADE as f64),
+                    count,
+                    cumulative_percentage: reads as f64 / self.reads as f64 * 100.0,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ExpectedErrorCounter;
+    #[test]
+    fn test_distribution() {
+        let mut counter = ExpectedErrorCounter::default();
+        // Ten Q10 bases, ten Q20 bases and five Q20 bases with an offset of 33
+        counter.observe(b"++++++++++");
+        counter.observe(b"5555555555");
+        counter.observe(b"");
+        let mut other = ExpectedErrorCounter::default();
+        other.observe(b"55555");
+        counter.merge(&other);
+        let distribution = counter.distribution(33);
+        assert_eq!(distribution.len(), 3);
+        assert!(distribution[0].errors <= 0.05 && distribution[0].errors > 0.05 / 1.3);
+        assert!((distribution[1].errors - 0.1).abs() < 1e-9);
+        assert!((distribution[2].errors - 1.0).abs() < 1e-9);
+        assert_eq!(distribution[2].count, 1);
+        assert_eq!(distribution[2].cumulative_percentage, 100.0);
+        assert!((counter.mean(33) - 1.15 / 3.0).abs() < 1e-9);
+        assert!((counter.error_rate(33) - 4.6).abs() < 1e-9);
+        assert!(ExpectedErrorCounter::default().is_empty());
+    }
+}
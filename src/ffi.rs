@@ -0,0 +1,192 @@
+use crate::error::FastqcError;
+use crate::qc::{Qc, QcConfig};
+use std::cell::RefCell;
+use std::ffi::{CStr, CString};
+use std::fs;
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+thread_local! {
+    /// Message of the last error on the calling thread.
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Options of [`fqc_process_file`], of which zero values mean the defaults of `fqc`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct FqcOptions {
+    /// Length of the counted k-mers, from 2 to 10, or 0 for the default of 5.
+    pub k: u8,
+    /// Number of threads processing the records.
+    pub threads: u32,
+    /// Number of reads randomly sampled instead of using all reads.
+    pub sample: u64,
+    /// Number of records read from the start of the file instead of all records.
+    pub max_reads: u64,
+    /// Whether positions of long reads are grouped into bins.
+    pub long_reads: bool,
+    /// Path of a FastQC limits file, or null.
+    pub limits: *const c_char,
+}
+
+/// Returns the default options.
+#[no_mangle]
+pub extern "C" fn fqc_default_options() -> FqcOptions {
+    FqcOptions {
+        k: 5,
+        threads: 1,
+        sample: 0,
+        max_reads: 0,
+        long_reads: false,
+        limits: ptr::null(),
+    }
+}
+
+/// Runs all quality control modules on the given FASTQ, FASTA or SAM/BAM/CRAM file and
+/// returns the report as written by `fqc --format json`, which must be released with
+/// [`fqc_string_free`]. Returns null on errors, whose message is returned by
+/// [`fqc_last_error`].
+///
+/// # Safety
+///
+/// `path` must be a valid null-terminated string and `options` null or a valid pointer
+/// to options whose `limits` is null or a valid null-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn fqc_process_file(
+    path: *const c_char,
+    options: *const FqcOptions,
+) -> *mut c_char {
+    if path.is_null() {
+        set_error("The path is null.".to_string());
+        return ptr::null_mut();
+    }
+    let path = CStr::from_ptr(path);
+    let options = options
+        .as_ref()
+        .copied()
+        .unwrap_or_else(|| fqc_default_options());
+    let limits = (!options.limits.is_null()).then(|| CStr::from_ptr(options.limits));
+    // Panics must not unwind into the calling C code
+    let result = panic::catch_unwind(AssertUnwindSafe(|| process(path, options, limits)));
+    match result {
+        Ok(Ok(json)) => json.into_raw(),
+        Ok(Err(e)) => {
+            set_error(e.to_string());
+            ptr::null_mut()
+        }
+        Err(_) => {
+            set_error("The analysis panicked.".to_string());
+            ptr::null_mut()
+        }
+    }
+}
+
+fn process(
+    path: &CStr,
+    options: FqcOptions,
+    limits: Option<&CStr>,
+) -> Result<CString, FastqcError> {
+    let k = match options.k {
+        0 => fqc_default_options().k,
+        k if (2..=10).contains(&k) => k,
+        _ => return Err("k must be from 2 to 10.".into()),
+    };
+    let mut config = QcConfig::new()
+        .k(k)
+        .threads(options.threads.max(1) as usize)
+        .long_reads(options.long_reads);
+    if options.sample > 0 {
+        config = config.sample(options.sample as usize);
+    }
+    if options.max_reads > 0 {
+        config = config.max_reads(options.max_reads as usize);
+    }
+    if let Some(limits) = limits {
+        let limits = utf8(limits)?;
+        let text =
+            fs::read_to_string(limits).map_err(|e| FastqcError::from(e).with_path(limits))?;
+        config = config.limits(&text).map_err(|e| e.with_path(limits))?;
+    }
+    let report = Qc::new(config).process_file(utf8(path)?)?;
+    // JSON escapes null characters, so that the report is a valid C string
+    Ok(CString::new(report.to_json().to_string()).expect("JSON has no null characters"))
+}
+
+fn utf8(path: &CStr) -> Result<&str, FastqcError> {
+    path.to_str()
+        .map_err(|_| FastqcError::from("The path is not valid UTF-8."))
+}
+
+fn set_error(message: String) {
+    let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|error| *error.borrow_mut() = Some(message));
+}
+
+/// Returns the message of the last error on the calling thread, or null. The message
+/// is valid until the next failing call on that thread.
+#[no_mangle]
+pub extern "C" fn fqc_last_error() -> *const c_char {
+    LAST_ERROR.with(|error| {
+        error
+            .borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
+
+/// Releases a string returned by [`fqc_process_file`].
+///
+/// # Safety
+///
+/// `string` must be null or returned by [`fqc_process_file`] and not released before.
+#[no_mangle]
+pub unsafe extern "C" fn fqc_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        fqc_default_options, fqc_last_error, fqc_process_file, fqc_string_free, FqcOptions,
+    };
+    use std::ffi::{CStr, CString};
+    use std::ptr;
+    #[test]
+    fn test_process_file() {
+        let path = CString::new("tests/resources/example.fastq").unwrap();
+        let mut options = fqc_default_options();
+        options.max_reads = 20;
+        unsafe {
+            let json = fqc_process_file(path.as_ptr(), &options);
+            assert!(!json.is_null());
+            let report: serde_json::Value =
+                serde_json::from_str(CStr::from_ptr(json).to_str().unwrap()).unwrap();
+            assert_eq!(
+                report["files"][0]["basic_statistics"]["total_sequences"],
+                20
+            );
+            fqc_string_free(json);
+            // Zero values are the defaults
+            let zeroed = FqcOptions {
+                k: 0,
+                threads: 0,
+                sample: 0,
+                max_reads: 0,
+                long_reads: false,
+                limits: ptr::null(),
+            };
+            let json = fqc_process_file(path.as_ptr(), &zeroed);
+            assert!(!json.is_null());
+            fqc_string_free(json);
+            options.k = 11;
+            assert!(fqc_process_file(path.as_ptr(), &options).is_null());
+            let error = CStr::from_ptr(fqc_last_error()).to_str().unwrap();
+            assert_eq!(error, "k must be from 2 to 10.");
+            let missing = CString::new("missing.fastq").unwrap();
+            assert!(fqc_process_file(missing.as_ptr(), ptr::null()).is_null());
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use crate::input::Record;
+use crate::modules::{ModuleResult, QcModule};
+use crate::qc::Status;
+
+const BASES: [u8; 4] = *b"ACGT";
+
+/// Counts the runs of the same base in the reads by their length, which shows the
+/// homopolymer errors of nanopore basecallers or the slippage of polymerases.
+#[derive(Default)]
+pub(crate) struct HomopolymerCounter {
+    /// Number of runs of each base by their length, starting at a length of 1.
+    runs: [Vec<usize>; 4],
+}
+
+/// Number of runs of a base with the same length.
+pub(crate) struct Runs {
+    pub(crate) base: char,
+    pub(crate) length: usize,
+    pub(crate) count: usize,
+    /// Percentage among all runs of the base.
+    pub(crate) percentage: f64,
+}
+
+impl HomopolymerCounter {
+    pub(crate) fn observe(&mut self, seq: &[u8]) {
+        let mut previous = None;
+        let mut length = 0;
+        for &base in seq.iter().chain([&b'N']) {
+            let index = BASES.iter().position(|&b| b == base.to_ascii_uppercase());
+            if index == previous {
+                length += 1;
+                continue;
+            }
+            if let Some(previous) = previous {
+                let runs = &mut self.runs[previous];
+                if runs.len() < length {
+                    runs.resize(length, 0);
+                }
+                runs[length - 1] += 1;
+            }
+            previous = index;
+            length = 1;
+        }
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &HomopolymerCounter) {
+        for (runs, other) in self.runs.iter_mut().zip(&other.runs) {
+            if runs.len() < other.len() {
+                runs.resize(other.len(), 0);
+            }
+            for (count, other) in runs.iter_mut().zip(other) {
+                *count += other;
+            }
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.runs.iter().all(Vec::is_empty)
+    }
+
+    /// Returns the number of runs of each base by length, leaving out lengths
+    /// without runs.
+    pub(crate) fn distribution(&self) -> Vec<Runs> {
+        let mut distribution = Vec::new();
+        for (&base, runs) in BASES.iter().zip(&self.runs) {
+            let total = runs.iter().sum::<usize>();
+            for (i, &count) in runs.iter().enumerate() {
+                if count > 0 {
+                    distribution.push(Runs {
+                        base: base as char,
+                        length: i + 1,
+                        count,
+                        percentage: count as f64 / total as f64 * 100.0,
+                    });
+                }
+            }
+        }
+        distribution
+    }
+}
+
+impl QcModule for HomopolymerCounter {
+    fn observe(&mut self, record: &Record) {
+        self.observe(&record.seq());
+    }
+
+    fn is_mergeable() -> bool {
+        true
+    }
+
+    fn merge(&mut self, other: &dyn QcModule) {
+        if let Some(other) = other.downcast_ref::<HomopolymerCounter>() {
+            self.merge(other);
+        }
+    }
+
+    /// Returns the run lengths, which have no status.
+    fn finish(&self) -> ModuleResult {
+        let mut result = ModuleResult::new("Homopolymer run lengths", Status::Pass)
+            .with_columns(&["Base", "Length", "Count", "Percentage"]);
+        for runs in self.distribution() {
+            result = result.with_row(&[
+                runs.base.to_string(),
+                runs.length.to_string(),
+                runs.count.to_string(),
+                runs.percentage.to_string(),
+            ]);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::HomopolymerCounter;
+    #[test]
+    fn test_distribution() {
+        let mut counter = HomopolymerCounter::default();
+        counter.observe(b"AAAcGNNTTTTa");
+        let mut other = HomopolymerCounter::default();
+        other.observe(b"aaaCC");
+        counter.merge(&other);
+        let distribution = counter
+            .distribution()
+            .iter()
+            .map(|runs| (runs.base, runs.length, runs.count))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            distribution,
+            [
+                ('A', 1, 1),
+                ('A', 3, 2),
+                ('C', 1, 1),
+                ('C', 2, 1),
+                ('G', 1, 1),
+                ('T', 4, 1)
+            ]
+        );
+        assert!(HomopolymerCounter::default().is_empty());
+    }
+}
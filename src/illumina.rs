@@ -0,0 +1,102 @@
+/// The fields of an Illumina read name, i.e.
+/// `@<instrument>:<run>:<flowcell>:<lane>:<tile>:<x>:<y>` for Casava 1.8+, optionally
+/// followed by `:<UMI>`, or `@<instrument>:<lane>:<tile>:<x>:<y>#<index>` for older
+/// pipelines. The fields are not checked to be numbers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct IlluminaHeader<'a> {
+    pub(crate) instrument: &'a str,
+    /// Run and flowcell of Casava 1.8+ names.
+    pub(crate) run: Option<(&'a str, &'a str)>,
+    pub(crate) lane: &'a str,
+    pub(crate) tile: &'a str,
+    pub(crate) x: &'a str,
+    pub(crate) y: &'a str,
+}
+
+impl<'a> IlluminaHeader<'a> {
+    /// Parses the ID of a read, i.e. its name followed by an optional comment.
+    pub(crate) fn parse(id: &'a [u8]) -> Option<Self> {
+        let (name, _) = split_id(std::str::from_utf8(id).ok()?)?;
+        IlluminaHeader::from_name(name)
+    }
+
+    /// Parses a read name, which may end in the mate like `/1`.
+    pub(crate) fn from_name(name: &'a str) -> Option<Self> {
+        let name = name.split(['/', '#']).next()?;
+        let fields = name.split(':').collect::<Vec<_>>();
+        let (run, position) = match fields.len() {
+            7 | 8 => (Some((fields[1], fields[2])), &fields[3..7]),
+            5 => (None, &fields[1..]),
+            _ => return None,
+        };
+        Some(IlluminaHeader {
+            instrument: fields[0],
+            run,
+            lane: position[0],
+            tile: position[1],
+            x: position[2],
+            y: position[3],
+        })
+    }
+
+    pub(crate) fn lane(&self) -> Option<u32> {
+        self.lane.parse().ok()
+    }
+
+    pub(crate) fn tile(&self) -> Option<u32> {
+        self.tile.parse().ok()
+    }
+}
+
+/// Splits the ID of a read into its name and comment. Reads from the SRA are named by
+/// their accession, followed by the original name, which is taken as their name.
+pub(crate) fn split_id(id: &str) -> Option<(&str, Option<&str>)> {
+    let mut fields = id.split_whitespace();
+    let name = fields.next()?;
+    let comment = fields.next();
+    match comment {
+        Some(original) if !name.contains(':') && original.contains(':') => {
+            Some((original, fields.next()))
+        }
+        _ => Some((name, comment)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{split_id, IlluminaHeader};
+    #[test]
+    fn test_parse() {
+        let header = IlluminaHeader {
+            instrument: "D00236",
+            run: Some(("723", "HG32CBCX2")),
+            lane: "1",
+            tile: "1108",
+            x: "1330",
+            y: "1935",
+        };
+        for id in [
+            &b"SRR9130495.1 D00236:723:HG32CBCX2:1:1108:1330:1935/1"[..],
+            b"D00236:723:HG32CBCX2:1:1108:1330:1935 1:N:0:ATCACG",
+            b"D00236:723:HG32CBCX2:1:1108:1330:1935:ACGTAC 1:N:0:ATCACG",
+        ] {
+            assert_eq!(IlluminaHeader::parse(id), Some(header));
+        }
+        let old = IlluminaHeader::parse(b"HWUSI-EAS100R:6:73:941:1973#0/1").unwrap();
+        assert_eq!((old.instrument, old.run), ("HWUSI-EAS100R", None));
+        assert_eq!((old.lane(), old.tile(), old.y), (Some(6), Some(73), "1973"));
+        let invalid = IlluminaHeader::parse(b"M:1:FC:x:1101:1:1").unwrap();
+        assert_eq!((invalid.lane(), invalid.tile()), (None, Some(1101)));
+        for id in [&b"read1"[..], b"SRR1.1 read1", b"M:1:FC:1:1101:1", b"\xff"] {
+            assert_eq!(IlluminaHeader::parse(id), None);
+        }
+        assert_eq!(
+            split_id("SRR1.1 M:1:FC:1:1101:1:1 length=100"),
+            Some(("M:1:FC:1:1101:1:1", Some("length=100")))
+        );
+        assert_eq!(
+            split_id("read1 length=100"),
+            Some(("read1", Some("length=100")))
+        );
+    }
+}
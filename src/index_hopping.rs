@@ -0,0 +1,165 @@
+use crate::barcodes::Barcodes;
+use std::collections::BTreeSet;
+
+/// Minimum share of the dual-indexed reads of a file for its most frequent dual index
+/// to be taken for the indices of a sample, unlike for a file of undetermined reads.
+/// Indices of only G or N, read without any signal on two-color chemistry, are never
+/// those of a sample.
+const SAMPLE_SHARE: f64 = 0.5;
+/// Percentage of the reads of the samples with either index from which an unexpected
+/// combination is flagged as index hopping, about the lowest rate reported for
+/// patterned flowcells.
+pub(crate) const HOPPING_RATE: f64 = 0.1;
+
+/// Combinations of the i7 and i5 indices of the samples in all files compared.
+pub(crate) struct IndexHopping {
+    /// Files of the samples with their i7 and i5 index.
+    pub(crate) samples: Vec<(String, String, String)>,
+    /// Every combination of an i7 and an i5 index of the samples.
+    pub(crate) combinations: Vec<Combination>,
+    /// Percentage of the reads with indices of the samples in unexpected combinations.
+    pub(crate) rate: f64,
+}
+
+pub(crate) struct Combination {
+    pub(crate) i7: String,
+    pub(crate) i5: String,
+    /// Number of reads in all files.
+    pub(crate) reads: usize,
+    /// Whether this is the combination of a sample.
+    pub(crate) expected: bool,
+    /// Percentage of the reads of the samples with the i7 or i5 index of an unexpected
+    /// combination.
+    pub(crate) rate: f64,
+    /// Whether the rate of an unexpected combination suggests index hopping.
+    pub(crate) hopping: bool,
+}
+
+/// Cross-tabulates the i7 and i5 indices of the samples over the dual indices counted
+/// in the given files, or returns `None` for less than two dual-indexed samples.
+pub(crate) fn index_hopping(files: &[(&str, &Barcodes)]) -> Option<IndexHopping> {
+    let samples = files
+        .iter()
+        .filter_map(|(file, barcodes)| {
+            let total = barcodes
+                .dual
+                .iter()
+                .map(|(_, _, count)| count)
+                .sum::<usize>();
+            let (i7, i5, count) = barcodes.dual.first()?;
+            let signal = |index: &str| !index.bytes().all(|base| base == b'G' || base == b'N');
+            let sample = *count as f64 >= total as f64 * SAMPLE_SHARE && signal(i7) && signal(i5);
+            sample.then(|| (file.to_string(), i7.clone(), i5.clone()))
+        })
+        .collect::<Vec<_>>();
+    if samples.len() < 2 {
+        return None;
+    }
+    let i7s = samples.iter().map(|(_, i7, _)| i7).collect::<BTreeSet<_>>();
+    let i5s = samples.iter().map(|(_, _, i5)| i5).collect::<BTreeSet<_>>();
+    let reads = |i7: &str, i5: &str| {
+        files
+            .iter()
+            .flat_map(|(_, barcodes)| &barcodes.dual)
+            .filter(|(a, b, _)| a == i7 && b == i5)
+            .map(|(_, _, count)| count)
+            .sum::<usize>()
+    };
+    let is_expected = |i7: &str, i5: &str| samples.iter().any(|(_, a, b)| a == i7 && b == i5);
+    let mut combinations = Vec::new();
+    for i7 in &i7s {
+        for i5 in &i5s {
+            combinations.push(Combination {
+                i7: i7.to_string(),
+                i5: i5.to_string(),
+                reads: reads(i7, i5),
+                expected: is_expected(i7, i5),
+                rate: 0.0,
+                hopping: false,
+            });
+        }
+    }
+    let pct = |count: usize, total: usize| match total {
+        0 => 0.0,
+        total => count as f64 * 100.0 / total as f64,
+    };
+    let sample_reads = |i7: &str, i5: &str| {
+        combinations
+            .iter()
+            .filter(|combination| combination.expected)
+            .filter(|combination| combination.i7 == i7 || combination.i5 == i5)
+            .map(|combination| combination.reads)
+            .sum::<usize>()
+    };
+    let rates = combinations
+        .iter()
+        .map(|combination| match combination.expected {
+            true => 0.0,
+            false => pct(
+                combination.reads,
+                sample_reads(&combination.i7, &combination.i5),
+            ),
+        })
+        .collect::<Vec<_>>();
+    for (combination, rate) in combinations.iter_mut().zip(rates) {
+        combination.rate = rate;
+        combination.hopping = !combination.expected && rate >= HOPPING_RATE;
+    }
+    let total = combinations
+        .iter()
+        .map(|combination| combination.reads)
+        .sum();
+    let unexpected = combinations
+        .iter()
+        .filter(|combination| !combination.expected)
+        .map(|combination| combination.reads)
+        .sum();
+    Some(IndexHopping {
+        samples,
+        combinations,
+        rate: pct(unexpected, total),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::index_hopping;
+    use crate::barcodes::BarcodeCounter;
+    #[test]
+    fn test_index_hopping() {
+        let counter = |indices: &[(&str, usize)]| {
+            let mut counter = BarcodeCounter::default();
+            for (index, count) in indices {
+                for _ in 0..*count {
+                    counter.observe(format!("M1:7:FC:1:1101:1:2 1:N:0:{}", index).as_bytes());
+                }
+            }
+            counter.barcodes()
+        };
+        let sample1 = counter(&[("AAAA+CCCC", 1000), ("AAAA+TTTT", 5)]);
+        let sample2 = counter(&[("TGCA+TTTT", 1000)]);
+        let undetermined = counter(&[("GGGG+GGGG", 10), ("TGCA+CCCC", 2), ("AAAA+TTTT", 3)]);
+        let files = [
+            ("sample1", &sample1),
+            ("sample2", &sample2),
+            ("undetermined", &undetermined),
+        ];
+        let hopping = index_hopping(&files).unwrap();
+        assert_eq!(hopping.samples.len(), 2);
+        assert_eq!(hopping.combinations.len(), 4);
+        let combination = |i7: &str, i5: &str| {
+            hopping
+                .combinations
+                .iter()
+                .find(|combination| combination.i7 == i7 && combination.i5 == i5)
+                .unwrap()
+        };
+        assert_eq!(combination("AAAA", "TTTT").reads, 8);
+        assert_eq!(combination("AAAA", "TTTT").rate, 0.4);
+        assert!(combination("AAAA", "TTTT").hopping);
+        assert_eq!(combination("TGCA", "CCCC").rate, 0.1);
+        assert!(!combination("AAAA", "CCCC").hopping);
+        assert_eq!(hopping.rate, 1000.0 / 2010.0);
+        assert!(index_hopping(&files[..1]).is_none());
+    }
+}
@@ -0,0 +1,1006 @@
+use crate::error::FastqcError;
+use crate::progress::Progress;
+use crate::remote;
+use needletail::errors::{ErrorPosition, ParseError, ParseErrorKind};
+use needletail::parse_fastx_reader;
+use needletail::parser::{FastxReader, Format, SequenceRecord};
+#[cfg(not(target_arch = "wasm32"))]
+use needletail::Sequence;
+#[cfg(not(target_arch = "wasm32"))]
+use noodles_util::alignment;
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::ffi::OsStr;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Cursor, Read};
+use std::mem;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::mpsc::{sync_channel, Receiver};
+use std::sync::{Arc, Mutex};
+#[cfg(not(target_arch = "wasm32"))]
+use std::thread;
+
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+const GZIP_MAGIC: [u8; 2] = [0x1F, 0x8B];
+const BZIP2_MAGIC: [u8; 3] = *b"BZh";
+const XZ_MAGIC: [u8; 6] = [0xFD, b'7', b'z', b'X', b'Z', 0x00];
+/// Number of decoded alignment records buffered between decoder and consumer.
+#[cfg(not(target_arch = "wasm32"))]
+const ALIGNMENT_BUFFER: usize = 4096;
+/// File extensions of compressed input files.
+const COMPRESSION_EXTENSIONS: [&str; 4] = ["gz", "bz2", "xz", "zst"];
+/// File extensions of the supported input formats.
+const FORMAT_EXTENSIONS: [&str; 9] = [
+    "fastq", "fq", "fasta", "fa", "fna", "txt", "sam", "bam", "cram",
+];
+
+/// Which records of SAM/BAM/CRAM files are used.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct AlignmentFilter {
+    /// Only use unmapped records.
+    pub(crate) unmapped_only: bool,
+    /// Skip secondary and supplementary alignments.
+    pub(crate) primary_only: bool,
+}
+
+/// A reader for sequencing records from any of the supported input formats.
+pub(crate) enum Reader {
+    Fastx(Box<dyn FastxReader>),
+    /// Records of a file or URL, whose recorded input is read on line by line after an
+    /// invalid FASTQ record, starting at the given line.
+    Resumable(Box<dyn FastxReader>, Recorder, Option<u64>),
+    Lines(LineReader),
+    #[cfg(not(target_arch = "wasm32"))]
+    Alignment(AlignmentReader),
+    /// A reader stopping after the given number of further records.
+    Limited(Box<Reader>, usize),
+    /// A reader showing its progress.
+    Progress(Box<Reader>, Progress),
+}
+
+/// A single sequencing record.
+pub struct Record<'a>(Inner<'a>);
+
+enum Inner<'a> {
+    Fastx(SequenceRecord<'a>),
+    Owned(&'a OwnedRecord),
+    /// A record whose sequence was already joined from several lines.
+    Parts {
+        id: &'a [u8],
+        seq: &'a [u8],
+        qual: Option<&'a [u8]>,
+    },
+}
+
+/// A read owning its data, e.g. taken from a SAM/BAM/CRAM record restored to its
+/// sequenced orientation or copied to be processed in another thread.
+#[derive(Default)]
+pub(crate) struct OwnedRecord {
+    id: Vec<u8>,
+    seq: Vec<u8>,
+    /// Qualities encoded as phred+33 characters like in FASTQ files.
+    qual: Option<Vec<u8>>,
+}
+
+/// Reads FASTQ records of four lines each, which unlike the parser continues after an
+/// invalid record.
+pub(crate) struct LineReader {
+    lines: Box<dyn BufRead>,
+    /// Number of the next line, starting at 1.
+    line: u64,
+    buffers: [Vec<u8>; 4],
+    current: OwnedRecord,
+}
+
+/// Decompressed input passed on to the parser, which keeps the reads from the current
+/// record on, i.e. about the bytes still in the buffer of the parser, so that the input
+/// can be read on after an invalid record without opening it again.
+#[derive(Clone)]
+pub(crate) struct Recorder(Arc<Mutex<Recording>>);
+
+struct Recording {
+    inner: Box<dyn Read + Send>,
+    /// Bytes passed on to the parser per read, starting at the byte `start` of the input.
+    reads: VecDeque<Vec<u8>>,
+    start: u64,
+    /// Buffers of forgotten reads, which are reused for the next reads.
+    spare: Vec<Vec<u8>>,
+}
+
+/// Reads alignment records which are decoded in a background thread.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) struct AlignmentReader {
+    records: Receiver<io::Result<OwnedRecord>>,
+    current: OwnedRecord,
+}
+
+impl Reader {
+    /// Stops reading after the first `max_records` records.
+    pub(crate) fn limit(self, max_records: usize) -> Reader {
+        Reader::Limited(Box::new(self), max_records)
+    }
+
+    /// Shows the progress of reading the records with the given progress bar.
+    pub(crate) fn with_progress(self, progress: Progress) -> Reader {
+        Reader::Progress(Box::new(self), progress)
+    }
+
+    pub(crate) fn next(&mut self) -> Option<Result<Record<'_>, FastqcError>> {
+        if let Reader::Resumable(reader, recorder, Some(line)) = self {
+            let position = reader.position();
+            *self = match recorder.resume(position.byte(), position.line(), *line) {
+                Ok(lines) => Reader::Lines(lines),
+                Err(e) => return Some(Err(e.into())),
+            };
+        }
+        match self {
+            Reader::Fastx(reader) => reader.next().map(|record| {
+                record
+                    .map(|record| Record(Inner::Fastx(record)))
+                    .map_err(|e| e.into())
+            }),
+            Reader::Resumable(reader, recorder, resume) => {
+                // The previous record is not needed after it was observed
+                recorder.forget(reader.position().byte());
+                match reader.next() {
+                    Some(Err(e)) => {
+                        *resume = resume_line(&e);
+                        Some(Err(e.into()))
+                    }
+                    record => record.map(|record| {
+                        record
+                            .map(|record| Record(Inner::Fastx(record)))
+                            .map_err(|e| e.into())
+                    }),
+                }
+            }
+            Reader::Lines(reader) => reader.next(),
+            #[cfg(not(target_arch = "wasm32"))]
+            Reader::Alignment(reader) => match reader.records.recv().ok()? {
+                Ok(record) => {
+                    reader.current = record;
+                    Some(Ok(Record::owned(&reader.current)))
+                }
+                Err(e) => Some(Err(e.into())),
+            },
+            Reader::Limited(reader, remaining) => {
+                if *remaining == 0 {
+                    return None;
+                }
+                *remaining -= 1;
+                reader.next()
+            }
+            Reader::Progress(reader, progress) => {
+                let record = reader.next();
+                match &record {
+                    Some(Ok(record)) => progress.observe(record.num_bases()),
+                    Some(Err(_)) => {}
+                    None => progress.finish(),
+                }
+                record
+            }
+        }
+    }
+}
+
+impl<'a> Record<'a> {
+    pub(crate) fn owned(record: &'a OwnedRecord) -> Self {
+        Record(Inner::Owned(record))
+    }
+
+    pub(crate) fn parts(id: &'a [u8], seq: &'a [u8], qual: Option<&'a [u8]>) -> Self {
+        Record(Inner::Parts { id, seq, qual })
+    }
+}
+
+impl Record<'_> {
+    pub fn id(&self) -> &[u8] {
+        match &self.0 {
+            Inner::Fastx(record) => record.id(),
+            Inner::Owned(record) => &record.id,
+            Inner::Parts { id, .. } => id,
+        }
+    }
+
+    pub fn seq(&self) -> Cow<'_, [u8]> {
+        match &self.0 {
+            Inner::Fastx(record) => record.seq(),
+            Inner::Owned(record) => Cow::Borrowed(&record.seq),
+            Inner::Parts { seq, .. } => Cow::Borrowed(seq),
+        }
+    }
+
+    /// Returns the quality characters, which are missing for FASTA records.
+    pub fn qual(&self) -> Option<&[u8]> {
+        match &self.0 {
+            Inner::Fastx(record) => record.qual(),
+            Inner::Owned(record) => record.qual.as_deref(),
+            Inner::Parts { qual, .. } => *qual,
+        }
+    }
+
+    pub fn num_bases(&self) -> usize {
+        match &self.0 {
+            Inner::Fastx(record) => record.num_bases(),
+            Inner::Owned(record) => record.seq.len(),
+            Inner::Parts { seq, .. } => seq.len(),
+        }
+    }
+}
+
+/// Returns whether the given path refers to standard input.
+pub(crate) fn is_stdin<P: AsRef<Path>>(path: P) -> bool {
+    path.as_ref() == Path::new("-")
+}
+
+/// Replaces directories by the input files they contain and glob patterns like
+/// `fastq_pass/*.fastq.gz` by the matching files, both in lexicographic order.
+pub(crate) fn expand_inputs(inputs: &[String]) -> Result<Vec<String>, FastqcError> {
+    let mut expanded = Vec::new();
+    for input in inputs {
+        if is_stdin(input) || remote::is_url(input) {
+            expanded.push(input.clone());
+        } else if Path::new(input).is_dir() {
+            let files = scan_dir(Path::new(input))?;
+            if files.is_empty() {
+                return Err(format!("No input files found in directory {}.", input).into());
+            }
+            expanded.extend(files);
+        } else if input.contains(['*', '?', '['].as_ref()) && !Path::new(input).exists() {
+            let mut files = glob::glob(input)?
+                .map(|path| path.map(|path| path.to_string_lossy().into_owned()))
+                .collect::<Result<Vec<_>, _>>()?;
+            if files.is_empty() {
+                return Err(format!("No input files match {}.", input).into());
+            }
+            files.sort();
+            expanded.extend(files);
+        } else {
+            expanded.push(input.clone());
+        }
+    }
+    Ok(expanded)
+}
+
+/// Returns the input files in the given directory in lexicographic order.
+pub(crate) fn scan_dir(dir: &Path) -> io::Result<Vec<String>> {
+    let mut files = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .filter(|path| path.is_file() && file_name(path).is_some_and(is_input_file))
+        .map(|path| path.to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    files.sort();
+    Ok(files)
+}
+
+/// Returns whether a file in a scanned directory is used as input judging by its name.
+fn is_input_file(name: &str) -> bool {
+    let name = match name.rsplit_once('.') {
+        Some((stem, extension)) if COMPRESSION_EXTENSIONS.contains(&extension) => stem,
+        _ => name,
+    };
+    match name.rsplit_once('.') {
+        Some((_, extension)) => extension != "txt" && FORMAT_EXTENSIONS.contains(&extension),
+        None => false,
+    }
+}
+
+/// Returns the file name of a local path or URL.
+pub(crate) fn file_name(path: &Path) -> Option<&str> {
+    let path = match path.to_str() {
+        Some(url) if remote::is_url(url) => Path::new(remote::url_path(url)),
+        _ => path,
+    };
+    path.file_name()?.to_str()
+}
+
+/// Returns the name of a report for the given input, i.e. its file name without
+/// compression and format extensions.
+pub(crate) fn report_name<P: AsRef<Path>>(path: P) -> String {
+    if is_stdin(&path) {
+        return "stdin".to_string();
+    }
+    let mut name = file_name(path.as_ref()).unwrap_or_default().to_string();
+    for extensions in [&COMPRESSION_EXTENSIONS[..], &FORMAT_EXTENSIONS[..]] {
+        if let Some((stem, extension)) = name.rsplit_once('.') {
+            if !stem.is_empty() && extensions.contains(&extension) {
+                name.truncate(stem.len());
+            }
+        }
+    }
+    name
+}
+
+/// Returns the name of a paired-end report, i.e. the report name of read 1 without `_R1`.
+pub(crate) fn pair_name<P: AsRef<Path>>(read1: P) -> String {
+    let mut name = report_name(read1);
+    if let Some(index) = name.rfind("_R1") {
+        name.replace_range(index..index + 3, "");
+    }
+    name
+}
+
+/// Returns the path of read 2 for a read 1 path containing `_R1` in its file name.
+fn mate_path(read1: &str) -> Option<String> {
+    let path = Path::new(read1);
+    let name = path.file_name()?.to_str()?;
+    let index = name.rfind("_R1")?;
+    let mut mate = name.to_string();
+    mate.replace_range(index..index + 3, "_R2");
+    Some(path.with_file_name(mate).to_string_lossy().into_owned())
+}
+
+/// Pairs up the given files by `_R1` and `_R2` in their file names.
+pub(crate) fn pair_files(inputs: &[String]) -> Result<Vec<[String; 2]>, FastqcError> {
+    let mut pairs = Vec::new();
+    for input in inputs {
+        if let Some(mate) = mate_path(input) {
+            if !inputs.contains(&mate) {
+                return Err(format!("No read 2 file {} found for {}.", mate, input).into());
+            }
+            pairs.push([input.clone(), mate]);
+        }
+    }
+    if let Some(input) = inputs
+        .iter()
+        .find(|input| !pairs.iter().any(|pair| pair.contains(input)))
+    {
+        return Err(format!("{} is not part of a read pair.", input).into());
+    }
+    Ok(pairs)
+}
+
+/// Returns whether the given path is a SAM, BAM or CRAM file judging by its extension.
+fn is_alignment<P: AsRef<Path>>(path: P) -> bool {
+    path.as_ref()
+        .extension()
+        .and_then(OsStr::to_str)
+        .is_some_and(|extension| ["sam", "bam", "cram"].contains(&extension))
+}
+
+/// Opens a FASTA/FASTQ file for parsing, reading from standard input if the path is `-`
+/// and streaming HTTP(S) and S3 URLs.
+/// Plain, gzip, bzip2 and xz compressed input is handled by needletail directly, zstd
+/// compressed input is detected by its magic bytes or a `.zst` extension. Files ending
+/// in `.sam`, `.bam` or `.cram` are read as alignments. BGZF compressed input is
+/// decompressed on the given number of threads. The bytes read from the input before
+/// decompression are counted in `consumed`.
+pub(crate) fn open<P: AsRef<Path>>(
+    path: P,
+    filter: AlignmentFilter,
+    threads: usize,
+    consumed: Arc<AtomicU64>,
+) -> Result<Reader, FastqcError> {
+    let path = path.as_ref();
+    if is_stdin(path) {
+        let reader = CountingReader {
+            inner: io::stdin(),
+            consumed,
+        };
+        return Ok(Reader::Fastx(open_reader(reader, false, threads)?));
+    }
+    let (reader, path): (Box<dyn Read + Send>, &Path) = match path.to_str() {
+        Some(url) if remote::is_url(url) => (remote::open(url)?, Path::new(remote::url_path(url))),
+        _ => (Box::new(File::open(path)?), path),
+    };
+    let reader = Box::new(CountingReader {
+        inner: reader,
+        consumed,
+    });
+    if is_alignment(path) {
+        return open_alignment(reader, filter);
+    }
+    let zstd = path.extension() == Some(OsStr::new("zst"));
+    resumable(reader, zstd, threads)
+}
+
+/// Reads the records of SAM/BAM/CRAM input, which are decoded in a background thread.
+#[cfg(not(target_arch = "wasm32"))]
+fn open_alignment<R: Read + Send + 'static>(
+    reader: R,
+    filter: AlignmentFilter,
+) -> Result<Reader, FastqcError> {
+    Ok(Reader::Alignment(AlignmentReader::new(reader, filter)))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn open_alignment<R>(_: R, _: AlignmentFilter) -> Result<Reader, FastqcError> {
+    Err("SAM/BAM/CRAM input is not supported in WebAssembly builds.".into())
+}
+
+/// Parses FASTA/FASTQ records of a reader like [`open_reader`], recording its
+/// decompressed input to read on after invalid FASTQ records.
+fn resumable<R: Read + Send + 'static>(
+    reader: R,
+    zstd: bool,
+    threads: usize,
+) -> Result<Reader, FastqcError> {
+    let recorder = Recorder::new(reader, zstd, threads)?;
+    Ok(Reader::Resumable(
+        parse_fastx_reader(recorder.clone())?,
+        recorder,
+        None,
+    ))
+}
+
+/// Returns the first line of the record after an invalid FASTQ record, from where the
+/// input can be read on, unless the input ends or cannot be read.
+fn resume_line(error: &ParseError) -> Option<u64> {
+    let offset = match error.kind {
+        ParseErrorKind::InvalidStart | ParseErrorKind::UnequalLengths => 0,
+        ParseErrorKind::InvalidSeparator => 2,
+        _ => return None,
+    };
+    (error.format == Some(Format::Fastq)).then(|| error.position.line - offset + 4)
+}
+
+/// Opens FASTA/FASTQ records from any reader, e.g. an in-memory buffer, detecting
+/// compression like for standard input.
+pub(crate) fn from_reader<R: Read + Send + 'static>(reader: R) -> Result<Reader, FastqcError> {
+    Ok(Reader::Fastx(open_reader(reader, false, 1)?))
+}
+
+/// Opens a FASTQ file like [`open`], but returns its decompressed bytes for checking
+/// the format instead of parsing the records.
+pub(crate) fn open_bytes<P: AsRef<Path>>(path: P) -> Result<Box<dyn BufRead>, FastqcError> {
+    let path = path.as_ref();
+    let (mut reader, path): (Box<dyn Read + Send>, &Path) = match path.to_str() {
+        _ if is_stdin(path) => (Box::new(io::stdin()), path),
+        Some(url) if remote::is_url(url) => (remote::open(url)?, Path::new(remote::url_path(url))),
+        _ => (Box::new(File::open(path)?), path),
+    };
+    if is_alignment(path) {
+        return Err("Only FASTQ files can be validated.".into());
+    }
+    let mut magic = [0; 6];
+    let read = read_magic(&mut reader, &mut magic)?;
+    let reader = Cursor::new(magic[..read].to_vec()).chain(reader);
+    let zstd =
+        path.extension() == Some(OsStr::new("zst")) || magic[..read].starts_with(&ZSTD_MAGIC);
+    Ok(Box::new(BufReader::new(decompress(
+        reader,
+        zstd,
+        &magic[..read],
+    )?)))
+}
+
+/// Reads the first bytes of the input into `magic`, returning their number.
+fn read_magic<R: Read>(reader: &mut R, magic: &mut [u8]) -> io::Result<usize> {
+    let mut read = 0;
+    while read < magic.len() {
+        match reader.read(&mut magic[read..])? {
+            0 => break,
+            n => read += n,
+        }
+    }
+    Ok(read)
+}
+
+/// Decompresses input starting with the given magic bytes if necessary.
+#[cfg(not(target_arch = "wasm32"))]
+fn decompress<R: Read + Send + 'static>(
+    reader: R,
+    zstd: bool,
+    magic: &[u8],
+) -> io::Result<Box<dyn Read + Send>> {
+    Ok(if zstd {
+        Box::new(zstd::Decoder::new(reader)?)
+    } else if magic.starts_with(&GZIP_MAGIC) {
+        Box::new(flate2::read::MultiGzDecoder::new(reader))
+    } else if magic.starts_with(&BZIP2_MAGIC) {
+        Box::new(bzip2::read::MultiBzDecoder::new(reader))
+    } else if magic.starts_with(&XZ_MAGIC) {
+        Box::new(xz2::read::XzDecoder::new_multi_decoder(reader))
+    } else {
+        Box::new(reader)
+    })
+}
+
+/// Decompresses gzip input, as the other compression formats need native libraries.
+#[cfg(target_arch = "wasm32")]
+fn decompress<R: Read + Send + 'static>(
+    reader: R,
+    zstd: bool,
+    magic: &[u8],
+) -> io::Result<Box<dyn Read + Send>> {
+    if zstd || magic.starts_with(&BZIP2_MAGIC) || magic.starts_with(&XZ_MAGIC) {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Only plain and gzip compressed input is supported in WebAssembly builds.",
+        ))
+    } else if magic.starts_with(&GZIP_MAGIC) {
+        Ok(Box::new(flate2::read::MultiGzDecoder::new(reader)))
+    } else {
+        Ok(Box::new(reader))
+    }
+}
+
+/// Counts the bytes read from the inner reader.
+struct CountingReader<R> {
+    inner: R,
+    consumed: Arc<AtomicU64>,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.consumed.fetch_add(read as u64, Ordering::Relaxed);
+        Ok(read)
+    }
+}
+
+impl Recorder {
+    /// Decompresses the input like [`open_reader`], BGZF on the given number of threads.
+    fn new<R: Read + Send + 'static>(
+        mut reader: R,
+        zstd: bool,
+        threads: usize,
+    ) -> io::Result<Self> {
+        let mut magic = [0; crate::bgzf::HEADER_LEN];
+        let read = read_magic(&mut reader, &mut magic)?;
+        let magic = &magic[..read];
+        let reader = Cursor::new(magic.to_vec()).chain(reader);
+        let zstd = zstd || magic.starts_with(&ZSTD_MAGIC);
+        let inner: Box<dyn Read + Send> =
+            if cfg!(not(target_arch = "wasm32")) && threads > 1 && crate::bgzf::is_bgzf(magic) {
+                Box::new(crate::bgzf::BgzfReader::new(reader, threads))
+            } else {
+                decompress(reader, zstd, magic)?
+            };
+        Ok(Recorder(Arc::new(Mutex::new(Recording {
+            inner,
+            reads: VecDeque::new(),
+            start: 0,
+            spare: Vec::new(),
+        }))))
+    }
+
+    /// Drops the reads which end at or before the given byte.
+    fn forget(&self, byte: u64) {
+        let recording = &mut *self.0.lock().unwrap();
+        while let Some(read) = recording.reads.front() {
+            let end = recording.start + read.len() as u64;
+            if end > byte {
+                break;
+            }
+            let mut read = recording.reads.pop_front().unwrap();
+            read.clear();
+            recording.spare.push(read);
+            recording.start = end;
+        }
+    }
+
+    /// Returns the number of recorded bytes.
+    #[cfg(test)]
+    pub(crate) fn recorded(&self) -> usize {
+        self.0.lock().unwrap().reads.iter().map(Vec::len).sum()
+    }
+
+    /// Returns a reader of the remaining input from the given line on, where the parser
+    /// stopped at a record starting at the given byte and line.
+    fn resume(&self, byte: u64, first_line: u64, line: u64) -> io::Result<LineReader> {
+        let mut recording = self.0.lock().unwrap();
+        let offset = (byte - recording.start) as usize;
+        let recorded = Vec::from(mem::take(&mut recording.reads))
+            .concat()
+            .split_off(offset);
+        let inner = mem::replace(&mut recording.inner, Box::new(io::empty()));
+        let mut lines: Box<dyn BufRead> =
+            Box::new(BufReader::new(Cursor::new(recorded).chain(inner)));
+        for _ in first_line..line {
+            if lines.skip_until(b'\n')? == 0 {
+                break;
+            }
+        }
+        Ok(LineReader::new(lines, line))
+    }
+}
+
+impl Read for Recorder {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let recording = &mut *self.0.lock().unwrap();
+        let read = recording.inner.read(buf)?;
+        if read > 0 {
+            let mut recorded = recording.spare.pop().unwrap_or_default();
+            recorded.extend_from_slice(&buf[..read]);
+            recording.reads.push_back(recorded);
+        }
+        Ok(read)
+    }
+}
+
+/// Creates a parser for the given reader, decompressing zstd if necessary and BGZF on
+/// the given number of threads.
+fn open_reader<R: Read + Send + 'static>(
+    mut reader: R,
+    zstd: bool,
+    threads: usize,
+) -> Result<Box<dyn FastxReader>, ParseError> {
+    let mut magic = [0; crate::bgzf::HEADER_LEN];
+    let read = read_magic(&mut reader, &mut magic)?;
+    let reader = Cursor::new(magic[..read].to_vec()).chain(reader);
+    let zstd = zstd || magic[..read].starts_with(&ZSTD_MAGIC);
+    if cfg!(not(target_arch = "wasm32")) && threads > 1 && crate::bgzf::is_bgzf(&magic[..read]) {
+        return parse_fastx_reader(crate::bgzf::BgzfReader::new(reader, threads));
+    }
+    parse(reader, zstd, magic[..read].starts_with(&GZIP_MAGIC))
+}
+
+/// Parses plain input or input compressed with zstd or any format handled by needletail.
+#[cfg(not(target_arch = "wasm32"))]
+fn parse<R: Read + Send + 'static>(
+    reader: R,
+    zstd: bool,
+    _: bool,
+) -> Result<Box<dyn FastxReader>, ParseError> {
+    if zstd {
+        parse_fastx_reader(zstd::Decoder::new(reader)?)
+    } else {
+        parse_fastx_reader(reader)
+    }
+}
+
+/// Parses plain or gzip compressed input, as the other compression formats need native
+/// libraries.
+#[cfg(target_arch = "wasm32")]
+fn parse<R: Read + Send + 'static>(
+    reader: R,
+    zstd: bool,
+    gzip: bool,
+) -> Result<Box<dyn FastxReader>, ParseError> {
+    if zstd {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "zstd input is not supported in WebAssembly builds.",
+        )
+        .into())
+    } else if gzip {
+        parse_fastx_reader(flate2::read::MultiGzDecoder::new(reader))
+    } else {
+        parse_fastx_reader(reader)
+    }
+}
+
+impl LineReader {
+    /// Reads the given lines, the first of which has the given number, starting at 1.
+    fn new(lines: Box<dyn BufRead>, line: u64) -> Self {
+        LineReader {
+            lines,
+            line,
+            buffers: Default::default(),
+            current: OwnedRecord::default(),
+        }
+    }
+
+    fn next(&mut self) -> Option<Result<Record<'_>, FastqcError>> {
+        let line = self.line;
+        let mut read = 0;
+        for buffer in &mut self.buffers {
+            buffer.clear();
+            match self.lines.read_until(b'\n', buffer) {
+                Ok(0) => break,
+                Ok(_) => {}
+                Err(e) => {
+                    // Errors of the input are not retried
+                    self.lines = Box::new(io::empty());
+                    return Some(Err(e.into()));
+                }
+            }
+            if buffer.ends_with(b"\n") {
+                buffer.pop();
+                if buffer.ends_with(b"\r") {
+                    buffer.pop();
+                }
+            }
+            read += 1;
+        }
+        self.line += read;
+        let position = |offset: u64| ErrorPosition {
+            line: line + offset,
+            id: None,
+        };
+        let [header, seq, separator, qual] = &self.buffers;
+        let error = match read {
+            0 => return None,
+            1..=3 => ParseError::new_unexpected_end(position(0), Format::Fastq),
+            _ if !header.starts_with(b"@") => ParseError::new_invalid_start(
+                header.first().copied().unwrap_or(b'\n'),
+                position(0),
+                Format::Fastq,
+            ),
+            _ if !separator.starts_with(b"+") => ParseError::new_invalid_separator(
+                separator.first().copied().unwrap_or(b'\n'),
+                position(2),
+            ),
+            _ if seq.len() != qual.len() => {
+                ParseError::new_unequal_length(seq.len(), qual.len(), position(0))
+            }
+            _ => {
+                let record = &mut self.current;
+                record.id.clear();
+                record.id.extend_from_slice(&header[1..]);
+                record.seq.clone_from(seq);
+                record.qual = Some(qual.clone());
+                return Some(Ok(Record::owned(&self.current)));
+            }
+        };
+        Some(Err(error.into()))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl AlignmentReader {
+    fn new<R: Read + Send + 'static>(reader: R, filter: AlignmentFilter) -> Self {
+        let (sender, records) = sync_channel(ALIGNMENT_BUFFER);
+        thread::spawn(move || {
+            let decode = || -> io::Result<()> {
+                let mut reader =
+                    alignment::io::reader::Builder::default().build_from_reader(reader)?;
+                let header = reader.read_header()?;
+                for record in reader.records(&header) {
+                    if let Some(record) = OwnedRecord::from_alignment(record?.as_ref(), filter)? {
+                        if sender.send(Ok(record)).is_err() {
+                            break;
+                        }
+                    }
+                }
+                Ok(())
+            };
+            if let Err(e) = decode() {
+                let _ = sender.send(Err(e));
+            }
+        });
+        AlignmentReader {
+            records,
+            current: OwnedRecord::default(),
+        }
+    }
+}
+
+impl From<&Record<'_>> for OwnedRecord {
+    fn from(record: &Record<'_>) -> Self {
+        OwnedRecord {
+            id: record.id().to_vec(),
+            seq: record.seq().into_owned(),
+            qual: record.qual().map(|qual| qual.to_vec()),
+        }
+    }
+}
+
+impl OwnedRecord {
+    /// Converts an alignment record, returning `None` if it is excluded by the filter.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn from_alignment(
+        record: &dyn noodles_sam::alignment::Record,
+        filter: AlignmentFilter,
+    ) -> io::Result<Option<Self>> {
+        let flags = record.flags()?;
+        if (filter.unmapped_only && !flags.is_unmapped())
+            || (filter.primary_only && (flags.is_secondary() || flags.is_supplementary()))
+        {
+            return Ok(None);
+        }
+        let id = record.name().map(|name| name.to_vec()).unwrap_or_default();
+        let mut seq = record.sequence().iter().collect::<Vec<_>>();
+        let quality_scores = record.quality_scores();
+        let mut qual = if quality_scores.is_empty() {
+            None
+        } else {
+            Some(
+                quality_scores
+                    .iter()
+                    .map(|score| score.map(|score| score.saturating_add(33)))
+                    .collect::<io::Result<Vec<_>>>()?,
+            )
+        };
+        if flags.is_reverse_complemented() {
+            seq = seq.reverse_complement();
+            if let Some(qual) = qual.as_mut() {
+                qual.reverse();
+            }
+        }
+        Ok(Some(OwnedRecord { id, seq, qual }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        expand_inputs, is_input_file, is_stdin, open, open_reader, pair_files, pair_name,
+        report_name, resumable, AlignmentFilter, Reader,
+    };
+    use std::fs;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+
+    const FASTQ: &[u8] = b"@read1\nACGT\n+\nIIII\n@read2\nGGCC\n+\nIIII\n";
+    const SAM: &[u8] = b"@HD\tVN:1.6\n\
+        read1\t4\t*\t0\t0\t*\t*\t0\t0\tACGT\tIIII\n\
+        read2\t16\tchr1\t1\t60\t4M\t*\t0\t0\tGGCA\t#II5\n\
+        read3\t256\tchr1\t1\t60\t4M\t*\t0\t0\tGGCC\t*\n";
+
+    fn count_records(mut reader: Reader) -> usize {
+        let mut records = 0;
+        while let Some(record) = reader.next() {
+            assert_eq!(record.unwrap().num_bases(), 4);
+            records += 1;
+        }
+        records
+    }
+
+    #[test]
+    fn test_open_zstd() {
+        let path = std::env::temp_dir().join("fastqc-rs-test-open.fastq.zst");
+        fs::write(&path, zstd::encode_all(FASTQ, 3).unwrap()).unwrap();
+        let records =
+            count_records(open(&path, AlignmentFilter::default(), 1, Arc::default()).unwrap());
+        fs::remove_file(&path).unwrap();
+        assert_eq!(records, 2);
+    }
+
+    #[test]
+    fn test_open_consumed() {
+        let path = std::env::temp_dir().join("fastqc-rs-test-consumed.fastq.zst");
+        let compressed = zstd::encode_all(FASTQ, 3).unwrap();
+        fs::write(&path, &compressed).unwrap();
+        let consumed = Arc::new(AtomicU64::new(0));
+        let reader = open(&path, AlignmentFilter::default(), 1, consumed.clone()).unwrap();
+        let records = count_records(reader);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(records, 2);
+        assert_eq!(consumed.load(Ordering::Relaxed), compressed.len() as u64);
+    }
+
+    #[test]
+    fn test_open_reader() {
+        let reader = Reader::Fastx(open_reader(FASTQ, false, 1).unwrap());
+        assert_eq!(count_records(reader), 2);
+        let compressed = zstd::encode_all(FASTQ, 3).unwrap();
+        let reader =
+            Reader::Fastx(open_reader(std::io::Cursor::new(compressed), false, 1).unwrap());
+        assert_eq!(count_records(reader), 2);
+        // BGZF input is decompressed on several threads, and like gzip on a single one
+        let bgzf = crate::bgzf::compress(FASTQ, 10);
+        for threads in [1, 4] {
+            let reader = open_reader(std::io::Cursor::new(bgzf.clone()), false, threads).unwrap();
+            assert_eq!(count_records(Reader::Fastx(reader)), 2);
+        }
+    }
+
+    #[test]
+    fn test_resume() {
+        let path = std::env::temp_dir().join("fastqc-rs-test-resume.fastq.gz");
+        let fastq = b"@read1\nACGT\n+\nIIII\n@read2\nACGT\n-\nIIII\n@read3\nACGT\n+\nIIII\n";
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), Default::default());
+        std::io::Write::write_all(&mut encoder, fastq).unwrap();
+        fs::write(&path, encoder.finish().unwrap()).unwrap();
+        let mut reader = open(&path, AlignmentFilter::default(), 1, Arc::default()).unwrap();
+        assert_eq!(reader.next().unwrap().unwrap().id(), b"read1");
+        assert!(reader.next().unwrap().is_err());
+        let record = reader.next().unwrap().unwrap();
+        assert_eq!(record.id(), b"read3");
+        assert_eq!(record.qual(), Some(&b"IIII"[..]));
+        assert!(reader.next().is_none());
+        fs::remove_file(&path).unwrap();
+        // Streams like downloads are read on without opening them again, after earlier
+        // records were dropped from the recording
+        let mut fastq = b"@read\nACGT\n+\nIIII\n".repeat(100_000);
+        fastq.extend_from_slice(b"@bad\nACGT\n-\nIIII\n@last\nACGT\n+\nIIII\n");
+        let mut reader = resumable(std::io::Cursor::new(fastq), false, 1).unwrap();
+        let (mut records, mut errors, mut last) = (0, 0, Vec::new());
+        while records < 50_000 {
+            reader.next().unwrap().unwrap();
+            records += 1;
+        }
+        // Only the reads still in the buffer of the parser are kept
+        match &reader {
+            Reader::Resumable(_, recorder, None) => assert!(recorder.recorded() <= 1 << 17),
+            _ => panic!("The input is not resumable."),
+        }
+        while let Some(record) = reader.next() {
+            match record {
+                Ok(record) => {
+                    records += 1;
+                    last = record.id().to_vec();
+                }
+                Err(_) => errors += 1,
+            }
+        }
+        assert_eq!((records, errors), (100_001, 1));
+        assert_eq!(last, b"last");
+    }
+
+    #[test]
+    fn test_limit() {
+        let reader = Reader::Fastx(open_reader(FASTQ, false, 1).unwrap());
+        assert_eq!(count_records(reader.limit(1)), 1);
+        let reader = Reader::Fastx(open_reader(FASTQ, false, 1).unwrap());
+        assert_eq!(count_records(reader.limit(5)), 2);
+    }
+
+    #[test]
+    fn test_open_sam() {
+        let path = std::env::temp_dir().join("fastqc-rs-test-open.sam");
+        fs::write(&path, SAM).unwrap();
+        let mut reader = open(&path, AlignmentFilter::default(), 1, Arc::default()).unwrap();
+        reader.next().unwrap().unwrap();
+        let record = reader.next().unwrap().unwrap();
+        assert_eq!(record.id(), b"read2");
+        assert_eq!(&record.seq()[..], b"TGCC");
+        assert_eq!(record.qual(), Some(&b"5II#"[..]));
+        assert!(reader.next().unwrap().unwrap().qual().is_none());
+        assert!(reader.next().is_none());
+        let filter = AlignmentFilter {
+            unmapped_only: true,
+            primary_only: false,
+        };
+        assert_eq!(
+            count_records(open(&path, filter, 1, Arc::default()).unwrap()),
+            1
+        );
+        let filter = AlignmentFilter {
+            unmapped_only: false,
+            primary_only: true,
+        };
+        assert_eq!(
+            count_records(open(&path, filter, 1, Arc::default()).unwrap()),
+            2
+        );
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_is_stdin() {
+        assert!(is_stdin("-"));
+        assert!(!is_stdin("reads.fastq"));
+    }
+
+    #[test]
+    fn test_report_name() {
+        assert_eq!(report_name("data/sample_R1.fastq.gz"), "sample_R1");
+        assert_eq!(report_name("sample.bam"), "sample");
+        assert_eq!(report_name("sample.v2.fq"), "sample.v2");
+        assert_eq!(report_name(".fastq"), ".fastq");
+        assert_eq!(report_name("-"), "stdin");
+        assert_eq!(report_name("https://example.com/a.fq.gz?x=1"), "a");
+    }
+
+    #[test]
+    fn test_expand_inputs() {
+        let dir = std::env::temp_dir().join("fastqc-rs-test-expand");
+        fs::create_dir_all(&dir).unwrap();
+        for file in ["b.fastq.gz", "a.fq", "notes.txt"] {
+            fs::write(dir.join(file), FASTQ).unwrap();
+        }
+        let dir_name = dir.to_string_lossy().into_owned();
+        let from_dir = expand_inputs(std::slice::from_ref(&dir_name)).unwrap();
+        let from_glob = expand_inputs(&[format!("{}/*.f*q*", dir_name)]).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            from_dir,
+            vec![
+                format!("{}/a.fq", dir_name),
+                format!("{}/b.fastq.gz", dir_name)
+            ]
+        );
+        assert_eq!(from_glob, from_dir);
+        assert_eq!(expand_inputs(&["-".to_string()]).unwrap(), vec!["-"]);
+        assert!(!is_input_file("reads.txt"));
+        assert!(is_input_file("reads.bam"));
+    }
+
+    #[test]
+    fn test_pair_files() {
+        let inputs = ["a_R2.fq", "b_R1.fq", "a_R1.fq", "b_R2.fq"].map(String::from);
+        let pairs = pair_files(&inputs).unwrap();
+        assert_eq!(pairs.len(), 2);
+        assert_eq!(pairs[1], ["a_R1.fq".to_string(), "a_R2.fq".to_string()]);
+        assert_eq!(pair_name("data/a_R1.fastq.gz"), "a");
+        assert!(pair_files(&inputs[..3]).is_err());
+        assert!(pair_files(&["a_R1.fq", "a_R2.fq", "c.fq"].map(String::from)).is_err());
+    }
+}
@@ -0,0 +1,142 @@
+use crate::adapters::Adapter;
+use needletail::Sequence;
+
+/// Minimum overlap of read 1 and the reverse complement of read 2, like in fastp.
+pub(crate) const MIN_OVERLAP: usize = 30;
+
+/// Maximum number of mismatches in an overlap, which must also be at most a fifth of
+/// its length.
+const MAX_MISMATCHES: usize = 5;
+
+/// Number of pairs from the start of the input whose insert size is estimated.
+pub(crate) const MAX_PAIRS: usize = 1_000_000;
+
+/// Estimates the insert sizes of paired-end reads from the overlap of read 1 with the
+/// reverse complement of read 2. Inserts shorter than the minimum overlap are counted
+/// as adapter dimers if read 1 starts with an adapter within them.
+#[derive(Default)]
+pub(crate) struct InsertSizeCounter {
+    /// Number of pairs per insert size.
+    sizes: Vec<usize>,
+    dimers: usize,
+    pairs: usize,
+}
+
+/// Insert size distribution and summary of the observed pairs.
+pub(crate) struct InsertSizes {
+    pub(crate) median: Option<usize>,
+    /// Percentage of pairs with an insert shorter than the minimum overlap.
+    pub(crate) dimers: f64,
+    /// Percentage of pairs whose reads do not overlap.
+    pub(crate) non_overlapping: f64,
+    /// Number of pairs per insert size, leaving out sizes without pairs.
+    pub(crate) counts: Vec<(usize, usize)>,
+}
+
+impl InsertSizeCounter {
+    pub(crate) fn is_full(&self) -> bool {
+        self.pairs >= MAX_PAIRS
+    }
+
+    pub(crate) fn observe(&mut self, read1: &[u8], read2: &[u8], adapters: &[Adapter]) {
+        self.pairs += 1;
+        let size = match insert_size(read1, &read2.reverse_complement()) {
+            Some(size) => size,
+            None => match adapters.iter().filter_map(|a| a.find(read1)).min() {
+                Some(start) if start < MIN_OVERLAP => {
+                    self.dimers += 1;
+                    start
+                }
+                _ => return,
+            },
+        };
+        if self.sizes.len() <= size {
+            self.sizes.resize(size + 1, 0);
+        }
+        self.sizes[size] += 1;
+    }
+
+    pub(crate) fn sizes(&self) -> InsertSizes {
+        let overlapping = self.sizes.iter().sum::<usize>();
+        let pct = |count: usize| match self.pairs {
+            0 => 0.0,
+            pairs => count as f64 / pairs as f64 * 100.0,
+        };
+        let mut seen = 0;
+        let median = self.sizes.iter().position(|&count| {
+            seen += count;
+            overlapping > 0 && seen * 2 >= overlapping
+        });
+        InsertSizes {
+            median,
+            dimers: pct(self.dimers),
+            non_overlapping: pct(self.pairs - overlapping),
+            counts: self
+                .sizes
+                .iter()
+                .enumerate()
+                .filter(|(_, &count)| count > 0)
+                .map(|(size, &count)| (size, count))
+                .collect(),
+        }
+    }
+}
+
+/// Returns the insert size of a pair from the longest overlap of read 1 with the
+/// reverse complement of read 2, which starts after the start of read 1 unless the
+/// insert is shorter than the reads.
+fn insert_size(read1: &[u8], reverse: &[u8]) -> Option<usize> {
+    let matches = |a: &[u8], b: &[u8]| {
+        let length = a.len().min(b.len());
+        let mismatches = a
+            .iter()
+            .zip(b)
+            .filter(|(x, y)| !x.eq_ignore_ascii_case(y) && x != &&b'N' && y != &&b'N')
+            .take(MAX_MISMATCHES + 1)
+            .count();
+        length >= MIN_OVERLAP && mismatches <= MAX_MISMATCHES && mismatches * 5 <= length
+    };
+    let forward = (0..read1.len().saturating_sub(MIN_OVERLAP) + 1)
+        .find(|&offset| matches(&read1[offset..], reverse))
+        .map(|offset| offset + reverse.len());
+    forward.or_else(|| {
+        (1..reverse.len().saturating_sub(MIN_OVERLAP) + 1)
+            .find(|&offset| matches(read1, &reverse[offset..]))
+            .map(|offset| reverse.len() - offset)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::InsertSizeCounter;
+    use crate::adapters::Adapter;
+    use needletail::Sequence;
+    #[test]
+    fn test_sizes() {
+        let insert = b"ACGTTGCATGCAGTCAGTCATGCATTGACACGTAGCTAGCATCGATCAGGACTTAGCATC";
+        let adapter = b"AGATCGGAAGAGCACACGTCTGAACTCCAGTCA";
+        let adapters = [Adapter::new("adapter", adapter)];
+        let mut counter = InsertSizeCounter::default();
+        // Reads of 50 bp of an insert of 60 bp
+        counter.observe(&insert[..50], &insert.reverse_complement()[..50], &adapters);
+        // Reads of 50 bp reading through an insert of 45 bp into the adapter
+        let mut read1 = insert[..45].to_vec();
+        read1.extend(&adapter[..5]);
+        let mut read2 = insert[..45].reverse_complement();
+        read2.extend(b"GGGGG");
+        counter.observe(&read1, &read2, &adapters);
+        // An adapter dimer and a pair without overlap
+        counter.observe(
+            adapter,
+            b"TTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTT",
+            &adapters,
+        );
+        counter.observe(&insert[..40], &insert[..40], &adapters);
+        let sizes = counter.sizes();
+        assert_eq!(sizes.counts, [(0, 1), (45, 1), (60, 1)]);
+        assert_eq!(sizes.median, Some(45));
+        assert_eq!(sizes.dimers, 25.0);
+        assert_eq!(sizes.non_overlapping, 25.0);
+        assert!(!counter.is_full());
+    }
+}
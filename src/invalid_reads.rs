@@ -0,0 +1,96 @@
+use crate::error::{FastqcError, InvalidRecord};
+use std::collections::BTreeMap;
+
+/// Number of invalid records whose record number is reported.
+pub(crate) const MAX_RECORDS: usize = 10;
+
+/// Counts the records that could not be parsed and were skipped by the kind of error,
+/// keeping the numbers of the first of them.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct InvalidReadCounter {
+    kinds: BTreeMap<&'static str, usize>,
+    /// Numbers of the first invalid records, starting at 1.
+    records: Vec<usize>,
+}
+
+impl InvalidReadCounter {
+    /// Counts the invalid record with the given number and the error of parsing it.
+    pub(crate) fn observe(&mut self, record: usize, error: &FastqcError) {
+        *self.kinds.entry(kind(error)).or_insert(0) += 1;
+        if self.records.len() < MAX_RECORDS {
+            self.records.push(record);
+        }
+    }
+
+    /// Adds the counts of another counter, e.g. from a different file.
+    pub(crate) fn merge(&mut self, other: &InvalidReadCounter) {
+        for (kind, count) in &other.kinds {
+            *self.kinds.entry(kind).or_insert(0) += count;
+        }
+        let missing = MAX_RECORDS - self.records.len();
+        self.records
+            .extend(other.records.iter().take(missing).copied());
+    }
+
+    pub(crate) fn count(&self) -> usize {
+        self.kinds.values().sum()
+    }
+
+    /// Returns the number of invalid records per kind of error.
+    pub(crate) fn kinds(&self) -> &BTreeMap<&'static str, usize> {
+        &self.kinds
+    }
+
+    /// Returns the numbers of the first invalid records.
+    pub(crate) fn records(&self) -> &[usize] {
+        &self.records
+    }
+}
+
+/// Returns the kind of a parsing error, named like the violations of `fqc validate`.
+fn kind(error: &FastqcError) -> &'static str {
+    let invalid = match error {
+        FastqcError::Parse { invalid, .. } => *invalid,
+        _ => None,
+    };
+    match invalid {
+        Some(InvalidRecord::MissingHeader) => "missing @ header",
+        Some(InvalidRecord::MissingSeparator) => "missing + separator",
+        Some(InvalidRecord::LengthMismatch) => "length mismatch",
+        Some(InvalidRecord::Truncated) => "truncated record",
+        Some(InvalidRecord::UnknownFormat) => "unknown format",
+        None => "unreadable record",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{InvalidReadCounter, MAX_RECORDS};
+    use needletail::errors::{ErrorPosition, ParseError};
+    use needletail::parser::Format;
+    use std::io;
+    #[test]
+    fn test_invalid_reads() {
+        let mut counter = InvalidReadCounter::default();
+        let error = ParseError::new_unequal_length(4, 3, ErrorPosition::default());
+        counter.observe(3, &error.into());
+        let mut other = InvalidReadCounter::default();
+        for record in 10..20 {
+            let error = ParseError::new_unexpected_end(ErrorPosition::default(), Format::Fastq);
+            other.observe(record, &error.into());
+        }
+        other.observe(20, &io::Error::other("broken BAM record").into());
+        counter.merge(&other);
+        assert_eq!(counter.count(), 12);
+        assert_eq!(
+            counter.kinds().iter().collect::<Vec<_>>(),
+            [
+                (&"length mismatch", &1),
+                (&"truncated record", &10),
+                (&"unreadable record", &1)
+            ]
+        );
+        assert_eq!(counter.records().len(), MAX_RECORDS);
+        assert_eq!(counter.records()[..3], [3, 10, 11]);
+    }
+}
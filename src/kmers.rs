@@ -0,0 +1,294 @@
+use crate::bins::Binning;
+use crate::input::Record;
+use crate::modules::{Module, ModuleResult, QcModule, Threshold};
+use crate::qc::Status;
+use rustc_hash::FxHashMap as HashMap;
+
+/// Maximal number of enriched k-mers reported.
+const MAX_REPORTED: usize = 20;
+/// Corrected p-value below which a k-mer is reported as positionally enriched.
+pub(crate) const MAX_PVALUE: f64 = 0.01;
+
+/// Counts k-mers at every position in the reads.
+pub(crate) struct KmerCounter {
+    k: u8,
+    binning: Binning,
+    /// Per 2-bit encoded k-mer the number of occurrences at each position bin.
+    counts: HashMap<u64, Vec<usize>>,
+    /// Number of k-mers starting at each position bin.
+    position_totals: Vec<usize>,
+    /// Binomial p-value of any enriched k-mer below which the module warns or fails.
+    threshold: Threshold,
+}
+
+/// A k-mer whose occurrences concentrate at a specific position in the reads.
+pub(crate) struct EnrichedKmer {
+    pub(crate) kmer: String,
+    pub(crate) count: usize,
+    pub(crate) pvalue: f64,
+    pub(crate) max_obs_exp: f64,
+    /// Bin of the position with the highest observed/expected ratio.
+    pub(crate) max_position: usize,
+    /// Observed/expected ratio for every position bin.
+    pub(crate) obs_exp: Vec<f64>,
+}
+
+impl KmerCounter {
+    pub(crate) fn new(k: u8, binning: Binning, threshold: Threshold) -> Self {
+        KmerCounter {
+            k,
+            binning,
+            counts: HashMap::default(),
+            position_totals: Vec::new(),
+            threshold,
+        }
+    }
+
+    pub(crate) fn observe(&mut self, seq: &[u8]) {
+        let k = self.k as usize;
+        if seq.len() < k {
+            return;
+        }
+        let positions = self.binning.bins(seq.len() - k + 1);
+        if self.position_totals.len() < positions {
+            self.position_totals.resize(positions, 0);
+        }
+        let mask = if k == 32 {
+            u64::MAX
+        } else {
+            (1 << (2 * k)) - 1
+        };
+        let mut kmer = 0_u64;
+        let mut valid = 0;
+        for (i, &base) in seq.iter().enumerate() {
+            let code = match base {
+                b'A' | b'a' => 0,
+                b'C' | b'c' => 1,
+                b'G' | b'g' => 2,
+                b'T' | b't' => 3,
+                _ => {
+                    valid = 0;
+                    continue;
+                }
+            };
+            kmer = ((kmer << 2) | code) & mask;
+            valid += 1;
+            if valid >= k {
+                let pos = self.binning.bin(i + 1 - k);
+                let counts = self
+                    .counts
+                    .entry(kmer)
+                    .or_insert_with(|| vec![0; positions]);
+                if counts.len() < positions {
+                    counts.resize(positions, 0);
+                }
+                counts[pos] += 1;
+                self.position_totals[pos] += 1;
+            }
+        }
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &KmerCounter) {
+        for (&kmer, other) in &other.counts {
+            let counts = self.counts.entry(kmer).or_default();
+            add_counts(counts, other);
+        }
+        add_counts(&mut self.position_totals, &other.position_totals);
+    }
+
+    /// Returns the total count of every observed k-mer.
+    pub(crate) fn totals(&self) -> Vec<(String, usize)> {
+        self.counts
+            .iter()
+            .map(|(&kmer, counts)| (self.decode(kmer), counts.iter().sum()))
+            .collect()
+    }
+
+    /// Returns the k-mers with significant positional enrichment, most enriched first.
+    pub(crate) fn enriched(&self) -> Vec<EnrichedKmer> {
+        let total = self.position_totals.iter().sum::<usize>();
+        let tests = 4_f64.powi(self.k as i32);
+        let mut enriched = Vec::new();
+        for (&kmer, counts) in &self.counts {
+            let count = counts.iter().sum::<usize>();
+            let proportion = count as f64 / total as f64;
+            let obs_exp = counts
+                .iter()
+                .zip(&self.position_totals)
+                .map(|(&obs, &n)| {
+                    if n == 0 {
+                        0.0
+                    } else {
+                        obs as f64 / (proportion * n as f64)
+                    }
+                })
+                .collect::<Vec<_>>();
+            let (max_position, &max_obs_exp) = obs_exp
+                .iter()
+                .enumerate()
+                .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+                .unwrap();
+            let pvalue = (binomial_upper_tail(
+                self.position_totals[max_position],
+                counts[max_position],
+                proportion,
+            ) * tests)
+                .min(1.0);
+            if pvalue < MAX_PVALUE {
+                enriched.push(EnrichedKmer {
+                    kmer: self.decode(kmer),
+                    count,
+                    pvalue,
+                    max_obs_exp,
+                    max_position,
+                    obs_exp,
+                });
+            }
+        }
+        enriched.sort_by(|a, b| {
+            b.max_obs_exp
+                .partial_cmp(&a.max_obs_exp)
+                .unwrap()
+                .then_with(|| a.pvalue.partial_cmp(&b.pvalue).unwrap())
+                .then_with(|| a.kmer.cmp(&b.kmer))
+        });
+        enriched.truncate(MAX_REPORTED);
+        enriched
+    }
+
+    fn decode(&self, kmer: u64) -> String {
+        (0..self.k)
+            .rev()
+            .map(|i| ['A', 'C', 'G', 'T'][((kmer >> (2 * i as u64)) & 3) as usize])
+            .collect()
+    }
+}
+
+impl QcModule for KmerCounter {
+    fn observe(&mut self, record: &Record) {
+        self.observe(&record.seq());
+    }
+
+    fn is_mergeable() -> bool {
+        true
+    }
+
+    fn merge(&mut self, other: &dyn QcModule) {
+        if let Some(other) = other.downcast_ref::<KmerCounter>() {
+            self.merge(other);
+        }
+    }
+
+    fn finish(&self) -> ModuleResult {
+        let enriched = self.enriched();
+        let status = match enriched.iter().map(|e| e.pvalue).reduce(f64::min) {
+            Some(pvalue) => Status::parse(self.threshold.below(pvalue)),
+            None => Status::Pass,
+        };
+        let mut result = ModuleResult::new(Module::KmerContent.name(), status).with_columns(&[
+            "Sequence",
+            "Count",
+            "PValue",
+            "Obs/Exp Max",
+            "Max Obs/Exp Position",
+        ]);
+        for entry in enriched {
+            result = result.with_row(&[
+                entry.kmer,
+                entry.count.to_string(),
+                format!("{:.2e}", entry.pvalue),
+                format!("{:.2}", entry.max_obs_exp),
+                (self.binning.start(entry.max_position) + 1).to_string(),
+            ]);
+        }
+        result
+    }
+}
+
+/// Adds the counts elementwise, extending `counts` to the positions of `other`.
+fn add_counts(counts: &mut Vec<usize>, other: &[usize]) {
+    if counts.len() < other.len() {
+        counts.resize(other.len(), 0);
+    }
+    for (count, other) in counts.iter_mut().zip(other) {
+        *count += other;
+    }
+}
+
+/// Probability of observing at least `x` successes in `n` trials with success probability `p`.
+fn binomial_upper_tail(n: usize, x: usize, p: f64) -> f64 {
+    if x == 0 {
+        return 1.0;
+    }
+    if p >= 1.0 {
+        return 1.0;
+    }
+    let ln_choose = ln_factorial(n) - ln_factorial(x) - ln_factorial(n - x);
+    let mut term = (ln_choose + x as f64 * p.ln() + (n - x) as f64 * (1.0 - p).ln()).exp();
+    let mut sum = 0.0;
+    for i in x..=n {
+        sum += term;
+        if term < sum * 1e-12 {
+            break;
+        }
+        term *= (n - i) as f64 / (i + 1) as f64 * p / (1.0 - p);
+    }
+    sum.min(1.0)
+}
+
+/// Natural logarithm of `n!`, using Stirling's series for larger `n`.
+fn ln_factorial(n: usize) -> f64 {
+    if n < 20 {
+        return (2..=n).map(|i| (i as f64).ln()).sum();
+    }
+    let n = n as f64;
+    n * n.ln() - n + 0.5 * (2.0 * std::f64::consts::PI * n).ln() + 1.0 / (12.0 * n)
+        - 1.0 / (360.0 * n.powi(3))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{binomial_upper_tail, ln_factorial, KmerCounter};
+    use crate::bins::Binning;
+    use crate::modules::{QcModule, Thresholds};
+    use crate::qc::Status;
+    #[test]
+    fn test_ln_factorial() {
+        assert!((ln_factorial(5) - 120_f64.ln()).abs() < 1e-12);
+        let exact = (2..=25).map(|i| (i as f64).ln()).sum::<f64>();
+        assert!((ln_factorial(25) - exact).abs() < 1e-9);
+    }
+    #[test]
+    fn test_binomial_upper_tail() {
+        assert!((binomial_upper_tail(2, 1, 0.5) - 0.75).abs() < 1e-12);
+        assert!((binomial_upper_tail(10, 10, 0.5) - 0.5_f64.powi(10)).abs() < 1e-12);
+        assert_eq!(binomial_upper_tail(10, 0, 0.1), 1.0);
+    }
+    #[test]
+    fn test_counts() {
+        let mut counter = KmerCounter::new(3, Binning::Exact, Thresholds::default().kmer_pvalue);
+        counter.observe(b"ACGTNACG");
+        let mut totals = counter.totals();
+        totals.sort();
+        assert_eq!(totals, vec![("ACG".to_string(), 2), ("CGT".to_string(), 1)]);
+    }
+    #[test]
+    fn test_enriched() {
+        let mut counter = KmerCounter::new(5, Binning::Exact, Thresholds::default().kmer_pvalue);
+        for i in 0..500_usize {
+            let mut read = format!("{:020b}", i * 7919 % 1_048_576)
+                .replace('0', "A")
+                .replace('1', "C");
+            read.replace_range(10..15, "GGTTT");
+            counter.observe(read.as_bytes());
+        }
+        let enriched = counter.enriched();
+        assert!(enriched
+            .iter()
+            .any(|kmer| kmer.kmer == "GGTTT" && kmer.max_position == 10));
+        let result = counter.finish();
+        assert_eq!(result.status, Status::Fail);
+        assert_eq!(result.rows.len(), enriched.len());
+    }
+}
@@ -0,0 +1,167 @@
+use crate::bins::Binning;
+use crate::encoding::{char_index, QUALITY_CHARS};
+use crate::illumina::IlluminaHeader;
+use rustc_hash::FxHashMap as HashMap;
+use std::ops::Range;
+
+/// Sums up the reads, bases and qualities per flowcell lane of Illumina reads, which
+/// tells lanes that failed apart within a file merged from several lanes.
+#[derive(Default)]
+pub(crate) struct LaneCounter {
+    binning: Binning,
+    lanes: HashMap<usize, LaneSums>,
+}
+
+#[derive(Clone)]
+struct LaneSums {
+    reads: usize,
+    bases: usize,
+    /// Number of bases of each quality character.
+    qualities: [usize; QUALITY_CHARS],
+    /// Quality sum and number of bases at each position bin.
+    positions: Vec<(u64, u64)>,
+}
+
+impl Default for LaneSums {
+    fn default() -> Self {
+        LaneSums {
+            reads: 0,
+            bases: 0,
+            qualities: [0; QUALITY_CHARS],
+            positions: Vec::new(),
+        }
+    }
+}
+
+/// The yield and qualities of a lane.
+pub(crate) struct Lane {
+    pub(crate) lane: usize,
+    pub(crate) reads: usize,
+    pub(crate) bases: usize,
+    /// Percentage of the bases with a quality of at least 30.
+    pub(crate) q30: f64,
+    pub(crate) mean_quality: f64,
+    /// Mean quality at each group of position bins.
+    pub(crate) means: Vec<(Range<usize>, f64)>,
+}
+
+impl LaneCounter {
+    pub(crate) fn new(binning: Binning) -> Self {
+        LaneCounter {
+            binning,
+            lanes: HashMap::default(),
+        }
+    }
+
+    /// Adds a record if its read name contains the lane.
+    pub(crate) fn observe(&mut self, id: &[u8], length: usize, qualities: Option<&[u8]>) {
+        let Some(lane) = IlluminaHeader::parse(id).and_then(|header| header.lane()) else {
+            return;
+        };
+        let sums = self.lanes.entry(lane as usize).or_default();
+        sums.reads += 1;
+        sums.bases += length;
+        let Some(qualities) = qualities else {
+            return;
+        };
+        let bins = self.binning.bins(qualities.len());
+        if sums.positions.len() < bins {
+            sums.positions.resize(bins, (0, 0));
+        }
+        for (pos, &q) in qualities.iter().enumerate() {
+            sums.qualities[char_index(q)] += 1;
+            let sum = &mut sums.positions[self.binning.bin(pos)];
+            sum.0 += q as u64;
+            sum.1 += 1;
+        }
+    }
+
+    /// Adds the sums of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &LaneCounter) {
+        for (&lane, other) in &other.lanes {
+            let sums = self.lanes.entry(lane).or_default();
+            sums.reads += other.reads;
+            sums.bases += other.bases;
+            for (count, other) in sums.qualities.iter_mut().zip(&other.qualities) {
+                *count += other;
+            }
+            if sums.positions.len() < other.positions.len() {
+                sums.positions.resize(other.positions.len(), (0, 0));
+            }
+            for (sum, other) in sums.positions.iter_mut().zip(&other.positions) {
+                sum.0 += other.0;
+                sum.1 += other.1;
+            }
+        }
+    }
+
+    /// Returns the statistics of every lane ordered by lane, with the mean qualities at
+    /// the given groups of position bins as phred scores of the given offset.
+    pub(crate) fn lanes(&self, groups: &[Range<usize>], offset: u8) -> Vec<Lane> {
+        let mut lanes = self.lanes.iter().collect::<Vec<_>>();
+        lanes.sort_by_key(|(&lane, _)| lane);
+        let mean = |(sum, n): (u64, u64)| match n {
+            0 => 0.0,
+            n => sum as f64 / n as f64 - offset as f64,
+        };
+        lanes
+            .into_iter()
+            .map(|(&lane, sums)| {
+                let scored = sums.qualities.iter().sum::<usize>();
+                let q30 = sums.qualities[char_index(offset + 30)..]
+                    .iter()
+                    .sum::<usize>();
+                let total = sums
+                    .positions
+                    .iter()
+                    .fold((0, 0), |(q, n), sum| (q + sum.0, n + sum.1));
+                let means = groups
+                    .iter()
+                    .filter(|group| group.start < sums.positions.len())
+                    .map(|group| {
+                        let bins =
+                            &sums.positions[group.start..group.end.min(sums.positions.len())];
+                        let sum = bins
+                            .iter()
+                            .fold((0, 0), |(q, n), sum| (q + sum.0, n + sum.1));
+                        (group.clone(), mean(sum))
+                    })
+                    .collect();
+                Lane {
+                    lane,
+                    reads: sums.reads,
+                    bases: sums.bases,
+                    q30: match scored {
+                        0 => 0.0,
+                        scored => q30 as f64 * 100.0 / scored as f64,
+                    },
+                    mean_quality: mean(total),
+                    means,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LaneCounter;
+    use crate::bins::Binning;
+    #[test]
+    fn test_lanes() {
+        let mut counter = LaneCounter::new(Binning::Exact);
+        counter.observe(b"M:1:FC:1:1101:1:1", 2, Some(b"II"));
+        counter.observe(b"M:1:FC:2:1101:1:1", 2, Some(b"++"));
+        let mut other = LaneCounter::new(Binning::Exact);
+        other.observe(b"M:1:FC:1:1101:1:2", 3, Some(b"I+I"));
+        other.observe(b"read1", 3, Some(b"III"));
+        counter.merge(&other);
+        let lanes = counter.lanes(&[0..1, 1..2, 2..3], 33);
+        assert_eq!(lanes.len(), 2);
+        assert_eq!((lanes[0].lane, lanes[0].reads, lanes[0].bases), (1, 2, 5));
+        assert_eq!(lanes[0].q30, 80.0);
+        assert_eq!(lanes[0].means, [(0..1, 40.0), (1..2, 25.0), (2..3, 40.0)]);
+        assert_eq!((lanes[1].q30, lanes[1].mean_quality), (0.0, 10.0));
+        assert_eq!(lanes[1].means.len(), 2);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::bins::Binning;
+use rustc_hash::FxHashMap as HashMap;
+
+/// Counts reads by binned read length and mean read quality.
+#[derive(Default)]
+pub(crate) struct LengthQualityCounter {
+    /// Number of reads per read length bin and mean quality character.
+    counts: HashMap<(usize, u8), usize>,
+}
+
+/// Number of reads with a read length and mean quality in the same bin.
+pub(crate) struct LengthQuality {
+    /// First read length of the bin.
+    pub(crate) length: usize,
+    pub(crate) quality: u8,
+    pub(crate) count: usize,
+}
+
+impl LengthQualityCounter {
+    pub(crate) fn observe(&mut self, qualities: &[u8]) {
+        if qualities.is_empty() {
+            return;
+        }
+        let bin = Binning::Adaptive.bin(qualities.len());
+        *self
+            .counts
+            .entry((bin, mean_quality(qualities)))
+            .or_insert(0) += 1;
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &LengthQualityCounter) {
+        for (&key, &count) in &other.counts {
+            *self.counts.entry(key).or_insert(0) += count;
+        }
+    }
+
+    /// Returns the number of reads per read length bin and mean phred score.
+    pub(crate) fn density(&self, offset: u8) -> Vec<LengthQuality> {
+        let mut density = HashMap::default();
+        for (&(bin, quality), &count) in &self.counts {
+            *density
+                .entry((bin, quality.saturating_sub(offset)))
+                .or_insert(0) += count;
+        }
+        let mut density = density
+            .into_iter()
+            .map(|((bin, quality), count)| LengthQuality {
+                length: Binning::Adaptive.start(bin),
+                quality,
+                count,
+            })
+            .collect::<Vec<_>>();
+        density.sort_by_key(|entry| (entry.length, entry.quality));
+        density
+    }
+}
+
+/// Returns the mean quality of a read as quality character, averaging the error
+/// probabilities instead of the phred scores like NanoPlot. As the phred offset only
+/// shifts the result, it is applied afterwards.
+pub(crate) fn mean_quality(qualities: &[u8]) -> u8 {
+    let error = qualities
+        .iter()
+        .map(|&q| 10_f64.powf(-(q as f64) / 10.0))
+        .sum::<f64>()
+        / qualities.len() as f64;
+    (-10.0 * error.log10()).round().clamp(0.0, 127.0) as u8
+}
+
+#[cfg(test)]
+mod test {
+    use super::{mean_quality, LengthQualityCounter};
+    #[test]
+    fn test_mean_quality() {
+        assert_eq!(mean_quality(b"IIII"), b'I');
+        // A single low quality base dominates the mean error probability
+        assert_eq!(mean_quality(b"+I") - 33, 13);
+    }
+    #[test]
+    fn test_density() {
+        let mut counter = LengthQualityCounter::default();
+        counter.observe(b"IIII");
+        counter.observe(b"IIII");
+        counter.observe(&[b'5'; 1000]);
+        let density = counter.density(33);
+        assert_eq!(density.len(), 2);
+        assert_eq!((density[0].length, density[0].quality), (4, 40));
+        assert_eq!(density[0].count, 2);
+        assert_eq!(density[1].quality, 20);
+        assert!(density[1].length <= 1000);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::input::Record;
+use crate::modules::{Module, ModuleResult, QcModule, Threshold, Thresholds};
+use crate::qc::Status;
+use itertools::Itertools;
+use rustc_hash::FxHashMap as HashMap;
+
+/// Counts the reads by their length.
+pub(crate) struct ReadLengthCounter {
+    /// Number of reads per length.
+    lengths: HashMap<usize, usize>,
+    /// Like in FastQC, a nonzero value enables the warning for reads of different
+    /// lengths and the failure for empty reads.
+    threshold: Threshold,
+}
+
+impl Default for ReadLengthCounter {
+    fn default() -> Self {
+        ReadLengthCounter::new(Thresholds::default().read_length)
+    }
+}
+
+impl ReadLengthCounter {
+    pub(crate) fn new(threshold: Threshold) -> Self {
+        ReadLengthCounter {
+            lengths: HashMap::default(),
+            threshold,
+        }
+    }
+
+    pub(crate) fn observe(&mut self, length: usize) {
+        *self.lengths.entry(length).or_insert(0) += 1;
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &ReadLengthCounter) {
+        for (&length, &count) in &other.lengths {
+            *self.lengths.entry(length).or_insert(0) += count;
+        }
+    }
+
+    /// Returns the number of reads per length.
+    pub(crate) fn lengths(&self) -> &HashMap<usize, usize> {
+        &self.lengths
+    }
+
+    /// Returns the number of reads.
+    pub(crate) fn reads(&self) -> usize {
+        self.lengths.values().sum()
+    }
+
+    /// Returns the length of the longest read, or 0 without reads.
+    pub(crate) fn max(&self) -> usize {
+        self.lengths.keys().max().copied().unwrap_or(0)
+    }
+}
+
+impl QcModule for ReadLengthCounter {
+    fn observe(&mut self, record: &Record) {
+        self.observe(record.num_bases());
+    }
+
+    fn is_mergeable() -> bool {
+        true
+    }
+
+    fn merge(&mut self, other: &dyn QcModule) {
+        if let Some(other) = other.downcast_ref::<ReadLengthCounter>() {
+            self.merge(other);
+        }
+    }
+
+    fn finish(&self) -> ModuleResult {
+        let status = if self.lengths.contains_key(&0) && self.threshold.fail != 0.0 {
+            Status::Fail
+        } else if self.lengths.len() > 1 && self.threshold.warn != 0.0 {
+            Status::Warn
+        } else {
+            Status::Pass
+        };
+        let mut result = ModuleResult::new(Module::ReadLengthDistribution.name(), status)
+            .with_columns(&["Length", "Count"]);
+        for (length, count) in self.lengths.iter().sorted() {
+            result = result.with_row(&[length, count]);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ReadLengthCounter;
+    use crate::modules::{QcModule, Threshold};
+    use crate::qc::Status;
+    #[test]
+    fn test_lengths() {
+        let mut counter = ReadLengthCounter::default();
+        counter.observe(150);
+        counter.observe(150);
+        assert_eq!(counter.finish().status, Status::Pass);
+        let mut other = ReadLengthCounter::default();
+        other.observe(0);
+        counter.merge(&other);
+        assert_eq!((counter.reads(), counter.max()), (3, 150));
+        let result = counter.finish();
+        assert_eq!(result.status, Status::Fail);
+        assert_eq!(result.rows, [["0", "1"], ["150", "2"]]);
+        counter.threshold = Threshold::new(1.0, 0.0);
+        assert_eq!(counter.finish().status, Status::Warn);
+    }
+}
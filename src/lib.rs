@@ -0,0 +1,90 @@
+//! Quality control of sequencing reads in FASTQ, FASTA and SAM/BAM/CRAM files,
+//! inspired by FastQC.
+//!
+//! This crate is the library behind the `fqc` binary. [`Qc`] runs all modules on a
+//! file or any reader and returns a [`QcReport`] with typed access to the results of
+//! every module, which can also be rendered as the HTML or JSON report of `fqc`.
+//! Custom checks implementing [`QcModule`] can be added with [`QcConfig::module`].
+
+mod adapters;
+mod barcodes;
+mod bgzf;
+mod bins;
+mod casava;
+#[doc(hidden)]
+pub mod cli;
+mod cohort;
+mod columnar;
+mod compare;
+mod composition;
+mod contaminants;
+mod coverage;
+mod db;
+mod duplication;
+mod encoding;
+mod error;
+mod expected_errors;
+#[cfg(feature = "ffi")]
+mod ffi;
+mod homopolymers;
+mod illumina;
+mod index_hopping;
+mod input;
+mod insert_size;
+mod invalid_reads;
+mod kmers;
+mod lanes;
+mod length_quality;
+mod lengths;
+mod limits;
+mod low_complexity;
+mod metrics;
+mod modules;
+mod motifs;
+mod optical;
+mod overrepresented;
+mod platform;
+mod poly_tails;
+mod pool;
+mod process;
+mod progress;
+#[cfg(feature = "python")]
+mod python;
+mod qc;
+mod qc_status;
+mod qualities;
+mod read_ids;
+mod read_names;
+mod remote;
+mod run_info;
+mod run_time;
+mod sample;
+mod screen;
+mod settings;
+mod simd;
+mod sketch;
+mod spectrum;
+mod survival;
+mod svg;
+mod tiles;
+mod timings;
+mod trim_simulation;
+mod trimming;
+mod umis;
+mod validate;
+#[cfg(target_arch = "wasm32")]
+mod wasm;
+mod watch;
+
+pub use crate::error::{FastqcError, InvalidRecord};
+pub use crate::input::Record;
+pub use crate::modules::{Module, ModuleResult, QcModule, Threshold, Thresholds};
+pub use crate::qc::{
+    AdapterContent, AdapterStarts, BarcodeCount, Barcodes, BaseContent, BaseQuality,
+    BasicStatistics, ContaminantHits, Coverage, Duplication, DuplicationLevel, EnrichedKmer,
+    FilterSurvival, HomopolymerRuns, InvalidReads, KmerSpectrum, LaneStatistics, LowComplexity,
+    MotifBias, OpticalDuplicates, OverrepresentedSequence, PolyTails, Qc, QcConfig, QcReport,
+    ReadNames, ReferenceMatches, RunInfo, SimulatedTrimming, Status, SuggestedTrimming,
+    TileQuality, Umis,
+};
+pub use crate::survival::SurvivalFilters;
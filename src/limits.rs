@@ -0,0 +1,113 @@
+use crate::error::FastqcError;
+use crate::modules::{Module, Modules, Threshold, Thresholds};
+
+/// Applies a limits file in the format of the `Configuration/limits.txt` of FastQC to
+/// the thresholds and enabled modules. Every line has a key, `warn`, `error` or
+/// `ignore` and a value, separated by whitespace, and lines starting with `#` are
+/// comments. A nonzero `ignore` disables the module of the key.
+pub(crate) fn apply(
+    text: &str,
+    thresholds: &mut Thresholds,
+    modules: &mut Modules,
+) -> Result<(), FastqcError> {
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let invalid =
+            || FastqcError::parse(format!("Invalid limit on line {}: {}", number + 1, line));
+        let [key, level, value] = line.split_whitespace().collect::<Vec<_>>()[..] else {
+            return Err(invalid());
+        };
+        let value = value.parse::<f64>().map_err(|_| invalid())?;
+        let (module, threshold) = match key {
+            "duplication" => (
+                Module::DuplicationLevels,
+                Some(&mut thresholds.duplication_remaining),
+            ),
+            "kmer" => (Module::KmerContent, Some(&mut thresholds.kmer_pvalue)),
+            "n_content" => (Module::PerBaseNContent, Some(&mut thresholds.n_content)),
+            "overrepresented" => (
+                Module::OverrepresentedSequences,
+                Some(&mut thresholds.overrepresented),
+            ),
+            "quality_base" => (Module::PerBaseQuality, None),
+            "quality_base_lower" => (
+                Module::PerBaseQuality,
+                Some(&mut thresholds.base_quality_lower_quartile),
+            ),
+            "quality_base_median" => (
+                Module::PerBaseQuality,
+                Some(&mut thresholds.base_quality_median),
+            ),
+            "sequence" => (
+                Module::PerBaseContent,
+                Some(&mut thresholds.base_content_difference),
+            ),
+            "gc_sequence" => (
+                Module::PerSequenceGcContent,
+                Some(&mut thresholds.gc_deviation),
+            ),
+            "quality_sequence" => (
+                Module::PerSequenceQuality,
+                Some(&mut thresholds.sequence_quality),
+            ),
+            "tile" => (Module::PerTileQuality, Some(&mut thresholds.tile_deviation)),
+            "sequence_length" => (
+                Module::ReadLengthDistribution,
+                Some(&mut thresholds.read_length),
+            ),
+            "adapter" => (
+                Module::AdapterContent,
+                Some(&mut thresholds.adapter_content),
+            ),
+            "poly_tail" => (Module::PolyTails, Some(&mut thresholds.poly_tail)),
+            "low_complexity" => (Module::LowComplexity, Some(&mut thresholds.low_complexity)),
+            _ => {
+                return Err(FastqcError::parse(format!(
+                    "Unknown limit {} on line {}.",
+                    key,
+                    number + 1
+                )))
+            }
+        };
+        // FastQC limits k-mers by the negative decimal logarithm of their p-value
+        let value = if key == "kmer" {
+            10_f64.powf(-value)
+        } else {
+            value
+        };
+        match (level, threshold) {
+            ("ignore", _) => modules.set(module, value == 0.0),
+            ("warn", Some(Threshold { warn, .. })) => *warn = value,
+            ("error", Some(Threshold { fail, .. })) => *fail = value,
+            _ => return Err(invalid()),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::apply;
+    use crate::modules::{Module, Modules, Threshold, Thresholds};
+    #[test]
+    fn test_apply() {
+        let mut thresholds = Thresholds::default();
+        let mut modules = Modules::default();
+        let limits = "# FastQC limits\n\nduplication\twarn\t70\nkmer ignore 1\nkmer warn 2\n\
+                      adapter\terror\t20\nquality_base_median  warn  28\n";
+        apply(limits, &mut thresholds, &mut modules).unwrap();
+        assert_eq!(thresholds.duplication_remaining, Threshold::new(70.0, 50.0));
+        assert_eq!(thresholds.kmer_pvalue.warn, 0.01);
+        assert_eq!(thresholds.adapter_content, Threshold::new(5.0, 20.0));
+        assert_eq!(thresholds.base_quality_median.warn, 28.0);
+        assert!(!modules.contains(Module::KmerContent));
+        assert!(modules.contains(Module::AdapterContent));
+        let error = apply("tile warn five", &mut thresholds, &mut modules).unwrap_err();
+        assert_eq!(error.to_string(), "Invalid limit on line 1: tile warn five");
+        assert!(apply("quality_base warn 5", &mut thresholds, &mut modules).is_err());
+        assert!(apply("contaminant warn 5", &mut thresholds, &mut modules).is_err());
+    }
+}
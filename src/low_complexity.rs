@@ -0,0 +1,219 @@
+use crate::input::Record;
+use crate::modules::{Module, ModuleResult, QcModule, Threshold, Thresholds};
+use crate::qc::Status;
+
+/// Number of bases of the windows whose DUST scores are averaged, like prinseq-lite.
+const WINDOW: usize = 64;
+const STEP: usize = 32;
+/// Highest DUST score of a window, that of a homopolymer of `WINDOW` bases, which is
+/// scaled to 100.
+const MAX_SCORE: f64 = (WINDOW - 2) as f64 / 2.0;
+/// Scaled DUST score above which a read has a low complexity, like the default of
+/// `-lc_threshold` of prinseq-lite.
+pub(crate) const LOW_COMPLEXITY_SCORE: f64 = 7.0;
+const BASES: [u8; 5] = *b"ACGTN";
+
+/// Returns the DUST score of a read from 0 to 100, the mean over windows of 64 bases
+/// of how often its triplets repeat, which is highest for homopolymers and short
+/// tandem repeats.
+pub(crate) fn dust_score(seq: &[u8]) -> f64 {
+    if seq.len() <= WINDOW {
+        return window_score(seq);
+    }
+    let mut starts = (0..=seq.len() - WINDOW).step_by(STEP).collect::<Vec<_>>();
+    // Also score the end of the read if the windows leave it out
+    if starts.last() != Some(&(seq.len() - WINDOW)) {
+        starts.push(seq.len() - WINDOW);
+    }
+    let sum = starts
+        .iter()
+        .map(|&start| window_score(&seq[start..start + WINDOW]))
+        .sum::<f64>();
+    sum / starts.len() as f64
+}
+
+fn window_score(seq: &[u8]) -> f64 {
+    let mut counts = [0_u16; 64];
+    let mut triplets = 0;
+    for triplet in seq.windows(3) {
+        let code = triplet.iter().try_fold(0, |code, &base| {
+            let index = BASES[..4]
+                .iter()
+                .position(|&b| b == base.to_ascii_uppercase())?;
+            Some(code << 2 | index)
+        });
+        // Triplets with N or other bases are left out
+        if let Some(code) = code {
+            counts[code] += 1;
+            triplets += 1;
+        }
+    }
+    if triplets < 2 {
+        return 0.0;
+    }
+    let repeats = counts
+        .iter()
+        .map(|&count| count as usize * count.saturating_sub(1) as usize / 2)
+        .sum::<usize>();
+    (repeats as f64 / (triplets - 1) as f64 / MAX_SCORE * 100.0).min(100.0)
+}
+
+/// Counts the reads by their DUST score and the bases of the reads with a low
+/// complexity, which are mostly adapter dimers, poly-G reads of failed clusters or
+/// simple repeats.
+pub(crate) struct LowComplexityCounter {
+    /// Number of reads by their score rounded down, from 0 to 100.
+    scores: Vec<usize>,
+    reads: usize,
+    low_complexity: usize,
+    /// Number of each base of `BASES` in the reads with a low complexity.
+    bases: [usize; 5],
+    /// Percentage of reads with a low complexity at which the module warns or fails.
+    threshold: Threshold,
+}
+
+impl Default for LowComplexityCounter {
+    fn default() -> Self {
+        LowComplexityCounter::new(Thresholds::default().low_complexity)
+    }
+}
+
+impl LowComplexityCounter {
+    pub(crate) fn new(threshold: Threshold) -> Self {
+        LowComplexityCounter {
+            scores: vec![0; 101],
+            reads: 0,
+            low_complexity: 0,
+            bases: [0; 5],
+            threshold,
+        }
+    }
+
+    pub(crate) fn observe(&mut self, seq: &[u8]) {
+        let score = dust_score(seq);
+        self.reads += 1;
+        self.scores[score as usize] += 1;
+        if score > LOW_COMPLEXITY_SCORE {
+            self.low_complexity += 1;
+            for &base in seq {
+                let index = BASES[..4]
+                    .iter()
+                    .position(|&b| b == base.to_ascii_uppercase())
+                    .unwrap_or(4);
+                self.bases[index] += 1;
+            }
+        }
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &LowComplexityCounter) {
+        for (count, other) in self.scores.iter_mut().zip(&other.scores) {
+            *count += other;
+        }
+        self.reads += other.reads;
+        self.low_complexity += other.low_complexity;
+        for (count, other) in self.bases.iter_mut().zip(other.bases) {
+            *count += other;
+        }
+    }
+
+    /// Returns the percentage of reads with a low complexity.
+    pub(crate) fn percentage(&self) -> f64 {
+        match self.reads {
+            0 => 0.0,
+            reads => self.low_complexity as f64 / reads as f64 * 100.0,
+        }
+    }
+
+    /// Returns the percentages of A, C, G, T and N among the bases of the reads with a
+    /// low complexity.
+    pub(crate) fn composition(&self) -> Vec<(char, f64)> {
+        let total = self.bases.iter().sum::<usize>().max(1);
+        BASES
+            .iter()
+            .zip(self.bases)
+            .map(|(&base, count)| (base as char, count as f64 / total as f64 * 100.0))
+            .collect()
+    }
+
+    /// Returns the number of reads by their score rounded down, leaving out scores
+    /// without reads.
+    pub(crate) fn distribution(&self) -> Vec<(usize, usize)> {
+        self.scores
+            .iter()
+            .enumerate()
+            .filter(|(_, &count)| count > 0)
+            .map(|(score, &count)| (score, count))
+            .collect()
+    }
+}
+
+impl QcModule for LowComplexityCounter {
+    fn observe(&mut self, record: &Record) {
+        self.observe(&record.seq());
+    }
+
+    fn is_mergeable() -> bool {
+        true
+    }
+
+    fn merge(&mut self, other: &dyn QcModule) {
+        if let Some(other) = other.downcast_ref::<LowComplexityCounter>() {
+            self.merge(other);
+        }
+    }
+
+    fn finish(&self) -> ModuleResult {
+        let status = Status::parse(self.threshold.above(self.percentage()));
+        let mut result = ModuleResult::new(Module::LowComplexity.name(), status)
+            .with_columns(&["DUST score", "Count"]);
+        for (score, count) in self.distribution() {
+            result = result.with_row(&[score, count]);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{dust_score, LowComplexityCounter};
+    #[test]
+    fn test_dust_score() {
+        assert_eq!(dust_score(&[b'A'; 64]), 100.0);
+        assert_eq!(dust_score(&[b'g'; 150]), 100.0);
+        assert_eq!(dust_score(b"ACGTN"), 0.0);
+        assert_eq!(dust_score(b""), 0.0);
+        let repeat = b"AC".repeat(32);
+        assert!((dust_score(&repeat) - 930.0 / 61.0 / 31.0 * 100.0).abs() < 1e-9);
+        let random = b"GATTACAGCTTGCACGTAGGCTAACGTTCGATCCGATGCAAGTCTGACCTAGTGGACTTAGCAT";
+        assert!(dust_score(random) < 7.0);
+    }
+    #[test]
+    fn test_counter() {
+        let mut counter = LowComplexityCounter::default();
+        counter.observe(&[b'G'; 20]);
+        counter.observe(b"GATTACAGCTTGCACGTAGGCTAACGTTCGATCCG");
+        let mut other = LowComplexityCounter::default();
+        other.observe(b"AAAAAAAANN");
+        other.observe(b"");
+        counter.merge(&other);
+        assert_eq!(counter.percentage(), 50.0);
+        let distribution = counter.distribution();
+        assert_eq!(
+            distribution.iter().map(|(_, count)| count).sum::<usize>(),
+            4
+        );
+        // 18 GGG triplets score 9 of 31
+        assert_eq!(distribution.last(), Some(&(29, 1)));
+        assert_eq!(
+            counter.composition(),
+            [
+                ('A', 8.0 / 30.0 * 100.0),
+                ('C', 0.0),
+                ('G', 20.0 / 30.0 * 100.0),
+                ('T', 0.0),
+                ('N', 2.0 / 30.0 * 100.0)
+            ]
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Metrics of a single file exported in the OpenMetrics text format.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Sample {
+    pub(crate) file: String,
+    pub(crate) reads: usize,
+    pub(crate) bases: usize,
+    /// Percentage of bases with a quality of at least 30, if the reads have qualities.
+    pub(crate) q30: Option<f64>,
+    /// Percentage of duplicate reads, if the duplication levels are counted.
+    pub(crate) duplication: Option<f64>,
+    /// Percentage of bases from the start of an adapter, if the adapters are counted.
+    pub(crate) adapters: Option<f64>,
+    pub(crate) statuses: Vec<(String, &'static str)>,
+}
+
+/// Returns the value of a gauge for a sample, if it has one.
+type Gauge = fn(&Sample) -> Option<f64>;
+
+/// Escapes a label value of the OpenMetrics text format.
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Renders the metrics of all samples as OpenMetrics gauges labeled with the sample,
+/// with the module statuses as 0 for pass, 1 for warn and 2 for fail.
+pub(crate) fn render(samples: &[Sample]) -> String {
+    let mut text = String::new();
+    let gauges: [(&str, &str, Gauge); 5] = [
+        ("reads", "Number of reads", |sample| {
+            Some(sample.reads as f64)
+        }),
+        ("bases", "Number of bases", |sample| {
+            Some(sample.bases as f64)
+        }),
+        (
+            "q30_bases_percent",
+            "Percentage of bases with a quality of at least 30",
+            |sample| sample.q30,
+        ),
+        (
+            "duplication_percent",
+            "Percentage of duplicate reads",
+            |sample| sample.duplication,
+        ),
+        (
+            "adapter_percent",
+            "Percentage of bases from the start of an adapter",
+            |sample| sample.adapters,
+        ),
+    ];
+    for (name, help, value) in gauges {
+        writeln!(text, "# TYPE fastqc_{} gauge", name).unwrap();
+        writeln!(text, "# HELP fastqc_{} {}.", name, help).unwrap();
+        for sample in samples {
+            if let Some(value) = value(sample) {
+                let file = escape(&sample.file);
+                writeln!(text, "fastqc_{}{{sample=\"{}\"}} {}", name, file, value).unwrap();
+            }
+        }
+    }
+    text.push_str("# TYPE fastqc_module_status gauge\n");
+    text.push_str(
+        "# HELP fastqc_module_status Status of a module, 0 for pass, 1 for warn and 2 for fail.\n",
+    );
+    for sample in samples {
+        for (module, status) in &sample.statuses {
+            let level = crate::process::STATUSES
+                .iter()
+                .position(|s| s == status)
+                .unwrap_or_default();
+            writeln!(
+                text,
+                "fastqc_module_status{{sample=\"{}\",module=\"{}\"}} {}",
+                escape(&sample.file),
+                escape(module),
+                level
+            )
+            .unwrap();
+        }
+    }
+    text.push_str("# EOF\n");
+    text
+}
+
+/// Writes the metrics to `path` through a temporary file renamed in its place, so that
+/// the textfile collector of the node exporter never reads a partial file.
+pub(crate) fn write(path: &Path, samples: &[Sample]) -> io::Result<()> {
+    let mut temporary = path.as_os_str().to_owned();
+    temporary.push(".tmp");
+    fs::write(&temporary, render(samples))?;
+    fs::rename(&temporary, path)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{render, Sample};
+    #[test]
+    fn test_render() {
+        let sample = |file: &str, q30| Sample {
+            file: file.to_string(),
+            reads: 200,
+            bases: 30200,
+            q30,
+            duplication: Some(2.5),
+            adapters: None,
+            statuses: vec![
+                ("Basic Statistics".to_string(), "pass"),
+                ("Kmer Content".to_string(), "fail"),
+            ],
+        };
+        let text = render(&[sample("a.fastq", Some(91.25)), sample("b \"1\".fq", None)]);
+        let lines = text.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "# TYPE fastqc_reads gauge");
+        assert!(lines.contains(&"fastqc_reads{sample=\"a.fastq\"} 200"));
+        assert!(lines.contains(&"fastqc_q30_bases_percent{sample=\"a.fastq\"} 91.25"));
+        assert!(!text.contains("fastqc_q30_bases_percent{sample=\"b"));
+        assert!(!text.contains("fastqc_adapter_percent{"));
+        assert!(lines.contains(&"fastqc_duplication_percent{sample=\"b \\\"1\\\".fq\"} 2.5"));
+        assert!(
+            lines.contains(&"fastqc_module_status{sample=\"a.fastq\",module=\"Kmer Content\"} 2")
+        );
+        assert_eq!(lines.last(), Some(&"# EOF"));
+    }
+}
@@ -0,0 +1,407 @@
+use crate::input::Record;
+use crate::kmers::MAX_PVALUE;
+use crate::qc::Status;
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+
+/// A quality control module that can be enabled or disabled. The basic statistics are
+/// always reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Module {
+    PerBaseQuality,
+    PerTileQuality,
+    PerSequenceQuality,
+    PerBaseContent,
+    PerSequenceGcContent,
+    PerBaseNContent,
+    ReadLengthDistribution,
+    DuplicationLevels,
+    OverrepresentedSequences,
+    AdapterContent,
+    KmerContent,
+    PolyTails,
+    LowComplexity,
+}
+
+impl Module {
+    /// All modules in the order of FastQC, followed by those only in fastqc-rs.
+    pub const ALL: [Module; 13] = [
+        Module::PerBaseQuality,
+        Module::PerTileQuality,
+        Module::PerSequenceQuality,
+        Module::PerBaseContent,
+        Module::PerSequenceGcContent,
+        Module::PerBaseNContent,
+        Module::ReadLengthDistribution,
+        Module::DuplicationLevels,
+        Module::OverrepresentedSequences,
+        Module::AdapterContent,
+        Module::KmerContent,
+        Module::PolyTails,
+        Module::LowComplexity,
+    ];
+
+    /// Returns the key of the module in JSON reports and data file names.
+    pub fn key(self) -> &'static str {
+        match self {
+            Module::PerBaseQuality => "per_base_quality",
+            Module::PerTileQuality => "per_tile_quality",
+            Module::PerSequenceQuality => "per_sequence_quality",
+            Module::PerBaseContent => "per_base_content",
+            Module::PerSequenceGcContent => "per_sequence_gc_content",
+            Module::PerBaseNContent => "per_base_n_content",
+            Module::ReadLengthDistribution => "read_length_distribution",
+            Module::DuplicationLevels => "duplication_levels",
+            Module::OverrepresentedSequences => "overrepresented_sequences",
+            Module::AdapterContent => "adapter_content",
+            Module::KmerContent => "kmer_content",
+            Module::PolyTails => "poly_tails",
+            Module::LowComplexity => "low_complexity",
+        }
+    }
+
+    /// Returns the name of the module like in the reports of FastQC.
+    pub fn name(self) -> &'static str {
+        match self {
+            Module::PerBaseQuality => "Per base sequence quality",
+            Module::PerTileQuality => "Per tile sequence quality",
+            Module::PerSequenceQuality => "Per sequence quality scores",
+            Module::PerBaseContent => "Per base sequence content",
+            Module::PerSequenceGcContent => "Per sequence GC content",
+            Module::PerBaseNContent => "Per base N content",
+            Module::ReadLengthDistribution => "Sequence Length Distribution",
+            Module::DuplicationLevels => "Sequence Duplication Levels",
+            Module::OverrepresentedSequences => "Overrepresented sequences",
+            Module::AdapterContent => "Adapter Content",
+            Module::KmerContent => "Kmer Content",
+            Module::PolyTails => "Poly-G and poly-A tails",
+            Module::LowComplexity => "Low complexity reads",
+        }
+    }
+
+    /// Returns the module with the given key.
+    pub fn from_key(key: &str) -> Option<Self> {
+        Module::ALL
+            .iter()
+            .copied()
+            .find(|module| module.key() == key)
+    }
+}
+
+/// A set of enabled modules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Modules(u16);
+
+impl Default for Modules {
+    /// Enables all modules.
+    fn default() -> Self {
+        Modules((1 << Module::ALL.len()) - 1)
+    }
+}
+
+impl Modules {
+    pub(crate) fn contains(self, module: Module) -> bool {
+        self.0 & (1 << module as u16) != 0
+    }
+
+    pub(crate) fn set(&mut self, module: Module, enabled: bool) {
+        if enabled {
+            self.0 |= 1 << module as u16;
+        } else {
+            self.0 &= !(1 << module as u16);
+        }
+    }
+
+    /// Returns the keys of the enabled modules.
+    pub(crate) fn keys(self) -> Vec<&'static str> {
+        Module::ALL
+            .iter()
+            .filter(|&&module| self.contains(module))
+            .map(|module| module.key())
+            .collect()
+    }
+}
+
+/// A quality control module, implemented by built-in analyses like the poly-G and
+/// poly-A tails and by modules added by users of the library, e.g. for checks specific
+/// to an assay, whose results are reported after the built-in modules.
+///
+/// A module sees every record in the order of the input unless it is mergeable, in
+/// which case every processing thread observes a part of the records with its own
+/// instance, and the instances are merged afterwards.
+///
+/// ```
+/// use fastqc_rs::{ModuleResult, Qc, QcConfig, QcModule, Record, Status};
+///
+/// /// Counts the reads starting with a primer.
+/// #[derive(Default)]
+/// struct PrimerCheck {
+///     reads: usize,
+///     primed: usize,
+/// }
+///
+/// impl QcModule for PrimerCheck {
+///     fn observe(&mut self, record: &Record) {
+///         self.reads += 1;
+///         if record.seq().starts_with(b"ACGT") {
+///             self.primed += 1;
+///         }
+///     }
+///
+///     fn finish(&self) -> ModuleResult {
+///         let status = if self.primed * 2 < self.reads { Status::Fail } else { Status::Pass };
+///         ModuleResult::new("Primer check", status)
+///             .with_columns(&["Primer", "Reads"])
+///             .with_row(&["ACGT".to_string(), self.primed.to_string()])
+///     }
+/// }
+///
+/// let qc = Qc::new(QcConfig::new().module(PrimerCheck::default));
+/// let report = qc.process_file("tests/resources/example.fastq").unwrap();
+/// assert_eq!(report.custom_modules()[0].status, Status::Fail);
+/// ```
+///
+/// As counting primed reads does not depend on their order, the module could also run
+/// on all threads by being mergeable:
+///
+/// ```
+/// # use fastqc_rs::{ModuleResult, QcModule, Record, Status};
+/// # #[derive(Default)]
+/// # struct PrimerCheck {
+/// #     reads: usize,
+/// #     primed: usize,
+/// # }
+/// impl QcModule for PrimerCheck {
+///     # fn observe(&mut self, record: &Record) {}
+///     # fn finish(&self) -> ModuleResult { ModuleResult::new("Primer check", Status::Pass) }
+///     fn is_mergeable() -> bool {
+///         true
+///     }
+///
+///     fn merge(&mut self, other: &dyn QcModule) {
+///         let other = other.downcast_ref::<PrimerCheck>().unwrap();
+///         self.reads += other.reads;
+///         self.primed += other.primed;
+///     }
+/// }
+/// ```
+pub trait QcModule: Send + Any {
+    fn observe(&mut self, record: &Record);
+
+    /// Returns whether instances observing parts of the records on different threads
+    /// can be combined with [`QcModule::merge`]. Otherwise, one instance observes all
+    /// records in the order of the input.
+    fn is_mergeable() -> bool
+    where
+        Self: Sized,
+    {
+        false
+    }
+
+    /// Adds the records observed by another instance of the same module, which is only
+    /// called for mergeable modules.
+    fn merge(&mut self, other: &dyn QcModule) {
+        let _ = other;
+    }
+
+    /// Evaluates the observed records.
+    fn finish(&self) -> ModuleResult;
+}
+
+impl dyn QcModule {
+    /// Returns the module as its concrete type, e.g. the other instance in
+    /// [`QcModule::merge`].
+    pub fn downcast_ref<M: QcModule>(&self) -> Option<&M> {
+        (self as &dyn Any).downcast_ref()
+    }
+}
+
+/// The status and table of a [`QcModule`] for one input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleResult {
+    /// Name of the module in the reports.
+    pub name: String,
+    pub status: Status,
+    /// Names of the columns of the table, which is left out of the reports if empty.
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl ModuleResult {
+    /// Creates a result without a table.
+    pub fn new(name: &str, status: Status) -> Self {
+        ModuleResult {
+            name: name.to_string(),
+            status,
+            columns: Vec::new(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn with_columns(mut self, columns: &[&str]) -> Self {
+        self.columns = columns.iter().map(|column| column.to_string()).collect();
+        self
+    }
+
+    pub fn with_row<T: ToString>(mut self, row: &[T]) -> Self {
+        self.rows
+            .push(row.iter().map(ToString::to_string).collect());
+        self
+    }
+}
+
+/// Creates a new instance of a custom module for every input, and for every thread if
+/// the module is mergeable.
+#[derive(Clone)]
+pub(crate) struct ModuleFactory {
+    pub(crate) new: Arc<dyn Fn() -> Box<dyn QcModule> + Send + Sync>,
+    pub(crate) mergeable: bool,
+}
+
+impl ModuleFactory {
+    pub(crate) fn new<M, F>(new: F) -> Self
+    where
+        M: QcModule,
+        F: Fn() -> M + Send + Sync + 'static,
+    {
+        ModuleFactory {
+            new: Arc::new(move || Box::new(new())),
+            mergeable: M::is_mergeable(),
+        }
+    }
+}
+
+impl fmt::Debug for ModuleFactory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ModuleFactory")
+    }
+}
+
+/// The values at which a module warns or fails.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Threshold {
+    pub warn: f64,
+    pub fail: f64,
+}
+
+/// The thresholds of all modules, mostly defaulting to those of FastQC. They can be
+/// read from a FastQC limits file with [`QcConfig::limits`](crate::QcConfig::limits).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Thresholds {
+    /// Median base quality at any position at or below which the per base sequence
+    /// quality warns or fails.
+    pub base_quality_median: Threshold,
+    /// Lower quartile of the base qualities at any position below this. Unlike in
+    /// FastQC, this never warns or fails by default.
+    pub base_quality_lower_quartile: Threshold,
+    /// Mean quality of a tile at a position below the mean over all tiles by more than
+    /// this.
+    pub tile_deviation: Threshold,
+    /// Most frequent mean read quality below this.
+    pub sequence_quality: Threshold,
+    /// Difference in percent between A and T or G and C at any position above this.
+    pub base_content_difference: Threshold,
+    /// Percentage of reads deviating from a normal GC distribution above this.
+    pub gc_deviation: Threshold,
+    /// Percentage of N at any position above this.
+    pub n_content: Threshold,
+    /// Percentage of reads remaining after deduplication below this.
+    pub duplication_remaining: Threshold,
+    /// Percentage of the reads of any overrepresented sequence above this.
+    pub overrepresented: Threshold,
+    /// Percentage of reads with any adapter at any position above this.
+    pub adapter_content: Threshold,
+    /// Binomial p-value of any enriched k-mer below this.
+    pub kmer_pvalue: Threshold,
+    /// Like in FastQC, a nonzero value enables the warning for reads of different
+    /// lengths and the failure for empty reads.
+    pub read_length: Threshold,
+    /// Percentage of reads ending in a poly-G or poly-A tail above this. This never
+    /// fails by default.
+    pub poly_tail: Threshold,
+    /// Percentage of reads with a DUST score above 7 of 100, e.g. adapter dimers or
+    /// poly-G reads, above this.
+    pub low_complexity: Threshold,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Thresholds {
+            base_quality_median: Threshold::new(25.0, 20.0),
+            base_quality_lower_quartile: Threshold::new(0.0, 0.0),
+            tile_deviation: Threshold::new(5.0, 10.0),
+            sequence_quality: Threshold::new(27.0, 20.0),
+            base_content_difference: Threshold::new(10.0, 20.0),
+            gc_deviation: Threshold::new(15.0, 30.0),
+            n_content: Threshold::new(5.0, 20.0),
+            duplication_remaining: Threshold::new(80.0, 50.0),
+            overrepresented: Threshold::new(0.0, 1.0),
+            adapter_content: Threshold::new(5.0, 10.0),
+            kmer_pvalue: Threshold::new(MAX_PVALUE, 1e-5),
+            read_length: Threshold::new(1.0, 1.0),
+            poly_tail: Threshold::new(5.0, 100.0),
+            low_complexity: Threshold::new(5.0, 20.0),
+        }
+    }
+}
+
+impl Threshold {
+    pub fn new(warn: f64, fail: f64) -> Self {
+        Threshold { warn, fail }
+    }
+
+    /// Returns the status of a value that is worse the higher it is.
+    pub(crate) fn above(&self, value: f64) -> &'static str {
+        if value > self.fail {
+            "fail"
+        } else if value > self.warn {
+            "warn"
+        } else {
+            "pass"
+        }
+    }
+
+    /// Returns the status of a value that is worse the lower it is.
+    pub(crate) fn below(&self, value: f64) -> &'static str {
+        if value < self.fail {
+            "fail"
+        } else if value < self.warn {
+            "warn"
+        } else {
+            "pass"
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Module, Modules, Threshold};
+    #[test]
+    fn test_modules() {
+        let mut modules = Modules::default();
+        assert!(Module::ALL.iter().all(|&module| modules.contains(module)));
+        modules.set(Module::KmerContent, false);
+        modules.set(Module::PerTileQuality, false);
+        assert!(!modules.contains(Module::KmerContent));
+        assert_eq!(modules.keys().len(), Module::ALL.len() - 2);
+        modules.set(Module::KmerContent, true);
+        assert!(modules.contains(Module::KmerContent));
+        assert_eq!(
+            Module::from_key("adapter_content"),
+            Some(Module::AdapterContent)
+        );
+        assert_eq!(Module::from_key("basic_statistics"), None);
+    }
+    #[test]
+    fn test_threshold() {
+        let threshold = Threshold::new(5.0, 10.0);
+        assert_eq!(threshold.above(5.0), "pass");
+        assert_eq!(threshold.above(10.0), "warn");
+        assert_eq!(threshold.above(10.5), "fail");
+        let threshold = Threshold::new(80.0, 50.0);
+        assert_eq!(threshold.below(80.0), "pass");
+        assert_eq!(threshold.below(50.0), "warn");
+        assert_eq!(threshold.below(49.0), "fail");
+    }
+}
@@ -0,0 +1,167 @@
+use crate::input::Record;
+use crate::modules::{ModuleResult, QcModule};
+use crate::qc::Status;
+
+const BASES: [u8; 4] = *b"ACGT";
+
+/// Counts the dinucleotides and trinucleotides of the reads, which are compared to
+/// the frequencies expected from the base composition to reveal e.g. the bias of
+/// restriction sites or random priming.
+pub(crate) struct MotifCounter {
+    bases: [usize; 4],
+    dinucleotides: [usize; 16],
+    trinucleotides: [usize; 64],
+}
+
+/// Observed and expected number of a motif.
+pub(crate) struct Bias {
+    pub(crate) motif: String,
+    pub(crate) observed: usize,
+    /// Number expected if the bases of the motif occurred independently.
+    pub(crate) expected: f64,
+    /// Observed divided by expected number.
+    pub(crate) ratio: f64,
+}
+
+impl Default for MotifCounter {
+    fn default() -> Self {
+        MotifCounter {
+            bases: [0; 4],
+            dinucleotides: [0; 16],
+            trinucleotides: [0; 64],
+        }
+    }
+}
+
+impl MotifCounter {
+    pub(crate) fn observe(&mut self, seq: &[u8]) {
+        // Motifs are only counted within runs of A, C, G and T
+        let mut code = 0;
+        let mut run = 0;
+        for &base in seq {
+            let index = match base {
+                b'A' | b'a' => 0,
+                b'C' | b'c' => 1,
+                b'G' | b'g' => 2,
+                b'T' | b't' => 3,
+                _ => {
+                    run = 0;
+                    continue;
+                }
+            };
+            code = (code << 2 | index) & 63;
+            run += 1;
+            self.bases[index] += 1;
+            if run >= 2 {
+                self.dinucleotides[code & 15] += 1;
+            }
+            if run >= 3 {
+                self.trinucleotides[code] += 1;
+            }
+        }
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &MotifCounter) {
+        for (count, other) in self.bases.iter_mut().zip(&other.bases) {
+            *count += other;
+        }
+        for (count, other) in self.dinucleotides.iter_mut().zip(&other.dinucleotides) {
+            *count += other;
+        }
+        for (count, other) in self.trinucleotides.iter_mut().zip(&other.trinucleotides) {
+            *count += other;
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.dinucleotides.iter().all(|&count| count == 0)
+    }
+
+    /// Returns the bias of all dinucleotides and trinucleotides whose bases occur,
+    /// the most over- or underrepresented ones first.
+    pub(crate) fn biases(&self) -> Vec<Bias> {
+        let total = self.bases.iter().sum::<usize>() as f64;
+        let mut biases = Vec::new();
+        for (length, counts) in [(2, &self.dinucleotides[..]), (3, &self.trinucleotides[..])] {
+            let motifs = counts.iter().sum::<usize>() as f64;
+            for (code, &observed) in counts.iter().enumerate() {
+                let indices = (0..length)
+                    .rev()
+                    .map(|i| (code >> (2 * i)) & 3)
+                    .collect::<Vec<_>>();
+                let expected = indices.iter().fold(motifs, |expected, &i| {
+                    expected * self.bases[i] as f64 / total
+                });
+                if expected > 0.0 {
+                    biases.push(Bias {
+                        motif: indices.iter().map(|&i| BASES[i] as char).collect(),
+                        observed,
+                        expected,
+                        ratio: observed as f64 / expected,
+                    });
+                }
+            }
+        }
+        // Underrepresentation by half is as biased as overrepresentation by twice
+        let bias = |bias: &Bias| bias.ratio.max(1.0 / bias.ratio);
+        biases.sort_by(|a, b| bias(b).total_cmp(&bias(a)));
+        biases
+    }
+}
+
+impl QcModule for MotifCounter {
+    fn observe(&mut self, record: &Record) {
+        self.observe(&record.seq());
+    }
+
+    fn is_mergeable() -> bool {
+        true
+    }
+
+    fn merge(&mut self, other: &dyn QcModule) {
+        if let Some(other) = other.downcast_ref::<MotifCounter>() {
+            self.merge(other);
+        }
+    }
+
+    /// Returns the biases, which have no status.
+    fn finish(&self) -> ModuleResult {
+        let mut result = ModuleResult::new("Motif bias", Status::Pass)
+            .with_columns(&["Motif", "Observed", "Expected", "Obs/Exp"]);
+        for bias in self.biases() {
+            result = result.with_row(&[
+                bias.motif,
+                bias.observed.to_string(),
+                bias.expected.to_string(),
+                bias.ratio.to_string(),
+            ]);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MotifCounter;
+    #[test]
+    fn test_biases() {
+        let mut counter = MotifCounter::default();
+        counter.observe(b"ACGTNacgt");
+        let mut other = MotifCounter::default();
+        other.observe(b"CG");
+        counter.merge(&other);
+        let biases = counter.biases();
+        assert_eq!(biases.len(), 16 + 64);
+        assert_eq!(
+            biases.iter().filter(|bias| bias.observed > 0).count(),
+            3 + 2
+        );
+        // Three of the seven dinucleotides are CG, of which C and G are 30% of the bases
+        let cg = biases.iter().find(|bias| bias.motif == "CG").unwrap();
+        assert_eq!(cg.observed, 3);
+        assert!((cg.expected - 7.0 * 0.3 * 0.3).abs() < 1e-9);
+        assert!(biases[0].ratio == 0.0 || biases[0].ratio > 1.0);
+        assert!(MotifCounter::default().is_empty());
+    }
+}
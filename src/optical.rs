@@ -0,0 +1,242 @@
+use crate::illumina::IlluminaHeader;
+use crate::sketch::hash;
+use rustc_hash::FxHashMap as HashMap;
+
+/// Length of the start of the reads compared to find duplicates.
+const PREFIX_LENGTH: usize = 50;
+/// Maximal number of distinct sequences whose positions are kept, beyond which only a
+/// sample of the sequences by their hash is kept.
+const MAX_SEQUENCES: usize = 1 << 21;
+/// Maximal number of positions kept per sequence, beyond which further reads of the
+/// sequence are only counted. As the reads of a file are ordered by tile, the kept
+/// positions are those of the first tiles, whose optical duplicates are extrapolated.
+const MAX_POSITIONS: usize = 1 << 10;
+/// Maximal number of positions kept of all sequences, beyond which the sequences are
+/// sampled like beyond `MAX_SEQUENCES`.
+const MAX_TOTAL_POSITIONS: usize = 1 << 22;
+
+/// Position of a cluster on an Illumina flowcell.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Position {
+    lane: u32,
+    tile: u32,
+    x: u32,
+    y: u32,
+}
+
+/// Collects the flowcell positions of the reads by the start of their sequence to
+/// tell optical and ExAmp duplicates, which lie close to each other on the same tile,
+/// from duplicates of the library preparation like Picard, but without alignments.
+pub(crate) struct OpticalCounter {
+    distance: u32,
+    /// Reads by the hash of the start of their sequence.
+    reads: HashMap<u64, Reads>,
+    /// Number of kept positions of all sequences.
+    positions: usize,
+    /// Sequences are only kept if their hash is at most this, halved whenever there
+    /// are too many sequences or positions, so that the kept sequences are a uniform
+    /// sample.
+    threshold: u64,
+}
+
+/// The reads of a sequence.
+#[derive(Default)]
+struct Reads {
+    count: usize,
+    /// Positions of the first `MAX_POSITIONS` reads.
+    positions: Vec<Position>,
+}
+
+/// Estimated duplicates among the reads with flowcell positions.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct OpticalDuplicates {
+    pub(crate) distance: u32,
+    /// Number of reads with a position, of the sampled sequences if there were many.
+    pub(crate) reads: usize,
+    /// Percentage of these reads duplicating another read.
+    pub(crate) duplicates: f64,
+    /// Percentage of these reads duplicating a read close by on the same tile.
+    pub(crate) optical: f64,
+    /// Percentage of the other duplicates, e.g. from PCR.
+    pub(crate) pcr: f64,
+}
+
+impl OpticalCounter {
+    pub(crate) fn new(distance: u32) -> Self {
+        OpticalCounter {
+            distance,
+            reads: HashMap::default(),
+            positions: 0,
+            threshold: u64::MAX,
+        }
+    }
+
+    pub(crate) fn observe(&mut self, id: &[u8], seq: &[u8]) {
+        let Some(position) = parse_position(id) else {
+            return;
+        };
+        let key = hash(&seq[..seq.len().min(PREFIX_LENGTH)]);
+        self.add(key, 1, &[position]);
+    }
+
+    /// Adds the given number of reads of a sequence, of which the given positions are
+    /// kept up to `MAX_POSITIONS`.
+    fn add(&mut self, key: u64, count: usize, positions: &[Position]) {
+        if key > self.threshold {
+            return;
+        }
+        let reads = self.reads.entry(key).or_default();
+        reads.count += count;
+        let kept = positions.len().min(MAX_POSITIONS - reads.positions.len());
+        reads.positions.extend(&positions[..kept]);
+        self.positions += kept;
+        while self.reads.len() > MAX_SEQUENCES || self.positions > MAX_TOTAL_POSITIONS {
+            self.threshold /= 2;
+            self.retain();
+        }
+    }
+
+    /// Drops the sequences whose hash is above the threshold.
+    fn retain(&mut self) {
+        let threshold = self.threshold;
+        self.reads.retain(|&key, _| key <= threshold);
+        self.positions = self.reads.values().map(|reads| reads.positions.len()).sum();
+    }
+
+    /// Adds the positions of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &OpticalCounter) {
+        self.threshold = self.threshold.min(other.threshold);
+        self.retain();
+        for (&key, reads) in &other.reads {
+            self.add(key, reads.count, &reads.positions);
+        }
+    }
+
+    /// Returns the estimated duplicates, or `None` if no read had a position.
+    pub(crate) fn duplicates(&self) -> Option<OpticalDuplicates> {
+        let mut reads = 0;
+        let mut duplicates = 0;
+        let mut optical = 0.0;
+        for sequence in self.reads.values() {
+            reads += sequence.count;
+            duplicates += sequence.count - 1;
+            if sequence.count == 1 {
+                continue;
+            }
+            let mut positions = sequence.positions.clone();
+            positions.sort_unstable();
+            // A read is an optical duplicate of a read before it on the same tile within
+            // the distance, of which only those with x up to the distance are compared
+            let mut close_reads = 0;
+            for (i, position) in positions.iter().enumerate() {
+                let close = positions[..i]
+                    .iter()
+                    .rev()
+                    .take_while(|other| {
+                        (other.lane, other.tile) == (position.lane, position.tile)
+                            && position.x - other.x <= self.distance
+                    })
+                    .any(|other| other.y.abs_diff(position.y) <= self.distance);
+                close_reads += close as usize;
+            }
+            // The reads beyond the kept positions are duplicates in the same proportions
+            optical +=
+                close_reads as f64 * (sequence.count - 1) as f64 / (positions.len() - 1) as f64;
+        }
+        if reads == 0 {
+            return None;
+        }
+        let percentage = |count: usize| count as f64 / reads as f64 * 100.0;
+        Some(OpticalDuplicates {
+            distance: self.distance,
+            reads,
+            duplicates: percentage(duplicates),
+            optical: optical / reads as f64 * 100.0,
+            pcr: (duplicates as f64 - optical) / reads as f64 * 100.0,
+        })
+    }
+}
+
+/// Extracts the lane, tile and x and y coordinates from Illumina read names.
+fn parse_position(id: &[u8]) -> Option<Position> {
+    let header = IlluminaHeader::parse(id)?;
+    Some(Position {
+        lane: header.lane()?,
+        tile: header.tile()?,
+        x: header.x.parse().ok()?,
+        y: header.y.parse().ok()?,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_position, OpticalCounter, Position, MAX_POSITIONS, MAX_TOTAL_POSITIONS};
+    #[test]
+    fn test_parse_position() {
+        let position = Position {
+            lane: 1,
+            tile: 1108,
+            x: 1330,
+            y: 1935,
+        };
+        for id in [
+            &b"SRR9130495.1 D00236:723:HG32CBCX2:1:1108:1330:1935/1"[..],
+            b"D00236:723:HG32CBCX2:1:1108:1330:1935 1:N:0:ATCACG",
+            b"HWUSI-EAS100R:1:1108:1330:1935#0/1",
+        ] {
+            assert_eq!(parse_position(id), Some(position));
+        }
+        assert_eq!(parse_position(b"read1"), None);
+        assert_eq!(parse_position(b"M:1:FC:1:x:1:1"), None);
+    }
+    #[test]
+    fn test_duplicates() {
+        let mut counter = OpticalCounter::new(100);
+        counter.observe(b"M:1:FC:1:1101:1000:1000", b"ACGTACGT");
+        // Close by on the same tile, but not on another tile
+        counter.observe(b"M:1:FC:1:1101:1050:1090", b"ACGTACGT");
+        counter.observe(b"M:1:FC:1:1102:1000:1000", b"ACGTACGT");
+        let mut other = OpticalCounter::new(100);
+        // Far away, and a distinct sequence
+        other.observe(b"M:1:FC:1:1101:1000:5000", b"ACGTACGT");
+        other.observe(b"M:1:FC:1:1101:1010:1010", b"TTTTACGT");
+        other.observe(b"read without position", b"ACGTACGT");
+        counter.merge(&other);
+        let duplicates = counter.duplicates().unwrap();
+        assert_eq!(duplicates.reads, 5);
+        assert_eq!(duplicates.duplicates, 60.0);
+        assert_eq!(duplicates.optical, 20.0);
+        assert_eq!(duplicates.pcr, 40.0);
+        assert_eq!(OpticalCounter::new(100).duplicates(), None);
+    }
+    #[test]
+    fn test_max_positions() {
+        // Only the first positions of a sequence are kept, extrapolating their optical
+        // duplicates to the others
+        let mut counter = OpticalCounter::new(100);
+        for i in 0..MAX_POSITIONS * 4 {
+            let x = 1000 + (i / 2) * 1000 + i % 2;
+            counter.observe(format!("M:1:FC:1:1101:{}:1000", x).as_bytes(), b"ACGT");
+        }
+        assert_eq!(counter.positions, MAX_POSITIONS);
+        let duplicates = counter.duplicates().unwrap();
+        assert_eq!(duplicates.reads, MAX_POSITIONS * 4);
+        let expected = (MAX_POSITIONS / 2) as f64 * (MAX_POSITIONS * 4 - 1) as f64
+            / (MAX_POSITIONS - 1) as f64
+            / (MAX_POSITIONS * 4) as f64
+            * 100.0;
+        assert!((duplicates.optical - expected).abs() < 1e-9);
+        // Sequences are sampled once there are too many positions
+        for i in 0..MAX_TOTAL_POSITIONS / MAX_POSITIONS + 1 {
+            let mut other = OpticalCounter::new(100);
+            other.add(
+                i as u64,
+                MAX_POSITIONS,
+                &vec![Position::default(); MAX_POSITIONS],
+            );
+            counter.merge(&other);
+        }
+        assert!(counter.positions <= MAX_TOTAL_POSITIONS);
+        assert!(counter.threshold < u64::MAX);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::contaminants::{best_hit, Contaminant};
+use crate::duplication::truncate;
+use rustc_hash::FxHashMap as HashMap;
+
+/// New sequences are only tracked within this many reads, afterwards only
+/// sequences already seen are counted to keep memory bounded.
+const TRACKING_LIMIT: usize = 200_000;
+/// Sequences making up more than this percentage of the library are reported.
+const MIN_PERCENTAGE: f64 = 0.1;
+
+/// Counts sequences to find the ones making up a large fraction of the library.
+#[derive(Default)]
+pub(crate) struct OverrepresentedCounter {
+    counts: HashMap<Vec<u8>, usize>,
+    total: usize,
+}
+
+/// A sequence making up a suspiciously large fraction of the library.
+pub(crate) struct OverrepresentedSequence {
+    pub(crate) sequence: String,
+    pub(crate) count: usize,
+    pub(crate) percentage: f64,
+    pub(crate) source: String,
+    /// Name of the best matching contaminant, if any.
+    pub(crate) contaminant: Option<String>,
+}
+
+impl OverrepresentedCounter {
+    pub(crate) fn observe(&mut self, seq: &[u8]) {
+        let seq = truncate(seq);
+        if self.total < TRACKING_LIMIT {
+            *self.counts.entry(seq.to_vec()).or_insert(0) += 1;
+        } else if let Some(count) = self.counts.get_mut(seq) {
+            *count += 1;
+        }
+        self.total += 1;
+    }
+
+    /// Returns all overrepresented sequences, most frequent first, annotated with
+    /// their best hit in the given contaminant list.
+    pub(crate) fn overrepresented(
+        &self,
+        contaminants: &[Contaminant],
+    ) -> Vec<OverrepresentedSequence> {
+        let mut sequences: Vec<_> = self
+            .counts
+            .iter()
+            .map(|(seq, &count)| (seq, count, count as f64 / self.total as f64 * 100.0))
+            .filter(|&(_, _, percentage)| percentage > MIN_PERCENTAGE)
+            .collect();
+        sequences.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        sequences
+            .into_iter()
+            .map(|(seq, count, percentage)| {
+                let hit = best_hit(contaminants, seq);
+                OverrepresentedSequence {
+                    sequence: String::from_utf8_lossy(seq).to_string(),
+                    count,
+                    percentage,
+                    source: hit
+                        .as_ref()
+                        .map_or_else(|| "No Hit".to_string(), |hit| hit.to_string()),
+                    contaminant: hit.map(|hit| hit.name.to_string()),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::OverrepresentedCounter;
+    #[test]
+    fn test_overrepresented() {
+        let mut counter = OverrepresentedCounter::default();
+        for i in 0..2000_usize {
+            let seq = if i % 100 == 0 {
+                b"GATCGGAAGAGCACACGTCTGAACTCCAGTCAC".to_vec()
+            } else {
+                format!("{:016b}", i).into_bytes()
+            };
+            counter.observe(&seq);
+        }
+        let overrepresented =
+            counter.overrepresented(&crate::contaminants::Contaminant::default_list());
+        assert_eq!(overrepresented.len(), 1);
+        assert_eq!(overrepresented[0].count, 20);
+        assert_eq!(overrepresented[0].percentage, 1.0);
+        assert!(overrepresented[0].source.ends_with("(100% over 33bp)"));
+        let custom = crate::contaminants::Contaminant::parse_list(
+            "# Own list\nMy adapter\tGATCGGAAGAGCACACGTCTGAACTCCAGTCAC\n",
+        );
+        let overrepresented = counter.overrepresented(&custom);
+        assert_eq!(
+            overrepresented[0].contaminant.as_deref(),
+            Some("My adapter")
+        );
+    }
+}
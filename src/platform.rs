@@ -0,0 +1,251 @@
+use crate::illumina::IlluminaHeader;
+
+/// Prefixes of the instrument IDs of Illumina sequencers with the model, chemistry
+/// and whether its flowcells are patterned. Longer prefixes come first, as the rest
+/// of an ID is a serial number starting with a digit.
+const ILLUMINA: [(&str, &str, &str, bool); 19] = [
+    ("HWUSI-EAS", "Genome Analyzer IIx", "four-color", false),
+    ("HWI-ST", "HiSeq 2000", "four-color", false),
+    ("HWI-M", "MiSeq", "four-color", false),
+    ("HWI-D", "HiSeq 2500", "four-color", false),
+    ("SN", "HiSeq 2000", "four-color", false),
+    ("NB", "NextSeq 500/550", "two-color", false),
+    ("NS", "NextSeq 500/550", "two-color", false),
+    ("VH", "NextSeq 1000/2000", "two-color", true),
+    ("VL", "NextSeq 1000/2000", "two-color", true),
+    ("MN", "MiniSeq", "two-color", false),
+    ("FS", "iSeq 100", "one-color", true),
+    ("LH", "NovaSeq X", "two-color", true),
+    ("M", "MiSeq", "four-color", false),
+    ("D", "HiSeq 2500", "four-color", false),
+    ("C", "HiSeq 1500", "four-color", false),
+    ("J", "HiSeq 3000", "four-color", true),
+    ("K", "HiSeq 4000", "four-color", true),
+    ("E", "HiSeq X", "four-color", true),
+    ("A", "NovaSeq 6000", "two-color", true),
+];
+
+/// Prefixes of the movie names of PacBio sequencers with the model.
+const PACBIO: [(&str, &str); 4] = [
+    ("m84", "Revio"),
+    ("m64", "Sequel II"),
+    ("m54", "Sequel"),
+    ("m", "RS II"),
+];
+
+/// Read length from which reads without a known header are taken for long reads.
+const LONG_READS: f64 = 1000.0;
+
+/// The sequencing platform, guessed from the read names or else the qualities.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Platform {
+    pub(crate) vendor: &'static str,
+    pub(crate) instrument: Option<&'static str>,
+    /// Chemistry of Illumina sequencers, e.g. `two-color`.
+    pub(crate) chemistry: Option<&'static str>,
+    /// Whether the flowcells have a fixed grid of wells, on which duplicates of
+    /// neighbouring clusters and tile effects are common.
+    pub(crate) patterned: bool,
+}
+
+impl Platform {
+    /// Guesses the platform from the ID of a read, the phred scores occurring in the
+    /// file and the mean read length.
+    pub(crate) fn detect(id: &[u8], scores: &[usize], mean_length: f64) -> Option<Self> {
+        let id = std::str::from_utf8(id).ok()?;
+        Platform::from_id(id).or_else(|| Platform::from_qualities(scores, mean_length))
+    }
+
+    fn from_id(id: &str) -> Option<Self> {
+        let name = id.split_whitespace().next()?;
+        if id.contains(" runid=") || is_uuid(name) {
+            return Some(Platform::new("Oxford Nanopore", None, None, false));
+        }
+        // PacBio reads are named by their movie, e.g. `m64011_190830_220126/1/ccs`
+        if let Some(movie) = name
+            .split('/')
+            .next()
+            .and_then(|name| name.strip_prefix('m'))
+        {
+            let digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+            let mut parts = movie.split('_');
+            let serial = parts
+                .next()?
+                .trim_end_matches(|c: char| c.is_ascii_alphabetic());
+            let date = parts.next().unwrap_or_default();
+            if name.contains('/') && digits(serial) && date.len() == 6 && digits(date) {
+                let instrument = PACBIO
+                    .iter()
+                    .find(|(prefix, _)| name.starts_with(prefix))
+                    .map(|&(_, instrument)| instrument);
+                return Some(Platform::new("PacBio", instrument, None, false));
+            }
+        }
+        IlluminaHeader::parse(id.as_bytes()).and_then(|header| illumina(header.instrument))
+    }
+
+    /// Guesses the platform from the binned qualities of recent Illumina sequencers or
+    /// the qualities and lengths of long reads.
+    fn from_qualities(scores: &[usize], mean_length: f64) -> Option<Self> {
+        let occurring = scores
+            .iter()
+            .enumerate()
+            .filter(|(_, &count)| count > 0)
+            .map(|(score, _)| score)
+            .collect::<Vec<_>>();
+        let binned = |bins: &[usize]| {
+            !occurring.is_empty() && occurring.iter().all(|score| bins.contains(score))
+        };
+        if binned(&[2, 12, 23, 37]) {
+            Some(Platform::new(
+                "Illumina",
+                Some("NovaSeq 6000"),
+                Some("two-color"),
+                true,
+            ))
+        } else if binned(&[2, 14, 21, 27, 32, 36]) {
+            Some(Platform::new(
+                "Illumina",
+                Some("NextSeq 500/550"),
+                Some("two-color"),
+                false,
+            ))
+        } else if mean_length < LONG_READS {
+            None
+        } else if occurring.last().is_some_and(|&max| max > 60) {
+            Some(Platform::new("PacBio", None, None, false))
+        } else {
+            Some(Platform::new("Oxford Nanopore", None, None, false))
+        }
+    }
+
+    fn new(
+        vendor: &'static str,
+        instrument: Option<&'static str>,
+        chemistry: Option<&'static str>,
+        patterned: bool,
+    ) -> Self {
+        Platform {
+            vendor,
+            instrument,
+            chemistry,
+            patterned,
+        }
+    }
+
+    /// Returns whether a lost signal is called as G, as on two-color and one-color
+    /// chemistry, so that reads may end in poly-G tails.
+    pub(crate) fn dark_g(&self) -> bool {
+        matches!(self.chemistry, Some("two-color" | "one-color"))
+    }
+
+    /// Returns the vendor and instrument, e.g. `Illumina NovaSeq 6000`.
+    pub(crate) fn name(&self) -> String {
+        match self.instrument {
+            Some(instrument) => format!("{} {}", self.vendor, instrument),
+            None => self.vendor.to_string(),
+        }
+    }
+}
+
+/// Returns the Illumina sequencer of the instrument of a read, e.g. `A00123`.
+fn illumina(instrument: &str) -> Option<Platform> {
+    ILLUMINA
+        .iter()
+        .find_map(|&(prefix, model, chemistry, patterned)| {
+            let serial = instrument.strip_prefix(prefix)?;
+            serial
+                .starts_with(|c: char| c.is_ascii_digit())
+                .then_some(Platform::new(
+                    "Illumina",
+                    Some(model),
+                    Some(chemistry),
+                    patterned,
+                ))
+        })
+}
+
+/// Returns whether the read name is a UUID, like those of nanopore reads.
+fn is_uuid(name: &str) -> bool {
+    let groups = name.split('-').map(str::len).collect::<Vec<_>>();
+    groups == [8, 4, 4, 4, 12]
+        && name
+            .bytes()
+            .all(|byte| byte == b'-' || byte.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod test {
+    use super::Platform;
+    #[test]
+    fn test_detect() {
+        let name = |id: &str| Platform::detect(id.as_bytes(), &[], 100.0).map(|p| p.name());
+        let novaseq = Platform::detect(
+            b"A00123:8:HVWKDDSXX:1:1101:1018:1000 1:N:0:ACGT",
+            &[],
+            150.0,
+        );
+        assert_eq!(
+            novaseq,
+            Some(Platform::new(
+                "Illumina",
+                Some("NovaSeq 6000"),
+                Some("two-color"),
+                true
+            ))
+        );
+        assert!(novaseq.unwrap().dark_g());
+        assert_eq!(
+            name("SRR9130495.1 D00236:723:HG32CBCX2:1:1108:1330:1935/1").as_deref(),
+            Some("Illumina HiSeq 2500")
+        );
+        assert_eq!(
+            name("M01234:12:000000000-A1B2C:1:1101:15589:1331").as_deref(),
+            Some("Illumina MiSeq")
+        );
+        assert_eq!(
+            name("VH00123:5:AAAKJ2:1:1101:1000:1000").as_deref(),
+            Some("Illumina NextSeq 1000/2000")
+        );
+        assert_eq!(
+            name("HWUSI-EAS100R:6:73:941:1973#0/1").as_deref(),
+            Some("Illumina Genome Analyzer IIx")
+        );
+        assert_eq!(
+            name("0a1b2c3d-4e5f-6a7b-8c9d-0e1f2a3b4c5d runid=abc ch=1").as_deref(),
+            Some("Oxford Nanopore")
+        );
+        assert_eq!(
+            name("read1 runid=abc start_time=2020-01-01T00:00:00Z").as_deref(),
+            Some("Oxford Nanopore")
+        );
+        assert_eq!(
+            name("m64011_190830_220126/1/ccs").as_deref(),
+            Some("PacBio Sequel II")
+        );
+        assert_eq!(
+            name("m54006_160504_020705/4194387/0_1234").as_deref(),
+            Some("PacBio Sequel")
+        );
+        assert_eq!(name("read1/1"), None);
+        assert_eq!(name("Apple:1:2:3:4"), None);
+    }
+    #[test]
+    fn test_qualities() {
+        let mut scores = vec![0; 42];
+        scores[2] = 10;
+        scores[37] = 100;
+        let platform = Platform::detect(b"SRR1.1 1 length=150", &scores, 150.0).unwrap();
+        assert_eq!(platform.name(), "Illumina NovaSeq 6000");
+        scores[30] = 1;
+        assert_eq!(Platform::detect(b"read1", &scores, 150.0), None);
+        let platform = Platform::detect(b"read1", &scores, 5000.0).unwrap();
+        assert_eq!(platform.vendor, "Oxford Nanopore");
+        scores.resize(94, 0);
+        scores[93] = 1;
+        assert_eq!(
+            Platform::detect(b"read1", &scores, 15000.0).unwrap().vendor,
+            "PacBio"
+        );
+    }
+}
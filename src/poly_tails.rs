@@ -0,0 +1,160 @@
+use crate::input::Record;
+use crate::modules::{Module, ModuleResult, QcModule, Threshold, Thresholds};
+use crate::process::most_severe;
+use crate::qc::Status;
+
+/// Minimum length of a trailing run of the same base to be counted as a tail, like
+/// the default of `--poly_g_min_len` of fastp.
+pub(crate) const MIN_TAIL: usize = 10;
+
+/// Bases of the counted tails: poly-G, which two-color sequencers like the NovaSeq
+/// call if the signal is lost, and poly-A.
+pub(crate) const TAILS: [u8; 2] = *b"GA";
+
+/// Counts the reads ending in a poly-G or poly-A tail by the length of the tail.
+pub(crate) struct PolyTailCounter {
+    /// Number of reads per tail length of each base, starting at `MIN_TAIL`.
+    lengths: [Vec<usize>; 2],
+    reads: usize,
+    /// Percentage of reads with either tail at which the module warns or fails.
+    threshold: Threshold,
+}
+
+impl Default for PolyTailCounter {
+    fn default() -> Self {
+        PolyTailCounter::new(Thresholds::default().poly_tail)
+    }
+}
+
+/// Number of reads with a tail of the same base and length.
+pub(crate) struct Tails {
+    pub(crate) base: char,
+    pub(crate) length: usize,
+    pub(crate) count: usize,
+}
+
+impl PolyTailCounter {
+    pub(crate) fn new(threshold: Threshold) -> Self {
+        PolyTailCounter {
+            lengths: [Vec::new(), Vec::new()],
+            reads: 0,
+            threshold,
+        }
+    }
+
+    pub(crate) fn observe(&mut self, seq: &[u8]) {
+        self.reads += 1;
+        let Some(&last) = seq.last() else {
+            return;
+        };
+        let Some(index) = TAILS.iter().position(|&b| b == last.to_ascii_uppercase()) else {
+            return;
+        };
+        let length = seq
+            .iter()
+            .rev()
+            .take_while(|base| base.to_ascii_uppercase() == TAILS[index])
+            .count();
+        if length >= MIN_TAIL {
+            let lengths = &mut self.lengths[index];
+            if lengths.len() <= length - MIN_TAIL {
+                lengths.resize(length - MIN_TAIL + 1, 0);
+            }
+            lengths[length - MIN_TAIL] += 1;
+        }
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &PolyTailCounter) {
+        for (lengths, other) in self.lengths.iter_mut().zip(&other.lengths) {
+            if lengths.len() < other.len() {
+                lengths.resize(other.len(), 0);
+            }
+            for (count, other) in lengths.iter_mut().zip(other) {
+                *count += other;
+            }
+        }
+        self.reads += other.reads;
+    }
+
+    /// Returns the percentage of reads with a tail of each base of [`TAILS`].
+    pub(crate) fn percentages(&self) -> [f64; 2] {
+        self.lengths.each_ref().map(|lengths| match self.reads {
+            0 => 0.0,
+            reads => lengths.iter().sum::<usize>() as f64 / reads as f64 * 100.0,
+        })
+    }
+
+    /// Returns the number of reads by tail base and length, leaving out lengths
+    /// without tails.
+    pub(crate) fn distribution(&self) -> Vec<Tails> {
+        let mut distribution = Vec::new();
+        for (&base, lengths) in TAILS.iter().zip(&self.lengths) {
+            for (i, &count) in lengths.iter().enumerate() {
+                if count > 0 {
+                    distribution.push(Tails {
+                        base: base as char,
+                        length: i + MIN_TAIL,
+                        count,
+                    });
+                }
+            }
+        }
+        distribution
+    }
+}
+
+impl QcModule for PolyTailCounter {
+    fn observe(&mut self, record: &Record) {
+        self.observe(&record.seq());
+    }
+
+    fn is_mergeable() -> bool {
+        true
+    }
+
+    fn merge(&mut self, other: &dyn QcModule) {
+        if let Some(other) = other.downcast_ref::<PolyTailCounter>() {
+            self.merge(other);
+        }
+    }
+
+    fn finish(&self) -> ModuleResult {
+        let status = most_severe(
+            self.percentages()
+                .map(|percentage| self.threshold.above(percentage)),
+        );
+        let mut result = ModuleResult::new(Module::PolyTails.name(), Status::parse(status))
+            .with_columns(&["Base", "Length", "Count"]);
+        for tails in self.distribution() {
+            result = result.with_row(&[
+                tails.base.to_string(),
+                tails.length.to_string(),
+                tails.count.to_string(),
+            ]);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PolyTailCounter;
+    #[test]
+    fn test_tails() {
+        let mut counter = PolyTailCounter::default();
+        counter.observe(b"ACGTGGGGGGGGGGGG");
+        counter.observe(b"ACGTAAAAAAAAA");
+        counter.observe(b"");
+        let mut other = PolyTailCounter::default();
+        other.observe(b"aaaaaaaaaaaaaaaaaaaa");
+        counter.merge(&other);
+        let distribution = counter
+            .distribution()
+            .iter()
+            .map(|tails| (tails.base, tails.length, tails.count))
+            .collect::<Vec<_>>();
+        assert_eq!(distribution, [('G', 12, 1), ('A', 20, 1)]);
+        assert_eq!(counter.percentages(), [25.0, 25.0]);
+    }
+}
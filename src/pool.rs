@@ -0,0 +1,101 @@
+use crate::error::FastqcError;
+use crate::progress;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Returns the number of files processed at the same time with the given threads, so
+/// that every file gets an equal share of at least one thread.
+pub(crate) fn workers(threads: usize, files: usize) -> usize {
+    threads.min(files).max(1)
+}
+
+/// Processes the files on the given number of worker threads, each taking the next
+/// file once it is done with one, and returns the results in the order of the files.
+/// No more files are started after an error, and the error of the first failed file is
+/// returned. The number of processed files is shown on standard error if `progress`.
+pub(crate) fn process_files<T, R, F>(
+    files: &[T],
+    workers: usize,
+    progress: bool,
+    process: F,
+) -> Result<Vec<R>, FastqcError>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> Result<R, FastqcError> + Sync,
+{
+    if workers <= 1 || files.len() <= 1 {
+        return files.iter().map(process).collect();
+    }
+    let bar = progress.then(|| progress::files(files.len()));
+    let next = AtomicUsize::new(0);
+    let failed = AtomicBool::new(false);
+    let results = Mutex::new((0..files.len()).map(|_| None).collect::<Vec<_>>());
+    thread::scope(|scope| {
+        for _ in 0..workers.min(files.len()) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                if i >= files.len() || failed.load(Ordering::Relaxed) {
+                    return;
+                }
+                let result = process(&files[i]);
+                if result.is_err() {
+                    failed.store(true, Ordering::Relaxed);
+                }
+                if let Some(bar) = &bar {
+                    bar.inc(1);
+                }
+                results.lock().unwrap()[i] = Some(result);
+            });
+        }
+    });
+    if let Some(bar) = bar {
+        bar.finish();
+    }
+    // Files are started in order, so only those after a failed one may lack a result
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map_while(|result| result)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{process_files, workers};
+    use crate::FastqcError;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+    use std::time::Duration;
+    #[test]
+    fn test_process_files() {
+        assert_eq!(workers(8, 3), 3);
+        assert_eq!(workers(2, 96), 2);
+        assert_eq!(workers(4, 0), 1);
+        let files = (0..20).collect::<Vec<u64>>();
+        let running = AtomicUsize::new(0);
+        let most_running = AtomicUsize::new(0);
+        let squares = process_files(&files, 4, false, |&file| {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            most_running.fetch_max(now, Ordering::SeqCst);
+            // Later files finish first, which must not change the order of the results
+            thread::sleep(Duration::from_millis(20 - file));
+            running.fetch_sub(1, Ordering::SeqCst);
+            Ok(file * file)
+        })
+        .unwrap();
+        assert_eq!(
+            squares,
+            files.iter().map(|file| file * file).collect::<Vec<_>>()
+        );
+        assert!(most_running.load(Ordering::SeqCst) <= 4);
+        let error = process_files(&files, 3, false, |&file| match file {
+            5 | 7 => Err(FastqcError::Invalid(format!("file {}", file))),
+            file => Ok(file),
+        })
+        .unwrap_err();
+        assert_eq!(error.to_string(), "file 5");
+    }
+}
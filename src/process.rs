@@ -1,26 +1,120 @@
-use chrono::{DateTime, Local};
+use crate::adapters::{Adapter, AdapterCounter};
+use crate::barcodes::{BarcodeCounter, Barcodes};
+use crate::bins::{group_counts, group_means, Binning};
+use crate::casava;
+use crate::cohort::{Cohort, Sample};
+use crate::compare::{Comparison, METRICS};
+use crate::composition::{
+    acgt_percentages, CompositionCounter, GcContentCounter, NContentCounter, A, C, G, T,
+};
+use crate::contaminants::Contaminant;
+use crate::coverage::Coverage;
+use crate::duplication::{DuplicationCounter, Saturation, LEVELS};
+use crate::encoding::{quality_bins, PhredEncoding};
+use crate::error::FastqcError;
+use crate::expected_errors::ExpectedErrorCounter;
+use crate::homopolymers::HomopolymerCounter;
+use crate::index_hopping::{index_hopping, IndexHopping};
+use crate::input::{AlignmentFilter, OwnedRecord, Reader, Record};
+use crate::insert_size::{InsertSizeCounter, InsertSizes};
+use crate::invalid_reads::InvalidReadCounter;
+use crate::kmers::KmerCounter;
+use crate::lanes::LaneCounter;
+use crate::length_quality::LengthQualityCounter;
+use crate::lengths::ReadLengthCounter;
+use crate::low_complexity::LowComplexityCounter;
+use crate::modules::{Module, ModuleFactory, ModuleResult, Modules, QcModule, Thresholds};
+use crate::motifs::MotifCounter;
+use crate::optical::{OpticalCounter, OpticalDuplicates};
+use crate::overrepresented::OverrepresentedCounter;
+use crate::platform::Platform;
+use crate::poly_tails::PolyTailCounter;
+use crate::progress::Progress;
+use crate::qualities::{BaseQualityCounter, SequenceQualityCounter};
+use crate::read_ids::ReadIdCounter;
+use crate::read_names::{ReadNameCounter, ReadNames};
+use crate::run_info::{RunInfo, RunInfoCounter};
+use crate::run_time::RunTimeCounter;
+use crate::sample::Reservoir;
+use crate::screen::{Reference, ScreenCounter, ScreenCounts};
+use crate::spectrum::{GenomeModel, Spectrum, SpectrumCounter};
+use crate::survival::{Survival, SurvivalCounter, SurvivalFilters};
+use crate::svg::{Chart, QualityBox, Series};
+use crate::tiles::TileCounter;
+use crate::timings::{timed, Timings};
+use crate::trim_simulation::{TrimCutoffs, TrimSimulationCounter};
+use crate::trimming::Trimming;
+use crate::umis::{UmiCounter, Umis};
+use crate::watch::Watcher;
+use chrono::{DateTime, Datelike, Local, Timelike};
 use itertools::Itertools;
-use needletail::{parse_fastx_file, Sequence};
-use rustc_hash::FxHashMap as HashMap;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use serde_json::json;
 use serde_json::Value;
-use std::error::Error;
 use std::ffi::OsStr;
-use std::fs::File;
+use std::fmt;
+use std::fs::{self, File};
 use std::io;
 use std::io::Write;
 use std::path::Path;
+use std::sync::atomic::AtomicU64;
+use std::sync::mpsc::sync_channel;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use std::{mem, thread};
 use tera::{self, Context, Tera};
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
 
-const BASES: [char; 5] = ['A', 'C', 'G', 'T', 'N'];
-#[allow(unused)]
-const A: usize = 0;
-const C: usize = 1;
-const G: usize = 2;
-const T: usize = 3;
-const N: usize = 4;
+/// JavaScript and CSS libraries of the HTML report by their CDN URL, so that reports
+/// can be created offline.
+const ASSETS: [(&str, &str); 7] = [
+    (
+        "https://stackpath.bootstrapcdn.com/bootstrap/4.4.1/css/bootstrap.min.css",
+        include_str!("report/assets/bootstrap-4.4.1.min.css"),
+    ),
+    (
+        "https://code.jquery.com/jquery-3.3.1.slim.min.js",
+        include_str!("report/assets/jquery-3.3.1.slim.min.js"),
+    ),
+    (
+        "https://cdnjs.cloudflare.com/ajax/libs/popper.js/1.14.7/umd/popper.min.js",
+        include_str!("report/assets/popper-1.14.7.min.js"),
+    ),
+    (
+        "https://stackpath.bootstrapcdn.com/bootstrap/4.3.1/js/bootstrap.min.js",
+        include_str!("report/assets/bootstrap-4.3.1.min.js"),
+    ),
+    (
+        "https://cdn.jsdelivr.net/npm/vega@5.17.0",
+        include_str!("report/assets/vega-5.17.0.min.js"),
+    ),
+    (
+        "https://cdn.jsdelivr.net/npm/vega-lite@4.17.0",
+        include_str!("report/assets/vega-lite-4.17.0.min.js"),
+    ),
+    (
+        "https://cdn.jsdelivr.net/npm/vega-embed@6.12.2",
+        include_str!("report/assets/vega-embed-6.12.2.min.js"),
+    ),
+];
 
-fn quartiles(hist: &[usize]) -> [f32; 5] {
+/// Number of k-mers shown in the k-mer quantities plot.
+const PLOTTED_KMERS: usize = 1000;
+/// Number of enriched k-mers shown in the positional enrichment plot.
+const PLOTTED_ENRICHED_KMERS: usize = 6;
+/// Number of records sent to a worker thread at once.
+const BATCH_SIZE: usize = 4096;
+/// Factor by which the error rates estimated from singleton k-mers and expected from
+/// the qualities have to differ for the qualities to be flagged as miscalibrated.
+const MISCALIBRATION_RATIO: f64 = 2.0;
+/// Version of the structure of the JSON report, increased if fields are removed,
+/// renamed or change their type, but not if fields are added.
+pub(crate) const SCHEMA_VERSION: u32 = 1;
+/// JSON Schema of the JSON report of the current [`SCHEMA_VERSION`].
+pub(crate) const REPORT_SCHEMA: &str = include_str!("report/report_schema.json");
+
+pub(crate) fn quartiles(hist: &[usize]) -> [f32; 5] {
     let sum = hist.iter().sum::<usize>();
     assert!(sum != 0);
     if sum == 1 {
@@ -30,143 +124,4928 @@ fn quartiles(hist: &[usize]) -> [f32; 5] {
         ) as f32;
         return [value, value, value, value, value];
     }
-    let mut ret = [0_f32; 5];
-    // compute the quartiles
-    for (i, quantile) in [0.25, 0.5, 0.75].iter().enumerate() {
-        let rank = quantile * (sum - 1) as f64;
-        let rank_ = rank.floor();
-        let delta = rank - rank_;
-        let n = rank_ as usize + 1;
-        let mut acc = 0;
-        let mut lo = None;
-        for (hi, &count) in hist.iter().enumerate().filter(|(_, &count)| count > 0) {
-            if acc == n && lo.is_some() {
-                let lo = lo.unwrap() as f64;
-                ret[i + 1] = (lo + (hi as f64 - lo) * delta) as f32;
-                break;
-            } else if acc + count > n {
-                ret[i + 1] = hi as f32;
-                break;
+    let mut ret = [0_f32; 5];
+    // compute the quartiles
+    for (i, quantile) in [0.25, 0.5, 0.75].iter().enumerate() {
+        let rank = quantile * (sum - 1) as f64;
+        let rank_ = rank.floor();
+        let delta = rank - rank_;
+        let n = rank_ as usize + 1;
+        let mut acc = 0;
+        let mut lo = None;
+        for (hi, &count) in hist.iter().enumerate().filter(|(_, &count)| count > 0) {
+            match lo {
+                Some(lo) if acc == n => {
+                    let lo = lo as f64;
+                    ret[i + 1] = (lo + (hi as f64 - lo) * delta) as f32;
+                    break;
+                }
+                _ if acc + count > n => {
+                    ret[i + 1] = hi as f32;
+                    break;
+                }
+                _ => {}
+            }
+            acc += count;
+            lo = Some(hi);
+        }
+    }
+    // compute lower, upper fences
+    // TODO(lhepler): the UI reports these as min/max, which is incorrect.
+    // If that's what we want, we can return those values.
+    let iqr = ret[3] - ret[1];
+    ret[0] = ret[1] - 1.5 * iqr;
+    ret[4] = ret[3] + 1.5 * iqr;
+    ret
+}
+
+/// Returns the lowest value of a histogram such that at least the given fraction of
+/// all entries is not greater, e.g. the 10th percentile for `0.1`.
+fn percentile(hist: &[usize], fraction: f64) -> usize {
+    let target = (hist.iter().sum::<usize>() as f64 * fraction).ceil() as usize;
+    let mut acc = 0;
+    for (value, &count) in hist.iter().enumerate() {
+        acc += count;
+        if acc >= target.max(1) {
+            return value;
+        }
+    }
+    hist.len().saturating_sub(1)
+}
+
+/// Options for the analysis of all input files.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Options {
+    /// Length of the counted k-mers.
+    pub(crate) k: u8,
+    /// Phred offset used instead of the detected one.
+    pub(crate) phred_offset: Option<u8>,
+    pub(crate) alignment_filter: AlignmentFilter,
+    pub(crate) binning: Binning,
+    pub(crate) format: Format,
+    pub(crate) data_format: DataFormat,
+    /// Whether report assets that are not bundled may be fetched from the network.
+    pub(crate) fetch_assets: bool,
+    /// Number of threads processing the records of a file.
+    pub(crate) threads: usize,
+    /// Number of input files of an aggregated report processed at the same time, each
+    /// on `threads` threads.
+    pub(crate) parallel_files: usize,
+    /// Number of reads randomly sampled from every file instead of using all reads.
+    pub(crate) sample: Option<usize>,
+    /// Seed of the random sample of the reads.
+    pub(crate) seed: u64,
+    /// Number of records read from the start of every file instead of all records.
+    pub(crate) max_reads: Option<usize>,
+    /// Whether the progress of reading a file is shown on standard error.
+    pub(crate) progress: bool,
+    /// Memory in bytes for counting duplicate sequences exactly, shared by all threads
+    /// processing files.
+    pub(crate) duplication_memory: usize,
+    /// Whether the duplication levels are estimated from the first distinct sequences
+    /// like FastQC instead of counting all sequences.
+    pub(crate) fastqc_duplication: bool,
+    /// Memory in bytes for detecting duplicate read IDs exactly, per thread.
+    pub(crate) read_id_memory: usize,
+    /// Size of the sequenced genome in bases, of which the coverage is then estimated.
+    pub(crate) genome_size: Option<u64>,
+    /// Whether reads flagged as filtered in Casava 1.8+ headers are left out of the
+    /// statistics, like `--casava` of FastQC.
+    pub(crate) casava: bool,
+    /// Number of bases at the start of the reads which make up their UMI, which is
+    /// otherwise taken from the read names.
+    pub(crate) umi_length: Option<usize>,
+    /// Scale of the k-mers sampled for the k-mer spectrum, which is only counted if set.
+    pub(crate) spectrum_scale: Option<u64>,
+    /// Maximal distance in pixels of optical duplicates, which are only estimated if
+    /// set.
+    pub(crate) optical_distance: Option<u32>,
+    /// Whether the number of bases of every quality score per position is plotted.
+    pub(crate) quality_heatmap: bool,
+    /// Whether the yield and qualities are also summed up per lane.
+    pub(crate) split_by_lane: bool,
+    /// Whether the poly-G tails and per tile qualities are only reported for the
+    /// detected platforms they apply to.
+    pub(crate) platform_modules: bool,
+    pub(crate) modules: Modules,
+    pub(crate) thresholds: Thresholds,
+    /// Filters of which the reads passing them are reported.
+    pub(crate) survival: SurvivalFilters,
+    /// Quality cutoffs of which the trimming is simulated, keeping the reads of the
+    /// minimal length of the filters.
+    pub(crate) simulate_trim: TrimCutoffs,
+    /// Number of records after which a JSON snapshot of the statistics of the file read
+    /// so far is written to standard error.
+    pub(crate) live_json: Option<usize>,
+    /// Number of data points of a plot above which its positions or lengths are thinned
+    /// out in HTML reports.
+    pub(crate) max_plot_points: Option<usize>,
+}
+
+/// Default number of data points of a plot above which it is thinned out.
+pub(crate) const DEFAULT_PLOT_POINTS: usize = 10_000;
+
+/// Format of the written reports.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Format {
+    /// HTML report with interactive plots.
+    #[default]
+    Html,
+    /// All computed data as JSON for further processing.
+    Json,
+    /// Text report with summary tables for issues and lab notebooks.
+    Markdown,
+}
+
+impl Format {
+    /// Returns the file extension of reports in this format.
+    pub(crate) fn extension(self) -> &'static str {
+        match self {
+            Format::Html => "html",
+            Format::Json => "json",
+            Format::Markdown => "md",
+        }
+    }
+}
+
+/// Format of the module data tables.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DataFormat {
+    /// Tab-separated text files.
+    #[default]
+    Tsv,
+    /// Typed columnar files for data frame libraries like Polars and pandas.
+    Parquet,
+}
+
+/// Options, templates and reference data shared by all processed files.
+pub(crate) struct Setup {
+    options: Options,
+    templates: Tera,
+    adapters: Vec<Adapter>,
+    contaminants: Vec<Contaminant>,
+    /// Reference genomes which reads are screened against, e.g. PhiX.
+    references: Vec<Reference>,
+    custom_modules: Vec<ModuleFactory>,
+    baseline: Option<Baseline>,
+    /// File the JSON snapshots of `live_json` are written to, e.g. a named pipe.
+    live_json: Option<Mutex<File>>,
+    branding: Branding,
+    /// Templates of a template directory rendered as additional sections of the HTML
+    /// report.
+    sections: Vec<String>,
+    /// JSON merge patches of the Vega-Lite specs of the plots by their short name, or
+    /// `*` for all plots.
+    plot_patches: serde_json::Map<String, Value>,
+}
+
+/// Names of the bundled templates, which may be replaced by user templates.
+const TEMPLATES: [&str; 6] = [
+    "report.html.tera",
+    "aggregate.html.tera",
+    "compare.html.tera",
+    "cohort.html.tera",
+    "report.md.tera",
+    "fastqc_summary.txt.tera",
+];
+
+/// Color theme of the HTML reports.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Theme {
+    #[default]
+    Light,
+    Dark,
+}
+
+/// Title, logo and theme of the reports, e.g. of a facility.
+#[derive(Default)]
+struct Branding {
+    /// Title replacing the default title of the reports.
+    title: Option<String>,
+    /// Logo as data URL, shown next to the title in HTML reports.
+    logo: Option<String>,
+    theme: Theme,
+}
+
+/// Results of a previous run which the results of every reported file are compared to.
+struct Baseline {
+    /// Results of the files of its JSON report, matched to the files of a report by
+    /// their name, or compared to all files if there is only one.
+    files: Vec<Value>,
+    /// Tolerances replacing the default ones of the metrics by their key.
+    tolerances: Vec<(&'static str, f64)>,
+}
+
+impl Setup {
+    /// Parses the report templates and loads the adapter and contaminant lists, using
+    /// the given named adapters instead of the bundled ones unless there are none, and
+    /// adding the given adapter sequences.
+    pub(crate) fn new(
+        options: Options,
+        adapters: &[(String, String)],
+        extra_adapters: &[String],
+    ) -> Result<Self, FastqcError> {
+        let mut templates = Tera::default();
+        // Assets are embedded from the binary, and only fetched if not bundled and allowed.
+        // Fetched assets are cached for all reports.
+        let fetch_assets = options.fetch_assets;
+        let sources = Mutex::new(HashMap::default());
+        templates.register_filter(
+            "embed_source",
+            move |value: &tera::Value, args: &std::collections::HashMap<String, tera::Value>| {
+                let url = value.as_str().unwrap_or_default().to_string();
+                if let Some((_, asset)) = ASSETS.iter().find(|(asset_url, _)| *asset_url == url) {
+                    return Ok(tera::Value::from(*asset));
+                }
+                if !fetch_assets {
+                    return Err(tera::Error::msg(format!(
+                        "The asset {} is not bundled, use --fetch-assets to download it.",
+                        url
+                    )));
+                }
+                let mut sources = sources.lock().unwrap();
+                if !sources.contains_key(&url) {
+                    sources.insert(url.clone(), embed_source(value, args)?);
+                }
+                Ok(sources[&url].clone())
+            },
+        );
+        templates.add_raw_template("report.html.tera", include_str!("report/report.html.tera"))?;
+        templates.add_raw_template(
+            "aggregate.html.tera",
+            include_str!("report/aggregate.html.tera"),
+        )?;
+        templates.add_raw_template(
+            "compare.html.tera",
+            include_str!("report/compare.html.tera"),
+        )?;
+        templates.add_raw_template("cohort.html.tera", include_str!("report/cohort.html.tera"))?;
+        templates.add_raw_template("report.md.tera", include_str!("report/report.md.tera"))?;
+        templates.add_raw_template(
+            "fastqc_summary.txt.tera",
+            include_str!("report/fastqc_summary.txt.tera"),
+        )?;
+        let mut adapters = if adapters.is_empty() {
+            Adapter::default_list()
+        } else {
+            adapters
+                .iter()
+                .map(|(name, sequence)| Adapter::new(name, sequence.as_bytes()))
+                .collect()
+        };
+        adapters.extend(
+            extra_adapters
+                .iter()
+                .map(|sequence| Adapter::new(sequence, sequence.as_bytes())),
+        );
+        Ok(Setup {
+            options,
+            templates,
+            adapters,
+            contaminants: Contaminant::default_list(),
+            references: Vec::new(),
+            custom_modules: Vec::new(),
+            baseline: None,
+            live_json: None,
+            branding: Branding::default(),
+            sections: Vec::new(),
+            plot_patches: serde_json::Map::new(),
+        })
+    }
+
+    /// Replaces the bundled contaminant list by the given names and sequences, unless
+    /// there are none.
+    pub(crate) fn with_contaminants(mut self, contaminants: &[(String, String)]) -> Self {
+        if !contaminants.is_empty() {
+            self.contaminants = contaminants
+                .iter()
+                .map(|(name, sequence)| Contaminant::new(name, sequence.as_bytes()))
+                .collect();
+        }
+        self
+    }
+
+    /// Screens the reads against the given reference genomes.
+    pub(crate) fn with_references(mut self, references: &[Reference]) -> Self {
+        self.references = references.to_vec();
+        self
+    }
+
+    /// Adds custom modules, of which every input gets new instances.
+    pub(crate) fn with_modules(mut self, modules: Vec<ModuleFactory>) -> Self {
+        self.custom_modules = modules;
+        self
+    }
+
+    /// Compares the results of every reported file to the results in the JSON report of
+    /// a previous run, flagging metrics which got worse by more than their tolerance.
+    pub(crate) fn with_baseline(
+        mut self,
+        report: &str,
+        tolerances: &[(&'static str, f64)],
+    ) -> Result<Self, FastqcError> {
+        let files = report_files(report)?;
+        if files.is_empty() {
+            return Err(format!("The baseline {} has no files.", report).into());
+        }
+        self.baseline = Some(Baseline {
+            files,
+            tolerances: tolerances.to_vec(),
+        });
+        Ok(self)
+    }
+
+    /// Writes the JSON snapshots of every `live_json` records to the given file, one per
+    /// line. Opening a named pipe waits for its reader.
+    pub(crate) fn with_live_json(mut self, path: &Path) -> Result<Self, FastqcError> {
+        let file = File::create(path).map_err(|e| FastqcError::from(e).with_path(path))?;
+        self.live_json = Some(Mutex::new(file));
+        Ok(self)
+    }
+
+    /// Sets the title of the reports.
+    pub(crate) fn with_title(mut self, title: &str) -> Self {
+        self.branding.title = Some(title.to_string());
+        self
+    }
+
+    /// Embeds the given PNG, JPEG, GIF or SVG image as logo into the HTML reports.
+    pub(crate) fn with_logo(mut self, path: &Path) -> Result<Self, FastqcError> {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let mime = match extension.as_str() {
+            "png" => "image/png",
+            "jpg" | "jpeg" => "image/jpeg",
+            "gif" => "image/gif",
+            "svg" => "image/svg+xml",
+            _ => {
+                return Err(format!(
+                    "The logo {} is no PNG, JPEG, GIF or SVG image.",
+                    path.display()
+                )
+                .into())
+            }
+        };
+        let image = fs::read(path).map_err(|e| FastqcError::from(e).with_path(path))?;
+        self.branding.logo = Some(format!("data:{};base64,{}", mime, base64::encode(&image)));
+        Ok(self)
+    }
+
+    /// Sets the color theme of the HTML reports.
+    pub(crate) fn with_theme(mut self, theme: Theme) -> Self {
+        self.branding.theme = theme;
+        self
+    }
+
+    /// Replaces the template of the HTML report of single files by the given one.
+    pub(crate) fn with_template(mut self, path: &Path) -> Result<Self, FastqcError> {
+        let template =
+            fs::read_to_string(path).map_err(|e| FastqcError::from(e).with_path(path))?;
+        self.templates
+            .add_raw_template("report.html.tera", &template)
+            .map_err(|e| FastqcError::from(e).with_path(path))?;
+        Ok(self)
+    }
+
+    /// Adds the `*.tera` templates of the given directory, which replace the bundled
+    /// templates of the same name. Other `*.html.tera` templates not starting with `_` are
+    /// rendered with the context of the HTML report of single files as additional
+    /// sections, in the order of their names.
+    pub(crate) fn with_template_dir(mut self, dir: &Path) -> Result<Self, FastqcError> {
+        let mut templates = Vec::new();
+        for entry in fs::read_dir(dir).map_err(|e| FastqcError::from(e).with_path(dir))? {
+            let path = entry
+                .map_err(|e| FastqcError::from(e).with_path(dir))?
+                .path();
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if path.is_file() && name.ends_with(".tera") {
+                let template =
+                    fs::read_to_string(&path).map_err(|e| FastqcError::from(e).with_path(&path))?;
+                templates.push((name.to_string(), template));
+            }
+        }
+        templates.sort();
+        self.templates
+            .add_raw_templates(templates.iter().map(|(name, template)| (name, template)))
+            .map_err(|e| FastqcError::from(e).with_path(dir))?;
+        self.sections = templates
+            .into_iter()
+            .map(|(name, _)| name)
+            .filter(|name| {
+                name.ends_with(".html.tera")
+                    && !name.starts_with('_')
+                    && !TEMPLATES.contains(&name.as_str())
+            })
+            .collect();
+        Ok(self)
+    }
+
+    /// Merges the patches of the given JSON object into the Vega-Lite specs of the plots,
+    /// e.g. `{"base": {"encoding": {"x": {"scale": {"domain": [0, 41]}}}}}` to fix the
+    /// range of the phred scores of the base sequence quality. The patches are given by
+    /// the short names of the plots in the IDs of their elements, or `*` for all plots,
+    /// and replace the values of the specs like JSON merge patches, with `null` removing
+    /// them.
+    pub(crate) fn with_plot_config(mut self, config: &str) -> Result<Self, FastqcError> {
+        let Value::Object(patches) = serde_json::from_str(config)? else {
+            return Err("The plot configuration is no JSON object.".into());
+        };
+        if let Some((short, _)) = patches.iter().find(|(_, patch)| !patch.is_object()) {
+            return Err(format!("The patch of the plot {} is no JSON object.", short).into());
+        }
+        self.plot_patches = patches;
+        Ok(self)
+    }
+
+    /// Applies the patches of the plot configuration to the specs of the plots.
+    fn patch_plots<'a>(
+        &self,
+        plots: impl IntoIterator<Item = &'a mut Value>,
+    ) -> Result<(), FastqcError> {
+        if self.plot_patches.is_empty() {
+            return Ok(());
+        }
+        for plot in plots {
+            let short = plot["short"].as_str().unwrap_or_default();
+            let patches = ["*", short]
+                .iter()
+                .filter_map(|key| self.plot_patches.get(*key))
+                .collect::<Vec<_>>();
+            if patches.is_empty() {
+                continue;
+            }
+            let mut specs: Value = serde_json::from_str(plot["specs"].as_str().unwrap_or("{}"))?;
+            for patch in patches {
+                merge_patch(&mut specs, patch);
+            }
+            plot["specs"] = json!(specs.to_string());
+        }
+        Ok(())
+    }
+
+    fn render(&self, template: &str, context: &Context) -> Result<String, FastqcError> {
+        Ok(self.templates.render(template, context)?)
+    }
+
+    /// Inserts the title, logo and theme into the context of a report, leaving the
+    /// default title of its template unless a title is set.
+    fn insert_branding(&self, context: &mut Context) {
+        if let Some(title) = &self.branding.title {
+            context.insert("title", title);
+        }
+        context.insert("logo", &self.branding.logo);
+        context.insert("dark", &(self.branding.theme == Theme::Dark));
+    }
+
+    pub(crate) fn options(&self) -> &Options {
+        &self.options
+    }
+}
+
+/// The time and counts of records at which reading on started.
+pub(crate) struct ReadStart {
+    time: Instant,
+    reads: usize,
+    invalid: usize,
+}
+
+/// The built-in analyses of the FastQC modules and of some modules only in fastqc-rs,
+/// which are observed and merged through [`QcModule`] and evaluated by its `finish`.
+struct Analyses {
+    base_quality: BaseQualityCounter,
+    sequence_quality: SequenceQualityCounter,
+    composition: CompositionCounter,
+    gc_content: GcContentCounter,
+    n_content: NContentCounter,
+    read_lengths: ReadLengthCounter,
+    duplication: DuplicationCounter,
+    adapter_content: AdapterCounter,
+    kmers: KmerCounter,
+    motifs: MotifCounter,
+    homopolymers: HomopolymerCounter,
+    poly_tails: PolyTailCounter,
+    low_complexity: LowComplexityCounter,
+}
+
+/// One of the [`Analyses`] with the key of its timings.
+struct Analysis<'a> {
+    key: &'static str,
+    module: &'a mut dyn QcModule,
+    /// Whether the records are observed, which they are not by the costly analyses of
+    /// disabled modules.
+    enabled: bool,
+    /// Whether the records are observed in order, on the thread reading them.
+    ordered: bool,
+}
+
+impl<'a> Analysis<'a> {
+    fn new(key: &'static str, module: &'a mut dyn QcModule) -> Self {
+        Analysis {
+            key,
+            module,
+            enabled: true,
+            ordered: false,
+        }
+    }
+}
+
+impl Analyses {
+    fn new(setup: &Setup) -> Self {
+        let Options {
+            k,
+            binning,
+            phred_offset,
+            thresholds,
+            ..
+        } = setup.options;
+        Analyses {
+            base_quality: BaseQualityCounter::new(binning, phred_offset, &thresholds),
+            sequence_quality: SequenceQualityCounter::new(
+                phred_offset,
+                thresholds.sequence_quality,
+            ),
+            composition: CompositionCounter::new(binning, thresholds.base_content_difference),
+            gc_content: GcContentCounter::new(thresholds.gc_deviation),
+            n_content: NContentCounter::new(binning, thresholds.n_content),
+            read_lengths: ReadLengthCounter::new(thresholds.read_length),
+            duplication: match setup.options.fastqc_duplication {
+                true => DuplicationCounter::fastqc(thresholds.duplication_remaining),
+                // Every thread counts the duplicates of its reads in its share of the memory
+                false => DuplicationCounter::new(
+                    setup.options.duplication_memory
+                        / (setup.options.threads * setup.options.parallel_files).max(1),
+                    thresholds.duplication_remaining,
+                ),
+            },
+            adapter_content: AdapterCounter::new(
+                setup.adapters.clone(),
+                binning,
+                thresholds.adapter_content,
+            ),
+            kmers: KmerCounter::new(k, binning, thresholds.kmer_pvalue),
+            motifs: MotifCounter::default(),
+            homopolymers: HomopolymerCounter::default(),
+            poly_tails: PolyTailCounter::new(thresholds.poly_tail),
+            low_complexity: LowComplexityCounter::new(thresholds.low_complexity),
+        }
+    }
+
+    /// Returns every analysis, in the same order for all statistics of the same setup.
+    fn each(&mut self, modules: Modules) -> [Analysis<'_>; 13] {
+        let ordered = self.duplication.is_ordered();
+        // Only the modules with costly counters are skipped if disabled
+        [
+            Analysis::new(Module::PerBaseQuality.key(), &mut self.base_quality),
+            Analysis::new(Module::PerSequenceQuality.key(), &mut self.sequence_quality),
+            Analysis::new(Module::PerBaseContent.key(), &mut self.composition),
+            Analysis::new(Module::PerSequenceGcContent.key(), &mut self.gc_content),
+            Analysis::new(Module::PerBaseNContent.key(), &mut self.n_content),
+            Analysis::new(Module::ReadLengthDistribution.key(), &mut self.read_lengths),
+            Analysis {
+                enabled: modules.contains(Module::DuplicationLevels),
+                ordered,
+                ..Analysis::new(Module::DuplicationLevels.key(), &mut self.duplication)
+            },
+            Analysis {
+                enabled: modules.contains(Module::AdapterContent),
+                ..Analysis::new(Module::AdapterContent.key(), &mut self.adapter_content)
+            },
+            Analysis {
+                enabled: modules.contains(Module::KmerContent),
+                ..Analysis::new(Module::KmerContent.key(), &mut self.kmers)
+            },
+            Analysis::new("motifs", &mut self.motifs),
+            Analysis::new("homopolymers", &mut self.homopolymers),
+            Analysis::new(Module::PolyTails.key(), &mut self.poly_tails),
+            Analysis {
+                enabled: modules.contains(Module::LowComplexity),
+                ..Analysis::new(Module::LowComplexity.key(), &mut self.low_complexity)
+            },
+        ]
+    }
+}
+
+/// Statistics gathered from the records of one input file.
+pub(crate) struct Stats {
+    analyses: Analyses,
+    overrepresented: OverrepresentedCounter,
+    tiles: TileCounter,
+    length_quality: LengthQualityCounter,
+    expected_errors: ExpectedErrorCounter,
+    survival: SurvivalCounter,
+    trim_simulation: Option<TrimSimulationCounter>,
+    run_time: RunTimeCounter,
+    read_ids: ReadIdCounter,
+    umis: UmiCounter,
+    barcodes: BarcodeCounter,
+    references: Vec<Reference>,
+    screen: ScreenCounter,
+    spectrum: Option<SpectrumCounter>,
+    optical: Option<OpticalCounter>,
+    lanes: Option<LaneCounter>,
+    invalid_reads: InvalidReadCounter,
+    run_info: RunInfoCounter,
+    read_names: ReadNameCounter,
+    /// ID of the first read, from which the platform is detected.
+    first_id: Option<Vec<u8>>,
+    /// Number of reads with a Casava 1.8+ header and of those flagged as filtered.
+    casava_reads: usize,
+    filtered_reads: usize,
+    /// Whether reads flagged as filtered are left out of the statistics.
+    exclude_filtered: bool,
+    /// Number of reads in the input if only a sample of them was used.
+    sampled_from: Option<usize>,
+    binning: Binning,
+    modules: Modules,
+    /// Custom modules, of which only the mergeable ones are created on the processing
+    /// threads, as the others see all records in order.
+    custom: Vec<Option<Box<dyn QcModule>>>,
+    timings: Option<Timings>,
+}
+
+impl Stats {
+    pub(crate) fn new(setup: &Setup) -> Self {
+        Stats::for_thread(setup, true)
+    }
+
+    /// Returns empty statistics, with only the mergeable custom modules unless they
+    /// observe the records in order.
+    fn for_thread(setup: &Setup, ordered: bool) -> Self {
+        let Options {
+            binning, modules, ..
+        } = setup.options;
+        Stats {
+            analyses: Analyses::new(setup),
+            overrepresented: OverrepresentedCounter::default(),
+            tiles: TileCounter::new(binning),
+            length_quality: LengthQualityCounter::default(),
+            expected_errors: ExpectedErrorCounter::default(),
+            survival: SurvivalCounter::new(setup.options.survival, setup.options.phred_offset),
+            trim_simulation: (!setup.options.simulate_trim.is_empty()).then(|| {
+                TrimSimulationCounter::new(
+                    setup.options.simulate_trim,
+                    setup.options.survival.min_length,
+                    setup.options.phred_offset,
+                )
+            }),
+            run_time: RunTimeCounter::default(),
+            read_ids: ReadIdCounter::new(setup.options.read_id_memory),
+            umis: UmiCounter::new(setup.options.umi_length),
+            barcodes: BarcodeCounter::default(),
+            references: setup.references.clone(),
+            screen: ScreenCounter::default(),
+            spectrum: setup.options.spectrum_scale.map(SpectrumCounter::new),
+            optical: setup.options.optical_distance.map(OpticalCounter::new),
+            lanes: setup
+                .options
+                .split_by_lane
+                .then(|| LaneCounter::new(binning)),
+            invalid_reads: InvalidReadCounter::default(),
+            run_info: RunInfoCounter::default(),
+            read_names: ReadNameCounter::default(),
+            first_id: None,
+            casava_reads: 0,
+            filtered_reads: 0,
+            exclude_filtered: setup.options.casava,
+            sampled_from: None,
+            binning,
+            modules,
+            custom: setup
+                .custom_modules
+                .iter()
+                .map(|factory| (ordered || factory.mergeable).then(|| (factory.new)()))
+                .collect(),
+            timings: log::log_enabled!(log::Level::Debug).then(Timings::default),
+        }
+    }
+
+    /// Gathers statistics from every record of the given file.
+    pub(crate) fn gather<P: AsRef<Path>>(filename: P, setup: &Setup) -> Result<Self, FastqcError> {
+        let mut stats = Stats::new(setup);
+        stats.read(filename, setup)?;
+        Ok(stats)
+    }
+
+    /// Adds every record of the given file to the statistics, processing the records
+    /// in batches on several threads or only a random sample of them if requested.
+    pub(crate) fn read<P: AsRef<Path>>(
+        &mut self,
+        filename: P,
+        setup: &Setup,
+    ) -> Result<(), FastqcError> {
+        let reader = open(&filename, setup)?;
+        let start = self.read_start();
+        match (setup.options.live_json, &setup.live_json) {
+            // Sampled records are only observed once the whole file has been read
+            (Some(every), Some(sink)) if setup.options.sample.is_none() => {
+                let name = display_name(&filename);
+                self.read_live(reader, every, &mut |stats| {
+                    let results = Results::new(stats, setup);
+                    let line = format!("{}\n", json_report(setup, &[(name, &results)]));
+                    // A reader of the snapshots going away does not stop the QC
+                    if let Err(e) = sink.lock().unwrap().write_all(line.as_bytes()) {
+                        log::debug!("Could not write a live JSON snapshot: {}", e);
+                    }
+                })
+            }
+            _ => self.read_from(reader, setup),
+        }
+        self.log_read(display_name(&filename), setup, start);
+        Ok(())
+    }
+
+    /// Returns the counts of the records so far, from which [`Stats::log_read`] logs
+    /// the records read on.
+    pub(crate) fn read_start(&self) -> ReadStart {
+        ReadStart {
+            time: Instant::now(),
+            reads: self.analyses.read_lengths.reads(),
+            invalid: self.invalid_reads.count(),
+        }
+    }
+
+    /// Logs the number of records read since `start` and the time it took, warning
+    /// about skipped invalid records and legacy quality encodings.
+    pub(crate) fn log_read(&self, name: &str, setup: &Setup, start: ReadStart) {
+        let seconds = start.time.elapsed().as_secs_f64();
+        let reads = self.analyses.read_lengths.reads() - start.reads;
+        log::info!(
+            "Read {} records of {} in {:.2}s, {:.0} records per second.",
+            reads,
+            name,
+            seconds,
+            reads as f64 / seconds.max(1e-9)
+        );
+        let invalid = self.invalid_reads.count() - start.invalid;
+        if invalid > 0 {
+            log::warn!("Skipped {} invalid records of {}.", invalid, name);
+        }
+        // Files merged into one report are expected to differ, so only the names of
+        // the first one are checked
+        if start.reads == 0 {
+            let read_names = self.read_names.read_names(self.sampled_from.is_none());
+            for warning in read_names.warnings() {
+                log::warn!("{}: {}.", name, warning);
+            }
+        }
+        let encoding = self.analyses.base_quality.encoding();
+        if setup.options.phred_offset.is_none() && encoding.offset != 33 {
+            log::warn!(
+                "{} has the legacy {} encoding with phred offset {}.",
+                name,
+                encoding.name,
+                encoding.offset
+            );
+        }
+        if let Some(timings) = &self.timings {
+            log::debug!("Time per module of {}: {}", name, timings.summary());
+        }
+    }
+
+    /// Like [`Stats::read_from`], but passes the statistics to `snapshot` after every
+    /// `every` records. The records are observed on the calling thread, so that every
+    /// snapshot covers all records read so far.
+    pub(crate) fn read_live(
+        &mut self,
+        mut reader: Reader,
+        every: usize,
+        snapshot: &mut dyn FnMut(&Stats),
+    ) {
+        let mut records = 0_usize;
+        while let Some(record) = reader.next() {
+            records += 1;
+            match record {
+                Ok(record) => self.observe(&record),
+                Err(e) => self.invalid_reads.observe(records, &e),
+            }
+            if records.is_multiple_of(every) {
+                snapshot(self);
+            }
+        }
+    }
+
+    /// Like [`Stats::read`], but for the records of an opened reader.
+    pub(crate) fn read_from(&mut self, mut reader: Reader, setup: &Setup) {
+        if let Some(size) = setup.options.sample {
+            let mut reservoir = Reservoir::new(size, setup.options.seed);
+            let mut records = 0_usize;
+            while let Some(record) = reader.next() {
+                records += 1;
+                match record {
+                    Ok(record) => reservoir.offer(|| OwnedRecord::from(&record)),
+                    Err(e) => self.invalid_reads.observe(records, &e),
+                }
+            }
+            self.sampled_from = Some(self.sampled_from.unwrap_or(0) + reservoir.seen());
+            for record in reservoir.into_items() {
+                self.observe(&Record::owned(&record));
+            }
+            return;
+        }
+        let threads = setup.options.threads;
+        let mut records = 0_usize;
+        if threads <= 1 {
+            while let Some(record) = reader.next() {
+                records += 1;
+                match record {
+                    Ok(record) => self.observe(&record),
+                    Err(e) => self.invalid_reads.observe(records, &e),
+                }
+            }
+            return;
+        }
+        let (sender, receiver) = sync_channel::<Vec<OwnedRecord>>(threads * 2);
+        let receiver = Mutex::new(receiver);
+        thread::scope(|scope| {
+            let workers = (0..threads)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut stats = Stats::for_thread(setup, false);
+                        loop {
+                            // The lock is released before the batch is processed
+                            let batch = receiver.lock().unwrap().recv();
+                            match batch {
+                                Ok(batch) => {
+                                    for record in &batch {
+                                        let record = Record::owned(record);
+                                        stats.observe_unordered(&record);
+                                        for module in stats.custom.iter_mut().flatten() {
+                                            module.observe(&record);
+                                        }
+                                    }
+                                }
+                                Err(_) => return stats,
+                            }
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+            let mut batch = Vec::with_capacity(BATCH_SIZE);
+            while let Some(record) = reader.next() {
+                records += 1;
+                match record {
+                    Ok(record) if self.skips(&record) => {}
+                    Ok(record) => {
+                        // Only sequences of the first reads are tracked, so they are
+                        // counted in the order of the file
+                        if self.modules.contains(Module::OverrepresentedSequences) {
+                            self.overrepresented.observe(&record.seq());
+                        }
+                        self.observe_ordered(&record);
+                        // Mergeable custom modules are observed by the workers instead
+                        let modules = self.custom.iter_mut().zip(&setup.custom_modules);
+                        for (module, factory) in modules {
+                            match module {
+                                Some(module) if !factory.mergeable => module.observe(&record),
+                                _ => {}
+                            }
+                        }
+                        batch.push(OwnedRecord::from(&record));
+                        if batch.len() == BATCH_SIZE {
+                            let full = mem::replace(&mut batch, Vec::with_capacity(BATCH_SIZE));
+                            sender.send(full).expect("Worker thread failed");
+                        }
+                    }
+                    Err(e) => self.invalid_reads.observe(records, &e),
+                }
+            }
+            sender.send(batch).expect("Worker thread failed");
+            drop(sender);
+            for worker in workers {
+                self.merge(worker.join().expect("Worker thread failed"));
+            }
+        });
+    }
+
+    /// Gathers statistics from a file with interleaved paired-end reads, splitting
+    /// alternating records into read 1 and read 2, and the insert sizes of the pairs.
+    pub(crate) fn gather_interleaved<P: AsRef<Path>>(
+        filename: P,
+        setup: &Setup,
+    ) -> Result<([Self; 2], InsertSizeCounter), FastqcError> {
+        let mut stats = [Stats::new(setup), Stats::new(setup)];
+        let mut inserts = InsertSizeCounter::default();
+        let mut reader = open(&filename, setup)?;
+        let start = [stats[0].read_start(), stats[1].read_start()];
+        let mut mate = 0;
+        let mut read1 = None;
+        let mut records = 0_usize;
+        while let Some(record) = reader.next() {
+            records += 1;
+            match record {
+                Ok(record) => {
+                    stats[mate].observe(&record);
+                    if mate == 0 {
+                        read1 = (!inserts.is_full()).then(|| record.seq().into_owned());
+                    } else if let Some(read1) = read1.take() {
+                        inserts.observe(&read1, &record.seq(), &setup.adapters);
+                    }
+                }
+                Err(e) => stats[mate].invalid_reads.observe(records, &e),
+            }
+            mate = 1 - mate;
+        }
+        let file = display_name(&filename);
+        for ((stats, start), read) in stats.iter().zip(start).zip(READS) {
+            stats.log_read(&format!("{} ({})", file, read), setup, start);
+        }
+        Ok((stats, inserts))
+    }
+
+    pub(crate) fn observe(&mut self, record: &Record) {
+        if self.skips(record) {
+            return;
+        }
+        // The sequence is only joined from the lines of FASTA records once
+        let seq = record.seq();
+        let record = Record::parts(record.id(), &seq, record.qual());
+        if self.modules.contains(Module::OverrepresentedSequences) {
+            timed!(
+                self.timings,
+                "overrepresented_sequences",
+                self.overrepresented.observe(&seq)
+            );
+        }
+        self.observe_ordered(&record);
+        for module in self.custom.iter_mut().flatten() {
+            timed!(self.timings, "custom_modules", module.observe(&record));
+        }
+        self.observe_unordered(&record);
+    }
+
+    /// Adds a record to the analyses that depend on the order of the records, like the
+    /// duplication levels in the FastQC mode.
+    fn observe_ordered(&mut self, record: &Record) {
+        for analysis in self.analyses.each(self.modules) {
+            if analysis.enabled && analysis.ordered {
+                timed!(self.timings, analysis.key, analysis.module.observe(record));
+            }
+        }
+    }
+
+    /// Counts the Casava filter flag of a record, returning whether the record is left
+    /// out of all other statistics.
+    fn skips(&mut self, record: &Record) -> bool {
+        match casava::is_filtered(record.id()) {
+            Some(filtered) => {
+                self.casava_reads += 1;
+                self.filtered_reads += filtered as usize;
+                filtered && self.exclude_filtered
+            }
+            None => false,
+        }
+    }
+
+    /// Adds a record to all statistics that do not depend on the order of the records.
+    fn observe_unordered(&mut self, record: &Record) {
+        for analysis in self.analyses.each(self.modules) {
+            if analysis.enabled && !analysis.ordered {
+                timed!(self.timings, analysis.key, analysis.module.observe(record));
+            }
+        }
+        let seq = record.seq();
+        timed!(
+            self.timings,
+            "run_time",
+            self.run_time
+                .observe(record.id(), record.num_bases(), record.qual())
+        );
+        timed!(self.timings, "read_ids", self.read_ids.observe(record.id()));
+        timed!(self.timings, "run_info", self.run_info.observe(record.id()));
+        timed!(
+            self.timings,
+            "read_names",
+            self.read_names.observe(record.id())
+        );
+        if self.first_id.is_none() {
+            self.first_id = Some(record.id().to_vec());
+        }
+        timed!(self.timings, "umis", self.umis.observe(record.id(), &seq));
+        timed!(self.timings, "barcodes", self.barcodes.observe(record.id()));
+        timed!(
+            self.timings,
+            "screen",
+            self.screen.observe(&self.references, &seq)
+        );
+        if let Some(spectrum) = &mut self.spectrum {
+            timed!(self.timings, "kmer_spectrum", spectrum.observe(&seq));
+        }
+        if let Some(optical) = &mut self.optical {
+            timed!(
+                self.timings,
+                "optical_duplicates",
+                optical.observe(record.id(), &seq)
+            );
+        }
+        if let Some(lanes) = &mut self.lanes {
+            timed!(
+                self.timings,
+                "lanes",
+                lanes.observe(record.id(), record.num_bases(), record.qual())
+            );
+        }
+        let mut errors = None;
+        if let Some(qualities) = record.qual() {
+            timed!(
+                self.timings,
+                "expected_errors",
+                errors = Some(self.expected_errors.observe(qualities))
+            );
+        }
+        timed!(
+            self.timings,
+            "filter_survival",
+            self.survival.observe(&seq, record.qual().zip(errors))
+        );
+        if let Some(trim_simulation) = &mut self.trim_simulation {
+            timed!(
+                self.timings,
+                "trim_simulation",
+                trim_simulation.observe(seq.len(), record.qual())
+            );
+        }
+        if let Some(qualities) = record.qual() {
+            if self.modules.contains(Module::PerTileQuality) {
+                timed!(
+                    self.timings,
+                    "per_tile_quality",
+                    self.tiles.observe(record.id(), qualities)
+                );
+            }
+            timed!(
+                self.timings,
+                "length_quality",
+                self.length_quality.observe(qualities)
+            );
+        }
+    }
+
+    /// Adds the statistics of another part of the same file, except for the
+    /// overrepresented sequences, custom modules which are not mergeable and duplication
+    /// levels of the FastQC mode which see the records in order.
+    fn merge(&mut self, mut other: Stats) {
+        let analyses = self.analyses.each(self.modules);
+        for (analysis, other) in
+            IntoIterator::into_iter(analyses).zip(other.analyses.each(self.modules))
+        {
+            analysis.module.merge(other.module);
+        }
+        for (module, other) in self.custom.iter_mut().zip(&other.custom) {
+            if let (Some(module), Some(other)) = (module, other) {
+                module.merge(other.as_ref());
+            }
+        }
+        self.tiles.merge(&other.tiles);
+        self.length_quality.merge(&other.length_quality);
+        self.expected_errors.merge(&other.expected_errors);
+        self.survival.merge(&other.survival);
+        if let (Some(trim_simulation), Some(other)) =
+            (&mut self.trim_simulation, &other.trim_simulation)
+        {
+            trim_simulation.merge(other);
+        }
+        self.run_time.merge(&other.run_time);
+        self.read_ids.merge(&other.read_ids);
+        self.umis.merge(&other.umis);
+        self.barcodes.merge(&other.barcodes);
+        self.screen.merge(&other.screen);
+        if let (Some(spectrum), Some(other)) = (&mut self.spectrum, &other.spectrum) {
+            spectrum.merge(other);
+        }
+        if let (Some(optical), Some(other)) = (&mut self.optical, &other.optical) {
+            optical.merge(other);
+        }
+        if let (Some(lanes), Some(other)) = (&mut self.lanes, &other.lanes) {
+            lanes.merge(other);
+        }
+        self.invalid_reads.merge(&other.invalid_reads);
+        self.run_info.merge(&other.run_info);
+        self.read_names.merge(&other.read_names);
+        if self.first_id.is_none() {
+            self.first_id = other.first_id;
+        }
+        self.casava_reads += other.casava_reads;
+        self.filtered_reads += other.filtered_reads;
+        if let (Some(timings), Some(other)) = (&mut self.timings, &other.timings) {
+            timings.merge(other);
+        }
+    }
+}
+
+/// Opens the given file, showing the progress of reading it and limited to the first
+/// records if requested.
+fn open<P: AsRef<Path>>(filename: P, setup: &Setup) -> Result<Reader, FastqcError> {
+    let consumed = Arc::new(AtomicU64::new(0));
+    let mut reader = crate::input::open(
+        &filename,
+        setup.options.alignment_filter,
+        setup.options.threads,
+        consumed.clone(),
+    )
+    .map_err(|e| e.with_path(&filename))?;
+    if let Some(max_reads) = setup.options.max_reads {
+        reader = reader.limit(max_reads);
+    }
+    if setup.options.progress {
+        reader = reader.with_progress(Progress::new(filename.as_ref(), consumed));
+    }
+    Ok(reader)
+}
+
+/// The results of all modules for one input file.
+pub(crate) struct Results {
+    encoding: PhredEncoding,
+    base_quality_warn: &'static str,
+    base_per_pos_data: Vec<Value>,
+    /// Number of bases of every quality score per position, if plotted.
+    quality_heatmap_data: Vec<Value>,
+    /// Distinct quality scores if the qualities are binned.
+    quality_bins: Option<Vec<usize>>,
+    /// Percentage of bases per quality bin and position, if the qualities are binned.
+    quality_bin_data: Vec<Value>,
+    sequence_quality_warn: &'static str,
+    sequence_quality_data: Vec<Value>,
+    base_content_warn: &'static str,
+    base_content_data: Vec<Value>,
+    gc_warn: &'static str,
+    gc_data: Vec<Value>,
+    n_content_warn: &'static str,
+    n_content_data: Vec<Value>,
+    read_length_warn: &'static str,
+    read_length_data: Vec<Value>,
+    reads: usize,
+    total_length: usize,
+    min_length: usize,
+    max_length: usize,
+    trimming: Trimming,
+    mean_length: f64,
+    sequence_length: String,
+    n50: usize,
+    n90: usize,
+    duplication_warn: &'static str,
+    remaining: f64,
+    duplication_data: Vec<Value>,
+    duplication_plot_data: Vec<Value>,
+    /// Distinct sequences at increasing depths, if the duplication levels are counted.
+    saturation: Option<Saturation>,
+    saturation_data: Vec<Value>,
+    overrepresented_warn: &'static str,
+    overrepresented_data: Vec<Value>,
+    contaminant_data: Vec<Value>,
+    adapter_warn: &'static str,
+    adapter_start_data: Vec<Value>,
+    poly_tail_warn: &'static str,
+    /// Percentage of reads with a poly-G and a poly-A tail.
+    poly_tail_percentages: [f64; 2],
+    poly_tail_data: Vec<Value>,
+    low_complexity_warn: &'static str,
+    /// Percentage of reads with a low complexity.
+    low_complexity_pct: f64,
+    low_complexity_composition: Vec<Value>,
+    low_complexity_data: Vec<Value>,
+    adapter_names: Vec<String>,
+    adapter_data: Vec<Value>,
+    /// Percentage of bases from the start of an adapter to the end of the reads, for the
+    /// adapter with the most of them.
+    adapter_bases_pct: f64,
+    adapter_rows: Vec<Value>,
+    kmer_warn: &'static str,
+    kmer_data: Vec<Value>,
+    kmer_count_data: Vec<Value>,
+    kmer_enrichment_data: Vec<Value>,
+    has_tiles: bool,
+    tile_warn: &'static str,
+    tile_data: Vec<Value>,
+    length_quality_data: Vec<Value>,
+    has_motifs: bool,
+    motif_data: Vec<Value>,
+    /// Insert sizes of paired-end reads.
+    insert_sizes: Option<InsertSizes>,
+    insert_size_data: Vec<Value>,
+    umis: Option<Umis>,
+    umi_composition_data: Vec<Value>,
+    umi_top_data: Vec<Value>,
+    barcodes: Option<Barcodes>,
+    barcode_data: Vec<Value>,
+    /// Number of the reads screened against and matching each reference genome.
+    screen: Vec<(String, ScreenCounts)>,
+    screen_data: Vec<Value>,
+    spectrum: Option<Spectrum>,
+    spectrum_data: Vec<Value>,
+    /// Optical and other duplicates, if estimated and any read name has a position.
+    optical_duplicates: Option<OpticalDuplicates>,
+    /// Whether the reads were split by lane and any read name contains its lane.
+    has_lanes: bool,
+    lane_statistics: Vec<Value>,
+    lane_quality_data: Vec<Value>,
+    has_homopolymers: bool,
+    homopolymer_data: Vec<Value>,
+    has_expected_errors: bool,
+    mean_expected_errors: f64,
+    /// Percentage of erroneous bases expected from their qualities.
+    quality_error_rate: f64,
+    expected_error_data: Vec<Value>,
+    survival: Survival,
+    survival_data: Vec<Value>,
+    /// Whether the quality trimming was simulated.
+    has_trim_simulation: bool,
+    trim_simulation_data: Vec<Value>,
+    trimmed_length_data: Vec<Value>,
+    has_run_time: bool,
+    run_time_data: Vec<Value>,
+    gc_content: f64,
+    /// Percentage of bases with a quality of at least 20.
+    q20: f64,
+    /// Percentage of bases with a quality of at least 30.
+    q30: f64,
+    /// Number of bases with a quality of at least 30.
+    q30_bases: usize,
+    /// Percentage of reads with a mean quality of at least 20.
+    q20_reads: f64,
+    /// Percentage of reads with a mean quality of at least 30.
+    q30_reads: f64,
+    /// Number of reads with the ID of a previous read.
+    duplicate_ids: usize,
+    duplicate_ids_estimated: bool,
+    /// Whether any read has qualities, unlike those of FASTA files.
+    has_qualities: bool,
+    /// Whether the reads have Casava 1.8+ headers with a filter flag.
+    has_casava: bool,
+    /// Number and percentage of the reads with a Casava header flagged as filtered.
+    filtered_reads: usize,
+    filtered_pct: f64,
+    filtered_excluded: bool,
+    invalid_reads: InvalidReadCounter,
+    sampled_from: Option<usize>,
+    /// Seed of the random sample of the reads.
+    seed: u64,
+    /// Mean coverage of the genome, if its size is given.
+    coverage: Option<Coverage>,
+    platform: Option<Platform>,
+    run_info: RunInfo,
+    read_names: ReadNames,
+    modules: Modules,
+    custom: Vec<ModuleResult>,
+}
+
+impl Results {
+    /// Evaluates the gathered statistics, using the given phred offset instead of
+    /// the detected one if present.
+    pub(crate) fn new(stats: &Stats, setup: &Setup) -> Self {
+        let binning = stats.binning;
+        let thresholds = setup.options.thresholds;
+        let analyses = &stats.analyses;
+        // Positions are reported in groups, which adapt to the longest read
+        let groups = binning.groups(analyses.read_lengths.max());
+        // Qualities are counted by their character, so the encoding can be chosen afterwards
+        let encoding = stats.analyses.base_quality.encoding();
+
+        // Data for base quality per position, which is shown as share of the bases per
+        // quality score for binned qualities as their quartiles mostly coincide
+        let scores = encoding.scores(&analyses.base_quality.totals());
+        let quality_bins = quality_bins(&scores);
+        let base_quality_warn = analyses.base_quality.finish().status.as_str();
+        let mut base_per_pos_data = Vec::new();
+        let mut quality_heatmap_data = Vec::new();
+        let mut quality_bin_data = Vec::new();
+        let mut quality_means = Vec::new();
+        let (mut q20_bases, mut q30_bases, mut scored_bases) = (0_usize, 0_usize, 0_usize);
+        for (bins, qualities) in group_counts(analyses.base_quality.counts(), &groups) {
+            let (pos, end) = binning.positions(&bins);
+            let qualities = encoding.scores(&qualities);
+            q20_bases += qualities.iter().skip(20).sum::<usize>();
+            q30_bases += qualities.iter().skip(30).sum::<usize>();
+            scored_bases += qualities.iter().sum::<usize>();
+            let (sum, len) = qualities
+                .iter()
+                .enumerate()
+                .fold((0_usize, 0_usize), |(s, l), (q, c)| (s + q * c, l + c));
+            let avg = sum as f64 / len as f64;
+            quality_means.push((pos..end + 1, avg));
+            if setup.options.quality_heatmap {
+                for (quality, &count) in qualities.iter().enumerate() {
+                    if count > 0 {
+                        quality_heatmap_data.push(json!({
+                            "pos": pos,
+                            "end": end,
+                            "quality": quality,
+                            "count": count,
+                            "pct": count as f64 * 100.0 / len as f64,
+                        }));
+                    }
+                }
+            }
+            for &quality in quality_bins.iter().flatten() {
+                quality_bin_data.push(json!({
+                    "pos": pos,
+                    "end": end,
+                    "quality": quality,
+                    "pct": qualities[quality] as f64 * 100.0 / len as f64,
+                }));
+            }
+            let values = quartiles(&qualities);
+            let (p10, p90) = (percentile(&qualities, 0.1), percentile(&qualities, 0.9));
+            base_per_pos_data.push(json!({
+            "pos": pos,
+            "end": end,
+            "average": avg,
+            "upper": values.get(4).unwrap(),
+            "lower": values.first().unwrap(),
+            "q1": values.get(1).unwrap(),
+            "q3": values.get(3).unwrap(),
+            "median":values.get(2).unwrap(),
+            "p10": p10,
+            "p90": p90,
+            }));
+        }
+
+        // Data for read length distribution
+        let read_lengths = analyses.read_lengths.lengths();
+        let read_length_warn = analyses.read_lengths.finish().status.as_str();
+        let mut read_length_data: Vec<Value> = Vec::new();
+        let (mut total_length, mut reads) = (0_usize, 0_usize);
+        for (&length, &count) in read_lengths.iter().sorted() {
+            total_length += length * count;
+            reads += count;
+            // Lengths are binned like positions, with the first length of the bin shown
+            let length = binning.start(binning.bin(length));
+            match read_length_data.last_mut() {
+                Some(entry) if entry["length"] == length => {
+                    entry["count"] = json!(entry["count"].as_u64().unwrap() as usize + count)
+                }
+                _ => read_length_data.push(json!({
+                    "length": length,
+                    "count": count,
+                })),
+            }
+        }
+        let min_length = read_lengths.keys().min().copied().unwrap_or(0);
+        let max_length = read_lengths.keys().max().copied().unwrap_or(0);
+        let mean_length = if reads > 0 {
+            total_length as f64 / reads as f64
+        } else {
+            0.0
+        };
+        let sequence_length = if min_length == max_length {
+            min_length.to_string()
+        } else {
+            format!("{}-{}", min_length, max_length)
+        };
+        let n50 = nx(read_lengths, 0.5);
+        let n90 = nx(read_lengths, 0.9);
+        let platform = stats
+            .first_id
+            .as_ref()
+            .and_then(|id| Platform::detect(id, &scores, mean_length));
+        let mut modules = stats.modules;
+        if let Some(platform) = platform.filter(|_| setup.options.platform_modules) {
+            if !platform.dark_g() {
+                modules.set(Module::PolyTails, false);
+            }
+            if !platform.patterned {
+                modules.set(Module::PerTileQuality, false);
+            }
+        }
+        // Reads without qualities, e.g. of FASTA files, are only left out of the modules
+        // of the qualities
+        let has_qualities = scored_bases > 0 || total_length == 0;
+        if !has_qualities {
+            for module in [
+                Module::PerBaseQuality,
+                Module::PerTileQuality,
+                Module::PerSequenceQuality,
+            ] {
+                modules.set(module, false);
+            }
+        }
+
+        // Data for sequence duplication levels
+        let levels = analyses.duplication.levels();
+        let duplication_warn = analyses.duplication.finish().status.as_str();
+        let mut duplication_data = Vec::new();
+        for (i, level) in LEVELS.iter().enumerate() {
+            duplication_data.push(json!({
+                "level": level,
+                "deduplicated": levels.deduplicated[i],
+                "total": levels.total[i],
+            }));
+        }
+        let mut duplication_plot_data = Vec::new();
+        for entry in &duplication_data {
+            for series in ["deduplicated", "total"] {
+                duplication_plot_data.push(json!({
+                    "level": entry["level"],
+                    "pct": entry[series],
+                    "type": format!("% {} sequences", series),
+                }));
+            }
+        }
+
+        // The coverage of a sample is extrapolated to all reads, and only corrected for
+        // the modules which are run
+        let coverage = setup.options.genome_size.map(|genome_size| {
+            let scale = stats
+                .sampled_from
+                .filter(|_| reads > 0)
+                .map_or(1.0, |sampled_from| sampled_from as f64 / reads as f64);
+            let adapter_bases = if modules.contains(Module::AdapterContent) {
+                analyses.adapter_content.bases()
+            } else {
+                0
+            };
+            let remaining = if modules.contains(Module::DuplicationLevels) {
+                levels.remaining
+            } else {
+                100.0
+            };
+            Coverage::new(genome_size, total_length, adapter_bases, remaining, scale)
+        });
+
+        // Data for the saturation curve, with the model extrapolated beyond the reads
+        let saturation = stats
+            .modules
+            .contains(Module::DuplicationLevels)
+            .then(|| analyses.duplication.saturation())
+            .flatten();
+        let saturation_data = saturation
+            .iter()
+            .flat_map(|saturation| {
+                let steps = saturation.observed.len().max(saturation.model.len());
+                (0..steps).map(move |i| {
+                    let observed = saturation.observed.get(i);
+                    let model = saturation.model.get(i);
+                    json!({
+                        "reads": observed.or(model).map(|(reads, _)| reads),
+                        "observed": observed.map(|(_, distinct)| distinct),
+                        "model": model.map(|(_, distinct)| distinct),
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Data for overrepresented sequences
+        let mut overrepresented_warn = "pass";
+        let mut overrepresented_data = Vec::new();
+        // Overrepresented sequences and reads hitting each contaminant
+        let mut hits: HashMap<String, (usize, usize, f64)> = HashMap::default();
+        for entry in stats.overrepresented.overrepresented(&setup.contaminants) {
+            overrepresented_warn = most_severe([
+                overrepresented_warn,
+                thresholds.overrepresented.above(entry.percentage),
+            ]);
+            if let Some(name) = &entry.contaminant {
+                let hit = hits.entry(name.clone()).or_default();
+                *hit = (hit.0 + 1, hit.1 + entry.count, hit.2 + entry.percentage);
+            }
+            overrepresented_data.push(json!({
+                "sequence": entry.sequence,
+                "count": entry.count,
+                "pct": entry.percentage,
+                "source": entry.source,
+            }));
+        }
+        let mut hits = hits.into_iter().collect::<Vec<_>>();
+        hits.sort_by(|a, b| b.1 .1.cmp(&a.1 .1).then_with(|| a.0.cmp(&b.0)));
+        let contaminant_data = hits
+            .into_iter()
+            .map(|(name, (sequences, count, percentage))| {
+                json!({
+                    "contaminant": name,
+                    "sequences": sequences,
+                    "count": count,
+                    "pct": percentage,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Data for adapter content
+        let adapter_warn = analyses.adapter_content.finish().status.as_str();
+        let adapter_percentages = analyses
+            .adapter_content
+            .percentages()
+            .iter()
+            .map(|percentages| group_means(percentages, &groups))
+            .collect::<Vec<_>>();
+        let adapter_names = analyses
+            .adapter_content
+            .adapters()
+            .iter()
+            .map(|adapter| adapter.name.clone())
+            .collect::<Vec<_>>();
+        let mut adapter_data = Vec::new();
+        for (name, percentages) in adapter_names.iter().zip(&adapter_percentages) {
+            for (bins, pct) in percentages {
+                let (pos, end) = binning.positions(bins);
+                let pct = *pct;
+                adapter_data.push(json!({
+                    "adapter": name,
+                    "pos": pos,
+                    "end": end,
+                    "pct": pct,
+                }));
+            }
+        }
+        let mut adapter_start_data = Vec::new();
+        for (name, starts) in adapter_names.iter().zip(analyses.adapter_content.starts()) {
+            for (bin, &count) in starts.iter().enumerate() {
+                if count > 0 {
+                    let (pos, end) = binning.positions(&(bin..bin + 1));
+                    adapter_start_data.push(json!({
+                        "adapter": name,
+                        "pos": pos,
+                        "end": end,
+                        "count": count,
+                        "pct": count as f64 / reads as f64 * 100.0,
+                    }));
+                }
+            }
+        }
+        let adapter_rows = groups
+            .iter()
+            .enumerate()
+            .map(|(i, bins)| {
+                let (pos, end) = binning.positions(bins);
+                json!({
+                    "pos": pos,
+                    "end": end,
+                    "values": adapter_percentages.iter().map(|p| p.get(i).map_or(0.0, |(_, pct)| *pct)).collect::<Vec<_>>(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Suggested hard trimming, by positions instead of bins
+        let adapter_positions = adapter_percentages
+            .iter()
+            .map(|percentages| {
+                percentages
+                    .iter()
+                    .map(|(bins, pct)| {
+                        let (pos, end) = binning.positions(bins);
+                        (pos..end + 1, *pct)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let trimming = Trimming::suggest(
+            &quality_means,
+            &adapter_positions,
+            max_length,
+            thresholds.adapter_content.warn,
+        );
+
+        // Data for k-mer content
+        let mut kmer_totals = analyses.kmers.totals();
+        kmer_totals.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        let kmer_count_data = kmer_totals
+            .iter()
+            .take(PLOTTED_KMERS)
+            .map(|(kmer, count)| json!({"k_mer": kmer, "count": count}))
+            .collect::<Vec<_>>();
+        let enriched_kmers = analyses.kmers.enriched();
+        let kmer_warn = analyses.kmers.finish().status.as_str();
+        let mut kmer_data = Vec::new();
+        let mut kmer_enrichment_data = Vec::new();
+        for (i, entry) in enriched_kmers.iter().enumerate() {
+            kmer_data.push(json!({
+                "k_mer": entry.kmer,
+                "count": entry.count,
+                "pvalue": format!("{:.2e}", entry.pvalue),
+                "obs_exp": entry.max_obs_exp,
+                "max_pos": binning.start(entry.max_position),
+            }));
+            if i < PLOTTED_ENRICHED_KMERS {
+                for (bins, obs_exp) in group_means(&entry.obs_exp, &groups) {
+                    let (pos, end) = binning.positions(&bins);
+                    kmer_enrichment_data.push(json!({
+                        "k_mer": entry.kmer,
+                        "pos": pos,
+                        "end": end,
+                        "obs_exp": obs_exp,
+                    }));
+                }
+            }
+        }
+
+        // Data for per tile sequence quality
+        let mut tile_warn = "pass";
+        let mut tile_data = Vec::new();
+        for entry in stats.tiles.deviations(&groups) {
+            let (pos, end) = binning.positions(&entry.bins);
+            tile_warn = most_severe([tile_warn, thresholds.tile_deviation.above(-entry.deviation)]);
+            tile_data.push(json!({
+                "tile": entry.tile,
+                "pos": pos,
+                "end": end,
+                "deviation": entry.deviation,
+            }));
+        }
+
+        // Data for read length vs mean read quality
+        let length_quality_data = stats
+            .length_quality
+            .density(encoding.offset)
+            .into_iter()
+            .map(|entry| {
+                json!({
+                    "length": entry.length,
+                    "quality": entry.quality,
+                    "count": entry.count,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Data for dinucleotide and trinucleotide bias
+        let motifs = &analyses.motifs;
+        let motif_data = motifs
+            .biases()
+            .into_iter()
+            .map(|bias| {
+                json!({
+                    "motif": bias.motif,
+                    "observed": bias.observed,
+                    "expected": bias.expected,
+                    "ratio": bias.ratio,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Data for UMIs
+        let umis = (!stats.umis.is_empty()).then(|| stats.umis.umis());
+        let umi_composition_data = umis
+            .iter()
+            .flat_map(|umis| umis.composition.iter().enumerate())
+            .map(|(pos, [a, c, g, t, n])| {
+                json!({"pos": pos, "a": a, "c": c, "g": g, "t": t, "n": n})
+            })
+            .collect::<Vec<_>>();
+        let umi_top_data = umis
+            .iter()
+            .flat_map(|umis| &umis.top)
+            .map(|(umi, count, pct)| json!({"umi": umi, "count": count, "pct": pct}))
+            .collect::<Vec<_>>();
+
+        // Data for the screen against reference genomes
+        let screen = stats
+            .references
+            .iter()
+            .map(|reference| reference.name.clone())
+            .zip(stats.screen.counts().iter().copied())
+            .collect::<Vec<_>>();
+        let screen_data = screen
+            .iter()
+            .flat_map(|(name, counts)| {
+                [
+                    ("one reference", counts.unique),
+                    ("several references", counts.matches - counts.unique),
+                ]
+                .map(|(hits, reads)| {
+                    json!({"reference": name, "hits": hits, "pct": screen_pct(reads, counts)})
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Data for the k-mer spectrum, up to the range of the model if fitted
+        let spectrum = stats.spectrum.as_ref().map(SpectrumCounter::spectrum);
+        let spectrum_data = spectrum
+            .iter()
+            .flat_map(|spectrum| {
+                let fit = spectrum.model.as_ref().map_or(&[][..], |model| &model.fit);
+                let last = spectrum.histogram.iter().rposition(|&kmers| kmers > 0);
+                let limit = match spectrum.model {
+                    Some(_) => fit.len(),
+                    None => last.map_or(0, |last| last + 1),
+                };
+                spectrum.histogram[..limit]
+                    .iter()
+                    .enumerate()
+                    .map(move |(i, kmers)| {
+                        json!({"coverage": i + 1, "kmers": kmers, "model": fit.get(i)})
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        // Data for the yield and qualities per lane
+        let lanes = stats
+            .lanes
+            .as_ref()
+            .map_or_else(Vec::new, |lanes| lanes.lanes(&groups, encoding.offset));
+        let lane_statistics = lanes
+            .iter()
+            .map(|lane| {
+                json!({
+                    "lane": lane.lane,
+                    "reads": lane.reads,
+                    "bases": lane.bases,
+                    "q30": lane.q30,
+                    "mean_quality": lane.mean_quality,
+                })
+            })
+            .collect::<Vec<_>>();
+        let lane_quality_data = lanes
+            .iter()
+            .flat_map(|lane| {
+                lane.means.iter().map(move |(bins, mean)| {
+                    let (pos, end) = binning.positions(bins);
+                    json!({"lane": lane.lane, "pos": pos, "end": end, "mean": mean})
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Data for index sequences
+        let barcodes = (!stats.barcodes.is_empty()).then(|| stats.barcodes.barcodes());
+        let barcode_data = barcodes
+            .iter()
+            .flat_map(|barcodes| &barcodes.top)
+            .map(|(barcode, count, pct, mismatches)| {
+                json!({"barcode": barcode, "count": count, "pct": pct, "mismatches": mismatches})
+            })
+            .collect::<Vec<_>>();
+
+        // Data for poly-G and poly-A tails
+        let poly_tails = &analyses.poly_tails;
+        let poly_tail_percentages = poly_tails.percentages();
+        let poly_tail_warn = poly_tails.finish().status.as_str();
+        let poly_tail_data = poly_tails
+            .distribution()
+            .into_iter()
+            .map(|tails| {
+                json!({
+                    "base": tails.base.to_string(),
+                    "length": tails.length,
+                    "count": tails.count,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Data for low complexity reads
+        let low_complexity = &analyses.low_complexity;
+        let low_complexity_pct = low_complexity.percentage();
+        let low_complexity_warn = low_complexity.finish().status.as_str();
+        let low_complexity_composition = low_complexity
+            .composition()
+            .into_iter()
+            .map(|(base, pct)| json!({"base": base.to_string(), "pct": pct}))
+            .collect::<Vec<_>>();
+        let low_complexity_data = low_complexity
+            .distribution()
+            .into_iter()
+            .map(|(score, count)| json!({"score": score, "count": count}))
+            .collect::<Vec<_>>();
+
+        // Data for homopolymer run lengths
+        let homopolymers = &analyses.homopolymers;
+        let homopolymer_data = homopolymers
+            .distribution()
+            .into_iter()
+            .map(|runs| {
+                json!({
+                    "base": runs.base.to_string(),
+                    "length": runs.length,
+                    "count": runs.count,
+                    "pct": runs.percentage,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Data for expected errors per read
+        let mean_expected_errors = stats.expected_errors.mean(encoding.offset);
+        let expected_error_data = stats
+            .expected_errors
+            .distribution(encoding.offset)
+            .into_iter()
+            .map(|entry| {
+                json!({
+                    "errors": entry.errors,
+                    "count": entry.count,
+                    "cumulative_pct": entry.cumulative_percentage,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Data for the reads passing the filters
+        let survival = stats.survival.survival(encoding.offset);
+        let survival_data = survival
+            .filters
+            .iter()
+            .map(|survivors| {
+                json!({
+                    "filter": survivors.filter,
+                    "reads": survivors.reads,
+                    "pct": survivors.percentage,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Data for the simulated quality trimming
+        let trim_simulation = stats
+            .trim_simulation
+            .as_ref()
+            .map_or_else(Vec::new, |counter| counter.simulate(encoding.offset));
+        let trim_simulation_data = trim_simulation
+            .iter()
+            .map(|trimmed| {
+                json!({
+                    "method": trimmed.method.name(),
+                    "cutoff": trimmed.cutoff,
+                    "reads": trimmed.reads,
+                    "reads_pct": trimmed.reads_percentage,
+                    "bases": trimmed.bases,
+                    "bases_pct": trimmed.bases_percentage,
+                })
+            })
+            .collect::<Vec<_>>();
+        let trimmed_length_data = trim_simulation
+            .iter()
+            .flat_map(|trimmed| {
+                let trimming = format!("{} Q{}", trimmed.method.name(), trimmed.cutoff);
+                trimmed.lengths.iter().map(move |(length, count)| {
+                    json!({
+                        "trimming": trimming,
+                        "length": length,
+                        "count": count,
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Data for yield and quality over run time
+        let run_time_data = stats
+            .run_time
+            .timeline(encoding.offset)
+            .into_iter()
+            .map(|entry| {
+                json!({
+                    "hours": entry.hours,
+                    "reads": entry.reads,
+                    "bases": entry.bases,
+                    "cumulative_bases": entry.cumulative_bases,
+                    "mean_quality": entry.mean_quality,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Data for per sequence quality scores, warning about a low most frequent quality
+        let sequence_qualities = encoding.scores(analyses.sequence_quality.counts());
+        let sequence_quality_data = sequence_qualities
+            .iter()
+            .enumerate()
+            .filter(|(_, &count)| count > 0)
+            .map(|(quality, count)| json!({"quality": quality, "count": count}))
+            .collect::<Vec<_>>();
+        let sequence_quality_warn = analyses.sequence_quality.finish().status.as_str();
+
+        // Data for per base sequence and N content
+        let base_content_warn = analyses.composition.finish().status.as_str();
+        let mut base_content_data = Vec::new();
+        for (bins, counts) in group_counts(&analyses.composition.base_content(), &groups) {
+            let (pos, end) = binning.positions(&bins);
+            let pct = acgt_percentages(&counts);
+            let skew = |x: usize, y: usize| match x + y {
+                0 => 0.0,
+                sum => (x as f64 - y as f64) / sum as f64,
+            };
+            base_content_data.push(json!({
+                "pos": pos,
+                "end": end,
+                "g": pct[G],
+                "a": pct[A],
+                "t": pct[T],
+                "c": pct[C],
+                "gc": pct[G] + pct[C],
+                "gc_skew": skew(counts[G], counts[C]),
+                "at_skew": skew(counts[A], counts[T]),
+            }));
+        }
+        let n_content_warn = analyses.n_content.finish().status.as_str();
+        let mut n_content_data = Vec::new();
+        for (bins, [n, total]) in group_counts(&analyses.n_content.content(), &groups) {
+            let (pos, end) = binning.positions(&bins);
+            let pct = n as f64 / total as f64 * 100.0;
+            n_content_data.push(json!({"pos": pos, "end": end, "pct": pct}));
+        }
+
+        // Data for per sequence GC content
+        let gc_hist = analyses.gc_content.gc_content();
+        let gc_warn = analyses.gc_content.finish().status.as_str();
+        let gc_data = gc_hist
+            .iter()
+            .enumerate()
+            .map(|(gc, count)| json!({"gc": gc, "count": count}))
+            .collect::<Vec<_>>();
+
+        // Data for basic statistics
+        let base_count = analyses.composition.totals();
+        let acgt = base_count[A] + base_count[C] + base_count[G] + base_count[T];
+        let gc_content = if acgt > 0 {
+            (base_count[G] + base_count[C]) as f64 / acgt as f64 * 100.0
+        } else {
+            0.0
+        };
+        let share = |count: usize, total: usize| match total {
+            0 => 0.0,
+            total => count as f64 / total as f64 * 100.0,
+        };
+        let q20 = share(q20_bases, scored_bases);
+        let q30 = share(q30_bases, scored_bases);
+        let scored_reads = sequence_qualities.iter().sum::<usize>();
+        let q20_reads = share(sequence_qualities.iter().skip(20).sum(), scored_reads);
+        let q30_reads = share(sequence_qualities.iter().skip(30).sum(), scored_reads);
+
+        Results {
+            encoding,
+            base_quality_warn,
+            base_per_pos_data,
+            quality_heatmap_data,
+            quality_bins,
+            quality_bin_data,
+            sequence_quality_warn,
+            sequence_quality_data,
+            base_content_warn,
+            base_content_data,
+            gc_warn,
+            gc_data,
+            n_content_warn,
+            n_content_data,
+            read_length_warn,
+            read_length_data,
+            reads,
+            total_length,
+            min_length,
+            max_length,
+            trimming,
+            mean_length,
+            sequence_length,
+            n50,
+            n90,
+            duplication_warn,
+            remaining: levels.remaining,
+            duplication_data,
+            duplication_plot_data,
+            saturation,
+            saturation_data,
+            overrepresented_warn,
+            overrepresented_data,
+            contaminant_data,
+            adapter_warn,
+            adapter_start_data,
+            poly_tail_warn,
+            poly_tail_percentages,
+            poly_tail_data,
+            low_complexity_warn,
+            low_complexity_pct,
+            low_complexity_composition,
+            low_complexity_data,
+            adapter_names,
+            adapter_data,
+            adapter_bases_pct: share(analyses.adapter_content.bases(), total_length),
+            adapter_rows,
+            kmer_warn,
+            kmer_data,
+            kmer_count_data,
+            kmer_enrichment_data,
+            has_tiles: !stats.tiles.is_empty(),
+            tile_warn,
+            tile_data,
+            length_quality_data,
+            has_motifs: !motifs.is_empty(),
+            motif_data,
+            insert_sizes: None,
+            insert_size_data: Vec::new(),
+            umis,
+            umi_composition_data,
+            umi_top_data,
+            barcodes,
+            barcode_data,
+            screen,
+            screen_data,
+            spectrum,
+            spectrum_data,
+            optical_duplicates: stats.optical.as_ref().and_then(OpticalCounter::duplicates),
+            has_lanes: !lanes.is_empty(),
+            lane_statistics,
+            lane_quality_data,
+            has_homopolymers: !homopolymers.is_empty(),
+            homopolymer_data,
+            has_expected_errors: !stats.expected_errors.is_empty(),
+            mean_expected_errors,
+            quality_error_rate: stats.expected_errors.error_rate(encoding.offset),
+            expected_error_data,
+            survival,
+            survival_data,
+            has_trim_simulation: stats.trim_simulation.is_some(),
+            trim_simulation_data,
+            trimmed_length_data,
+            has_run_time: !stats.run_time.is_empty(),
+            run_time_data,
+            gc_content,
+            q20,
+            q30,
+            q30_bases,
+            q20_reads,
+            q30_reads,
+            duplicate_ids: stats.read_ids.duplicates(),
+            duplicate_ids_estimated: stats.read_ids.is_estimated(),
+            has_qualities,
+            has_casava: stats.casava_reads > 0,
+            filtered_reads: stats.filtered_reads,
+            filtered_pct: share(stats.filtered_reads, stats.casava_reads),
+            filtered_excluded: stats.exclude_filtered,
+            invalid_reads: stats.invalid_reads.clone(),
+            sampled_from: stats.sampled_from,
+            seed: setup.options.seed,
+            coverage,
+            platform,
+            run_info: stats.run_info.run_info(),
+            read_names: stats.read_names.read_names(stats.sampled_from.is_none()),
+            modules,
+            custom: stats
+                .custom
+                .iter()
+                .flatten()
+                .map(|module| module.finish())
+                .collect(),
+        }
+    }
+
+    /// Returns the key metrics and module statuses exported with `--metrics-out`.
+    pub(crate) fn metrics(&self, file: &str) -> crate::metrics::Sample {
+        crate::metrics::Sample {
+            file: file.to_string(),
+            reads: self.reads,
+            bases: self.total_length,
+            q30: self.has_qualities.then_some(self.q30),
+            duplication: self
+                .shows(Module::DuplicationLevels.key())
+                .then_some(100.0 - self.remaining),
+            adapters: self
+                .shows(Module::AdapterContent.key())
+                .then_some(self.adapter_bases_pct),
+            statuses: self
+                .module_statuses()
+                .into_iter()
+                .map(|(module, status)| (module.to_string(), status))
+                .collect(),
+        }
+    }
+
+    /// Returns the name and status of every module in the order of FastQC, followed by
+    /// the custom modules.
+    pub(crate) fn module_statuses(&self) -> Vec<(&str, &'static str)> {
+        let mut statuses = vec![("Basic Statistics", "pass")];
+        for module in Module::ALL {
+            if self.shows(module.key()) && (module != Module::PerTileQuality || self.has_tiles) {
+                statuses.push((module.name(), self.module_status(module)));
+            }
+        }
+        for module in &self.custom {
+            statuses.push((&module.name, module.status.as_str()));
+        }
+        statuses
+    }
+
+    fn module_status(&self, module: Module) -> &'static str {
+        match module {
+            Module::PerBaseQuality => self.base_quality_warn,
+            Module::PerTileQuality => self.tile_warn,
+            Module::PerSequenceQuality => self.sequence_quality_warn,
+            Module::PerBaseContent => self.base_content_warn,
+            Module::PerSequenceGcContent => self.gc_warn,
+            Module::PerBaseNContent => self.n_content_warn,
+            Module::ReadLengthDistribution => self.read_length_warn,
+            Module::DuplicationLevels => self.duplication_warn,
+            Module::OverrepresentedSequences => self.overrepresented_warn,
+            Module::AdapterContent => self.adapter_warn,
+            Module::KmerContent => self.kmer_warn,
+            Module::PolyTails => self.poly_tail_warn,
+            Module::LowComplexity => self.low_complexity_warn,
+        }
+    }
+
+    /// Adds the insert sizes estimated from the pairs of paired-end reads.
+    fn with_insert_sizes(mut self, inserts: &InsertSizeCounter) -> Self {
+        let sizes = inserts.sizes();
+        self.insert_size_data = sizes
+            .counts
+            .iter()
+            .map(|(size, count)| json!({"size": size, "count": count}))
+            .collect();
+        self.insert_sizes = Some(sizes);
+        self
+    }
+
+    /// Returns the number of invalid records, their number per kind of error and the
+    /// numbers of the first of them.
+    fn invalid_reads_json(&self) -> Value {
+        json!({
+            "count": self.invalid_reads.count(),
+            "kinds": self.invalid_reads.kinds(),
+            "records": self.invalid_reads.records(),
+        })
+    }
+
+    fn read_names_json(&self) -> Value {
+        let names = &self.read_names;
+        let counts = |counts: Vec<(&str, usize)>| {
+            counts
+                .into_iter()
+                .map(|(name, reads)| (name.to_string(), json!(reads)))
+                .collect::<serde_json::Map<_, _>>()
+        };
+        let runs = names.runs.iter().map(|(run, reads)| (run.as_str(), *reads));
+        json!({
+            "schemes": counts(names.schemes.clone()),
+            "runs": counts(runs.collect()),
+            "mates": {"none": names.mates[0], "1": names.mates[1], "2": names.mates[2]},
+            "tile_restarts": names.tile_restarts,
+            "implausible_coordinates": names.implausible_coordinates,
+            "warnings": names.warnings(),
+        })
+    }
+
+    /// Returns the model of the genome fitted to the k-mer spectrum, if counted and fitted.
+    fn genome_model(&self) -> Option<&GenomeModel> {
+        self.spectrum.as_ref()?.model.as_ref()
+    }
+
+    /// Returns the error rates estimated from singleton k-mers and expected from the
+    /// qualities if they differ so much that the qualities seem miscalibrated.
+    fn miscalibration(&self) -> Option<(f64, f64)> {
+        let kmers = self.spectrum.as_ref()?.singleton_error_rate?;
+        let qualities = self.quality_error_rate;
+        let ratio = kmers.max(qualities) / kmers.min(qualities);
+        (self.has_expected_errors && ratio >= MISCALIBRATION_RATIO).then_some((kmers, qualities))
+    }
+
+    /// Returns the reads matching each reference genome, for JSON reports.
+    fn screen_json(&self) -> Vec<Value> {
+        self.screen
+            .iter()
+            .map(|(name, counts)| {
+                json!({
+                    "name": name,
+                    "screened": counts.screened,
+                    "reads": counts.matches,
+                    "pct": screen_pct(counts.matches, counts),
+                    "unique_pct": screen_pct(counts.unique, counts),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the estimated number of distinct sequences in the library, or `-` if all
+    /// reads are distinct.
+    fn library_size_text(&self) -> String {
+        match self
+            .saturation
+            .as_ref()
+            .and_then(|saturation| saturation.library_size)
+        {
+            Some(size) => format!("{:.0}", size),
+            None => "-".to_string(),
+        }
+    }
+
+    /// Returns the detected platform with its chemistry and flowcell, or `-`.
+    fn platform_text(&self) -> String {
+        let Some(platform) = self.platform else {
+            return "-".to_string();
+        };
+        let details = platform
+            .chemistry
+            .into_iter()
+            .chain(platform.patterned.then_some("patterned flowcell"))
+            .join(", ");
+        match details.is_empty() {
+            true => platform.name(),
+            false => format!("{} ({})", platform.name(), details),
+        }
+    }
+
+    /// Returns the distinct quality scores of binned qualities, or `-` if not binned.
+    fn quality_bins_text(&self) -> String {
+        match &self.quality_bins {
+            Some(bins) => bins.iter().join(", "),
+            None => "-".to_string(),
+        }
+    }
+
+    /// Returns the mean coverage and the one corrected for duplicates and adapters,
+    /// e.g. `31.20x (25.10x corrected)`.
+    fn coverage_text(&self) -> String {
+        match &self.coverage {
+            Some(coverage) => format!(
+                "{:.2}x ({:.2}x corrected)",
+                coverage.mean, coverage.corrected
+            ),
+            None => "-".to_string(),
+        }
+    }
+
+    /// Returns the number of duplicate read IDs, prefixed with `~` if estimated.
+    fn duplicate_ids_text(&self) -> String {
+        match self.duplicate_ids_estimated {
+            true => format!("~{}", self.duplicate_ids),
+            false => self.duplicate_ids.to_string(),
+        }
+    }
+
+    /// Returns whether the data with the given module key, table or plot name is
+    /// reported, which is the case unless it belongs to a disabled module.
+    fn shows(&self, key: &str) -> bool {
+        let key = match key {
+            "kmer_counts" => "kmer_content",
+            "contaminant_hits" => "overrepresented_sequences",
+            "adapter_starts" => "adapter_content",
+            "per_base_skew" | "per_base_gc" => "per_base_content",
+            "quality_heatmap" | "quality_bins" => "per_base_quality",
+            "saturation" => "duplication_levels",
+            key => key,
+        };
+        Module::from_key(key).is_none_or(|module| self.modules.contains(module))
+    }
+
+    pub(crate) fn custom_modules(&self) -> &[ModuleResult] {
+        &self.custom
+    }
+
+    /// Returns the most severe status of all modules.
+    pub(crate) fn status(&self) -> &'static str {
+        most_severe(self.module_statuses().into_iter().map(|(_, status)| status))
+    }
+
+    /// Returns the file name, columns and rows of the data of every module.
+    fn tables(&self, long_reads: bool) -> Vec<(&'static str, &'static [&'static str], &[Value])> {
+        let mut tables: Vec<(_, &[_], _)> = vec![
+            (
+                "per_base_quality",
+                &[
+                    "pos", "end", "average", "median", "q1", "q3", "p10", "p90", "lower", "upper",
+                ],
+                &self.base_per_pos_data[..],
+            ),
+            (
+                "per_sequence_quality",
+                &["quality", "count"],
+                &self.sequence_quality_data,
+            ),
+            (
+                "per_base_content",
+                &["pos", "end", "g", "a", "t", "c", "gc", "gc_skew", "at_skew"],
+                &self.base_content_data,
+            ),
+            ("per_sequence_gc_content", &["gc", "count"], &self.gc_data),
+            (
+                "per_base_n_content",
+                &["pos", "end", "pct"],
+                &self.n_content_data,
+            ),
+            (
+                "read_length_distribution",
+                &["length", "count"],
+                &self.read_length_data,
+            ),
+            (
+                "duplication_levels",
+                &["level", "deduplicated", "total"],
+                &self.duplication_data,
+            ),
+            (
+                "saturation",
+                &["reads", "observed", "model"],
+                &self.saturation_data,
+            ),
+            (
+                "overrepresented_sequences",
+                &["sequence", "count", "pct", "source"],
+                &self.overrepresented_data,
+            ),
+            (
+                "contaminant_hits",
+                &["contaminant", "sequences", "count", "pct"],
+                &self.contaminant_data,
+            ),
+            (
+                "adapter_content",
+                &["adapter", "pos", "end", "pct"],
+                &self.adapter_data,
+            ),
+            (
+                "adapter_starts",
+                &["adapter", "pos", "end", "count", "pct"],
+                &self.adapter_start_data,
+            ),
+            (
+                "kmer_content",
+                &["k_mer", "count", "pvalue", "obs_exp", "max_pos"],
+                &self.kmer_data,
+            ),
+            ("kmer_counts", &["k_mer", "count"], &self.kmer_count_data),
+        ];
+        if !self.quality_heatmap_data.is_empty() {
+            tables.push((
+                "quality_heatmap",
+                &["pos", "end", "quality", "count", "pct"],
+                &self.quality_heatmap_data,
+            ));
+        }
+        if !self.quality_bin_data.is_empty() {
+            tables.push((
+                "quality_bins",
+                &["pos", "end", "quality", "pct"],
+                &self.quality_bin_data,
+            ));
+        }
+        if self.has_tiles {
+            tables.push((
+                "per_tile_quality",
+                &["tile", "pos", "end", "deviation"],
+                &self.tile_data,
+            ));
+        }
+        if long_reads {
+            tables.push((
+                "length_quality",
+                &["length", "quality", "count"],
+                &self.length_quality_data,
+            ));
+        }
+        if self.has_motifs {
+            tables.push((
+                "motif_bias",
+                &["motif", "observed", "expected", "ratio"],
+                &self.motif_data,
+            ));
+        }
+        tables.push((
+            "poly_tails",
+            &["base", "length", "count"],
+            &self.poly_tail_data,
+        ));
+        tables.push((
+            "low_complexity",
+            &["score", "count"],
+            &self.low_complexity_data,
+        ));
+        tables.push((
+            "low_complexity_composition",
+            &["base", "pct"],
+            &self.low_complexity_composition,
+        ));
+        if self.umis.is_some() {
+            tables.push((
+                "umi_composition",
+                &["pos", "a", "c", "g", "t", "n"],
+                &self.umi_composition_data,
+            ));
+            tables.push(("umi_top", &["umi", "count", "pct"], &self.umi_top_data));
+        }
+        if self.barcodes.is_some() {
+            tables.push((
+                "barcodes",
+                &["barcode", "count", "pct", "mismatches"],
+                &self.barcode_data,
+            ));
+        }
+        if !self.screen.is_empty() {
+            tables.push(("screen", &["reference", "hits", "pct"], &self.screen_data));
+        }
+        if self.spectrum.is_some() {
+            tables.push((
+                "kmer_spectrum",
+                &["coverage", "kmers", "model"],
+                &self.spectrum_data,
+            ));
+        }
+        if self.has_lanes {
+            tables.push((
+                "lane_statistics",
+                &["lane", "reads", "bases", "q30", "mean_quality"],
+                &self.lane_statistics,
+            ));
+            tables.push((
+                "lane_quality",
+                &["lane", "pos", "end", "mean"],
+                &self.lane_quality_data,
+            ));
+        }
+        if self.insert_sizes.is_some() {
+            tables.push(("insert_size", &["size", "count"], &self.insert_size_data));
+        }
+        if self.has_homopolymers {
+            tables.push((
+                "homopolymers",
+                &["base", "length", "count", "pct"],
+                &self.homopolymer_data,
+            ));
+        }
+        if self.has_expected_errors {
+            tables.push((
+                "expected_errors",
+                &["errors", "count", "cumulative_pct"],
+                &self.expected_error_data,
+            ));
+        }
+        tables.push((
+            "filter_survival",
+            &["filter", "reads", "pct"],
+            &self.survival_data,
+        ));
+        if self.has_trim_simulation {
+            tables.push((
+                "trim_simulation",
+                &[
+                    "method",
+                    "cutoff",
+                    "reads",
+                    "reads_pct",
+                    "bases",
+                    "bases_pct",
+                ],
+                &self.trim_simulation_data,
+            ));
+            tables.push((
+                "trimmed_lengths",
+                &["trimming", "length", "count"],
+                &self.trimmed_length_data,
+            ));
+        }
+        if self.has_run_time {
+            tables.push((
+                "run_time",
+                &[
+                    "hours",
+                    "reads",
+                    "bases",
+                    "cumulative_bases",
+                    "mean_quality",
+                ],
+                &self.run_time_data,
+            ));
+        }
+        tables.retain(|(name, _, _)| self.shows(name));
+        tables
+    }
+
+    /// Returns the file name and a static chart of every plotted module.
+    fn charts(&self) -> Vec<(&'static str, Chart)> {
+        let number = |value: &Value| value.as_f64().unwrap_or(0.0);
+        let points = |data: &[Value], x: &str, y: &str| {
+            data.iter()
+                .map(|entry| (number(&entry[x]), number(&entry[y])))
+                .collect::<Vec<_>>()
+        };
+        let series = |name: &str, points: Vec<(f64, f64)>| Series {
+            name: name.to_string(),
+            points,
+        };
+        let by_position = "Position in read (bp)".to_string();
+
+        let mut adapters = Vec::new();
+        for name in &self.adapter_names {
+            let entries = self
+                .adapter_data
+                .iter()
+                .filter(|entry| entry["adapter"] == name.as_str())
+                .cloned()
+                .collect::<Vec<_>>();
+            adapters.push(series(name, points(&entries, "pos", "pct")));
+        }
+        let duplication = |column: &str| {
+            self.duplication_data
+                .iter()
+                .enumerate()
+                .map(|(i, entry)| (i as f64, number(&entry[column])))
+                .collect()
+        };
+        // Binned qualities are plotted as the share of the bases in every bin
+        let quality_bin_chart = self.quality_bins.as_ref().map(|bins| Chart {
+            title: "Quality bins across all bases".to_string(),
+            x_label: by_position.clone(),
+            y_label: "% of bases".to_string(),
+            series: bins
+                .iter()
+                .map(|&quality| {
+                    let entries = self
+                        .quality_bin_data
+                        .iter()
+                        .filter(|entry| entry["quality"] == quality)
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    series(&format!("Q{}", quality), points(&entries, "pos", "pct"))
+                })
+                .collect(),
+            y_range: Some((0.0, 100.0)),
+            ..Chart::default()
+        });
+        let mut charts = vec![
+            (
+                "per_base_quality",
+                Chart {
+                    title: "Quality scores across all bases".to_string(),
+                    x_label: by_position.clone(),
+                    y_label: "Phred score".to_string(),
+                    series: vec![series(
+                        "Mean",
+                        points(&self.base_per_pos_data, "pos", "average"),
+                    )],
+                    boxes: self
+                        .base_per_pos_data
+                        .iter()
+                        .map(|entry| QualityBox {
+                            x: number(&entry["pos"]),
+                            whiskers: (number(&entry["p10"]), number(&entry["p90"])),
+                            quartiles: (number(&entry["q1"]), number(&entry["q3"])),
+                            median: number(&entry["median"]),
+                        })
+                        .collect(),
+                    ..Chart::default()
+                },
+            ),
+            (
+                "per_sequence_quality",
+                Chart {
+                    title: "Quality score distribution over all sequences".to_string(),
+                    x_label: "Mean sequence quality (Phred score)".to_string(),
+                    y_label: "Sequences".to_string(),
+                    series: vec![series(
+                        "Sequences",
+                        points(&self.sequence_quality_data, "quality", "count"),
+                    )],
+                    ..Chart::default()
+                },
+            ),
+            (
+                "per_base_content",
+                Chart {
+                    title: "Sequence content across all bases".to_string(),
+                    x_label: by_position.clone(),
+                    y_label: "%".to_string(),
+                    series: ["g", "a", "t", "c"]
+                        .iter()
+                        .map(|&base| {
+                            let name = format!("%{}", base.to_uppercase());
+                            series(&name, points(&self.base_content_data, "pos", base))
+                        })
+                        .collect(),
+                    y_range: Some((0.0, 100.0)),
+                    ..Chart::default()
+                },
+            ),
+            (
+                "per_base_gc",
+                Chart {
+                    title: "GC content across all bases".to_string(),
+                    x_label: by_position.clone(),
+                    y_label: "%GC".to_string(),
+                    series: vec![series("%GC", points(&self.base_content_data, "pos", "gc"))],
+                    y_range: Some((0.0, 100.0)),
+                    ..Chart::default()
+                },
+            ),
+            (
+                "per_base_skew",
+                Chart {
+                    title: "GC and AT skew across all bases".to_string(),
+                    x_label: by_position.clone(),
+                    y_label: "Skew".to_string(),
+                    series: vec![
+                        series("GC skew", points(&self.base_content_data, "pos", "gc_skew")),
+                        series("AT skew", points(&self.base_content_data, "pos", "at_skew")),
+                    ],
+                    y_range: Some((-1.0, 1.0)),
+                    ..Chart::default()
+                },
+            ),
+            (
+                "per_sequence_gc_content",
+                Chart {
+                    title: "GC distribution over all sequences".to_string(),
+                    x_label: "Mean GC content (%)".to_string(),
+                    y_label: "Sequences".to_string(),
+                    series: vec![series(
+                        "GC count per read",
+                        points(&self.gc_data, "gc", "count"),
+                    )],
+                    ..Chart::default()
+                },
+            ),
+            (
+                "per_base_n_content",
+                Chart {
+                    title: "N content across all bases".to_string(),
+                    x_label: by_position.clone(),
+                    y_label: "%".to_string(),
+                    series: vec![series("%N", points(&self.n_content_data, "pos", "pct"))],
+                    y_range: Some((0.0, 100.0)),
+                    ..Chart::default()
+                },
+            ),
+            (
+                "read_length_distribution",
+                Chart {
+                    title: "Distribution of sequence lengths over all sequences".to_string(),
+                    x_label: "Sequence length (bp)".to_string(),
+                    y_label: "Sequences".to_string(),
+                    series: vec![series(
+                        "Sequence length",
+                        points(&self.read_length_data, "length", "count"),
+                    )],
+                    ..Chart::default()
+                },
+            ),
+            (
+                "duplication_levels",
+                Chart {
+                    title: format!(
+                        "Percent of seqs remaining if deduplicated {:.2}%",
+                        self.remaining
+                    ),
+                    x_label: "Sequence duplication level".to_string(),
+                    y_label: "%".to_string(),
+                    series: vec![
+                        series("% Deduplicated sequences", duplication("deduplicated")),
+                        series("% Total sequences", duplication("total")),
+                    ],
+                    categories: LEVELS.iter().map(|level| level.to_string()).collect(),
+                    y_range: Some((0.0, 100.0)),
+                    ..Chart::default()
+                },
+            ),
+            (
+                "adapter_content",
+                Chart {
+                    title: "% Adapter".to_string(),
+                    x_label: by_position,
+                    y_label: "% of sequences".to_string(),
+                    series: adapters,
+                    y_range: Some((0.0, 100.0)),
+                    ..Chart::default()
+                },
+            ),
+        ];
+        charts.extend(quality_bin_chart.map(|chart| ("quality_bins", chart)));
+        charts.retain(|(name, _)| self.shows(name));
+        charts
+    }
+
+    /// Returns the results of the given file with the status and data of every module.
+    pub(crate) fn to_json(&self, file: &str, long_reads: bool) -> Value {
+        let mut modules = json!({
+            "per_base_quality": {"status": self.base_quality_warn, "data": self.base_per_pos_data},
+            "per_sequence_quality": {"status": self.sequence_quality_warn, "data": self.sequence_quality_data},
+            "per_base_content": {"status": self.base_content_warn, "data": self.base_content_data},
+            "per_sequence_gc_content": {"status": self.gc_warn, "data": self.gc_data},
+            "per_base_n_content": {"status": self.n_content_warn, "data": self.n_content_data},
+            "read_length_distribution": {"status": self.read_length_warn, "data": self.read_length_data},
+            "duplication_levels": {
+                "status": self.duplication_warn,
+                "remaining": self.remaining,
+                "data": self.duplication_data,
+                "library_size": self.saturation.as_ref().and_then(|saturation| saturation.library_size),
+                "saturation": self.saturation_data,
+            },
+            "overrepresented_sequences": {
+                "status": self.overrepresented_warn,
+                "data": self.overrepresented_data,
+                "contaminants": self.contaminant_data,
+            },
+            "adapter_content": {
+                "status": self.adapter_warn,
+                "data": self.adapter_data,
+                "starts": self.adapter_start_data,
+            },
+            "kmer_content": {
+                "status": self.kmer_warn,
+                "data": self.kmer_data,
+                "counts": self.kmer_count_data,
+            },
+            "poly_tails": {
+                "status": self.poly_tail_warn,
+                "poly_g": self.poly_tail_percentages[0],
+                "poly_a": self.poly_tail_percentages[1],
+                "data": self.poly_tail_data,
+            },
+            "low_complexity": {
+                "status": self.low_complexity_warn,
+                "percentage": self.low_complexity_pct,
+                "composition": self.low_complexity_composition,
+                "data": self.low_complexity_data,
+            },
+        });
+        if !self.quality_bin_data.is_empty() {
+            modules["per_base_quality"]["bins"] = json!(self.quality_bin_data);
+        }
+        if !self.quality_heatmap_data.is_empty() {
+            modules["quality_heatmap"] = json!({"data": self.quality_heatmap_data});
+        }
+        if self.has_tiles {
+            modules["per_tile_quality"] = json!({"status": self.tile_warn, "data": self.tile_data});
+        }
+        if long_reads {
+            modules["length_quality"] = json!({"data": self.length_quality_data});
+        }
+        if self.has_motifs {
+            modules["motif_bias"] = json!({"data": self.motif_data});
+        }
+        if let Some(sizes) = &self.insert_sizes {
+            modules["insert_size"] = json!({
+                "median": sizes.median,
+                "dimers": sizes.dimers,
+                "non_overlapping": sizes.non_overlapping,
+                "data": self.insert_size_data,
+            });
+        }
+        if self.has_homopolymers {
+            modules["homopolymers"] = json!({"data": self.homopolymer_data});
+        }
+        if let Some(umis) = &self.umis {
+            modules["umis"] = json!({
+                "reads": umis.reads,
+                "pct": umis.percentage,
+                "distinct": umis.distinct,
+                "expected_distinct": umis.expected,
+                "diversity": umis.diversity,
+                "composition": self.umi_composition_data,
+                "top": self.umi_top_data,
+            });
+        }
+        if let Some(barcodes) = &self.barcodes {
+            modules["barcodes"] = json!({
+                "reads": barcodes.reads,
+                "expected": barcodes.expected,
+                "mismatches": barcodes.mismatches,
+                "entropy": barcodes.entropy,
+                "unexpected": barcodes.unexpected,
+                "unexpected_pct": barcodes.unexpected_percentage,
+                "top": self.barcode_data,
+            });
+        }
+        if !self.screen.is_empty() {
+            modules["screen"] = json!({"references": self.screen_json()});
+        }
+        if let Some(optical) = &self.optical_duplicates {
+            modules["optical_duplicates"] = json!({
+                "distance": optical.distance,
+                "reads": optical.reads,
+                "duplicates": optical.duplicates,
+                "optical": optical.optical,
+                "pcr": optical.pcr,
+            });
+        }
+        if let Some(spectrum) = &self.spectrum {
+            let model = spectrum.model.as_ref();
+            modules["kmer_spectrum"] = json!({
+                "k": crate::screen::K,
+                "scale": spectrum.scale,
+                "kmers": spectrum.kmers,
+                "distinct": spectrum.distinct,
+                "coverage": model.map(|model| model.coverage),
+                "genome_size": model.map(|model| model.genome_size),
+                "heterozygosity": model.map(|model| model.heterozygosity),
+                "error_rate": model.map(|model| model.error_rate),
+                "singleton_error_rate": spectrum.singleton_error_rate,
+                "quality_error_rate": self.has_expected_errors.then_some(self.quality_error_rate),
+                "miscalibrated": self.miscalibration().is_some(),
+                "data": self.spectrum_data,
+            });
+        }
+        if self.has_lanes {
+            modules["lanes"] = json!({
+                "statistics": self.lane_statistics,
+                "data": self.lane_quality_data,
+            });
+        }
+        if self.has_expected_errors {
+            modules["expected_errors"] = json!({
+                "mean": self.mean_expected_errors,
+                "data": self.expected_error_data,
+            });
+        }
+        modules["filter_survival"] = json!({
+            "bases": self.survival.bases,
+            "bases_pct": self.survival.bases_percentage,
+            "data": self.survival_data,
+        });
+        if self.has_trim_simulation {
+            modules["trim_simulation"] = json!({
+                "data": self.trim_simulation_data,
+                "lengths": self.trimmed_length_data,
+            });
+        }
+        if self.has_run_time {
+            modules["run_time"] = json!({"data": self.run_time_data});
+        }
+        if let Some(modules) = modules.as_object_mut() {
+            modules.retain(|key, _| self.shows(key));
+        }
+        let mut json = json!({
+            "filename": file,
+            "encoding": self.encoding.name,
+            "invalid_reads": self.invalid_reads_json(),
+            "read_names": self.read_names_json(),
+            "run": (!self.run_info.is_empty()).then(|| json!({
+                "instruments": self.run_info.instruments,
+                "runs": self.run_info.runs,
+                "flowcells": self.run_info.flowcells,
+                "lanes": self.run_info.lanes,
+                "channels": self.run_info.channels,
+            })),
+            "basic_statistics": {
+                "total_sequences": self.reads,
+                "sampled_from": self.sampled_from,
+                "sample_seed": self.sampled_from.map(|_| self.seed),
+                "has_qualities": self.has_qualities,
+                "quality_bins": self.quality_bins,
+                "coverage": self.coverage.map(|coverage| json!({
+                    "genome_size": coverage.genome_size,
+                    "mean": coverage.mean,
+                    "corrected": coverage.corrected,
+                })),
+                "platform": self.platform.map(|platform| json!({
+                    "name": platform.name(),
+                    "vendor": platform.vendor,
+                    "instrument": platform.instrument,
+                    "chemistry": platform.chemistry,
+                    "patterned": platform.patterned,
+                })),
+                "total_bases": self.total_length,
+                "sequence_length": self.sequence_length,
+                "min_length": self.min_length,
+                "mean_length": self.mean_length,
+                "max_length": self.max_length,
+                "n50": self.n50,
+                "n90": self.n90,
+                "gc_content": self.gc_content,
+                // Reads without qualities have no metrics of them instead of zeros
+                "q20": self.has_qualities.then_some(self.q20),
+                "q30": self.has_qualities.then_some(self.q30),
+                "q30_bases": self.has_qualities.then_some(self.q30_bases),
+                "q20_reads": self.has_qualities.then_some(self.q20_reads),
+                "q30_reads": self.has_qualities.then_some(self.q30_reads),
+                "duplicate_ids": self.duplicate_ids,
+                "duplicate_ids_estimated": self.duplicate_ids_estimated,
+                "filtered_reads": self.filtered_reads,
+                "filtered_pct": self.filtered_pct,
+                "filtered_excluded": self.filtered_excluded,
+            },
+            "suggested_trimming": {
+                "five_prime": self.trimming.five_prime,
+                "three_prime": self.trimming.three_prime,
+                "quality_cutoff": self.trimming.quality_cutoff,
+            },
+            "modules": modules,
+        });
+        if !self.custom.is_empty() {
+            json["custom_modules"] = json!(self.custom_json());
+        }
+        json
+    }
+
+    /// Returns the results of the custom modules with their rows keyed by column.
+    fn custom_json(&self) -> Vec<Value> {
+        self.custom
+            .iter()
+            .map(|module| {
+                let rows = module
+                    .rows
+                    .iter()
+                    .map(|row| {
+                        module
+                            .columns
+                            .iter()
+                            .zip(row)
+                            .map(|(column, value)| (column.clone(), json!(value)))
+                            .collect::<serde_json::Map<_, _>>()
+                    })
+                    .collect::<Vec<_>>();
+                json!({
+                    "name": module.name,
+                    "status": module.status.as_str(),
+                    "columns": module.columns,
+                    "data": rows,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Files written for the results of a single input file besides the report.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct FileOutputs<'a> {
+    /// Directory to write `fastqc_data.txt` to.
+    pub(crate) summary: Option<&'a Path>,
+    /// Path of a FastQC-style zip archive with the report and summaries.
+    pub(crate) bundle: Option<&'a Path>,
+    /// Directory to write the data of every module to as TSV files.
+    pub(crate) tables: Option<&'a Path>,
+    /// Directory to write every plot to as SVG file.
+    pub(crate) plots: Option<&'a Path>,
+    /// SQLite database to append the metrics and module statuses to.
+    pub(crate) database: Option<&'a Path>,
+}
+
+/// Runs all analyses on the given file and writes the HTML report to `output`, or to
+/// standard output if `None`, along with the given file outputs. Returns the outcome
+/// of the QC, like all other `process` functions for their reported files.
+pub(crate) fn process<P: AsRef<Path> + AsRef<OsStr>>(
+    filename: P,
+    setup: &Setup,
+    output: Option<&Path>,
+    outputs: FileOutputs,
+) -> Result<Outcome, FastqcError> {
+    let stats = Stats::gather(&filename, setup)?;
+    let results = Results::new(&stats, setup);
+    let file = display_name(&filename);
+    write_report(setup, &[(file, &results)], output, false)?;
+    write_outputs(setup, file, &results, outputs)?;
+    Ok(outcome(setup, &[(file, &results)]))
+}
+
+/// Like [`process`], but combines all given files into a single report shown as `name`.
+pub(crate) fn process_merged<P: AsRef<Path>>(
+    filenames: &[P],
+    name: &str,
+    setup: &Setup,
+    output: Option<&Path>,
+    outputs: FileOutputs,
+) -> Result<Outcome, FastqcError> {
+    let mut stats = Stats::new(setup);
+    for filename in filenames {
+        stats.read(filename, setup)?;
+    }
+    let results = Results::new(&stats, setup);
+    let file = format!("{} ({} files)", name, filenames.len());
+    write_report(setup, &[(&file, &results)], output, false)?;
+    write_outputs(setup, &file, &results, outputs)?;
+    Ok(outcome(setup, &[(&file, &results)]))
+}
+
+/// How a directory is watched for new input files.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Watch {
+    /// Time between two scans of the directory.
+    pub(crate) interval: Duration,
+    /// Time without new files after which watching stops, or `None` to watch until the
+    /// run finished.
+    pub(crate) idle: Option<Duration>,
+}
+
+/// Like [`process_merged`], but adds the input files to the report as they appear in
+/// the given directory, rewriting the report at `output` after every scan with new
+/// files until the run finished or no files appeared for the idle time. The file
+/// outputs are written once at the end.
+pub(crate) fn process_watched(
+    dir: &Path,
+    name: &str,
+    setup: &Setup,
+    output: &Path,
+    outputs: FileOutputs,
+    watch: Watch,
+) -> Result<Outcome, FastqcError> {
+    let mut watcher = Watcher::new(dir);
+    let mut stats = Stats::new(setup);
+    let mut files = 0;
+    let mut updated = Instant::now();
+    // The report is replaced at once, so that it is never read half written
+    let partial = output.with_extension("partial");
+    loop {
+        let finished = watcher.is_finished();
+        let new = watcher.poll(finished)?;
+        if !new.is_empty() {
+            updated = Instant::now();
+        }
+        let mut read = 0;
+        for filename in &new {
+            // A corrupt file is left out, as it would end a run otherwise
+            match stats.read(filename, setup) {
+                Ok(()) => read += 1,
+                Err(e) => log::warn!("Skipped {}: {}", filename, e),
+            }
+        }
+        if read > 0 {
+            files += read;
+            let results = Results::new(&stats, setup);
+            let file = format!("{} ({} files)", name, files);
+            write_report(setup, &[(&file, &results)], Some(&partial), false)?;
+            std::fs::rename(&partial, output)?;
+            log::info!(
+                "Read {} files with {} reads from {}.",
+                files,
+                results.reads,
+                dir.display()
+            );
+        }
+        if finished || watch.idle.is_some_and(|idle| updated.elapsed() >= idle) {
+            break;
+        }
+        thread::sleep(watch.interval);
+    }
+    if files == 0 {
+        return Err(format!("No input files appeared in directory {}.", dir.display()).into());
+    }
+    let results = Results::new(&stats, setup);
+    let file = format!("{} ({} files)", name, files);
+    write_outputs(setup, &file, &results, outputs)?;
+    Ok(outcome(setup, &[(&file, &results)]))
+}
+
+/// Runs all analyses on every given file and writes an aggregated report comparing
+/// all files to `output`, or to standard output if `None`. Each file is also reported
+/// to the given path if present, along with its file outputs. Several files are
+/// processed at the same time if set up with `parallel_files`.
+pub(crate) fn process_aggregated<P: AsRef<Path> + AsRef<OsStr> + Sync>(
+    filenames: &[P],
+    setup: &Setup,
+    output: Option<&Path>,
+    reports: &[(Option<&Path>, FileOutputs)],
+) -> Result<Outcome, FastqcError> {
+    let jobs = filenames.iter().zip(reports).collect::<Vec<_>>();
+    let samples = crate::pool::process_files(
+        &jobs,
+        setup.options.parallel_files,
+        setup.options.progress,
+        |&(filename, &(report, outputs))| {
+            let stats = Stats::gather(filename, setup)?;
+            let results = Results::new(&stats, setup);
+            let file = display_name(filename);
+            if let Some(path) = report {
+                write_report(setup, &[(file, &results)], Some(path), false)?;
+            }
+            write_outputs(setup, file, &results, outputs)?;
+            Ok((file, results))
+        },
+    )?;
+    let files = samples
+        .iter()
+        .map(|(file, results)| (*file, results))
+        .collect::<Vec<_>>();
+    write_report(setup, &files, output, true)?;
+    Ok(outcome(setup, &files))
+}
+
+/// Compares two samples, each given as input file or JSON report of a single file,
+/// and writes a report of their differences with the given tolerances of metrics to
+/// `output`, or to standard output if `None`. Returns the number of regressions from
+/// the first to the second sample.
+pub(crate) fn process_comparison(
+    filenames: [&str; 2],
+    setup: &Setup,
+    tolerances: &[(&str, f64)],
+    output: Option<&Path>,
+) -> Result<usize, FastqcError> {
+    let samples = [
+        load_sample(filenames[0], setup)?,
+        load_sample(filenames[1], setup)?,
+    ];
+    let comparison = Comparison::new(&samples[0], &samples[1], tolerances);
+    let report = match setup.options.format {
+        Format::Json => serde_json::to_string_pretty(&json!({
+            "version": env!("CARGO_PKG_VERSION"),
+            "before": samples[0]["filename"],
+            "after": samples[1]["filename"],
+            "comparison": comparison.to_json(),
+        }))?,
+        _ => render_comparison(setup, &samples, &comparison)?,
+    };
+    match output {
+        Some(path) => File::create(path)?.write_all(report.as_bytes())?,
+        None => io::stdout().write_all(report.as_bytes())?,
+    }
+    Ok(comparison.regressions())
+}
+
+/// Returns the results of a file as in the JSON report, read from a JSON report of a
+/// single file written with `--format json` or analyzing any other input file.
+fn load_sample(filename: &str, setup: &Setup) -> Result<Value, FastqcError> {
+    if filename.ends_with(".json") {
+        return match report_files(filename)?.as_mut_slice() {
+            [results] => Ok(results.take()),
+            _ => Err(format!("{} is not a JSON report of a single file.", filename).into()),
+        };
+    }
+    let stats = Stats::gather(filename, setup)?;
+    let results = Results::new(&stats, setup);
+    let long_reads = setup.options.binning == Binning::Adaptive;
+    Ok(results.to_json(display_name(&filename), long_reads))
+}
+
+/// Reads the samples of previous JSON reports and `fastqc_data.txt` files and writes
+/// their metrics and module statuses as wide TSV matrix to `output`, or to standard
+/// output if `None`, and as HTML overview to `html` if given.
+pub(crate) fn process_cohort(
+    filenames: &[String],
+    setup: &Setup,
+    output: Option<&Path>,
+    html: Option<&Path>,
+) -> Result<(), FastqcError> {
+    let mut samples = Vec::new();
+    for filename in filenames {
+        if filename.ends_with(".json") {
+            samples.extend(report_files(filename)?.iter().map(Sample::from_json));
+        } else {
+            let text = std::fs::read_to_string(filename)
+                .map_err(|e| FastqcError::from(e).with_path(filename))?;
+            let sample = Sample::from_fastqc_data(&text)
+                .map_err(|message| FastqcError::parse(message).with_path(filename))?;
+            samples.push(sample);
+        }
+    }
+    let cohort = Cohort::new(samples);
+    match output {
+        Some(path) => File::create(path)?.write_all(cohort.to_tsv().as_bytes())?,
+        None => io::stdout().write_all(cohort.to_tsv().as_bytes())?,
+    }
+    if let Some(path) = html {
+        File::create(path)?.write_all(render_cohort(setup, &cohort)?.as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Renders the HTML overview of a cohort with a row of metrics and module statuses per
+/// sample and the number of samples passing, warning and failing each module.
+fn render_cohort(setup: &Setup, cohort: &Cohort) -> Result<String, FastqcError> {
+    let metrics = cohort
+        .metrics
+        .iter()
+        .map(|key| {
+            let metric = METRICS.iter().find(|metric| metric.key == *key).unwrap();
+            json!({"key": metric.key, "name": metric.name})
+        })
+        .collect::<Vec<_>>();
+    let modules = cohort
+        .modules
+        .iter()
+        .map(|module| {
+            let count = |status: &str| {
+                cohort
+                    .samples
+                    .iter()
+                    .filter(|sample| sample.status(module) == status)
+                    .count()
+            };
+            json!({"key": module, "pass": count("pass"), "warn": count("warn"), "fail": count("fail")})
+        })
+        .collect::<Vec<_>>();
+    let samples = cohort
+        .samples
+        .iter()
+        .map(|sample| {
+            json!({
+                "name": sample.name,
+                "status": sample.overall_status(),
+                "metrics": cohort.metrics.iter().map(|key| sample.metric(key)).collect::<Vec<_>>(),
+                "statuses": cohort.modules.iter().map(|module| sample.status(module)).collect::<Vec<_>>(),
+            })
+        })
+        .collect::<Vec<_>>();
+    let mut context = Context::new();
+    context.insert("metrics", &metrics);
+    context.insert("modules", &modules);
+    context.insert("samples", &samples);
+    let local: DateTime<Local> = Local::now();
+    context.insert("time", &local.format("%a %b %e %T %Y").to_string());
+    context.insert("version", &env!("CARGO_PKG_VERSION"));
+    setup.insert_branding(&mut context);
+    setup.render("cohort.html.tera", &context)
+}
+
+/// Screens the reads of every given file against the reference genomes and writes the
+/// percentages of reads matching each of them as TSV table, or as JSON if chosen, to
+/// `output`, or to standard output if `None`.
+pub(crate) fn process_screen(
+    filenames: &[String],
+    setup: &Setup,
+    output: Option<&Path>,
+) -> Result<(), FastqcError> {
+    let mut files = Vec::new();
+    let mut table = "file\treference\tscreened\tmatching_pct\tunique_pct\n".to_string();
+    for filename in filenames {
+        let stats = Stats::gather(filename, setup)?;
+        let results = Results::new(&stats, setup);
+        let file = display_name(filename);
+        for (name, counts) in &results.screen {
+            table.push_str(&format!(
+                "{}\t{}\t{}\t{:.2}\t{:.2}\n",
+                file,
+                name,
+                counts.screened,
+                screen_pct(counts.matches, counts),
+                screen_pct(counts.unique, counts)
+            ));
+        }
+        files.push(json!({"filename": file, "references": results.screen_json()}));
+    }
+    let report = match setup.options.format {
+        Format::Json => serde_json::to_string_pretty(&json!({
+            "version": env!("CARGO_PKG_VERSION"),
+            "files": files,
+        }))?,
+        _ => table,
+    };
+    match output {
+        Some(path) => File::create(path)?.write_all(report.as_bytes())?,
+        None => io::stdout().write_all(report.as_bytes())?,
+    }
+    Ok(())
+}
+
+/// Returns the results of the files of a JSON report written with `--format json`.
+fn report_files(filename: &str) -> Result<Vec<Value>, FastqcError> {
+    let report =
+        std::fs::read_to_string(filename).map_err(|e| FastqcError::from(e).with_path(filename))?;
+    let mut report: Value =
+        serde_json::from_str(&report).map_err(|e| FastqcError::from(e).with_path(filename))?;
+    match report["files"].take() {
+        Value::Array(files) => Ok(files),
+        _ => Err(format!("{} is not a JSON report.", filename).into()),
+    }
+}
+
+/// Outcome of the QC of the files of one report.
+#[derive(Debug, Clone)]
+pub(crate) struct Outcome {
+    /// The most severe module status of the files.
+    pub(crate) status: &'static str,
+    /// Number of metrics and module statuses of the files which got worse than in the
+    /// baseline.
+    pub(crate) regressions: usize,
+    /// Metrics of every file exported with `--metrics-out`.
+    pub(crate) metrics: Vec<crate::metrics::Sample>,
+}
+
+/// Returns the outcome of the QC of the given files of a report, logging the
+/// regressions from the baseline as warnings.
+fn outcome(setup: &Setup, files: &[(&str, &Results)]) -> Outcome {
+    for (file, results) in files {
+        let statuses = results.module_statuses();
+        let flagged = |status: &str| {
+            let modules = statuses
+                .iter()
+                .filter(|(_, s)| *s == status)
+                .map(|(module, _)| module)
+                .join(", ");
+            if modules.is_empty() {
+                "none".to_string()
+            } else {
+                modules
+            }
+        };
+        log::info!(
+            "{}: {}; warnings in {}; failures in {}.",
+            file,
+            results.status(),
+            flagged("warn"),
+            flagged("fail")
+        );
+    }
+    let mut regressions = 0;
+    if let Some(baseline) = &setup.baseline {
+        let long_reads = setup.options.binning == Binning::Adaptive;
+        for (file, results) in files {
+            let before = match baseline.files.as_slice() {
+                [before] => Some(before),
+                before => before.iter().find(|before| before["filename"] == *file),
+            };
+            let Some(before) = before else {
+                log::warn!("{}: Not in the baseline, so it was not compared.", file);
+                continue;
+            };
+            let after = results.to_json(file, long_reads);
+            let comparison = Comparison::new(before, &after, &baseline.tolerances);
+            for delta in comparison
+                .metrics
+                .iter()
+                .filter(|delta| delta.is_regression())
+            {
+                log::warn!(
+                    "{}: {} changed from {:.2} in the baseline to {:.2}, beyond the tolerance of {}.",
+                    file,
+                    delta.metric.name,
+                    delta.before,
+                    delta.after,
+                    delta.tolerance.unwrap_or_default()
+                );
+            }
+            for change in comparison
+                .statuses
+                .iter()
+                .filter(|change| change.is_regression())
+            {
+                log::warn!(
+                    "{}: {} changed from {} in the baseline to {}.",
+                    file,
+                    change.module,
+                    if change.before.is_empty() {
+                        "no status"
+                    } else {
+                        &change.before
+                    },
+                    change.after
+                );
+            }
+            regressions += comparison.regressions();
+        }
+    }
+    Outcome {
+        status: most_severe(files.iter().map(|(_, results)| results.status())),
+        regressions,
+        metrics: files
+            .iter()
+            .map(|(file, results)| results.metrics(file))
+            .collect(),
+    }
+}
+
+/// Runs all analyses on both files of paired-end reads and writes a combined HTML
+/// report to `output`, or to standard output if `None`, along with the file outputs
+/// of each read.
+pub(crate) fn process_paired<P: AsRef<Path> + AsRef<OsStr>>(
+    filenames: [P; 2],
+    setup: &Setup,
+    output: Option<&Path>,
+    outputs: [FileOutputs; 2],
+) -> Result<Outcome, FastqcError> {
+    let [stats1, stats2] =
+        [&filenames[0], &filenames[1]].map(|filename| Stats::gather(filename, setup));
+    let stats = [stats1?, stats2?];
+    let files = [&filenames[0], &filenames[1]].map(|filename| display_name(filename).to_string());
+    let inserts = gather_insert_sizes(&filenames, setup)?;
+    report_paired(setup, files, stats, inserts, output, outputs)
+}
+
+/// Estimates the insert sizes from the first pairs of both files of paired-end reads,
+/// which are read again together as the statistics of each file are gathered apart.
+fn gather_insert_sizes<P: AsRef<Path>>(
+    filenames: &[P; 2],
+    setup: &Setup,
+) -> Result<InsertSizeCounter, FastqcError> {
+    let mut inserts = InsertSizeCounter::default();
+    let [reader1, reader2] = [&filenames[0], &filenames[1]].map(|filename| {
+        let reader = crate::input::open(
+            filename,
+            setup.options.alignment_filter,
+            1,
+            Default::default(),
+        )
+        .map_err(|e| e.with_path(filename))?;
+        Ok::<_, FastqcError>(match setup.options.max_reads {
+            Some(max_reads) => reader.limit(max_reads),
+            None => reader,
+        })
+    });
+    let (mut reader1, mut reader2) = (reader1?, reader2?);
+    while !inserts.is_full() {
+        match (reader1.next(), reader2.next()) {
+            (Some(Ok(read1)), Some(Ok(read2))) => {
+                inserts.observe(&read1.seq(), &read2.seq(), &setup.adapters)
+            }
+            (Some(Err(_)), _) | (_, Some(Err(_))) => continue,
+            _ => break,
+        }
+    }
+    Ok(inserts)
+}
+
+/// Like [`process_paired`], but for a single file with interleaved paired-end reads.
+pub(crate) fn process_interleaved<P: AsRef<Path> + AsRef<OsStr>>(
+    filename: P,
+    setup: &Setup,
+    output: Option<&Path>,
+    outputs: [FileOutputs; 2],
+) -> Result<Outcome, FastqcError> {
+    let (stats, inserts) = Stats::gather_interleaved(&filename, setup)?;
+    let file = display_name(&filename);
+    let files = READS.map(|read| format!("{} ({})", file, read));
+    report_paired(setup, files, stats, inserts, output, outputs)
+}
+
+fn report_paired(
+    setup: &Setup,
+    files: [String; 2],
+    stats: [Stats; 2],
+    inserts: InsertSizeCounter,
+    output: Option<&Path>,
+    outputs: [FileOutputs; 2],
+) -> Result<Outcome, FastqcError> {
+    let results = stats.map(|stats| Results::new(&stats, setup).with_insert_sizes(&inserts));
+    write_report(
+        setup,
+        &[(&files[0], &results[0]), (&files[1], &results[1])],
+        output,
+        false,
+    )?;
+    for ((file, results), outputs) in files.iter().zip(&results).zip(outputs) {
+        write_outputs(setup, file, results, outputs)?;
+    }
+    Ok(outcome(
+        setup,
+        &[(&files[0], &results[0]), (&files[1], &results[1])],
+    ))
+}
+
+/// Module statuses from the least to the most severe.
+pub(crate) const STATUSES: [&str; 3] = ["pass", "warn", "fail"];
+
+/// Returns the most severe of the given module statuses, or pass if there are none.
+pub(crate) fn most_severe(statuses: impl IntoIterator<Item = &'static str>) -> &'static str {
+    statuses
+        .into_iter()
+        .max_by_key(|status| STATUSES.iter().position(|s| s == status))
+        .unwrap_or("pass")
+}
+
+/// Labels of the reads in a paired-end report.
+const READS: [&str; 2] = ["R1", "R2"];
+
+/// Writes the report for a single file or for both files of paired-end reads in the
+/// chosen format to `output`, or to standard output if `None`. JSON reports list the
+/// results of each file. If `aggregate`, the files are distinct samples compared in
+/// an aggregated HTML report.
+fn write_report(
+    setup: &Setup,
+    files: &[(&str, &Results)],
+    output: Option<&Path>,
+    aggregate: bool,
+) -> Result<(), FastqcError> {
+    let start = Instant::now();
+    let report = match setup.options.format {
+        Format::Html if aggregate => render_aggregate(setup, files)?,
+        Format::Json if aggregate => {
+            let mut report = json_report(setup, files);
+            if let Some(hopping) = files_index_hopping(files) {
+                report["index_hopping"] = index_hopping_json(&hopping);
+            }
+            serde_json::to_string_pretty(&report)?
+        }
+        Format::Html => render_report(setup, files)?,
+        Format::Markdown => render_markdown(setup, files)?,
+        Format::Json => serde_json::to_string_pretty(&json_report(setup, files))?,
+    };
+    match output {
+        Some(path) => File::create(path)?.write_all(report.as_bytes())?,
+        None => io::stdout().write_all(report.as_bytes())?,
+    }
+    log::info!(
+        "Wrote the report of {} to {} in {:.2}s.",
+        files.iter().map(|(file, _)| file).join(", "),
+        output.map_or("standard output".into(), |path| path.display().to_string()),
+        start.elapsed().as_secs_f64()
+    );
+    Ok(())
+}
+
+/// Returns the JSON report listing the results of each of the given files.
+pub(crate) fn json_report(setup: &Setup, files: &[(&str, &Results)]) -> Value {
+    let long_reads = setup.options.binning == Binning::Adaptive;
+    json!({
+        "schema_version": SCHEMA_VERSION,
+        "version": env!("CARGO_PKG_VERSION"),
+        "k": setup.options.k,
+        "files": files
+            .iter()
+            .map(|(file, results)| results.to_json(file, long_reads))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Renders the HTML report for the given files. If paired, every plot shows the reads
+/// as separate series.
+pub(crate) fn render_report(
+    setup: &Setup,
+    files: &[(&str, &Results)],
+) -> Result<String, FastqcError> {
+    let long_reads = setup.options.binning == Binning::Adaptive;
+    let paired = files.len() > 1;
+    // Combines the data of all files, labeling every entry with its read if paired
+    let data = |values: fn(&Results) -> &Vec<Value>| {
+        let mut data = Vec::new();
+        for ((_, results), read) in files.iter().zip(READS) {
+            for value in values(results) {
+                let mut value = value.clone();
+                if paired {
+                    value["read"] = json!(read);
+                }
+                data.push(value);
+            }
+        }
+        json!(data)
+    };
+
+    // The quartiles of binned qualities mostly coincide with the bins, so the share of
+    // the bases in every bin is plotted instead of flat boxes
+    let binned = files
+        .iter()
+        .all(|(_, results)| results.quality_bins.is_some());
+    let mut qpp_specs: Value = if binned {
+        let mut specs: Value =
+            serde_json::from_str(include_str!("report/quality_bins_specs.json"))?;
+        specs["data"]["values"] = data(|r| &r.quality_bin_data);
+        specs
+    } else {
+        let mut specs: Value =
+            serde_json::from_str(include_str!("report/quality_per_pos_specs.json"))?;
+        specs["data"]["values"] = data(|r| &r.base_per_pos_data);
+        specs
+    };
+
+    let mut quality_heatmap_specs: Value =
+        serde_json::from_str(include_str!("report/quality_heatmap_specs.json"))?;
+    quality_heatmap_specs["data"]["values"] = data(|r| &r.quality_heatmap_data);
+
+    let mut rl_specs: Value = if long_reads {
+        serde_json::from_str(include_str!("report/read_lengths_long_specs.json"))?
+    } else {
+        serde_json::from_str(include_str!("report/read_lengths_specs.json"))?
+    };
+    rl_specs["data"]["values"] = data(|r| &r.read_length_data);
+
+    let mut dup_specs: Value =
+        serde_json::from_str(include_str!("report/duplication_levels_specs.json"))?;
+    dup_specs["data"]["values"] = data(|r| &r.duplication_plot_data);
+    dup_specs["title"] = json!(format!(
+        "Percent of seqs remaining if deduplicated {}",
+        files
+            .iter()
+            .map(|(_, results)| format!("{:.2}%", results.remaining))
+            .join(" / ")
+    ));
+
+    let mut adapter_specs: Value =
+        serde_json::from_str(include_str!("report/adapter_content_specs.json"))?;
+    adapter_specs["data"]["values"] = data(|r| &r.adapter_data);
+
+    let mut counter_specs: Value = serde_json::from_str(include_str!("report/counter_specs.json"))?;
+    counter_specs["data"]["values"] = data(|r| &r.kmer_count_data);
+
+    let mut kmer_specs: Value =
+        serde_json::from_str(include_str!("report/kmer_content_specs.json"))?;
+    kmer_specs["data"]["values"] = data(|r| &r.kmer_enrichment_data);
+
+    let mut tile_specs: Value =
+        serde_json::from_str(include_str!("report/per_tile_quality_specs.json"))?;
+    tile_specs["data"]["values"] = data(|r| &r.tile_data);
+
+    let mut lq_specs: Value =
+        serde_json::from_str(include_str!("report/length_quality_specs.json"))?;
+    lq_specs["data"]["values"] = data(|r| &r.length_quality_data);
+
+    let mut skew_specs: Value = serde_json::from_str(include_str!("report/base_skew_specs.json"))?;
+    skew_specs["data"]["values"] = data(|r| &r.base_content_data);
+
+    let mut base_gc_specs: Value = serde_json::from_str(include_str!("report/base_gc_specs.json"))?;
+    base_gc_specs["data"]["values"] = data(|r| &r.base_content_data);
+
+    let mut motif_specs: Value =
+        serde_json::from_str(include_str!("report/motif_bias_specs.json"))?;
+    motif_specs["data"]["values"] = data(|r| &r.motif_data);
+
+    let mut adapter_start_specs: Value =
+        serde_json::from_str(include_str!("report/adapter_starts_specs.json"))?;
+    adapter_start_specs["data"]["values"] = data(|r| &r.adapter_start_data);
+
+    let mut poly_tail_specs: Value =
+        serde_json::from_str(include_str!("report/poly_tails_specs.json"))?;
+    poly_tail_specs["data"]["values"] = data(|r| &r.poly_tail_data);
+
+    let mut low_complexity_specs: Value =
+        serde_json::from_str(include_str!("report/low_complexity_specs.json"))?;
+    low_complexity_specs["data"]["values"] = data(|r| &r.low_complexity_data);
+
+    let mut insert_specs: Value =
+        serde_json::from_str(include_str!("report/insert_size_specs.json"))?;
+    // Both reads of a pair have the same insert sizes
+    insert_specs["data"]["values"] = json!(files[0].1.insert_size_data);
+
+    let mut homopolymer_specs: Value =
+        serde_json::from_str(include_str!("report/homopolymers_specs.json"))?;
+    homopolymer_specs["data"]["values"] = data(|r| &r.homopolymer_data);
+
+    let mut umi_composition_specs: Value =
+        serde_json::from_str(include_str!("report/umi_composition_specs.json"))?;
+    umi_composition_specs["data"]["values"] = data(|r| &r.umi_composition_data);
+
+    let mut umi_top_specs: Value = serde_json::from_str(include_str!("report/umi_top_specs.json"))?;
+    umi_top_specs["data"]["values"] = data(|r| &r.umi_top_data);
+
+    let mut barcode_specs: Value =
+        serde_json::from_str(include_str!("report/barcodes_specs.json"))?;
+    barcode_specs["data"]["values"] = data(|r| &r.barcode_data);
+
+    let mut screen_specs: Value = serde_json::from_str(include_str!("report/screen_specs.json"))?;
+    screen_specs["data"]["values"] = data(|r| &r.screen_data);
+
+    let mut spectrum_specs: Value =
+        serde_json::from_str(include_str!("report/kmer_spectrum_specs.json"))?;
+    spectrum_specs["data"]["values"] = data(|r| &r.spectrum_data);
+
+    let mut saturation_specs: Value =
+        serde_json::from_str(include_str!("report/saturation_specs.json"))?;
+    saturation_specs["data"]["values"] = data(|r| &r.saturation_data);
+
+    let mut lane_statistics_specs: Value =
+        serde_json::from_str(include_str!("report/lane_statistics_specs.json"))?;
+    lane_statistics_specs["data"]["values"] = data(|r| &r.lane_statistics);
+
+    let mut lane_quality_specs: Value =
+        serde_json::from_str(include_str!("report/lane_quality_specs.json"))?;
+    lane_quality_specs["data"]["values"] = data(|r| &r.lane_quality_data);
+
+    let mut ee_specs: Value =
+        serde_json::from_str(include_str!("report/expected_errors_specs.json"))?;
+    ee_specs["data"]["values"] = data(|r| &r.expected_error_data);
+
+    let mut survival_specs: Value =
+        serde_json::from_str(include_str!("report/filter_survival_specs.json"))?;
+    survival_specs["data"]["values"] = data(|r| &r.survival_data);
+
+    let mut trim_specs: Value =
+        serde_json::from_str(include_str!("report/trim_simulation_specs.json"))?;
+    trim_specs["data"]["values"] = data(|r| &r.trim_simulation_data);
+
+    let mut trimmed_length_specs: Value =
+        serde_json::from_str(include_str!("report/trimmed_lengths_specs.json"))?;
+    trimmed_length_specs["data"]["values"] = data(|r| &r.trimmed_length_data);
+
+    let mut run_time_specs: Value =
+        serde_json::from_str(include_str!("report/run_time_specs.json"))?;
+    run_time_specs["data"]["values"] = data(|r| &r.run_time_data);
+
+    if long_reads {
+        adapter_specs["encoding"]["x"]["scale"] = json!({"type": "symlog"});
+        adapter_start_specs["encoding"]["x"]["scale"] = json!({"type": "symlog"});
+        kmer_specs["encoding"]["x"]["scale"] = json!({"type": "symlog"});
+    }
+
+    // Large plots slow down browsers, so their data is thinned out after all statistics
+    // were computed from the full data
+    if let Some(max_points) = setup.options.max_plot_points {
+        for (specs, field) in [
+            (&mut qpp_specs, "pos"),
+            (&mut quality_heatmap_specs, "pos"),
+            (&mut tile_specs, "pos"),
+            (&mut adapter_specs, "pos"),
+            (&mut adapter_start_specs, "pos"),
+            (&mut kmer_specs, "pos"),
+            (&mut skew_specs, "pos"),
+            (&mut base_gc_specs, "pos"),
+            (&mut umi_composition_specs, "pos"),
+            (&mut lane_quality_specs, "pos"),
+            (&mut rl_specs, "length"),
+            (&mut lq_specs, "length"),
+            (&mut trimmed_length_specs, "length"),
+            (&mut spectrum_specs, "coverage"),
+            (&mut run_time_specs, "hours"),
+        ] {
+            thin_points(specs, field, max_points);
+        }
+    }
+
+    if paired {
+        encode_reads(&mut adapter_specs, "strokeDash");
+        encode_reads(&mut dup_specs, "strokeDash");
+        encode_reads(&mut saturation_specs, "strokeDash");
+        encode_reads(&mut lane_statistics_specs, "color");
+        encode_reads(&mut lane_quality_specs, "strokeDash");
+        encode_reads(&mut kmer_specs, "strokeDash");
+        encode_reads(&mut counter_specs, "color");
+        encode_reads(&mut ee_specs, "strokeDash");
+        encode_reads(&mut skew_specs, "strokeDash");
+        encode_reads(&mut base_gc_specs, "strokeDash");
+        encode_reads(&mut homopolymer_specs, "strokeDash");
+        encode_reads(&mut poly_tail_specs, "strokeDash");
+        encode_reads(&mut low_complexity_specs, "strokeDash");
+        encode_reads(&mut adapter_start_specs, "strokeDash");
+        encode_reads(&mut umi_composition_specs, "strokeDash");
+        encode_reads(&mut trimmed_length_specs, "strokeDash");
+        facet_reads(&mut umi_top_specs);
+        facet_reads(&mut barcode_specs);
+        facet_reads(&mut screen_specs);
+        facet_reads(&mut survival_specs);
+        facet_reads(&mut trim_specs);
+        facet_reads(&mut spectrum_specs);
+        facet_reads(&mut qpp_specs);
+        facet_reads(&mut quality_heatmap_specs);
+        facet_reads(&mut rl_specs);
+        facet_reads(&mut tile_specs);
+        facet_reads(&mut lq_specs);
+        facet_reads(&mut motif_specs);
+        facet_reads(&mut run_time_specs);
+    }
+
+    let mut plots = json!({
+        "adapter content": {"short": "adapter", "specs": adapter_specs.to_string()},
+        "adapter start positions": {"short": "astart", "specs": adapter_start_specs.to_string()},
+        "base sequence quality": {"short": "base", "specs": qpp_specs.to_string()},
+        "base sequence skew": {"short": "skew", "specs": skew_specs.to_string()},
+        "base GC content": {"short": "basegc", "specs": base_gc_specs.to_string()},
+        "read lengths": {"short": "rlen", "specs": rl_specs.to_string()},
+        "k-mer quantities": {"short": "count", "specs": counter_specs.to_string()},
+        "k-mer content": {"short": "kmer", "specs": kmer_specs.to_string()},
+        "sequence duplication levels": {"short": "dup", "specs": dup_specs.to_string()},
+        "library saturation": {"short": "saturation", "specs": saturation_specs.to_string()},
+        "poly-G and poly-A tails": {"short": "tail", "specs": poly_tail_specs.to_string()},
+        "read complexity": {"short": "dust", "specs": low_complexity_specs.to_string()},
+    });
+
+    if files
+        .iter()
+        .any(|(_, results)| !results.quality_heatmap_data.is_empty())
+    {
+        plots["quality score heatmap"] =
+            json!({"short": "qheat", "specs": quality_heatmap_specs.to_string()});
+    }
+
+    if files.iter().any(|(_, results)| results.has_tiles) {
+        plots["per tile sequence quality"] =
+            json!({"short": "tile", "specs": tile_specs.to_string()});
+    }
+
+    if long_reads {
+        plots["read length vs quality"] = json!({"short": "lq", "specs": lq_specs.to_string()});
+    }
+
+    if files.iter().any(|(_, results)| results.has_motifs) {
+        plots["motif bias"] = json!({"short": "motif", "specs": motif_specs.to_string()});
+    }
+
+    if files[0].1.insert_sizes.is_some() {
+        plots["insert sizes"] = json!({"short": "insert", "specs": insert_specs.to_string()});
+    }
+
+    if files.iter().any(|(_, results)| results.umis.is_some()) {
+        plots["UMI base composition"] =
+            json!({"short": "umi", "specs": umi_composition_specs.to_string()});
+        plots["most frequent UMIs"] =
+            json!({"short": "umitop", "specs": umi_top_specs.to_string()});
+    }
+
+    if !setup.references.is_empty() {
+        plots["reference screen"] = json!({"short": "screen", "specs": screen_specs.to_string()});
+    }
+
+    if files.iter().any(|(_, results)| results.has_lanes) {
+        plots["per lane yield and quality"] =
+            json!({"short": "lanes", "specs": lane_statistics_specs.to_string()});
+        plots["per lane quality per position"] =
+            json!({"short": "lanequal", "specs": lane_quality_specs.to_string()});
+    }
+
+    if files.iter().any(|(_, results)| results.spectrum.is_some()) {
+        plots["k-mer spectrum"] = json!({"short": "spectrum", "specs": spectrum_specs.to_string()});
+    }
+
+    if files.iter().any(|(_, results)| results.barcodes.is_some()) {
+        plots["index sequences"] = json!({"short": "index", "specs": barcode_specs.to_string()});
+    }
+
+    if files.iter().any(|(_, results)| results.has_homopolymers) {
+        plots["homopolymer run lengths"] =
+            json!({"short": "homopolymer", "specs": homopolymer_specs.to_string()});
+    }
+
+    if files.iter().any(|(_, results)| results.has_expected_errors) {
+        plots["expected errors per read"] = json!({"short": "ee", "specs": ee_specs.to_string()});
+    }
+
+    plots["reads passing filters"] =
+        json!({"short": "survival", "specs": survival_specs.to_string()});
+
+    if files.iter().any(|(_, results)| results.has_trim_simulation) {
+        plots["quality trimming simulation"] =
+            json!({"short": "trim", "specs": trim_specs.to_string()});
+        plots["read lengths after trimming"] =
+            json!({"short": "trimlen", "specs": trimmed_length_specs.to_string()});
+    }
+
+    if files.iter().any(|(_, results)| results.has_run_time) {
+        plots["yield and quality over time"] =
+            json!({"short": "time", "specs": run_time_specs.to_string()});
+    }
+
+    // Values of paired reads are shown next to each other
+    let value = |value: fn(&str, &Results) -> Value| match files {
+        [(file, results)] => value(file, results),
+        _ => json!(files
+            .iter()
+            .map(|(file, results)| match value(file, results) {
+                Value::String(value) => value,
+                value => value.to_string(),
+            })
+            .join(" / ")),
+    };
+    let mut meta = json!({
+        "file name": {"name": "file name", "value": value(|file, _| json!(file))},
+        "k": {"name": "k", "value": setup.options.k},
+        "total sequences": {"name": "total sequences", "value": value(|_, r| json!(r.reads))},
+        "total bases": {"name": "total bases", "value": value(|_, r| json!(format_bases(r.total_length)))},
+        "sequence length": {"name": "sequence length", "value": value(|_, r| json!(r.sequence_length))},
+        "gc content": {"name": "%GC", "value": value(|_, r| json!(format!("{:.2}", r.gc_content)))},
+        "q20": {"name": "%Q20 bases", "value": value(|_, r| json!(format!("{:.2}", r.q20)))},
+        "q30": {"name": "%Q30 bases", "value": value(|_, r| json!(format!("{:.2}", r.q30)))},
+        "q20 reads": {"name": "%Q20 reads", "value": value(|_, r| json!(format!("{:.2}", r.q20_reads)))},
+        "q30 reads": {"name": "%Q30 reads", "value": value(|_, r| json!(format!("{:.2}", r.q30_reads)))},
+        "duplicate ids": {"name": "duplicate read IDs", "value": value(|_, r| json!(r.duplicate_ids_text()))},
+        "filtered": {"name": "% filtered (Casava)", "value": value(|_, r| json!(format!("{:.2}", r.filtered_pct)))},
+        "encoding": {"name": "encoding", "value": value(|_, r| json!(r.encoding.name))},
+        "platform": {"name": "platform", "value": value(|_, r| json!(r.platform_text()))},
+        "min read length": {"name": "min read length", "value": value(|_, r| json!(r.min_length))},
+        "mean read length": {"name": "mean read length", "value": value(|_, r| json!(format!("{:.2}", r.mean_length)))},
+        "max read length": {"name": "max read length", "value": value(|_, r| json!(r.max_length))},
+        "trimming": {"name": "suggested trimming", "value": value(|_, r| json!(r.trimming.to_string()))},
+        "n50": {"name": "N50", "value": value(|_, r| json!(r.n50))},
+        "n90": {"name": "N90", "value": value(|_, r| json!(r.n90))},
+        "yield": {"name": "yield (Gb)", "value": value(|_, r| json!(format!("{:.3}", r.total_length as f64 / 1e9)))},
+        "q30 yield": {"name": "Q30 yield (Gb)", "value": value(|_, r| json!(format!("{:.3}", r.q30_bases as f64 / 1e9)))},
+        "deduplicated": {"name": "% remaining if deduplicated", "value": value(|_, r| json!(format!("{:.2}", r.remaining)))},
+        "library size": {"name": "estimated library size", "value": value(|_, r| json!(r.library_size_text()))},
+        "poly g": {"name": "% poly-G tails", "value": value(|_, r| json!(format!("{:.2}", r.poly_tail_percentages[0])))},
+        "poly a": {"name": "% poly-A tails", "value": value(|_, r| json!(format!("{:.2}", r.poly_tail_percentages[1])))},
+        "low complexity": {"name": "% low complexity reads", "value": value(|_, r| json!(format!("{:.2}", r.low_complexity_pct)))},
+        "passing filters": {"name": "% reads passing filters", "value": value(|_, r| json!(format!("{:.2}", r.survival.all().percentage)))},
+        "usable bases": {"name": "usable bases (%)", "value": value(|_, r| json!(format!("{} ({:.2})", format_bases(r.survival.bases), r.survival.bases_percentage)))},
+    });
+    // Modules may be left out of some files for their platform
+    let enabled = |module| {
+        files
+            .iter()
+            .any(|(_, results)| results.modules.contains(module))
+    };
+    if !enabled(Module::DuplicationLevels) {
+        meta.as_object_mut().unwrap().remove("deduplicated");
+        meta.as_object_mut().unwrap().remove("library size");
+    }
+    if !files.iter().any(|(_, results)| results.has_casava) {
+        meta.as_object_mut().unwrap().remove("filtered");
+    }
+    if !files.iter().any(|(_, results)| results.has_qualities) {
+        for key in [
+            "q20",
+            "q30",
+            "q20 reads",
+            "q30 reads",
+            "q30 yield",
+            "encoding",
+        ] {
+            meta.as_object_mut().unwrap().remove(key);
+        }
+    }
+    if files
+        .iter()
+        .any(|(_, results)| !results.run_info.is_empty())
+    {
+        meta["instrument"] = json!({
+            "name": "instrument",
+            "value": value(|_, r| json!(join_or_dash(&r.run_info.instruments))),
+        });
+        meta["run"] =
+            json!({"name": "run", "value": value(|_, r| json!(join_or_dash(&r.run_info.runs)))});
+        meta["flowcell"] = json!({
+            "name": "flowcell",
+            "value": value(|_, r| json!(join_or_dash(&r.run_info.flowcells))),
+        });
+        meta["lanes"] =
+            json!({"name": "lanes", "value": value(|_, r| json!(join_or_dash(&r.run_info.lanes)))});
+    }
+    if files
+        .iter()
+        .any(|(_, results)| results.run_info.channels > 0)
+    {
+        meta["channels"] =
+            json!({"name": "channels", "value": value(|_, r| json!(r.run_info.channels))});
+    }
+    if files.iter().any(|(_, results)| results.umis.is_some()) {
+        meta["umi reads"] = json!({
+            "name": "% reads with UMI",
+            "value": value(|_, r| json!(format!("{:.2}", r.umis.as_ref().map_or(0.0, |umis| umis.percentage)))),
+        });
+        meta["umi distinct"] = json!({
+            "name": "distinct UMIs (% of random)",
+            "value": value(|_, r| json!(r.umis.as_ref().map_or_else(
+                || "-".to_string(),
+                |umis| format!("{} ({:.1})", umis.distinct, umis.diversity)
+            ))),
+        });
+    }
+    if files.iter().any(|(_, results)| results.barcodes.is_some()) {
+        meta["unexpected index"] = json!({
+            "name": "% unexpected index",
+            "value": value(|_, r| json!(r.barcodes.as_ref().map_or_else(
+                || "-".to_string(),
+                |barcodes| format!("{:.2}", barcodes.unexpected_percentage)
+            ))),
+        });
+    }
+    if files
+        .iter()
+        .any(|(_, results)| results.optical_duplicates.is_some())
+    {
+        meta["optical duplicates"] = json!({
+            "name": "% optical duplicates",
+            "value": value(|_, r| json!(r.optical_duplicates.as_ref().map_or_else(
+                || "-".to_string(),
+                |optical| format!("{:.2}", optical.optical)
+            ))),
+        });
+        meta["pcr duplicates"] = json!({
+            "name": "% PCR duplicates",
+            "value": value(|_, r| json!(r.optical_duplicates.as_ref().map_or_else(
+                || "-".to_string(),
+                |optical| format!("{:.2}", optical.pcr)
+            ))),
+        });
+    }
+    if files.iter().any(|(_, results)| results.spectrum.is_some()) {
+        meta["genome size"] = json!({
+            "name": "estimated genome size",
+            "value": value(|_, r| json!(r.genome_model().map_or_else(
+                || "-".to_string(),
+                |model| format_bases(model.genome_size.round() as usize)
+            ))),
+        });
+        meta["heterozygosity"] = json!({
+            "name": "% heterozygosity",
+            "value": value(|_, r| json!(r.genome_model().map_or_else(
+                || "-".to_string(),
+                |model| format!("{:.2}", model.heterozygosity)
+            ))),
+        });
+        meta["kmer error rate"] = json!({
+            "name": "% error rate (k-mers)",
+            "value": value(|_, r| json!(r.genome_model().map_or_else(
+                || "-".to_string(),
+                |model| format!("{:.2}", model.error_rate)
+            ))),
+        });
+        meta["singleton error rate"] = json!({
+            "name": "% error rate (singleton k-mers)",
+            "value": value(|_, r| json!(r.spectrum.as_ref().and_then(|spectrum| spectrum.singleton_error_rate).map_or_else(
+                || "-".to_string(),
+                |rate| format!("{:.2}", rate)
+            ))),
+        });
+        if files.iter().any(|(_, results)| results.has_expected_errors) {
+            meta["quality error rate"] = json!({
+                "name": "% error rate (qualities)",
+                "value": value(|_, r| json!(format!("{:.2}", r.quality_error_rate))),
+            });
+        }
+    }
+    for (i, reference) in setup.references.iter().enumerate() {
+        let percentages = files
+            .iter()
+            .map(|(_, results)| match results.screen.get(i) {
+                Some((_, counts)) => format!("{:.2}", screen_pct(counts.matches, counts)),
+                None => "-".to_string(),
+            })
+            .join(" / ");
+        meta[format!("screen {}", reference.name)] = json!({
+            "name": format!("% {}", reference.name),
+            "value": percentages,
+        });
+    }
+    if !enabled(Module::PolyTails) {
+        meta.as_object_mut().unwrap().remove("poly g");
+        meta.as_object_mut().unwrap().remove("poly a");
+    }
+    if !enabled(Module::LowComplexity) {
+        meta.as_object_mut().unwrap().remove("low complexity");
+    }
+    if let Some(sizes) = &files[0].1.insert_sizes {
+        let median = sizes
+            .median
+            .map_or_else(|| "-".to_string(), |median| median.to_string());
+        meta["pairs median insert"] = json!({"name": "median insert size", "value": median});
+        meta["pairs dimers"] =
+            json!({"name": "% adapter dimers", "value": format!("{:.2}", sizes.dimers)});
+        meta["pairs non-overlapping"] = json!({
+            "name": "% non-overlapping pairs",
+            "value": format!("{:.2}", sizes.non_overlapping),
+        });
+    }
+    if let Some(plots) = plots.as_object_mut() {
+        plots.retain(|_, plot| {
+            let module = match plot["short"].as_str() {
+                Some("adapter" | "astart") => Module::AdapterContent,
+                Some("base" | "qheat") => Module::PerBaseQuality,
+                Some("skew" | "basegc") => Module::PerBaseContent,
+                Some("rlen") => Module::ReadLengthDistribution,
+                Some("count" | "kmer") => Module::KmerContent,
+                Some("dup" | "saturation") => Module::DuplicationLevels,
+                Some("tile") => Module::PerTileQuality,
+                Some("tail") => Module::PolyTails,
+                Some("dust") => Module::LowComplexity,
+                _ => return true,
+            };
+            enabled(module)
+        });
+        setup.patch_plots(plots.values_mut())?;
+    }
+    if files
+        .iter()
+        .any(|(_, results)| results.sampled_from.is_some())
+    {
+        meta["sampled from"] =
+            json!({"name": "sampled from", "value": value(|_, r| json!(r.sampled_from))});
+        meta["sample seed"] = json!({"name": "sample seed", "value": value(|_, r| json!(r.seed))});
+    }
+    if files
+        .iter()
+        .any(|(_, results)| results.quality_bins.is_some())
+    {
+        meta["quality bins"] =
+            json!({"name": "quality bins", "value": value(|_, r| json!(r.quality_bins_text()))});
+    }
+    if files.iter().any(|(_, results)| results.coverage.is_some()) {
+        meta["coverage"] = json!({
+            "name": "estimated coverage",
+            "value": value(|_, r| json!(r.coverage_text())),
+        });
+    }
+    let read_counts = files
+        .iter()
+        .map(|(_, results)| results.reads)
+        .collect::<Vec<_>>();
+
+    let mut context = Context::new();
+    context.insert("plots", &plots);
+    context.insert("meta", &meta);
+    let local: DateTime<Local> = Local::now();
+    context.insert("time", &local.format("%a %b %e %T %Y").to_string());
+    context.insert("version", &env!("CARGO_PKG_VERSION"));
+    context.insert(
+        "invalid_reads",
+        &files
+            .iter()
+            .filter(|(_, results)| results.invalid_reads.count() > 0)
+            .map(|(file, results)| {
+                let mut invalid = results.invalid_reads_json();
+                invalid["name"] = json!(file);
+                invalid
+            })
+            .collect::<Vec<_>>(),
+    );
+    context.insert("paired", &paired);
+    context.insert("read_counts", &read_counts);
+    context.insert(
+        "read_count_mismatch",
+        &read_counts.iter().any(|&reads| reads != read_counts[0]),
+    );
+    context.insert(
+        "miscalibrated",
+        &files
+            .iter()
+            .filter_map(|(file, results)| {
+                let (kmers, qualities) = results.miscalibration()?;
+                Some(json!({"name": file, "kmers": kmers, "qualities": qualities}))
+            })
+            .collect::<Vec<_>>(),
+    );
+    context.insert(
+        "read_name_warnings",
+        &files
+            .iter()
+            .filter_map(|(file, results)| {
+                let warnings = results.read_names.warnings();
+                (!warnings.is_empty()).then(|| json!({"name": file, "warnings": warnings}))
+            })
+            .collect::<Vec<_>>(),
+    );
+    context.insert("overrepresented", &data(|r| &r.overrepresented_data));
+    context.insert("contaminant_hits", &data(|r| &r.contaminant_data));
+    context.insert("modules", &setup.options.modules.keys());
+    // Custom modules are the same for all files, with their rows labeled by read if paired
+    let mut custom = files
+        .first()
+        .map_or_else(Vec::new, |(_, results)| results.custom_json());
+    for (i, module) in custom.iter_mut().enumerate() {
+        module["short"] = json!(format!("custom{}", i));
+        let mut rows = Vec::new();
+        for ((_, results), read) in files.iter().zip(READS) {
+            for row in results.custom_json()[i]["data"]
+                .as_array()
+                .into_iter()
+                .flatten()
+            {
+                let mut row = row.clone();
+                if paired {
+                    row["read"] = json!(read);
+                }
+                rows.push(row);
             }
-            acc += count;
-            lo = Some(hi);
         }
+        module["data"] = json!(rows);
     }
-    // compute lower, upper fences
-    // TODO(lhepler): the UI reports these as min/max, which is incorrect.
-    // If that's what we want, we can return those values.
-    let iqr = ret[3] - ret[1];
-    ret[0] = ret[1] - 1.5 * iqr;
-    ret[4] = ret[3] + 1.5 * iqr;
-    ret
+    context.insert("custom_modules", &custom);
+    context.insert(
+        "files",
+        &files
+            .iter()
+            .map(|(file, results)| results.to_json(file, long_reads))
+            .collect::<Vec<_>>(),
+    );
+    setup.insert_branding(&mut context);
+    let mut sections = Vec::new();
+    for (i, name) in setup.sections.iter().enumerate() {
+        let html = setup
+            .render(name, &context)
+            .map_err(|e| e.with_path(name))?;
+        let title = name.trim_end_matches(".html.tera").replace(['_', '-'], " ");
+        sections.push(json!({"name": title, "short": format!("section{}", i), "html": html}));
+    }
+    context.insert("sections", &sections);
+    setup.render("report.html.tera", &context)
 }
 
-pub(crate) fn process<P: AsRef<Path> + AsRef<OsStr>>(
-    filename: P,
-    k: u8,
-    summary: Option<P>,
-) -> Result<(), Box<dyn Error>> {
-    let mut base_quality_count = HashMap::default();
+/// Cross-tabulates the dual indices of the given files, if at least two of them are
+/// samples with dual indices.
+fn files_index_hopping(files: &[(&str, &Results)]) -> Option<IndexHopping> {
+    let barcodes = files
+        .iter()
+        .filter_map(|(file, results)| Some((*file, results.barcodes.as_ref()?)))
+        .collect::<Vec<_>>();
+    index_hopping(&barcodes)
+}
+
+fn index_hopping_json(hopping: &IndexHopping) -> Value {
+    json!({
+        "samples": hopping
+            .samples
+            .iter()
+            .map(|(file, i7, i5)| json!({"file": file, "i7": i7, "i5": i5}))
+            .collect::<Vec<_>>(),
+        "rate": hopping.rate,
+        "combinations": hopping
+            .combinations
+            .iter()
+            .map(|combination| {
+                json!({
+                    "i7": combination.i7,
+                    "i5": combination.i5,
+                    "reads": combination.reads,
+                    "expected": combination.expected,
+                    "rate": combination.rate,
+                    "hopping": combination.hopping,
+                })
+            })
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Renders the aggregated HTML report with the basic statistics and module statuses of
+/// every file and plots overlaying the mean quality, GC content and read lengths, and
+/// the combinations of the indices of dual-indexed samples.
+fn render_aggregate(setup: &Setup, files: &[(&str, &Results)]) -> Result<String, FastqcError> {
+    let long_reads = setup.options.binning == Binning::Adaptive;
+    let samples = files
+        .iter()
+        .map(|(file, results)| {
+            (
+                *file,
+                [
+                    results.base_per_pos_data.as_slice(),
+                    &results.gc_data,
+                    &results.read_length_data,
+                ],
+            )
+        })
+        .collect::<Vec<_>>();
+    let mut plots = overlay_plots(&samples, long_reads, setup.options.max_plot_points)?;
+    let hopping = files_index_hopping(files);
+    if let Some(hopping) = &hopping {
+        let mut specs: Value =
+            serde_json::from_str(include_str!("report/index_hopping_specs.json"))?;
+        specs["data"]["values"] = index_hopping_json(hopping)["combinations"].take();
+        plots.push(json!({"short": "hopping", "specs": specs.to_string()}));
+    }
+    setup.patch_plots(&mut plots)?;
+    // Only some files may have tiles, whose modules are a subset of the others
+    let modules = files
+        .iter()
+        .map(|(_, results)| results.module_statuses())
+        .max_by_key(Vec::len)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(module, _)| module)
+        .collect::<Vec<_>>();
+    let samples = files
+        .iter()
+        .map(|(file, results)| {
+            let statuses = results.module_statuses();
+            json!({
+                "name": file,
+                "invalid_reads": results.invalid_reads.count(),
+                "reads": results.reads,
+                "sequence_length": results.sequence_length,
+                "gc_content": results.gc_content,
+                "statuses": modules
+                    .iter()
+                    .map(|module| {
+                        statuses
+                            .iter()
+                            .find(|(name, _)| name == module)
+                            .map_or("", |(_, status)| status)
+                    })
+                    .collect::<Vec<_>>(),
+            })
+        })
+        .collect::<Vec<_>>();
 
-    let mut reader = parse_fastx_file(&filename).expect("Invalid path/file");
-    let mut broken_read = false;
+    let mut context = Context::new();
+    context.insert("plots", &plots);
+    context.insert("samples", &samples);
+    context.insert("modules", &modules);
+    context.insert("index_hopping", &hopping.as_ref().map(index_hopping_json));
+    context.insert("hopping_rate", &crate::index_hopping::HOPPING_RATE);
+    let local: DateTime<Local> = Local::now();
+    context.insert("time", &local.format("%a %b %e %T %Y").to_string());
+    context.insert("version", &env!("CARGO_PKG_VERSION"));
+    setup.insert_branding(&mut context);
+    setup.render("aggregate.html.tera", &context)
+}
 
-    // Gather data from every record
-    while let Some(record) = reader.next() {
-        if let Ok(seqrec) = record {
-            if let Some(qualities) = seqrec.qual() {
-                for (pos, &q) in qualities.iter().enumerate() {
-                    let rec = base_quality_count
-                        .entry(pos)
-                        .or_insert_with(|| vec![0_usize; 94]);
-                    rec[q as usize - 33] += 1;
+/// Returns the plots overlaying the mean quality per position, the GC content and the
+/// read lengths of the given samples, each given with the data of these modules.
+fn overlay_plots(
+    samples: &[(&str, [&[Value]; 3])],
+    long_reads: bool,
+    max_points: Option<usize>,
+) -> Result<Vec<Value>, FastqcError> {
+    // Counts are shown as percentages, so that samples of different sizes are comparable
+    let overlay = |module: usize, x: &str, y: &str, relative: bool| {
+        let mut data = Vec::new();
+        for (sample, modules) in samples {
+            let values = modules[module];
+            let total = values
+                .iter()
+                .filter_map(|entry| entry[y].as_f64())
+                .sum::<f64>();
+            for entry in values {
+                let mut value = entry[y].as_f64().unwrap_or(0.0);
+                if relative && total > 0.0 {
+                    value *= 100.0 / total;
                 }
+                data.push(json!({"sample": sample, "x": entry[x], "y": value}));
             }
-        } else {
-            broken_read = true;
         }
+        json!(data)
+    };
+    let mut plots = Vec::new();
+    for (short, title, x_title, y_title, data) in [
+        (
+            "quality",
+            "Mean quality per position",
+            "Position in read (bp)",
+            "Mean phred score",
+            overlay(0, "pos", "average", false),
+        ),
+        (
+            "gc",
+            "GC distribution over all sequences",
+            "Mean GC content (%)",
+            "% of sequences",
+            overlay(1, "gc", "count", true),
+        ),
+        (
+            "lengths",
+            "Read lengths distribution",
+            "Sequence length (bp)",
+            "% of sequences",
+            overlay(2, "length", "count", true),
+        ),
+    ] {
+        let mut specs: Value = serde_json::from_str(include_str!("report/aggregate_specs.json"))?;
+        specs["title"] = json!(title);
+        specs["description"] = json!(title);
+        specs["encoding"]["x"]["title"] = json!(x_title);
+        specs["encoding"]["y"]["title"] = json!(y_title);
+        if long_reads && short == "lengths" {
+            specs["encoding"]["x"]["scale"] = json!({"type": "log"});
+        }
+        specs["data"]["values"] = data;
+        if let Some(max_points) = max_points {
+            thin_points(&mut specs, "x", max_points);
+        }
+        plots.push(json!({"short": short, "specs": specs.to_string()}));
     }
-   
-    // Data for base quality per position
-    let mut base_quality_warn = "pass";
-    let mut base_per_pos_data = Vec::new();
-    for (position, qualities) in base_quality_count {
-        let (sum, len) = qualities
-            .iter()
-            .enumerate()
-            .fold((0_usize, 0_usize), |(s, l), (q, c)| (s + q * c, l + c));
-        let avg = sum as f64 / len as f64;
-        let values = quartiles(&qualities);
-        if values.get(2).unwrap() <= &20_f32 {
-            base_quality_warn = "fail"
-        } else if values.get(2).unwrap() <= &25_f32 && base_quality_warn != "fail" {
-            base_quality_warn = "warn"
-        }
-        base_per_pos_data.push(json!({
-        "pos": position,
-        "average": avg,
-        "upper": values.get(4).unwrap(),
-        "lower": values.get(0).unwrap(),
-        "q1": values.get(1).unwrap(),
-        "q3": values.get(3).unwrap(),
-        "median":values.get(2).unwrap(),
-        }));
-    }
-
-    let mut qpp_specs: Value =
-        serde_json::from_str(include_str!("report/quality_per_pos_specs.json"))?;
-    qpp_specs["data"]["values"] = json!(base_per_pos_data);
-
-    let plots = json!({
-        "base sequence quality": {"short": "base", "specs": qpp_specs.to_string()},
-    });
+    Ok(plots)
+}
 
-    let file = Path::new(&filename).file_name().unwrap().to_str().unwrap();
-    let meta = json!({
-        "file name": {"name": "file name", "value": file},
-        "canonical": {"name": "canonical", "value": "True"},
+/// Renders the HTML report comparing two samples with a table of the changes of their
+/// metrics and module statuses with the regressions highlighted, and plots overlaying
+/// their mean quality, GC content and read lengths.
+fn render_comparison(
+    setup: &Setup,
+    samples: &[Value; 2],
+    comparison: &Comparison,
+) -> Result<String, FastqcError> {
+    let names = samples
+        .each_ref()
+        .map(|sample| sample["filename"].as_str().unwrap_or_default().to_string());
+    // Both samples need distinct names in the plots, e.g. for files of the same name
+    let labels = if names[0] == names[1] {
+        [0, 1].map(|i| format!("{} ({})", names[i], ["before", "after"][i]))
+    } else {
+        names.clone()
+    };
+    fn data<'a>(sample: &'a Value, module: &str) -> &'a [Value] {
+        sample["modules"][module]["data"]
+            .as_array()
+            .map_or(&[], Vec::as_slice)
+    }
+    let overlaid = [0, 1].map(|i| {
+        (
+            labels[i].as_str(),
+            [
+                data(&samples[i], "per_base_quality"),
+                data(&samples[i], "per_sequence_gc_content"),
+                data(&samples[i], "read_length_distribution"),
+            ],
+        )
     });
+    let long_reads = setup.options.binning == Binning::Adaptive;
+    let mut plots = overlay_plots(&overlaid, long_reads, setup.options.max_plot_points)?;
+    setup.patch_plots(&mut plots)?;
 
-    let mut templates = Tera::default();
-    templates.register_filter("embed_source", embed_source);
-    templates.add_raw_template("report.html.tera", include_str!("report/report.html.tera"))?;
     let mut context = Context::new();
+    context.insert("before", &names[0]);
+    context.insert("after", &names[1]);
+    context.insert("comparison", &comparison.to_json());
     context.insert("plots", &plots);
-    context.insert("meta", &meta);
     let local: DateTime<Local> = Local::now();
     context.insert("time", &local.format("%a %b %e %T %Y").to_string());
     context.insert("version", &env!("CARGO_PKG_VERSION"));
-    context.insert("invalid_reads", &broken_read);
-    let html = templates.render("report.html.tera", &context)?;
-    io::stdout().write_all(html.as_bytes())?;
+    setup.insert_branding(&mut context);
+    setup.render("compare.html.tera", &context)
+}
 
-    if let Some(path) = summary {
-        let output_path = Path::new(&path);
-        templates.add_raw_template(
-            "fastqc_summary.txt.tera",
-            include_str!("report/fastqc_summary.txt.tera"),
+/// Renders a Markdown report with the basic statistics, the module statuses and
+/// sparklines of the main plots of the given files.
+fn render_markdown(setup: &Setup, files: &[(&str, &Results)]) -> Result<String, FastqcError> {
+    let column = |data: &[Value], key: &str| {
+        data.iter()
+            .map(|entry| entry[key].as_f64().unwrap_or(0.0))
+            .collect::<Vec<_>>()
+    };
+    let files = files
+        .iter()
+        .map(|(file, results)| {
+            let sparklines = [
+                (
+                    Module::PerBaseQuality,
+                    "Mean quality per position",
+                    sparkline(&column(&results.base_per_pos_data, "average"), Some(41.0)),
+                ),
+                (
+                    Module::PerSequenceQuality,
+                    "Mean quality per sequence",
+                    sparkline(&column(&results.sequence_quality_data, "count"), None),
+                ),
+                (
+                    Module::PerSequenceGcContent,
+                    "GC content per sequence",
+                    sparkline(&column(&results.gc_data, "count"), None),
+                ),
+                (
+                    Module::PerBaseNContent,
+                    "N content per position",
+                    sparkline(&column(&results.n_content_data, "pct"), Some(100.0)),
+                ),
+                (
+                    Module::ReadLengthDistribution,
+                    "Read lengths",
+                    sparkline(&column(&results.read_length_data, "count"), None),
+                ),
+            ];
+            let mut statistics = vec![
+                json!(["Encoding", results.encoding.name]),
+                json!(["Platform", results.platform_text()]),
+                json!(["Total sequences", results.reads]),
+                json!(["Total bases", format_bases(results.total_length)]),
+                json!([
+                    "Yield (Gb)",
+                    format!("{:.3}", results.total_length as f64 / 1e9)
+                ]),
+                json!([
+                    "Q30 yield (Gb)",
+                    format!("{:.3}", results.q30_bases as f64 / 1e9)
+                ]),
+                json!(["Sequence length", results.sequence_length]),
+                json!(["Mean read length", format!("{:.2}", results.mean_length)]),
+                json!(["N50", results.n50]),
+                json!(["%GC", format!("{:.2}", results.gc_content)]),
+                json!(["%Q20 bases", format!("{:.2}", results.q20)]),
+                json!(["%Q30 bases", format!("{:.2}", results.q30)]),
+                json!(["Duplicate read IDs", results.duplicate_ids_text()]),
+                json!([
+                    "% reads passing filters",
+                    format!("{:.2}", results.survival.all().percentage)
+                ]),
+                json!([
+                    "Usable bases (%)",
+                    format!(
+                        "{} ({:.2})",
+                        format_bases(results.survival.bases),
+                        results.survival.bases_percentage
+                    )
+                ]),
+            ];
+            if !results.has_qualities {
+                let quality_rows = ["Encoding", "Q30 yield (Gb)", "%Q20 bases", "%Q30 bases"];
+                statistics.retain(|row| !quality_rows.contains(&row[0].as_str().unwrap_or_default()));
+            }
+            if results.quality_bins.is_some() {
+                statistics.push(json!(["Quality bins", results.quality_bins_text()]));
+            }
+            if results.coverage.is_some() {
+                statistics.push(json!(["Estimated coverage", results.coverage_text()]));
+            }
+            let run_info = &results.run_info;
+            if !run_info.is_empty() {
+                statistics.push(json!(["Instrument", join_or_dash(&run_info.instruments)]));
+                statistics.push(json!(["Run", join_or_dash(&run_info.runs)]));
+                statistics.push(json!(["Flowcell", join_or_dash(&run_info.flowcells)]));
+                statistics.push(json!(["Lanes", join_or_dash(&run_info.lanes)]));
+            }
+            if run_info.channels > 0 {
+                statistics.push(json!(["Channels", run_info.channels]));
+            }
+            if let Some(umis) = &results.umis {
+                statistics.push(json!([
+                    "% reads with UMI",
+                    format!("{:.2}", umis.percentage)
+                ]));
+                statistics.push(json!([
+                    "Distinct UMIs (% of random)",
+                    format!("{} ({:.1})", umis.distinct, umis.diversity)
+                ]));
+            }
+            for (name, counts) in &results.screen {
+                let pct = screen_pct(counts.matches, counts);
+                statistics.push(json!([format!("% {}", name), format!("{:.2}", pct)]));
+            }
+            if let Some(model) = results.genome_model() {
+                statistics.push(json!([
+                    "Estimated genome size",
+                    format_bases(model.genome_size.round() as usize)
+                ]));
+                statistics.push(json!([
+                    "% heterozygosity",
+                    format!("{:.2}", model.heterozygosity)
+                ]));
+                statistics.push(json!([
+                    "% error rate (k-mers)",
+                    format!("{:.2}", model.error_rate)
+                ]));
+            }
+            if let Some(spectrum) = &results.spectrum {
+                if let Some(rate) = spectrum.singleton_error_rate {
+                    statistics.push(json!([
+                        "% error rate (singleton k-mers)",
+                        format!("{:.2}", rate)
+                    ]));
+                }
+                if results.has_expected_errors {
+                    statistics.push(json!([
+                        "% error rate (qualities)",
+                        format!("{:.2}", results.quality_error_rate)
+                    ]));
+                }
+            }
+            if let Some(barcodes) = &results.barcodes {
+                statistics.push(json!(["Expected index", barcodes.expected]));
+                statistics.push(json!([
+                    "% unexpected index",
+                    format!("{:.2}", barcodes.unexpected_percentage)
+                ]));
+            }
+            if results.has_casava {
+                statistics.push(json!([
+                    "% filtered (Casava)",
+                    format!("{:.2}", results.filtered_pct)
+                ]));
+            }
+            if results.modules.contains(Module::DuplicationLevels) {
+                statistics.push(json!([
+                    "% remaining if deduplicated",
+                    format!("{:.2}", results.remaining)
+                ]));
+                statistics.push(json!(["Estimated library size", results.library_size_text()]));
+            }
+            json!({
+                "name": file.replace('|', "\\|"),
+                "invalid_reads": results.invalid_reads.count(),
+                "reads": results.reads,
+                "sampled_from": results.sampled_from,
+                "seed": setup.options.seed,
+                "miscalibrated": results.miscalibration().map(|(kmers, qualities)| [kmers, qualities]),
+                "read_name_warnings": results.read_names.warnings(),
+                "statistics": statistics,
+                "modules": results.module_statuses(),
+                "sparklines": sparklines
+                    .iter()
+                    .filter(|(module, _, _)| results.modules.contains(*module))
+                    .map(|(_, name, line)| json!([name, line]))
+                    .collect::<Vec<_>>(),
+                "lanes": results.lane_statistics,
+                "overrepresented": results.overrepresented_data,
+                "motifs": &results.motif_data[..results.motif_data.len().min(5)],
+            })
+        })
+        .collect::<Vec<_>>();
+    let mut context = Context::new();
+    context.insert("files", &files);
+    let local: DateTime<Local> = Local::now();
+    context.insert("time", &local.format("%a %b %e %T %Y").to_string());
+    context.insert("version", &env!("CARGO_PKG_VERSION"));
+    setup.insert_branding(&mut context);
+    setup.render("report.md.tera", &context)
+}
+
+/// Returns a line of block characters with heights from zero to `max`, or to the
+/// highest value if `None`, following the given values averaged into at most 60
+/// characters.
+fn sparkline(values: &[f64], max: Option<f64>) -> String {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let chunk = values.len().div_ceil(60).max(1);
+    let values = values
+        .chunks(chunk)
+        .map(|chunk| chunk.iter().sum::<f64>() / chunk.len() as f64)
+        .collect::<Vec<_>>();
+    let max = max.unwrap_or_else(|| values.iter().copied().fold(0.0, f64::max));
+    values
+        .iter()
+        .map(|&value| {
+            let level = if max > 0.0 {
+                value.clamp(0.0, max) / max * (BLOCKS.len() - 1) as f64
+            } else {
+                0.0
+            };
+            BLOCKS[level.round() as usize]
+        })
+        .collect()
+}
+
+/// Writes the summary and bundle of a single file if requested.
+fn write_outputs(
+    setup: &Setup,
+    file: &str,
+    results: &Results,
+    outputs: FileOutputs,
+) -> Result<(), FastqcError> {
+    if let Some(dir) = outputs.summary {
+        let txt = render_summary(setup, file, results)?;
+        File::create(dir.join("fastqc_data.txt"))?.write_all(txt.as_bytes())?;
+        File::create(dir.join("summary.txt"))?
+            .write_all(module_summary(file, results).as_bytes())?;
+    }
+    if let Some(path) = outputs.bundle {
+        write_bundle(setup, file, results, path)?;
+    }
+    if let Some(dir) = outputs.tables {
+        let long_reads = setup.options.binning == Binning::Adaptive;
+        for (name, columns, rows) in results.tables(long_reads) {
+            match setup.options.data_format {
+                DataFormat::Parquet => {
+                    let path = dir.join(format!("{}.parquet", name));
+                    crate::columnar::write_parquet(&path, name, columns, rows)?;
+                }
+                DataFormat::Tsv => {
+                    let mut tsv = columns.join("\t");
+                    for row in rows {
+                        let values = columns.iter().map(|&column| match &row[column] {
+                            Value::String(value) => value.clone(),
+                            Value::Null => "NA".to_string(),
+                            value => value.to_string(),
+                        });
+                        tsv.push('\n');
+                        tsv.push_str(&values.collect::<Vec<_>>().join("\t"));
+                    }
+                    tsv.push('\n');
+                    File::create(dir.join(format!("{}.tsv", name)))?.write_all(tsv.as_bytes())?;
+                }
+            }
+        }
+    }
+    if let Some(path) = outputs.database {
+        crate::db::append(
+            path,
+            &crate::db::Sample {
+                file,
+                total_reads: results.reads,
+                total_bases: results.total_length,
+                q30: results.q30,
+                gc_content: results.gc_content,
+                statuses: &results.module_statuses(),
+            },
         )?;
-        context.insert("filename", &file);
-        context.insert("base_quality_warn", &base_quality_warn);
-        let txt = templates.render("fastqc_summary.txt.tera", &context)?;
-        let mut file = File::create(output_path.join("fastqc_data.txt"))?;
-        file.write_all(txt.as_bytes())?;
     }
+    if let Some(dir) = outputs.plots {
+        for (name, chart) in results.charts() {
+            File::create(dir.join(format!("{}.svg", name)))?
+                .write_all(chart.render().as_bytes())?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes a zip archive laid out like the one of FastQC, with the HTML report,
+/// `fastqc_data.txt`, `summary.txt` and the plots as SVG files in `Images/` in a
+/// directory named after the archive.
+fn write_bundle(
+    setup: &Setup,
+    file: &str,
+    results: &Results,
+    path: &Path,
+) -> Result<(), FastqcError> {
+    let dir = path
+        .file_stem()
+        .and_then(OsStr::to_str)
+        .ok_or("Invalid bundle path")?;
+    let mut zip = ZipWriter::new(File::create(path)?);
+    let now = Local::now();
+    let time = zip::DateTime::from_date_and_time(
+        now.year() as u16,
+        now.month() as u8,
+        now.day() as u8,
+        now.hour() as u8,
+        now.minute() as u8,
+        now.second() as u8,
+    )
+    .unwrap_or_default();
+    let options = SimpleFileOptions::default().last_modified_time(time);
+    zip.add_directory(format!("{}/", dir), options)?;
+    for (name, content) in [
+        (
+            "fastqc_report.html",
+            render_report(setup, &[(file, results)])?,
+        ),
+        ("fastqc_data.txt", render_summary(setup, file, results)?),
+        ("summary.txt", module_summary(file, results)),
+    ] {
+        zip.start_file(format!("{}/{}", dir, name), options)?;
+        zip.write_all(content.as_bytes())?;
+    }
+    zip.add_directory(format!("{}/Images/", dir), options)?;
+    for (name, chart) in results.charts() {
+        zip.start_file(format!("{}/Images/{}.svg", dir, name), options)?;
+        zip.write_all(chart.render().as_bytes())?;
+    }
+    zip.finish()?;
     Ok(())
 }
 
+/// Returns the status of every module like the `summary.txt` of FastQC.
+fn module_summary(file: &str, results: &Results) -> String {
+    results
+        .module_statuses()
+        .iter()
+        .map(|(module, status)| format!("{}\t{}\t{}\n", status.to_uppercase(), module, file))
+        .collect()
+}
+
+/// Renders the results of a single file as `fastqc_data.txt`.
+fn render_summary(setup: &Setup, file: &str, results: &Results) -> Result<String, FastqcError> {
+    let mut context = Context::new();
+    context.insert("version", &env!("CARGO_PKG_VERSION"));
+    context.insert("overrepresented", &results.overrepresented_data);
+    context.insert("filename", &file);
+    context.insert("encoding", &results.encoding.name);
+    context.insert("reads", &results.reads);
+    context.insert("total_bases", &format_bases(results.total_length));
+    // Like FastQC, only reads left out of the statistics count as flagged
+    context.insert(
+        "flagged",
+        &(results.filtered_reads * results.filtered_excluded as usize),
+    );
+    context.insert("gc_content", &(results.gc_content.round() as usize));
+    for (key, value) in [
+        ("q20", results.q20),
+        ("q30", results.q30),
+        ("q20_reads", results.q20_reads),
+        ("q30_reads", results.q30_reads),
+    ] {
+        context.insert(key, &format!("{:.2}", value));
+    }
+    context.insert("base_quality_warn", &results.base_quality_warn);
+    context.insert("bpp_data", &results.base_per_pos_data);
+    context.insert("sequence_length", &results.sequence_length);
+    context.insert("mean_length", &format!("{:.2}", results.mean_length));
+    context.insert("n50", &results.n50);
+    context.insert("n90", &results.n90);
+    context.insert(
+        "yield",
+        &format!("{:.3}", results.total_length as f64 / 1e9),
+    );
+    context.insert(
+        "q30_yield",
+        &format!("{:.3}", results.q30_bases as f64 / 1e9),
+    );
+    context.insert("sequence_quality_warn", &results.sequence_quality_warn);
+    context.insert("sequence_quality_data", &results.sequence_quality_data);
+    context.insert("base_content_warn", &results.base_content_warn);
+    context.insert("base_content_data", &results.base_content_data);
+    context.insert("gc_warn", &results.gc_warn);
+    context.insert("gc_data", &results.gc_data);
+    context.insert("n_content_warn", &results.n_content_warn);
+    context.insert("n_content_data", &results.n_content_data);
+    context.insert("read_length_warn", &results.read_length_warn);
+    context.insert("read_length_data", &results.read_length_data);
+    context.insert("duplication_warn", &results.duplication_warn);
+    context.insert("total_deduplicated", &results.remaining);
+    context.insert("duplication_data", &results.duplication_data);
+    context.insert("overrepresented_warn", &results.overrepresented_warn);
+    context.insert("adapter_warn", &results.adapter_warn);
+    context.insert("adapter_names", &results.adapter_names);
+    context.insert("adapter_rows", &results.adapter_rows);
+    context.insert("kmer_warn", &results.kmer_warn);
+    context.insert("kmer_data", &results.kmer_data);
+    context.insert("poly_tail_warn", &results.poly_tail_warn);
+    context.insert("poly_tail_data", &results.poly_tail_data);
+    context.insert("low_complexity_warn", &results.low_complexity_warn);
+    context.insert("low_complexity_data", &results.low_complexity_data);
+    context.insert("has_tiles", &results.has_tiles);
+    context.insert("tile_warn", &results.tile_warn);
+    context.insert("tile_data", &results.tile_data);
+    context.insert("modules", &results.modules.keys());
+    context.insert("custom_modules", &results.custom_json());
+    setup.render("fastqc_summary.txt.tera", &context)
+}
+
+/// Returns the file name shown in reports for the given input.
+fn display_name<P: AsRef<Path>>(filename: &P) -> &str {
+    if crate::input::is_stdin(filename) {
+        "stdin"
+    } else {
+        crate::input::file_name(filename.as_ref()).unwrap()
+    }
+}
+
+/// Merges the patch into the value like a JSON merge patch of RFC 7386: objects are
+/// merged recursively, `null` removes a key and other values replace the value.
+fn merge_patch(value: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *value = patch.clone();
+        return;
+    };
+    if !value.is_object() {
+        *value = json!({});
+    }
+    let object = value.as_object_mut().unwrap();
+    for (key, patch) in patch {
+        if patch.is_null() {
+            object.remove(key);
+        } else {
+            merge_patch(object.entry(key.clone()).or_insert(Value::Null), patch);
+        }
+    }
+}
+
+/// Thins out the data of the plot to about `max_points` points if it has more, keeping
+/// the data of evenly spaced values of the given field, e.g. of every other position,
+/// including the first and last one. All series of the plot keep the same values.
+fn thin_points(specs: &mut Value, field: &str, max_points: usize) {
+    let Some(values) = specs["data"]["values"].as_array_mut() else {
+        return;
+    };
+    if values.len() <= max_points {
+        return;
+    }
+    let mut seen = HashSet::default();
+    let mut distinct = Vec::new();
+    for value in values.iter() {
+        let x = value[field].to_string();
+        if seen.insert(x.clone()) {
+            distinct.push(x);
+        }
+    }
+    let keep = (max_points * distinct.len() / values.len()).max(2);
+    if keep >= distinct.len() {
+        return;
+    }
+    let kept = (0..keep)
+        .map(|i| distinct[i * (distinct.len() - 1) / (keep - 1)].clone())
+        .collect::<HashSet<_>>();
+    values.retain(|value| kept.contains(&value[field].to_string()));
+}
+
+/// Distinguishes the reads of a paired-end report by the given encoding channel,
+/// e.g. the stroke dash of line plots or the color of bar charts.
+fn encode_reads(specs: &mut Value, channel: &str) {
+    let encoding = json!({"field": "read", "type": "nominal", "title": "Read"});
+    match specs.get_mut("hconcat").and_then(Value::as_array_mut) {
+        Some(views) => {
+            for view in views {
+                view["encoding"][channel] = encoding.clone();
+            }
+        }
+        None => specs["encoding"][channel] = encoding,
+    }
+}
+
+/// Shows the reads of a paired-end report in separate rows of plots that can not
+/// be overlaid, like box plots and heatmaps.
+fn facet_reads(specs: &mut Value) {
+    let mut spec = specs.take();
+    let mut faceted = json!({
+        "facet": {"row": {"field": "read", "type": "nominal", "title": "Read"}},
+    });
+    for key in ["$schema", "title", "description", "data"] {
+        if let Some(value) = spec.as_object_mut().and_then(|spec| spec.remove(key)) {
+            faceted[key] = value;
+        }
+    }
+    faceted["spec"] = spec;
+    *specs = faceted;
+}
+
+/// Returns the percentage of the given number of reads among those screened against a
+/// reference genome.
+fn screen_pct(reads: usize, counts: &ScreenCounts) -> f64 {
+    match counts.screened {
+        0 => 0.0,
+        screened => reads as f64 * 100.0 / screened as f64,
+    }
+}
+
+/// Joins the values of a field of the read headers, or returns `-` if there are none.
+fn join_or_dash<T: fmt::Display>(values: &[T]) -> String {
+    match values {
+        [] => "-".to_string(),
+        values => values.iter().join(", "),
+    }
+}
+
+/// Returns the length such that reads of at least this length contain the given
+/// fraction of all bases, e.g. the N50 for `0.5`.
+fn nx(read_lengths: &HashMap<usize, usize>, fraction: f64) -> usize {
+    let total = read_lengths
+        .iter()
+        .map(|(length, count)| length * count)
+        .sum::<usize>();
+    let mut bases = 0;
+    for (&length, &count) in read_lengths.iter().sorted().rev() {
+        bases += length * count;
+        if bases as f64 >= total as f64 * fraction {
+            return length;
+        }
+    }
+    0
+}
+
+/// Formats a number of bases with a unit like FastQC, e.g. `20.2 kbp`.
+pub(crate) fn format_bases(bases: usize) -> String {
+    let units = ["kbp", "Mbp", "Gbp", "Tbp"];
+    if bases < 1000 {
+        return format!("{} bp", bases);
+    }
+    let mut value = bases as f64 / 1000.0;
+    let mut unit = 0;
+    while value >= 1000.0 && unit < units.len() - 1 {
+        value /= 1000.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, units[unit])
+}
+
+#[cfg(not(target_arch = "wasm32"))]
 fn embed_source(
     value: &tera::Value,
     _: &std::collections::HashMap<String, tera::Value>,
 ) -> tera::Result<tera::Value> {
     let url = tera::try_get_value!("upper", "value", String, value);
-    let source = reqwest::get(&url).unwrap().text().unwrap();
-    Ok(tera::to_value(source).unwrap())
+    let source = reqwest::get(&url)
+        .and_then(|response| response.error_for_status()?.text())
+        .map_err(|e| tera::Error::msg(format!("Cannot download {}: {}", url, e)))?;
+    Ok(tera::Value::String(source))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn embed_source(
+    _: &tera::Value,
+    _: &std::collections::HashMap<String, tera::Value>,
+) -> tera::Result<tera::Value> {
+    Err(tera::Error::msg(
+        "Assets cannot be fetched in WebAssembly builds.",
+    ))
 }
 
 #[cfg(test)]
 mod test {
-    use super::quartiles;
+    use super::{
+        format_bases, gather_insert_sizes, json_report, merge_patch, module_summary, most_severe,
+        nx, percentile, process_watched, quartiles, render_report, render_summary, sparkline,
+        thin_points, FileOutputs, Results, Setup, Stats, Watch, REPORT_SCHEMA, SCHEMA_VERSION,
+    };
+    use crate::modules::{Module, Threshold, Thresholds};
+    use crate::qc::QcConfig;
+    use itertools::Itertools;
+    use serde_json::{json, Value};
+    use std::time::Duration;
     #[test]
     fn test_quartiles1() {
         let v1 = [-49.5, 24.75, 49.5, 74.25, 148.5];
@@ -191,4 +5070,419 @@ mod test {
         let v2 = quartiles(&h);
         assert!(v1 == v2);
     }
+    #[test]
+    fn test_percentile() {
+        let hist = [0, 5, 0, 4, 1];
+        assert_eq!(percentile(&hist, 0.1), 1);
+        assert_eq!(percentile(&hist, 0.5), 1);
+        assert_eq!(percentile(&hist, 0.9), 3);
+        assert_eq!(percentile(&hist, 1.0), 4);
+    }
+    #[test]
+    fn test_most_severe() {
+        assert_eq!(most_severe(["pass", "fail", "warn"]), "fail");
+        assert_eq!(most_severe(["pass", "warn"]), "warn");
+        assert_eq!(most_severe([]), "pass");
+    }
+    #[test]
+    fn test_sparkline() {
+        assert_eq!(sparkline(&[0.0, 7.0, 3.5], None), "▁█▅");
+        assert_eq!(sparkline(&[2.0, 2.0], None), "██");
+        assert_eq!(sparkline(&[20.0, 50.0], Some(40.0)), "▅█");
+        assert_eq!(sparkline(&vec![1.0; 200], None).chars().count(), 50);
+    }
+    #[test]
+    fn test_format_bases() {
+        assert_eq!(format_bases(101), "101 bp");
+        assert_eq!(format_bases(20_200), "20.2 kbp");
+        assert_eq!(format_bases(3_100_000_000), "3.1 Gbp");
+    }
+    #[test]
+    fn test_nx() {
+        let read_lengths = [(2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 1), (8, 1)]
+            .iter()
+            .copied()
+            .collect();
+        assert_eq!(nx(&read_lengths, 0.5), 6);
+        assert_eq!(nx(&read_lengths, 0.9), 3);
+        assert_eq!(nx(&Default::default(), 0.5), 0);
+    }
+    #[test]
+    fn test_process_watched() {
+        let dir = std::env::temp_dir().join(format!("fqc-watched-{}", std::process::id()));
+        let fastq_pass = dir.join("fastq_pass");
+        std::fs::create_dir_all(&fastq_pass).unwrap();
+        std::fs::write(fastq_pass.join("a.fastq"), "@r1\nACGT\n+\nIIII\n").unwrap();
+        // A truncated gzip file is skipped instead of ending the watch
+        std::fs::write(fastq_pass.join("b.fastq.gz"), [0x1F, 0x8B, 0x08, 0x00]).unwrap();
+        std::fs::write(dir.join("final_summary_FAL12345_abc.txt"), "").unwrap();
+        let setup = Setup::new(QcConfig::new().options, &[], &[]).unwrap();
+        let output = dir.join("report.html");
+        let watch = Watch {
+            interval: Duration::from_millis(10),
+            idle: None,
+        };
+        let outcome = process_watched(
+            &fastq_pass,
+            "run",
+            &setup,
+            &output,
+            FileOutputs::default(),
+            watch,
+        )
+        .unwrap();
+        assert_eq!(outcome.regressions, 0);
+        assert!(std::fs::read_to_string(&output)
+            .unwrap()
+            .contains("run (1 files)"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+    #[test]
+    fn test_gather_interleaved() {
+        let path = std::env::temp_dir().join("fastqc-rs-test-interleaved.fastq");
+        std::fs::write(
+            &path,
+            "@r/1\nACGT\n+\nIIII\n@r/2\nGG\n+\nII\n@s/1\nACGT\n+\nIIII\n",
+        )
+        .unwrap();
+        let options = QcConfig::new().k(3).options;
+        let setup = Setup::new(options, &[], &[]).unwrap();
+        let ([read1, read2], _) = Stats::gather_interleaved(&path, &setup).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(read1.analyses.read_lengths.lengths().get(&4), Some(&2));
+        assert_eq!(read2.analyses.read_lengths.lengths().get(&2), Some(&1));
+        assert_eq!(read2.analyses.read_lengths.lengths().len(), 1);
+    }
+    #[test]
+    fn test_gather_insert_sizes() {
+        let insert = "ACGTTGCATGCAGTCAGTCATGCATTGACACGTAGCTAGCATCGATCAGGACTTAGCATC";
+        let reverse = "GATGCTAAGTCCTGATCGATGCTAGCTACGTGTCAATGCATGACTGACTGCATGCAACGT";
+        let fastq = |seq: &str| format!("@r\n{}\n+\n{}\n", seq, "I".repeat(seq.len()));
+        let paths = ["1", "2"]
+            .map(|read| std::env::temp_dir().join(format!("fastqc-rs-test-insert-{}.fastq", read)));
+        std::fs::write(&paths[0], fastq(&insert[..50]).repeat(3)).unwrap();
+        std::fs::write(&paths[1], fastq(&reverse[..50]).repeat(2)).unwrap();
+        let options = QcConfig::new().options;
+        let setup = Setup::new(options, &[], &[]).unwrap();
+        let sizes = gather_insert_sizes(&paths, &setup).unwrap().sizes();
+        for path in &paths {
+            std::fs::remove_file(path).unwrap();
+        }
+        assert_eq!(sizes.counts, [(60, 2)]);
+        assert_eq!(sizes.median, Some(60));
+        assert_eq!(sizes.non_overlapping, 0.0);
+    }
+    #[test]
+    fn test_duplication_memory() {
+        // The memory for counting duplicates is shared by the threads of all files
+        let mut options = QcConfig::new().duplication_memory(1 << 20).options;
+        options.threads = 4;
+        options.parallel_files = 2;
+        let stats = Stats::new(&Setup::new(options, &[], &[]).unwrap());
+        assert_eq!(stats.analyses.duplication.memory(), 1 << 17);
+    }
+    #[test]
+    fn test_read_threads() {
+        let path = std::env::temp_dir().join("fastqc-rs-test-threads.fastq");
+        let reads = (0..10_000)
+            .map(|i| {
+                let seq = &"ACGTTGCANNGGCCAATT"[i % 7..];
+                format!("@r{}\n{}\n+\n{}\n", i, seq, &"IIII#IIII5IIIII+II"[i % 7..])
+            })
+            .collect::<String>();
+        std::fs::write(&path, reads).unwrap();
+        let mut options = QcConfig::new().k(3).options;
+        let sequential = Stats::gather(&path, &Setup::new(options, &[], &[]).unwrap()).unwrap();
+        options.threads = 3;
+        let threaded = Stats::gather(&path, &Setup::new(options, &[], &[]).unwrap()).unwrap();
+        // Below the cutoff of FastQC, its levels are exact
+        options.fastqc_duplication = true;
+        let fastqc = Stats::gather(&path, &Setup::new(options, &[], &[]).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let (threaded, sequential, fastqc) =
+            (threaded.analyses, sequential.analyses, fastqc.analyses);
+        assert_eq!(
+            threaded.read_lengths.lengths(),
+            sequential.read_lengths.lengths()
+        );
+        assert_eq!(
+            threaded.base_quality.counts(),
+            sequential.base_quality.counts()
+        );
+        assert_eq!(
+            threaded.sequence_quality.counts(),
+            sequential.sequence_quality.counts()
+        );
+        assert_eq!(
+            threaded.composition.base_content(),
+            sequential.composition.base_content()
+        );
+        assert_eq!(threaded.n_content.content(), sequential.n_content.content());
+        assert_eq!(
+            threaded
+                .kmers
+                .totals()
+                .into_iter()
+                .sorted()
+                .collect::<Vec<_>>(),
+            sequential
+                .kmers
+                .totals()
+                .into_iter()
+                .sorted()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            threaded.duplication.levels().remaining,
+            sequential.duplication.levels().remaining
+        );
+        assert_eq!(
+            fastqc.duplication.levels().remaining,
+            sequential.duplication.levels().remaining
+        );
+    }
+    #[test]
+    fn test_summary() {
+        let path = std::env::temp_dir().join("fastqc-rs-test-summary.fastq");
+        std::fs::write(&path, "@r1\nACGTN\n+\nIIII#\n@r2\nGGCC\n+\nIIII\n").unwrap();
+        // The N at the last position of only one read makes it all N
+        let thresholds = Thresholds {
+            n_content: Threshold::new(100.0, 100.0),
+            ..Thresholds::default()
+        };
+        let configs = [
+            QcConfig::new().k(3),
+            QcConfig::new()
+                .k(3)
+                .disable(Module::KmerContent)
+                .disable(Module::DuplicationLevels)
+                .thresholds(thresholds),
+        ];
+        for config in configs {
+            let setup = Setup::new(config.options, &[], &[]).unwrap();
+            let results = Results::new(&Stats::gather(&path, &setup).unwrap(), &setup);
+            let summary = render_summary(&setup, "reads.fq", &results).unwrap();
+            // Parsed like MultiQC does: every module is named with its status, and data
+            // rows have at most as many columns as the header row before them
+            let mut modules = Vec::new();
+            let mut columns = None;
+            for line in summary.lines().skip(1) {
+                if line == ">>END_MODULE" {
+                    columns = None;
+                } else if let Some(module) = line.strip_prefix(">>") {
+                    let (name, status) = module.split_once('\t').unwrap();
+                    modules.push((name, status));
+                } else if let Some(header) = line.strip_prefix('#') {
+                    columns = Some(header.split('\t').count());
+                } else {
+                    assert!(line.split('\t').count() <= columns.unwrap(), "{}", line);
+                }
+            }
+            assert_eq!(modules, results.module_statuses());
+            let statuses = module_summary("reads.fq", &results);
+            assert_eq!(statuses.lines().count(), modules.len());
+            assert!(statuses.starts_with("PASS\tBasic Statistics\treads.fq\n"));
+            assert!(summary.contains("\nFilename\treads.fq\n"));
+            assert!(summary.ends_with(">>END_MODULE\n"));
+            let enabled = config.is_enabled(Module::KmerContent);
+            assert_eq!(
+                modules.iter().any(|(name, _)| *name == "Kmer Content"),
+                enabled
+            );
+            let n_content = if enabled { "fail" } else { "pass" };
+            assert!(modules.contains(&("Per base N content", n_content)));
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+    #[test]
+    fn test_plot_config() {
+        let mut specs = json!({"title": "Phred score", "width": 500, "encoding": {"x": {"field": "pos", "type": "quantitative"}}});
+        merge_patch(
+            &mut specs,
+            &json!({"width": null, "encoding": {"x": {"scale": {"domain": [0, 41]}}, "y": "q"}}),
+        );
+        assert_eq!(
+            specs,
+            json!({"title": "Phred score", "encoding": {"x": {"field": "pos", "type": "quantitative", "scale": {"domain": [0, 41]}}, "y": "q"}})
+        );
+        let setup = Setup::new(QcConfig::new().options, &[], &[])
+            .unwrap()
+            .with_plot_config(r#"{"*": {"width": 800}, "base": {"title": "Custom"}}"#)
+            .unwrap();
+        let mut plots = vec![
+            json!({"short": "base", "specs": r#"{"title": "Phred score"}"#}),
+            json!({"short": "gc", "specs": r#"{"title": "GC"}"#}),
+        ];
+        setup.patch_plots(&mut plots).unwrap();
+        assert_eq!(plots[0]["specs"], r#"{"title":"Custom","width":800}"#);
+        assert_eq!(plots[1]["specs"], r#"{"title":"GC","width":800}"#);
+        let setup = Setup::new(QcConfig::new().options, &[], &[]).unwrap();
+        assert!(setup.with_plot_config(r#"{"base": 5}"#).is_err());
+    }
+    #[test]
+    fn test_thin_points() {
+        let values = (1..=10)
+            .flat_map(|pos| ["a", "b"].map(|series| json!({"pos": pos, "series": series})))
+            .collect::<Vec<_>>();
+        let mut specs = json!({"data": {"values": values}});
+        thin_points(&mut specs, "pos", 20);
+        assert_eq!(specs["data"]["values"].as_array().unwrap().len(), 20);
+        // Both series keep the first, last and evenly spaced positions in between
+        thin_points(&mut specs, "pos", 8);
+        let kept = specs["data"]["values"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|value| {
+                (
+                    value["pos"].as_u64().unwrap(),
+                    value["series"].as_str().unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kept,
+            [
+                (1, "a"),
+                (1, "b"),
+                (4, "a"),
+                (4, "b"),
+                (7, "a"),
+                (7, "b"),
+                (10, "a"),
+                (10, "b")
+            ]
+        );
+    }
+    #[test]
+    fn test_template_dir() {
+        let dir = std::env::temp_dir().join(format!("fqc-templates-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("reads.fastq");
+        std::fs::write(&path, "@r1\nACGT\n+\nIIII\n").unwrap();
+        std::fs::write(
+            dir.join("lab_notes.html.tera"),
+            "{{ files.0.basic_statistics.total_sequences }} read{% include \"_unit.tera\" %}",
+        )
+        .unwrap();
+        std::fs::write(dir.join("_unit.tera"), "s checked").unwrap();
+        std::fs::write(dir.join("report.md.tera"), "# {{ title }}").unwrap();
+        let setup = Setup::new(QcConfig::new().k(3).options, &[], &[])
+            .unwrap()
+            .with_template_dir(&dir)
+            .unwrap()
+            .with_title("Core");
+        assert_eq!(setup.sections, ["lab_notes.html.tera"]);
+        let results = Results::new(&Stats::gather(&path, &setup).unwrap(), &setup);
+        let report = render_report(&setup, &[("reads.fastq", &results)]).unwrap();
+        assert!(report.contains(">lab notes</a>"));
+        assert!(report.contains("1 reads checked"));
+        let markdown = setup
+            .render("report.md.tera", &tera::Context::new())
+            .unwrap_err();
+        assert!(markdown.to_string().contains("Variable `title` not found"));
+        std::fs::write(dir.join("broken.html.tera"), "{% if %}").unwrap();
+        let setup = Setup::new(QcConfig::new().options, &[], &[]).unwrap();
+        assert!(setup.with_template_dir(&dir).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+    /// Checks the given value against the types, required fields, properties, items,
+    /// enumerations, constants and references of the schema, as far as the report schema
+    /// uses them, returning the path of the first mismatch.
+    fn validate(value: &Value, schema: &Value, root: &Value, path: &str) -> Result<(), String> {
+        if let Some(reference) = schema["$ref"].as_str() {
+            let name = reference.strip_prefix("#/$defs/").unwrap();
+            return validate(value, &root["$defs"][name], root, path);
+        }
+        let types = match &schema["type"] {
+            Value::String(name) => vec![name.as_str()],
+            Value::Array(names) => names.iter().filter_map(Value::as_str).collect(),
+            _ => vec![],
+        };
+        let matches = |name: &str| match name {
+            "object" => value.is_object(),
+            "array" => value.is_array(),
+            "string" => value.is_string(),
+            "integer" => value.is_u64() || value.is_i64(),
+            "number" => value.is_number(),
+            "boolean" => value.is_boolean(),
+            "null" => value.is_null(),
+            _ => panic!("unknown type {}", name),
+        };
+        if !types.is_empty() && !types.into_iter().any(matches) {
+            return Err(format!("{} is not of type {}", path, schema["type"]));
+        }
+        if schema
+            .get("const")
+            .is_some_and(|constant| constant != value)
+        {
+            return Err(format!("{} is not {}", path, schema["const"]));
+        }
+        if let Some(values) = schema["enum"].as_array() {
+            if !values.contains(value) {
+                return Err(format!("{} is not one of {:?}", path, values));
+            }
+        }
+        if let Some(number) = value.as_f64() {
+            if schema["minimum"].as_f64().is_some_and(|min| number < min)
+                || schema["maximum"].as_f64().is_some_and(|max| number > max)
+            {
+                return Err(format!("{} = {} is out of range", path, number));
+            }
+        }
+        if let Some(object) = value.as_object() {
+            for field in schema["required"].as_array().into_iter().flatten() {
+                if !object.contains_key(field.as_str().unwrap()) {
+                    return Err(format!("{} lacks {}", path, field));
+                }
+            }
+            for (key, field) in object {
+                let path = format!("{}.{}", path, key);
+                if let Some(property) = schema["properties"].get(key) {
+                    validate(field, property, root, &path)?;
+                }
+                if schema["additionalProperties"].is_object() {
+                    validate(field, &schema["additionalProperties"], root, &path)?;
+                }
+            }
+        }
+        if let (Some(items), true) = (value.as_array(), schema["items"].is_object()) {
+            for (i, item) in items.iter().enumerate() {
+                validate(item, &schema["items"], root, &format!("{}[{}]", path, i))?;
+            }
+        }
+        Ok(())
+    }
+    #[test]
+    fn test_schema() {
+        let schema: Value = serde_json::from_str(REPORT_SCHEMA).unwrap();
+        assert_eq!(
+            schema["properties"]["schema_version"]["const"],
+            SCHEMA_VERSION
+        );
+        let path = std::path::Path::new("tests/resources/example.fastq");
+        for config in [QcConfig::new(), QcConfig::new().genome_size(5000)] {
+            let setup = Setup::new(config.options, &[], &[]).unwrap();
+            let results = Results::new(&Stats::gather(path, &setup).unwrap(), &setup);
+            let report = json_report(&setup, &[("example.fastq", &results)]);
+            assert_eq!(validate(&report, &schema, &schema, "report"), Ok(()));
+        }
+        // Reads without qualities have null metrics of the qualities
+        let fasta = std::env::temp_dir().join("fastqc-rs-test-schema.fasta");
+        std::fs::write(&fasta, ">r1\nACGTACGTGG\n>r2\nACGTTTGCAN\n").unwrap();
+        let setup = Setup::new(QcConfig::new().options, &[], &[]).unwrap();
+        let results = Results::new(&Stats::gather(&fasta, &setup).unwrap(), &setup);
+        std::fs::remove_file(&fasta).unwrap();
+        let report = json_report(&setup, &[("r.fasta", &results)]);
+        assert!(report["files"][0]["basic_statistics"]["q30"].is_null());
+        assert_eq!(validate(&report, &schema, &schema, "report"), Ok(()));
+        let mut report =
+            json!({"schema_version": SCHEMA_VERSION, "version": "1", "k": 5, "files": [{}]});
+        assert_eq!(
+            validate(&report, &schema, &schema, "report"),
+            Err("report.files[0] lacks \"filename\"".to_string())
+        );
+        report["schema_version"] = json!(0);
+        assert!(validate(&report, &schema, &schema, "report").is_err());
+    }
 }
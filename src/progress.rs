@@ -0,0 +1,96 @@
+use crate::input::{file_name, is_stdin};
+use crate::process::format_bases;
+use crate::remote;
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+
+/// Number of records between updates of the progress bar.
+const UPDATE_INTERVAL: u64 = 4096;
+
+/// Returns the progress bars of all files, which are shown below each other while
+/// several files are read at the same time.
+fn bars() -> &'static MultiProgress {
+    static BARS: OnceLock<MultiProgress> = OnceLock::new();
+    BARS.get_or_init(MultiProgress::new)
+}
+
+/// Returns a progress bar of the number of processed files out of `total`.
+pub(crate) fn files(total: usize) -> ProgressBar {
+    let bar = ProgressBar::new(total as u64)
+        .with_style(ProgressStyle::with_template("{pos}/{len} files done, {elapsed}").unwrap());
+    bars().add(bar)
+}
+
+/// A progress bar on standard error with the throughput of reading a file and, if the
+/// size of the file is known, the remaining time. Nothing is shown if standard error
+/// is not a terminal.
+pub(crate) struct Progress {
+    bar: ProgressBar,
+    name: String,
+    /// Number of bytes read from the file so far, before decompression.
+    consumed: Arc<AtomicU64>,
+    reads: u64,
+    bases: u64,
+}
+
+impl Progress {
+    pub(crate) fn new(path: &Path, consumed: Arc<AtomicU64>) -> Self {
+        let size = if is_stdin(path) || path.to_str().is_some_and(remote::is_url) {
+            None
+        } else {
+            fs::metadata(path).ok().map(|metadata| metadata.len())
+        };
+        let bar = match size {
+            Some(size) => ProgressBar::new(size).with_style(
+                ProgressStyle::with_template("{msg} [{bar:30}] {percent}% ETA {eta}")
+                    .unwrap()
+                    .progress_chars("=> "),
+            ),
+            None => ProgressBar::new_spinner()
+                .with_style(ProgressStyle::with_template("{spinner} {msg}").unwrap()),
+        };
+        let bar = bars().add(bar);
+        let name = if is_stdin(path) {
+            "stdin"
+        } else {
+            file_name(path).unwrap_or_default()
+        };
+        Progress {
+            bar,
+            name: name.to_string(),
+            consumed,
+            reads: 0,
+            bases: 0,
+        }
+    }
+
+    /// Counts a record with the given number of bases.
+    pub(crate) fn observe(&mut self, bases: usize) {
+        self.reads += 1;
+        self.bases += bases as u64;
+        if self.reads.is_multiple_of(UPDATE_INTERVAL) {
+            self.update();
+        }
+    }
+
+    /// Shows the final throughput after the file has been read.
+    pub(crate) fn finish(&self) {
+        self.update();
+        self.bar.finish();
+    }
+
+    fn update(&self) {
+        self.bar.set_position(self.consumed.load(Ordering::Relaxed));
+        let seconds = self.bar.elapsed().as_secs_f64().max(1e-3);
+        self.bar.set_message(format!(
+            "{}: {} reads ({:.0} reads/s, {}/s)",
+            self.name,
+            self.reads,
+            self.reads as f64 / seconds,
+            format_bases((self.bases as f64 / seconds) as usize)
+        ));
+    }
+}
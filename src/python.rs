@@ -0,0 +1,140 @@
+use crate::modules::Module;
+use crate::qc::{Qc, QcConfig};
+use pyo3::exceptions::{PyOSError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::fs;
+use std::path::PathBuf;
+
+/// Runs all quality control modules on the given FASTQ, FASTA or SAM/BAM/CRAM file and
+/// returns the report as written by `fqc --format json`.
+///
+/// The options are those of `QcConfig`: `k`, `phred_offset`, `long_reads`, `nogroup`,
+/// `threads`, `sample`, `max_reads`, `duplication_memory` and `read_id_memory` in
+/// bytes, `fastqc_duplication`, `genome_size` in bases, `casava`, `umi_length`,
+/// `kmer_spectrum` as the scale of the sampled 21-mers, `optical_duplicates` as the
+/// distance in pixels, `simulate_trimming` as a list of quality cutoffs, `quality_heatmap`, `split_by_lane`, `platform_modules`, `survival_filters` as a
+/// tuple of the minimum length, minimum mean quality, maximum N bases and maximum
+/// expected errors, `adapters` as a list of sequences, `adapter_list` as the path of a
+/// FASTA or FastQC adapter list, `contaminants` as the path of a FastQC contaminant
+/// list, `screen` as a list of paths of indices written by `fqc index`, `phix` as the
+/// path of a FASTA of the PhiX genome, `disable` as a list of module keys like
+/// `"kmer_content"`, `limits` as the path of a FastQC limits file, `unmapped_only` and
+/// `primary_only`.
+#[pyfunction]
+#[pyo3(signature = (path, **options))]
+fn run(py: Python<'_>, path: PathBuf, options: Option<&Bound<'_, PyDict>>) -> PyResult<PyObject> {
+    let mut config = QcConfig::new();
+    for (key, value) in options.into_iter().flatten() {
+        let key = key.extract::<String>()?;
+        config = match key.as_str() {
+            "k" => {
+                let k = value.extract::<u8>()?;
+                if !(2..=10).contains(&k) {
+                    return Err(PyValueError::new_err("k must be from 2 to 10"));
+                }
+                config.k(k)
+            }
+            "phred_offset" => config.phred_offset(value.extract()?),
+            "long_reads" => config.long_reads(value.extract()?),
+            "nogroup" => config.nogroup(value.extract()?),
+            "threads" => config.threads(value.extract()?),
+            "sample" => config.sample(value.extract()?),
+            "max_reads" => config.max_reads(value.extract()?),
+            "duplication_memory" => config.duplication_memory(value.extract()?),
+            "fastqc_duplication" => config.fastqc_duplication(value.extract()?),
+            "read_id_memory" => config.read_id_memory(value.extract()?),
+            "genome_size" => config.genome_size(value.extract()?),
+            "casava" => config.casava(value.extract()?),
+            "umi_length" => config.umi_length(value.extract()?),
+            "kmer_spectrum" => config.kmer_spectrum(value.extract()?),
+            "optical_duplicates" => config.optical_duplicates(value.extract()?),
+            "simulate_trimming" => config.simulate_trimming(&value.extract::<Vec<u8>>()?),
+            "quality_heatmap" => config.quality_heatmap(value.extract()?),
+            "split_by_lane" => config.split_by_lane(value.extract()?),
+            "platform_modules" => config.platform_modules(value.extract()?),
+            "survival_filters" => {
+                let (min_length, min_mean_quality, max_n, max_expected_errors) = value.extract()?;
+                config.survival_filters(crate::SurvivalFilters {
+                    min_length,
+                    min_mean_quality,
+                    max_n,
+                    max_expected_errors,
+                })
+            }
+            "adapters" => value
+                .extract::<Vec<String>>()?
+                .iter()
+                .fold(config, |config, adapter| config.adapter(adapter)),
+            "adapter_list" => {
+                let list = fs::read_to_string(value.extract::<PathBuf>()?)?;
+                config
+                    .adapters(&list)
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?
+            }
+            "contaminants" => {
+                let list = fs::read_to_string(value.extract::<PathBuf>()?)?;
+                config
+                    .contaminants(&list)
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?
+            }
+            "phix" => {
+                let fasta = fs::read_to_string(value.extract::<PathBuf>()?)?;
+                config
+                    .phix(&fasta)
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?
+            }
+            "screen" => {
+                for index in value.extract::<Vec<PathBuf>>()? {
+                    config = config
+                        .screen(&fs::read(index)?)
+                        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+                }
+                config
+            }
+            "disable" => {
+                for key in value.extract::<Vec<String>>()? {
+                    let module = Module::from_key(&key)
+                        .ok_or_else(|| PyValueError::new_err(format!("Unknown module {}", key)))?;
+                    config = config.disable(module);
+                }
+                config
+            }
+            "limits" => {
+                let text = fs::read_to_string(value.extract::<PathBuf>()?)?;
+                config
+                    .limits(&text)
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?
+            }
+            "unmapped_only" => config.unmapped_only(value.extract()?),
+            "primary_only" => config.primary_only(value.extract()?),
+            _ => {
+                return Err(PyTypeError::new_err(format!(
+                    "run() got an unexpected keyword argument '{}'",
+                    key
+                )))
+            }
+        };
+    }
+    // The GIL is released while reading, so that other Python threads keep running
+    let report = py
+        .allow_threads(|| {
+            Qc::new(config)
+                .process_file(&path)
+                .map(|report| report.to_json().to_string())
+                .map_err(|e| e.to_string())
+        })
+        .map_err(PyOSError::new_err)?;
+    Ok(py
+        .import("json")?
+        .call_method1("loads", (report,))?
+        .unbind())
+}
+
+/// The `fastqc_rs` Python extension module, which is built with maturin.
+#[pymodule]
+fn fastqc_rs(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    module.add_function(wrap_pyfunction!(run, module)?)?;
+    Ok(())
+}
@@ -0,0 +1,2224 @@
+use crate::adapters::Adapter;
+use crate::bins::Binning;
+use crate::contaminants::parse_sequence_list;
+use crate::duplication::DEFAULT_MEMORY;
+use crate::error::FastqcError;
+use crate::input::{self, AlignmentFilter, Reader};
+use crate::limits;
+use crate::modules::{Module, ModuleFactory, ModuleResult, Modules, QcModule, Thresholds};
+use crate::process::{
+    json_report, render_report, DataFormat, Format, Options, Results, Setup, Stats,
+};
+use crate::screen::Reference;
+use crate::survival::SurvivalFilters;
+use crate::trim_simulation::{TrimCutoffs, MAX_CUTOFF};
+use serde_json::Value;
+use std::io::{Cursor, Read};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Settings of the analyses run by [`Qc`], built by chaining setters on the defaults:
+///
+/// ```
+/// use fastqc_rs::{Module, QcConfig, Threshold, Thresholds};
+///
+/// let config = QcConfig::new()
+///     .k(7)
+///     .threads(4)
+///     .max_reads(100_000)
+///     .disable(Module::KmerContent)
+///     .thresholds(Thresholds {
+///         adapter_content: Threshold::new(1.0, 5.0),
+///         ..Thresholds::default()
+///     });
+/// assert!(!config.is_enabled(Module::KmerContent));
+/// ```
+#[derive(Debug, Clone)]
+pub struct QcConfig {
+    pub(crate) options: Options,
+    pub(crate) adapter_list: Vec<(String, String)>,
+    pub(crate) adapters: Vec<String>,
+    pub(crate) contaminants: Vec<(String, String)>,
+    pub(crate) references: Vec<Reference>,
+    pub(crate) custom_modules: Vec<ModuleFactory>,
+}
+
+impl Default for QcConfig {
+    fn default() -> Self {
+        QcConfig {
+            options: Options {
+                k: 5,
+                phred_offset: None,
+                alignment_filter: AlignmentFilter::default(),
+                binning: Binning::Grouped,
+                format: Format::Html,
+                data_format: DataFormat::Tsv,
+                fetch_assets: false,
+                threads: 1,
+                parallel_files: 1,
+                sample: None,
+                seed: crate::sample::DEFAULT_SEED,
+                max_reads: None,
+                progress: false,
+                duplication_memory: DEFAULT_MEMORY,
+                fastqc_duplication: false,
+                read_id_memory: crate::read_ids::DEFAULT_MEMORY,
+                genome_size: None,
+                casava: false,
+                umi_length: None,
+                spectrum_scale: None,
+                optical_distance: None,
+                quality_heatmap: false,
+                split_by_lane: false,
+                platform_modules: false,
+                modules: Modules::default(),
+                thresholds: Thresholds::default(),
+                survival: SurvivalFilters::default(),
+                simulate_trim: TrimCutoffs::default(),
+                live_json: None,
+                max_plot_points: Some(crate::process::DEFAULT_PLOT_POINTS),
+            },
+            adapter_list: Vec::new(),
+            adapters: Vec::new(),
+            contaminants: Vec::new(),
+            references: Vec::new(),
+            custom_modules: Vec::new(),
+        }
+    }
+}
+
+impl QcConfig {
+    /// Returns the default settings with all modules enabled.
+    pub fn new() -> Self {
+        QcConfig::default()
+    }
+
+    /// Sets the length of the counted k-mers, 5 by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is not from 2 to 10.
+    pub fn k(mut self, k: u8) -> Self {
+        assert!((2..=10).contains(&k), "k must be from 2 to 10");
+        self.options.k = k;
+        self
+    }
+
+    /// Uses the given phred offset instead of the one detected from the qualities.
+    pub fn phred_offset(mut self, offset: u8) -> Self {
+        self.options.phred_offset = Some(offset);
+        self
+    }
+
+    /// Reports every position of the reads individually instead of grouping them into
+    /// windows for reads longer than 75 bp or into bins for [`QcConfig::long_reads`].
+    pub fn nogroup(mut self, nogroup: bool) -> Self {
+        if nogroup {
+            self.options.binning = Binning::Exact;
+        }
+        self
+    }
+
+    /// Groups positions into exponentially growing bins for long reads.
+    pub fn long_reads(mut self, long_reads: bool) -> Self {
+        self.options.binning = if long_reads {
+            Binning::Adaptive
+        } else {
+            Binning::Grouped
+        };
+        self
+    }
+
+    /// Sets the number of threads processing the records of an input, 1 by default.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.options.threads = threads.max(1);
+        self
+    }
+
+    /// Estimates all metrics from the given number of reads sampled uniformly at random,
+    /// or from all reads for 0.
+    pub fn sample(mut self, reads: usize) -> Self {
+        self.options.sample = Some(reads).filter(|&reads| reads > 0);
+        self
+    }
+
+    /// Sets the seed of the random sample of the reads, 0 by default. The same seed
+    /// samples the same reads of an input.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.options.seed = seed;
+        self
+    }
+
+    /// Only uses the given number of records from the start of the input.
+    pub fn max_reads(mut self, reads: usize) -> Self {
+        self.options.max_reads = Some(reads);
+        self
+    }
+
+    /// Sets the memory in bytes for counting duplicate sequences exactly, which is
+    /// divided among the threads, above which the duplication levels are estimated.
+    pub fn duplication_memory(mut self, bytes: usize) -> Self {
+        self.options.duplication_memory = bytes;
+        self
+    }
+
+    /// Sets whether the duplication levels are estimated like FastQC from the first
+    /// 100,000 distinct sequences, so that they are comparable with those of FastQC.
+    /// This gives no saturation curve.
+    pub fn fastqc_duplication(mut self, fastqc: bool) -> Self {
+        self.options.fastqc_duplication = fastqc;
+        self
+    }
+
+    /// Estimates the mean coverage of a genome of the given size in bases.
+    pub fn genome_size(mut self, bases: u64) -> Self {
+        self.options.genome_size = Some(bases);
+        self
+    }
+
+    /// Sets the memory in bytes per thread for detecting duplicate read IDs exactly,
+    /// above which their number is estimated.
+    pub fn read_id_memory(mut self, bytes: usize) -> Self {
+        self.options.read_id_memory = bytes;
+        self
+    }
+
+    /// Sets whether reads flagged as filtered in Casava 1.8+ headers like
+    /// `@name 1:Y:0:ATCACG` are left out of the statistics, like `--casava` of FastQC.
+    /// Filtered reads are counted either way.
+    pub fn casava(mut self, exclude_filtered: bool) -> Self {
+        self.options.casava = exclude_filtered;
+        self
+    }
+
+    /// Takes the UMIs from the given number of bases at the start of the reads instead
+    /// of the end of the read names.
+    pub fn umi_length(mut self, length: usize) -> Self {
+        self.options.umi_length = Some(length);
+        self
+    }
+
+    /// Counts the 21-mers of the reads for their spectrum and a GenomeScope-style model
+    /// of the genome, only those sampled by their hash for a scale above 1 to save
+    /// memory for large genomes.
+    pub fn kmer_spectrum(mut self, scale: u64) -> Self {
+        self.options.spectrum_scale = Some(scale);
+        self
+    }
+
+    /// Estimates optical and ExAmp duplicates, i.e. reads starting like another read
+    /// whose cluster lies within the given distance in pixels on the same tile, taken
+    /// from Illumina read names. Picard uses 100 for unpatterned and 2500 for patterned
+    /// flowcells.
+    pub fn optical_duplicates(mut self, distance: u32) -> Self {
+        self.options.optical_distance = Some(distance);
+        self
+    }
+
+    /// Sets whether the number of bases of every phred score per position is reported
+    /// and plotted as a heatmap, which shows populations of reads of different quality
+    /// that the quartiles hide.
+    pub fn quality_heatmap(mut self, quality_heatmap: bool) -> Self {
+        self.options.quality_heatmap = quality_heatmap;
+        self
+    }
+
+    /// Sets whether the yield, Q30 bases and mean quality per position are also
+    /// reported per flowcell lane, taken from Illumina read names.
+    pub fn split_by_lane(mut self, split_by_lane: bool) -> Self {
+        self.options.split_by_lane = split_by_lane;
+        self
+    }
+
+    /// Sets whether the poly-G tails are only reported for two-color and one-color
+    /// chemistry and the per tile qualities only for patterned flowcells, if the
+    /// platform is detected from the read names or qualities.
+    pub fn platform_modules(mut self, platform_modules: bool) -> Self {
+        self.options.platform_modules = platform_modules;
+        self
+    }
+
+    /// Sets the filters of which the reads and bases passing them are reported, as an
+    /// estimate of the data left after filtering.
+    pub fn survival_filters(mut self, filters: SurvivalFilters) -> Self {
+        self.options.survival = filters;
+        self
+    }
+
+    /// Simulates BWA-style and sliding-window quality trimming with each of the given
+    /// phred cutoffs, reporting the reads and bases left of the reads at least as long
+    /// as the minimum length of the survival filters.
+    ///
+    /// # Panics
+    ///
+    /// If a cutoff is above 63.
+    pub fn simulate_trimming(mut self, cutoffs: &[u8]) -> Self {
+        assert!(
+            cutoffs.iter().all(|&cutoff| cutoff <= MAX_CUTOFF),
+            "Quality cutoffs must be at most {}.",
+            MAX_CUTOFF
+        );
+        self.options.simulate_trim = TrimCutoffs::new(cutoffs);
+        self
+    }
+
+    /// Searches for the adapters of the given FASTA or FastQC adapter list instead of
+    /// the built-in adapters.
+    pub fn adapters(mut self, list: &str) -> Result<Self, FastqcError> {
+        self.adapter_list = Adapter::parse_file(list);
+        if self.adapter_list.is_empty() {
+            return Err(FastqcError::parse(
+                "The adapter list contains no sequences.",
+            ));
+        }
+        Ok(self)
+    }
+
+    /// Searches for the given adapter sequence in addition to the built-in adapters.
+    pub fn adapter(mut self, sequence: &str) -> Self {
+        self.adapters.push(sequence.to_string());
+        self
+    }
+
+    /// Annotates overrepresented sequences with the given contaminant list in the
+    /// format of FastQC, i.e. a name and a sequence separated by tabs per line, instead
+    /// of the bundled list.
+    pub fn contaminants(mut self, list: &str) -> Result<Self, FastqcError> {
+        self.contaminants = parse_sequence_list(list)
+            .map(|(name, sequence)| (name.to_string(), sequence.to_string()))
+            .collect();
+        if self.contaminants.is_empty() {
+            return Err(FastqcError::parse(
+                "The contaminant list contains no sequences.",
+            ));
+        }
+        Ok(self)
+    }
+
+    /// Estimates the percentage of reads from a PhiX spike-in by their k-mers shared
+    /// with the given FASTA of the PhiX genome.
+    pub fn phix(mut self, fasta: &str) -> Result<Self, FastqcError> {
+        self.references.push(Reference::from_fasta("PhiX", fasta)?);
+        Ok(self)
+    }
+
+    /// Screens the reads against the reference genome of an index written by
+    /// `fqc index`, like FastQ Screen.
+    pub fn screen(mut self, index: &[u8]) -> Result<Self, FastqcError> {
+        self.references.push(Reference::from_bytes(index)?);
+        Ok(self)
+    }
+
+    /// Only uses unmapped records of SAM/BAM/CRAM files.
+    pub fn unmapped_only(mut self, unmapped_only: bool) -> Self {
+        self.options.alignment_filter.unmapped_only = unmapped_only;
+        self
+    }
+
+    /// Skips secondary and supplementary alignments of SAM/BAM/CRAM files.
+    pub fn primary_only(mut self, primary_only: bool) -> Self {
+        self.options.alignment_filter.primary_only = primary_only;
+        self
+    }
+
+    pub fn enable(mut self, module: Module) -> Self {
+        self.options.modules.set(module, true);
+        self
+    }
+
+    /// Leaves the module out of all reports and skips its counting if costly.
+    pub fn disable(mut self, module: Module) -> Self {
+        self.options.modules.set(module, false);
+        self
+    }
+
+    /// Only enables the given modules.
+    pub fn only(mut self, modules: &[Module]) -> Self {
+        for &module in &Module::ALL {
+            self.options.modules.set(module, modules.contains(&module));
+        }
+        self
+    }
+
+    /// Sets the thresholds at which the modules warn or fail.
+    pub fn thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.options.thresholds = thresholds;
+        self
+    }
+
+    /// Applies the thresholds and ignored modules of a FastQC limits file, given as
+    /// its text.
+    pub fn limits(mut self, text: &str) -> Result<Self, FastqcError> {
+        limits::apply(
+            text,
+            &mut self.options.thresholds,
+            &mut self.options.modules,
+        )?;
+        Ok(self)
+    }
+
+    /// Adds a custom module, of which every input gets a new instance from `new`, and
+    /// every processing thread if the module is mergeable.
+    pub fn module<M, F>(mut self, new: F) -> Self
+    where
+        M: QcModule,
+        F: Fn() -> M + Send + Sync + 'static,
+    {
+        self.custom_modules.push(ModuleFactory::new(new));
+        self
+    }
+
+    pub fn is_enabled(&self, module: Module) -> bool {
+        self.options.modules.contains(module)
+    }
+}
+
+/// Runs all quality control modules on FASTQ, FASTA or SAM/BAM/CRAM input.
+///
+/// ```
+/// use fastqc_rs::{Qc, QcConfig, Status};
+///
+/// let qc = Qc::new(QcConfig::default());
+/// let report = qc.process_file("tests/resources/example.fastq").unwrap();
+/// assert_eq!(report.basic_statistics().total_sequences, 200);
+/// assert_ne!(report.status(), Status::Fail);
+/// ```
+pub struct Qc {
+    setup: Arc<Setup>,
+}
+
+impl Qc {
+    /// Prepares the analyses with the given settings, which are shared by all inputs.
+    pub fn new(config: QcConfig) -> Self {
+        let setup = Setup::new(config.options, &config.adapter_list, &config.adapters)
+            .expect("The bundled report templates are valid")
+            .with_contaminants(&config.contaminants)
+            .with_references(&config.references)
+            .with_modules(config.custom_modules);
+        Qc {
+            setup: Arc::new(setup),
+        }
+    }
+
+    /// Analyzes the FASTA/FASTQ records of the given reader, which may be compressed.
+    /// The report is named `reader` unless renamed with [`QcReport::with_name`].
+    pub fn process_reader<R: Read + Send + 'static>(
+        &self,
+        reader: R,
+    ) -> Result<QcReport, FastqcError> {
+        Ok(self.report("reader", input::from_reader(reader)?, None, &mut |_| {}))
+    }
+
+    /// Analyzes FASTA/FASTQ records in memory, which may be compressed, e.g. a file
+    /// selected in a browser.
+    pub fn process_bytes(&self, bytes: &[u8]) -> Result<QcReport, FastqcError> {
+        self.process_reader(Cursor::new(bytes.to_vec()))
+    }
+
+    /// Analyzes the given file, which is opened like the input files of `fqc`.
+    pub fn process_file<P: AsRef<Path>>(&self, path: P) -> Result<QcReport, FastqcError> {
+        let path = path.as_ref();
+        let options = self.setup.options();
+        let reader = input::open(
+            path,
+            options.alignment_filter,
+            options.threads,
+            Arc::default(),
+        )?;
+        let name = input::file_name(path).unwrap_or("reader");
+        Ok(self.report(name, reader, None, &mut |_| {}))
+    }
+
+    /// Like [`Qc::process_file`], but passes a report of the records read so far to
+    /// `on_snapshot` after every `every` records, e.g. for a dashboard showing the QC of
+    /// a large file converge. The records are then observed on one thread, and no
+    /// snapshots are taken if reads are sampled.
+    pub fn process_file_with_snapshots<P, F>(
+        &self,
+        path: P,
+        every: usize,
+        mut on_snapshot: F,
+    ) -> Result<QcReport, FastqcError>
+    where
+        P: AsRef<Path>,
+        F: FnMut(&QcReport),
+    {
+        let path = path.as_ref();
+        let options = self.setup.options();
+        let reader = input::open(
+            path,
+            options.alignment_filter,
+            options.threads,
+            Arc::default(),
+        )?;
+        let name = input::file_name(path).unwrap_or("reader");
+        Ok(self.report(name, reader, Some(every), &mut on_snapshot))
+    }
+
+    fn report(
+        &self,
+        name: &str,
+        mut reader: Reader,
+        snapshot_every: Option<usize>,
+        on_snapshot: &mut dyn FnMut(&QcReport),
+    ) -> QcReport {
+        if let Some(max_reads) = self.setup.options().max_reads {
+            reader = reader.limit(max_reads);
+        }
+        let mut stats = Stats::new(&self.setup);
+        let start = stats.read_start();
+        match snapshot_every {
+            Some(every) if self.setup.options().sample.is_none() => {
+                stats.read_live(reader, every, &mut |stats| {
+                    on_snapshot(&self.report_of(name, stats));
+                })
+            }
+            _ => stats.read_from(reader, &self.setup),
+        }
+        stats.log_read(name, &self.setup, start);
+        self.report_of(name, &stats)
+    }
+
+    fn report_of(&self, name: &str, stats: &Stats) -> QcReport {
+        QcReport::new(
+            name.to_string(),
+            Results::new(stats, &self.setup),
+            self.setup.clone(),
+        )
+    }
+}
+
+/// Status of a module, from the least to the most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Status {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl Status {
+    pub(crate) fn parse(status: &str) -> Self {
+        match status {
+            "fail" => Status::Fail,
+            "warn" => Status::Warn,
+            _ => Status::Pass,
+        }
+    }
+
+    /// Returns the status as written in reports, `pass`, `warn` or `fail`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Warn => "warn",
+            Status::Fail => "fail",
+        }
+    }
+}
+
+/// Summary statistics of all reads.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BasicStatistics {
+    /// Name of the detected or chosen quality encoding.
+    pub encoding: String,
+    /// Sequencing platform and instrument guessed from the read names or qualities,
+    /// e.g. `Illumina NovaSeq 6000`.
+    pub platform: Option<String>,
+    pub total_sequences: usize,
+    /// Number of reads in the input if only a sample of them was analyzed.
+    pub sampled_from: Option<usize>,
+    pub total_bases: usize,
+    pub min_length: usize,
+    pub mean_length: f64,
+    pub max_length: usize,
+    pub n50: usize,
+    pub n90: usize,
+    /// Percentage of G and C among all A, C, G and T bases.
+    pub gc_content: f64,
+    /// Percentage of bases with a quality of at least 20.
+    pub q20: f64,
+    /// Percentage of bases with a quality of at least 30.
+    pub q30: f64,
+    /// Number of bases with a quality of at least 30, which make up the Q30 yield.
+    pub q30_bases: usize,
+    /// Percentage of reads with a mean quality of at least 20.
+    pub q20_reads: f64,
+    /// Percentage of reads with a mean quality of at least 30.
+    pub q30_reads: f64,
+    /// Whether the reads have qualities. Reads without, e.g. of FASTA files, get no
+    /// modules of the qualities.
+    pub has_qualities: bool,
+    /// Distinct quality scores if the qualities are binned into a few of them, as by
+    /// NovaSeq and NextSeq instruments.
+    pub quality_bins: Option<Vec<usize>>,
+    /// Mean coverage of the genome if its size is given with [`QcConfig::genome_size`].
+    pub coverage: Option<Coverage>,
+    /// Number of reads with the ID of a previous read, which hints at concatenated or
+    /// repeated files.
+    pub duplicate_ids: usize,
+    /// Whether the number of duplicate read IDs is estimated to bound the memory.
+    pub duplicate_ids_estimated: bool,
+    /// Number of reads flagged as filtered in their Casava 1.8+ header.
+    pub filtered_reads: usize,
+    /// Percentage of the reads with a Casava 1.8+ header flagged as filtered.
+    pub filtered_pct: f64,
+    /// Whether the filtered reads are left out of all other statistics.
+    pub filtered_excluded: bool,
+    /// Number of records that could not be parsed and were skipped.
+    pub invalid_reads: usize,
+}
+
+/// Mean coverage of a genome estimated from the bases of the reads, extrapolated to
+/// all reads if only a sample of them was analyzed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Coverage {
+    pub genome_size: u64,
+    /// Bases of all reads per base of the genome.
+    pub mean: f64,
+    /// Coverage without the duplicate reads and the bases from the start of adapters.
+    pub corrected: f64,
+}
+
+/// Distribution of the base qualities at a position, in phred scores.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BaseQuality {
+    /// First position of the bin, starting at 0.
+    pub position: usize,
+    pub mean: f64,
+    pub median: f64,
+    pub lower_quartile: f64,
+    pub upper_quartile: f64,
+    pub p10: f64,
+    pub p90: f64,
+    /// Lower quartile minus 1.5 times the interquartile range.
+    pub lower_fence: f64,
+    /// Upper quartile plus 1.5 times the interquartile range.
+    pub upper_fence: f64,
+}
+
+/// Percentage of each base among the A, C, G and T bases at a position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BaseContent {
+    pub position: usize,
+    pub a: f64,
+    pub c: f64,
+    pub g: f64,
+    pub t: f64,
+    /// Percentage of G and C among the A, C, G and T bases, which shows composition
+    /// biases at the read starts that the per sequence GC content averages away.
+    pub gc: f64,
+    /// (G - C) / (G + C), or 0 without G and C.
+    pub gc_skew: f64,
+    /// (A - T) / (A + T), or 0 without A and T.
+    pub at_skew: f64,
+}
+
+/// Share of the sequences and of the reads with the given number of duplicates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicationLevel {
+    /// Number of copies like in FastQC, from `1` to `>10k`.
+    pub level: String,
+    /// Percentage of the distinct sequences at this level.
+    pub deduplicated: f64,
+    /// Percentage of all reads at this level.
+    pub total: f64,
+}
+
+/// Duplication levels of all sequences.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Duplication {
+    /// Percentage of reads remaining after deduplication.
+    pub remaining: f64,
+    pub levels: Vec<DuplicationLevel>,
+    /// Number of distinct sequences in the library estimated by the Lander-Waterman
+    /// model, or `None` if all reads are distinct.
+    pub library_size: Option<f64>,
+    /// Expected number of distinct sequences in random subsamples of the reads, per
+    /// number of subsampled reads.
+    pub saturation: Vec<(usize, f64)>,
+}
+
+/// A sequence making up more than 0.1% of the first reads.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverrepresentedSequence {
+    pub sequence: String,
+    pub count: usize,
+    pub percentage: f64,
+    /// Best matching known contaminant, or `No Hit`.
+    pub source: String,
+}
+
+/// Reads matching a reference genome that the reads were screened against.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceMatches {
+    pub reference: String,
+    /// Number of reads with k-mers sampled by the reference, which could be screened.
+    pub screened: usize,
+    /// Number and percentage of the screened reads matching the reference.
+    pub count: usize,
+    pub percentage: f64,
+    /// Percentage of the screened reads matching no other reference.
+    pub unique_percentage: f64,
+}
+
+/// The k-mer spectrum of the reads with the estimates of a model of a diploid genome
+/// fitted to it, which are `None` without a clear peak of the genome's k-mers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KmerSpectrum {
+    /// Number of k-mers and of distinct ones, only of those sampled for a scale above 1.
+    pub kmers: usize,
+    pub distinct: usize,
+    /// Number of distinct k-mers occurring once, twice and so on, up to the range the
+    /// model was fitted to.
+    pub histogram: Vec<usize>,
+    /// Mean coverage of the k-mers of one haplotype.
+    pub coverage: Option<f64>,
+    /// Estimated length of the haploid genome.
+    pub genome_size: Option<f64>,
+    /// Percentages of heterozygous bases and of bases with sequencing errors.
+    pub heterozygosity: Option<f64>,
+    pub error_rate: Option<f64>,
+    /// Percentage of bases with sequencing errors estimated from the k-mers occurring
+    /// once, and expected from the qualities of the bases.
+    pub singleton_error_rate: Option<f64>,
+    pub quality_error_rate: Option<f64>,
+    /// Whether both error rates differ by a factor of 2 or more, suggesting
+    /// miscalibrated quality scores.
+    pub miscalibrated: bool,
+}
+
+/// Duplicates among the reads with a position on an Illumina flowcell, which are
+/// optical or ExAmp duplicates if their clusters lie close by on the same tile.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpticalDuplicates {
+    /// Maximal distance in pixels of optical duplicates.
+    pub distance: u32,
+    /// Number of reads with a position, of a sample of the sequences for large files.
+    pub reads: usize,
+    /// Percentages of these reads duplicating another read, close by or elsewhere.
+    pub duplicates: f64,
+    pub optical: f64,
+    pub pcr: f64,
+}
+
+/// Overrepresented sequences whose best hit is a contaminant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContaminantHits {
+    pub contaminant: String,
+    /// Number of overrepresented sequences hitting the contaminant.
+    pub sequences: usize,
+    /// Number of reads of these sequences.
+    pub count: usize,
+    pub percentage: f64,
+}
+
+/// Number of a dinucleotide or trinucleotide compared to the number expected from
+/// the base composition.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MotifBias {
+    pub motif: String,
+    pub observed: usize,
+    pub expected: f64,
+    /// Observed divided by expected number.
+    pub ratio: f64,
+}
+
+/// Number of runs of a base with the same length.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HomopolymerRuns {
+    pub base: char,
+    pub length: usize,
+    pub count: usize,
+    /// Percentage among all runs of the base.
+    pub percentage: f64,
+}
+
+/// Frequencies of the index sequences in the read headers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Barcodes {
+    /// Number of reads with an index.
+    pub reads: usize,
+    /// Most frequent index, taken for the one the reads were demultiplexed by.
+    pub expected: String,
+    /// Number of reads with each number of mismatches to the expected index.
+    pub mismatches: Vec<usize>,
+    /// Shannon entropy in bits of the other indices, low for few indices of other
+    /// samples bleeding through.
+    pub entropy: f64,
+    /// Number and percentage of reads with an index at least 2 mismatches away from
+    /// the expected one.
+    pub unexpected: usize,
+    pub unexpected_percentage: f64,
+    /// Most frequent indices.
+    pub top: Vec<BarcodeCount>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BarcodeCount {
+    pub barcode: String,
+    pub count: usize,
+    pub percentage: f64,
+    /// Number of mismatches to the expected index.
+    pub mismatches: usize,
+}
+
+/// Number, diversity and composition of the unique molecular identifiers of the reads.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Umis {
+    /// Number and percentage of reads with a UMI.
+    pub reads: usize,
+    pub percentage: f64,
+    /// Number of distinct UMIs, estimated for many of them.
+    pub distinct: usize,
+    /// Number of distinct UMIs expected for random UMIs of the most common length.
+    pub expected_distinct: f64,
+    /// Percentage of distinct among expected UMIs.
+    pub diversity: f64,
+    /// Percentages of A, C, G, T and N per position of the UMIs.
+    pub composition: Vec<[f64; 5]>,
+    /// Most frequent UMIs with their number of reads and percentage of the UMIs.
+    pub top: Vec<(String, usize, f64)>,
+}
+
+/// Reads ending in a run of at least 10 G or A.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolyTails {
+    /// Percentage of reads with a poly-G tail.
+    pub poly_g: f64,
+    /// Percentage of reads with a poly-A tail.
+    pub poly_a: f64,
+    /// Number of reads by the base and length of their tail.
+    pub lengths: Vec<(char, usize, usize)>,
+}
+
+/// Reads with a low complexity by their DUST score, which is above 7 of 100 for
+/// homopolymers, short tandem repeats and most adapter dimers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LowComplexity {
+    /// Percentage of reads with a low complexity.
+    pub percentage: f64,
+    /// Percentages of A, C, G, T and N among the bases of these reads.
+    pub composition: Vec<(char, f64)>,
+    /// Number of reads by their DUST score rounded down, leaving out scores without
+    /// reads.
+    pub scores: Vec<(usize, usize)>,
+}
+
+/// Records that could not be parsed and were skipped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidReads {
+    pub count: usize,
+    /// Number of invalid records per kind of error, e.g. `"length mismatch"`.
+    pub kinds: Vec<(String, usize)>,
+    /// Numbers of the first invalid records, starting at 1.
+    pub records: Vec<usize>,
+}
+
+/// The runs the reads were sequenced in, from their Illumina or nanopore headers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunInfo {
+    /// IDs of the Illumina instruments.
+    pub instruments: Vec<String>,
+    /// Illumina run numbers or nanopore run IDs.
+    pub runs: Vec<String>,
+    pub flowcells: Vec<String>,
+    pub lanes: Vec<u32>,
+    /// Number of channels of the nanopore flowcells with reads.
+    pub channels: usize,
+}
+
+/// The consistency of the read names, of which mixtures usually come from
+/// accidentally concatenated files.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadNames {
+    /// Number of reads named like Illumina, nanopore, PacBio or other reads.
+    pub schemes: Vec<(String, usize)>,
+    /// Number of Illumina reads of each instrument, run and flowcell, e.g.
+    /// `A00123:8:HVWKDDSXX`.
+    pub runs: Vec<(String, usize)>,
+    /// Number of reads without a mate number, of the first and of the second mate.
+    pub mates: [usize; 3],
+    /// Number of times the lanes or tiles start over, or `None` if the reads were
+    /// sampled.
+    pub tile_restarts: Option<usize>,
+    /// Number of Illumina reads with a lane, tile or coordinate which is not a number.
+    pub implausible_coordinates: usize,
+    /// A description of every inconsistency.
+    pub warnings: Vec<String>,
+}
+
+/// Hard trimming suggested from the quality and adapter content per position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SuggestedTrimming {
+    /// Number of bases to remove from the 5' end.
+    pub five_prime: usize,
+    /// Length to crop the reads to at the 3' end.
+    pub three_prime: usize,
+    /// Mean quality below which positions at the ends are trimmed.
+    pub quality_cutoff: f64,
+}
+
+/// Cumulative percentage of reads with an adapter starting at or before a position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdapterContent {
+    pub adapter: String,
+    pub position: usize,
+    pub percentage: f64,
+}
+
+/// Number of reads in which an adapter starts at a position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdapterStarts {
+    pub adapter: String,
+    pub position: usize,
+    pub count: usize,
+    pub percentage: f64,
+}
+
+/// A k-mer enriched at some positions of the reads.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnrichedKmer {
+    pub kmer: String,
+    pub count: usize,
+    pub pvalue: f64,
+    /// Highest ratio of observed to expected count at any position.
+    pub max_obs_exp: f64,
+    /// Position with the highest ratio.
+    pub max_position: usize,
+}
+
+/// Deviation of the mean quality of a tile from the mean over all tiles at a position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TileQuality {
+    pub tile: usize,
+    pub position: usize,
+    pub deviation: f64,
+}
+
+/// The yield and qualities of the reads of a flowcell lane.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LaneStatistics {
+    pub lane: usize,
+    pub reads: usize,
+    pub bases: usize,
+    /// Percentage of bases with a quality of at least 30.
+    pub q30: f64,
+    pub mean_quality: f64,
+    /// Mean quality at the first position of each group of positions.
+    pub means: Vec<(usize, f64)>,
+}
+
+/// The reads and bases passing the filters set with [`QcConfig::survival_filters`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterSurvival {
+    /// Number and percentage of the reads passing each filter, with the filter and its
+    /// threshold like `length >= 36`, and passing all of them last.
+    pub filters: Vec<(String, usize, f64)>,
+    /// Number and percentage of the bases of the reads passing all filters.
+    pub bases: usize,
+    pub bases_percentage: f64,
+}
+
+/// The reads and bases left after quality trimming with a cutoff of
+/// [`QcConfig::simulate_trimming`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedTrimming {
+    /// Trimming algorithm, `BWA` or `sliding window`, and its phred cutoff.
+    pub method: String,
+    pub cutoff: u8,
+    /// Number and percentage of the reads kept, i.e. at least as long as the minimum
+    /// length of the survival filters after trimming.
+    pub reads: usize,
+    pub reads_percentage: f64,
+    /// Number and percentage of the bases of the kept reads.
+    pub bases: usize,
+    pub bases_percentage: f64,
+    /// Number of all reads by their length after trimming.
+    pub lengths: Vec<(usize, usize)>,
+}
+
+/// The results of all modules for one input, which can be rendered like the reports
+/// of `fqc`.
+pub struct QcReport {
+    name: String,
+    results: Results,
+    setup: Arc<Setup>,
+    /// The report as JSON, from which the data of the modules is taken.
+    json: Value,
+}
+
+impl QcReport {
+    fn new(name: String, results: Results, setup: Arc<Setup>) -> Self {
+        let json = json_report(&setup, &[(&name, &results)]);
+        QcReport {
+            name,
+            results,
+            setup,
+            json,
+        }
+    }
+
+    /// Changes the name of the input shown in the report.
+    pub fn with_name(self, name: &str) -> Self {
+        QcReport::new(name.to_string(), self.results, self.setup)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the most severe status of all modules.
+    pub fn status(&self) -> Status {
+        Status::parse(self.results.status())
+    }
+
+    /// Returns the name and status of every module in the order of FastQC, followed by
+    /// the custom modules.
+    pub fn modules(&self) -> Vec<(&str, Status)> {
+        self.results
+            .module_statuses()
+            .into_iter()
+            .map(|(name, status)| (name, Status::parse(status)))
+            .collect()
+    }
+
+    /// Returns the results of the custom modules in the order they were added.
+    pub fn custom_modules(&self) -> &[ModuleResult] {
+        self.results.custom_modules()
+    }
+
+    pub fn basic_statistics(&self) -> BasicStatistics {
+        let file = &self.json["files"][0];
+        let stats = &file["basic_statistics"];
+        BasicStatistics {
+            encoding: text(&file["encoding"]),
+            platform: stats["platform"]["name"].as_str().map(str::to_string),
+            total_sequences: count(&stats["total_sequences"]),
+            sampled_from: stats["sampled_from"].as_u64().map(|n| n as usize),
+            total_bases: count(&stats["total_bases"]),
+            min_length: count(&stats["min_length"]),
+            mean_length: number(&stats["mean_length"]),
+            max_length: count(&stats["max_length"]),
+            n50: count(&stats["n50"]),
+            n90: count(&stats["n90"]),
+            gc_content: number(&stats["gc_content"]),
+            q20: number(&stats["q20"]),
+            q30: number(&stats["q30"]),
+            q30_bases: count(&stats["q30_bases"]),
+            q20_reads: number(&stats["q20_reads"]),
+            q30_reads: number(&stats["q30_reads"]),
+            has_qualities: stats["has_qualities"].as_bool().unwrap_or(true),
+            quality_bins: stats["quality_bins"]
+                .as_array()
+                .map(|bins| bins.iter().map(count).collect()),
+            coverage: stats["coverage"].as_object().map(|coverage| Coverage {
+                genome_size: coverage["genome_size"].as_u64().unwrap_or(0),
+                mean: number(&coverage["mean"]),
+                corrected: number(&coverage["corrected"]),
+            }),
+            duplicate_ids: count(&stats["duplicate_ids"]),
+            duplicate_ids_estimated: stats["duplicate_ids_estimated"].as_bool().unwrap_or(false),
+            filtered_reads: count(&stats["filtered_reads"]),
+            filtered_pct: number(&stats["filtered_pct"]),
+            filtered_excluded: stats["filtered_excluded"].as_bool().unwrap_or(false),
+            invalid_reads: count(&file["invalid_reads"]["count"]),
+        }
+    }
+
+    pub fn per_base_quality(&self) -> Vec<BaseQuality> {
+        self.data("per_base_quality")
+            .map(|entry| BaseQuality {
+                position: count(&entry["pos"]),
+                mean: number(&entry["average"]),
+                median: number(&entry["median"]),
+                lower_quartile: number(&entry["q1"]),
+                upper_quartile: number(&entry["q3"]),
+                p10: number(&entry["p10"]),
+                p90: number(&entry["p90"]),
+                lower_fence: number(&entry["lower"]),
+                upper_fence: number(&entry["upper"]),
+            })
+            .collect()
+    }
+
+    /// Returns the position, phred score and number of bases of every phred score at
+    /// every position, leaving out those of no bases, if enabled with
+    /// [`QcConfig::quality_heatmap`].
+    pub fn quality_heatmap(&self) -> Vec<(usize, usize, usize)> {
+        self.data("quality_heatmap")
+            .map(|entry| {
+                (
+                    count(&entry["pos"]),
+                    count(&entry["quality"]),
+                    count(&entry["count"]),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the number of reads per mean phred score, leaving out scores of no reads.
+    pub fn per_sequence_quality(&self) -> Vec<(usize, usize)> {
+        self.data("per_sequence_quality")
+            .map(|entry| (count(&entry["quality"]), count(&entry["count"])))
+            .collect()
+    }
+
+    /// Returns the number of reads per bin of expected errors, i.e. the sum of the error
+    /// probabilities of their bases, with the lowest expected errors of each bin.
+    pub fn expected_errors(&self) -> Vec<(f64, usize)> {
+        self.data("expected_errors")
+            .map(|entry| (number(&entry["errors"]), count(&entry["count"])))
+            .collect()
+    }
+
+    /// Returns the reads passing common filters and the bases left after filtering.
+    pub fn filter_survival(&self) -> FilterSurvival {
+        let survival = self.module("filter_survival");
+        FilterSurvival {
+            filters: self
+                .data("filter_survival")
+                .map(|entry| {
+                    (
+                        text(&entry["filter"]),
+                        count(&entry["reads"]),
+                        number(&entry["pct"]),
+                    )
+                })
+                .collect(),
+            bases: count(&survival["bases"]),
+            bases_percentage: number(&survival["bases_pct"]),
+        }
+    }
+
+    /// Returns the reads and bases left after the quality trimming of
+    /// [`QcConfig::simulate_trimming`] with every method and cutoff, or nothing if it
+    /// was not simulated.
+    pub fn simulated_trimming(&self) -> Vec<SimulatedTrimming> {
+        let lengths = &self.module("trim_simulation")["lengths"];
+        self.data("trim_simulation")
+            .map(|entry| {
+                let method = text(&entry["method"]);
+                let cutoff = count(&entry["cutoff"]) as u8;
+                let trimming = format!("{} Q{}", method, cutoff);
+                SimulatedTrimming {
+                    lengths: lengths
+                        .as_array()
+                        .map_or(&[][..], Vec::as_slice)
+                        .iter()
+                        .filter(|entry| entry["trimming"] == trimming.as_str())
+                        .map(|entry| (count(&entry["length"]), count(&entry["count"])))
+                        .collect(),
+                    method,
+                    cutoff,
+                    reads: count(&entry["reads"]),
+                    reads_percentage: number(&entry["reads_pct"]),
+                    bases: count(&entry["bases"]),
+                    bases_percentage: number(&entry["bases_pct"]),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the number of invalid records by the kind of error, which tells isolated
+    /// from pervasive corruption of the input.
+    pub fn invalid_reads(&self) -> InvalidReads {
+        let invalid = &self.json["files"][0]["invalid_reads"];
+        InvalidReads {
+            count: count(&invalid["count"]),
+            kinds: invalid["kinds"]
+                .as_object()
+                .into_iter()
+                .flatten()
+                .map(|(kind, n)| (kind.clone(), count(n)))
+                .collect(),
+            records: invalid["records"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(count)
+                .collect(),
+        }
+    }
+
+    /// Returns the instruments, runs, flowcells and lanes or channels in the read
+    /// headers, or `None` if the headers have none of them.
+    pub fn run_info(&self) -> Option<RunInfo> {
+        let run = &self.json["files"][0]["run"];
+        let texts = |key: &str| {
+            run[key]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(text)
+                .collect()
+        };
+        run.is_object().then(|| RunInfo {
+            instruments: texts("instruments"),
+            runs: texts("runs"),
+            flowcells: texts("flowcells"),
+            lanes: run["lanes"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(|lane| count(lane) as u32)
+                .collect(),
+            channels: count(&run["channels"]),
+        })
+    }
+
+    /// Returns the naming schemes, runs and mate numbers of the reads, with warnings
+    /// about mixtures of them.
+    pub fn read_names(&self) -> ReadNames {
+        let names = &self.json["files"][0]["read_names"];
+        let counts = |key: &str| {
+            names[key]
+                .as_object()
+                .into_iter()
+                .flatten()
+                .map(|(name, reads)| (name.clone(), count(reads)))
+                .collect()
+        };
+        ReadNames {
+            schemes: counts("schemes"),
+            runs: counts("runs"),
+            mates: ["none", "1", "2"].map(|mate| count(&names["mates"][mate])),
+            tile_restarts: names["tile_restarts"].as_u64().map(|n| n as usize),
+            implausible_coordinates: count(&names["implausible_coordinates"]),
+            warnings: names["warnings"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(text)
+                .collect(),
+        }
+    }
+
+    /// Returns the trimming suggested to remove the positions of low quality at both
+    /// ends and those from where adapter content exceeds its warning threshold.
+    pub fn suggested_trimming(&self) -> SuggestedTrimming {
+        let trimming = &self.json["files"][0]["suggested_trimming"];
+        SuggestedTrimming {
+            five_prime: count(&trimming["five_prime"]),
+            three_prime: count(&trimming["three_prime"]),
+            quality_cutoff: number(&trimming["quality_cutoff"]),
+        }
+    }
+
+    pub fn per_base_content(&self) -> Vec<BaseContent> {
+        self.data("per_base_content")
+            .map(|entry| BaseContent {
+                position: count(&entry["pos"]),
+                a: number(&entry["a"]),
+                c: number(&entry["c"]),
+                g: number(&entry["g"]),
+                t: number(&entry["t"]),
+                gc: number(&entry["gc"]),
+                gc_skew: number(&entry["gc_skew"]),
+                at_skew: number(&entry["at_skew"]),
+            })
+            .collect()
+    }
+
+    /// Returns the number of reads per GC percentage from 0 to 100.
+    pub fn per_sequence_gc_content(&self) -> Vec<usize> {
+        self.data("per_sequence_gc_content")
+            .map(|entry| count(&entry["count"]))
+            .collect()
+    }
+
+    /// Returns the percentage of N bases per position.
+    pub fn per_base_n_content(&self) -> Vec<(usize, f64)> {
+        self.data("per_base_n_content")
+            .map(|entry| (count(&entry["pos"]), number(&entry["pct"])))
+            .collect()
+    }
+
+    /// Returns the number of reads per length, or per first length of a bin for long reads.
+    pub fn read_length_distribution(&self) -> Vec<(usize, usize)> {
+        self.data("read_length_distribution")
+            .map(|entry| (count(&entry["length"]), count(&entry["count"])))
+            .collect()
+    }
+
+    pub fn duplication_levels(&self) -> Duplication {
+        let duplication = self.module("duplication_levels");
+        Duplication {
+            remaining: number(&duplication["remaining"]),
+            levels: self
+                .data("duplication_levels")
+                .map(|entry| DuplicationLevel {
+                    level: text(&entry["level"]),
+                    deduplicated: number(&entry["deduplicated"]),
+                    total: number(&entry["total"]),
+                })
+                .collect(),
+            library_size: duplication["library_size"].as_f64(),
+            saturation: duplication["saturation"]
+                .as_array()
+                .map_or(&[][..], Vec::as_slice)
+                .iter()
+                .filter(|entry| !entry["observed"].is_null())
+                .map(|entry| (count(&entry["reads"]), number(&entry["observed"])))
+                .collect(),
+        }
+    }
+
+    pub fn overrepresented_sequences(&self) -> Vec<OverrepresentedSequence> {
+        self.data("overrepresented_sequences")
+            .map(|entry| OverrepresentedSequence {
+                sequence: text(&entry["sequence"]),
+                count: count(&entry["count"]),
+                percentage: number(&entry["pct"]),
+                source: text(&entry["source"]),
+            })
+            .collect()
+    }
+
+    /// Returns the contaminants hit by overrepresented sequences, most reads first.
+    pub fn contaminant_hits(&self) -> Vec<ContaminantHits> {
+        self.module("overrepresented_sequences")["contaminants"]
+            .as_array()
+            .map_or(&[][..], Vec::as_slice)
+            .iter()
+            .map(|entry| ContaminantHits {
+                contaminant: text(&entry["contaminant"]),
+                sequences: count(&entry["sequences"]),
+                count: count(&entry["count"]),
+                percentage: number(&entry["pct"]),
+            })
+            .collect()
+    }
+
+    /// Returns the reads matching each reference genome of [`QcConfig::phix`] and
+    /// [`QcConfig::screen`].
+    pub fn screen(&self) -> Vec<ReferenceMatches> {
+        self.module("screen")["references"]
+            .as_array()
+            .map_or(&[][..], Vec::as_slice)
+            .iter()
+            .map(|entry| ReferenceMatches {
+                reference: text(&entry["name"]),
+                screened: count(&entry["screened"]),
+                count: count(&entry["reads"]),
+                percentage: number(&entry["pct"]),
+                unique_percentage: number(&entry["unique_pct"]),
+            })
+            .collect()
+    }
+
+    /// Returns the k-mer spectrum of [`QcConfig::kmer_spectrum`], or `None` if it was
+    /// not counted.
+    pub fn kmer_spectrum(&self) -> Option<KmerSpectrum> {
+        let spectrum = self.module("kmer_spectrum");
+        spectrum.is_object().then(|| KmerSpectrum {
+            kmers: count(&spectrum["kmers"]),
+            distinct: count(&spectrum["distinct"]),
+            histogram: spectrum["data"]
+                .as_array()
+                .map_or(&[][..], Vec::as_slice)
+                .iter()
+                .map(|entry| count(&entry["kmers"]))
+                .collect(),
+            coverage: spectrum["coverage"].as_f64(),
+            genome_size: spectrum["genome_size"].as_f64(),
+            heterozygosity: spectrum["heterozygosity"].as_f64(),
+            error_rate: spectrum["error_rate"].as_f64(),
+            singleton_error_rate: spectrum["singleton_error_rate"].as_f64(),
+            quality_error_rate: spectrum["quality_error_rate"].as_f64(),
+            miscalibrated: spectrum["miscalibrated"].as_bool().unwrap_or_default(),
+        })
+    }
+
+    /// Returns the duplicates of [`QcConfig::optical_duplicates`], or `None` if they
+    /// were not estimated or no read name has a flowcell position.
+    pub fn optical_duplicates(&self) -> Option<OpticalDuplicates> {
+        let optical = self.module("optical_duplicates");
+        optical.is_object().then(|| OpticalDuplicates {
+            distance: count(&optical["distance"]) as u32,
+            reads: count(&optical["reads"]),
+            duplicates: number(&optical["duplicates"]),
+            optical: number(&optical["optical"]),
+            pcr: number(&optical["pcr"]),
+        })
+    }
+
+    /// Returns the bias of all dinucleotides and trinucleotides, the most over- or
+    /// underrepresented ones first.
+    pub fn motif_bias(&self) -> Vec<MotifBias> {
+        self.data("motif_bias")
+            .map(|entry| MotifBias {
+                motif: text(&entry["motif"]),
+                observed: count(&entry["observed"]),
+                expected: number(&entry["expected"]),
+                ratio: number(&entry["ratio"]),
+            })
+            .collect()
+    }
+
+    pub fn poly_tails(&self) -> PolyTails {
+        let module = self.module("poly_tails");
+        PolyTails {
+            poly_g: number(&module["poly_g"]),
+            poly_a: number(&module["poly_a"]),
+            lengths: self
+                .data("poly_tails")
+                .map(|entry| {
+                    let base = text(&entry["base"]).chars().next().unwrap_or('N');
+                    (base, count(&entry["length"]), count(&entry["count"]))
+                })
+                .collect(),
+        }
+    }
+
+    pub fn low_complexity(&self) -> LowComplexity {
+        let module = self.module("low_complexity");
+        LowComplexity {
+            percentage: number(&module["percentage"]),
+            composition: module["composition"]
+                .as_array()
+                .map_or(&[][..], Vec::as_slice)
+                .iter()
+                .map(|entry| {
+                    let base = text(&entry["base"]).chars().next().unwrap_or('N');
+                    (base, number(&entry["pct"]))
+                })
+                .collect(),
+            scores: self
+                .data("low_complexity")
+                .map(|entry| (count(&entry["score"]), count(&entry["count"])))
+                .collect(),
+        }
+    }
+
+    /// Returns the number of runs of each base by length, leaving out lengths without
+    /// runs.
+    pub fn homopolymers(&self) -> Vec<HomopolymerRuns> {
+        self.data("homopolymers")
+            .map(|entry| HomopolymerRuns {
+                base: text(&entry["base"]).chars().next().unwrap_or('N'),
+                length: count(&entry["length"]),
+                count: count(&entry["count"]),
+                percentage: number(&entry["pct"]),
+            })
+            .collect()
+    }
+
+    /// Returns the frequencies of the index sequences in the read headers, or `None`
+    /// without them.
+    pub fn barcodes(&self) -> Option<Barcodes> {
+        let barcodes = self.module("barcodes");
+        barcodes.is_object().then(|| Barcodes {
+            reads: count(&barcodes["reads"]),
+            expected: text(&barcodes["expected"]),
+            mismatches: barcodes["mismatches"]
+                .as_array()
+                .map_or(&[][..], Vec::as_slice)
+                .iter()
+                .map(count)
+                .collect(),
+            entropy: number(&barcodes["entropy"]),
+            unexpected: count(&barcodes["unexpected"]),
+            unexpected_percentage: number(&barcodes["unexpected_pct"]),
+            top: barcodes["top"]
+                .as_array()
+                .map_or(&[][..], Vec::as_slice)
+                .iter()
+                .map(|entry| BarcodeCount {
+                    barcode: text(&entry["barcode"]),
+                    count: count(&entry["count"]),
+                    percentage: number(&entry["pct"]),
+                    mismatches: count(&entry["mismatches"]),
+                })
+                .collect(),
+        })
+    }
+
+    /// Returns the UMIs found at the end of the read names or, with
+    /// [`QcConfig::umi_length`], at the start of the reads, or `None` without UMIs.
+    pub fn umis(&self) -> Option<Umis> {
+        let umis = self.module("umis");
+        let entries = |key: &str| umis[key].as_array().map_or(&[][..], Vec::as_slice);
+        umis.is_object().then(|| Umis {
+            reads: count(&umis["reads"]),
+            percentage: number(&umis["pct"]),
+            distinct: count(&umis["distinct"]),
+            expected_distinct: number(&umis["expected_distinct"]),
+            diversity: number(&umis["diversity"]),
+            composition: entries("composition")
+                .iter()
+                .map(|entry| ["a", "c", "g", "t", "n"].map(|base| number(&entry[base])))
+                .collect(),
+            top: entries("top")
+                .iter()
+                .map(|entry| {
+                    (
+                        text(&entry["umi"]),
+                        count(&entry["count"]),
+                        number(&entry["pct"]),
+                    )
+                })
+                .collect(),
+        })
+    }
+
+    pub fn adapter_content(&self) -> Vec<AdapterContent> {
+        self.data("adapter_content")
+            .map(|entry| AdapterContent {
+                adapter: text(&entry["adapter"]),
+                position: count(&entry["pos"]),
+                percentage: number(&entry["pct"]),
+            })
+            .collect()
+    }
+
+    /// Returns the positions at which adapters start in the reads, leaving out those
+    /// without a start.
+    pub fn adapter_starts(&self) -> Vec<AdapterStarts> {
+        self.module("adapter_content")["starts"]
+            .as_array()
+            .map_or(&[][..], Vec::as_slice)
+            .iter()
+            .map(|entry| AdapterStarts {
+                adapter: text(&entry["adapter"]),
+                position: count(&entry["pos"]),
+                count: count(&entry["count"]),
+                percentage: number(&entry["pct"]),
+            })
+            .collect()
+    }
+
+    pub fn kmer_content(&self) -> Vec<EnrichedKmer> {
+        self.data("kmer_content")
+            .map(|entry| EnrichedKmer {
+                kmer: text(&entry["k_mer"]),
+                count: count(&entry["count"]),
+                pvalue: entry["pvalue"]
+                    .as_str()
+                    .and_then(|pvalue| pvalue.parse().ok())
+                    .unwrap_or(1.0),
+                max_obs_exp: number(&entry["obs_exp"]),
+                max_position: count(&entry["max_pos"]),
+            })
+            .collect()
+    }
+
+    /// Returns the quality deviations of every tile, or `None` if the read names
+    /// contain no tiles.
+    pub fn per_tile_quality(&self) -> Option<Vec<TileQuality>> {
+        self.module("per_tile_quality").as_object()?;
+        Some(
+            self.data("per_tile_quality")
+                .map(|entry| TileQuality {
+                    tile: count(&entry["tile"]),
+                    position: count(&entry["pos"]),
+                    deviation: number(&entry["deviation"]),
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the yield and qualities per lane, or `None` unless split by lane with
+    /// [`QcConfig::split_by_lane`] and the read names contain their lane.
+    pub fn lanes(&self) -> Option<Vec<LaneStatistics>> {
+        let lanes = self.module("lanes");
+        let statistics = lanes["statistics"].as_array()?;
+        Some(
+            statistics
+                .iter()
+                .map(|entry| LaneStatistics {
+                    lane: count(&entry["lane"]),
+                    reads: count(&entry["reads"]),
+                    bases: count(&entry["bases"]),
+                    q30: number(&entry["q30"]),
+                    mean_quality: number(&entry["mean_quality"]),
+                    means: self
+                        .data("lanes")
+                        .filter(|mean| mean["lane"] == entry["lane"])
+                        .map(|mean| (count(&mean["pos"]), number(&mean["mean"])))
+                        .collect(),
+                })
+                .collect(),
+        )
+    }
+
+    /// Renders the interactive HTML report.
+    pub fn render_html(&self) -> Result<String, FastqcError> {
+        render_report(&self.setup, &[(&self.name, &self.results)])
+    }
+
+    /// Returns the report in the format written by `fqc --format json`.
+    pub fn to_json(&self) -> Value {
+        self.json.clone()
+    }
+
+    fn module(&self, name: &str) -> &Value {
+        &self.json["files"][0]["modules"][name]
+    }
+
+    fn data(&self, module: &str) -> impl Iterator<Item = &Value> {
+        self.module(module)["data"]
+            .as_array()
+            .map_or(&[][..], Vec::as_slice)
+            .iter()
+    }
+}
+
+fn number(value: &Value) -> f64 {
+    value.as_f64().unwrap_or(0.0)
+}
+
+fn count(value: &Value) -> usize {
+    value.as_u64().unwrap_or(0) as usize
+}
+
+fn text(value: &Value) -> String {
+    value.as_str().unwrap_or_default().to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Qc, QcConfig, Status};
+    use crate::input::Record;
+    use crate::modules::{ModuleResult, QcModule};
+    use crate::survival::SurvivalFilters;
+    use std::fs;
+
+    struct ReadCounter(usize);
+
+    impl QcModule for ReadCounter {
+        fn observe(&mut self, _: &Record) {
+            self.0 += 1;
+        }
+
+        fn finish(&self) -> ModuleResult {
+            ModuleResult::new("Read counter", Status::Warn)
+                .with_columns(&["Reads"])
+                .with_row(&[self.0])
+        }
+    }
+
+    /// Counts the bases in every thread, merging the counts at the end.
+    struct BaseCounter(usize);
+
+    impl QcModule for BaseCounter {
+        fn observe(&mut self, record: &Record) {
+            self.0 += record.num_bases();
+        }
+
+        fn is_mergeable() -> bool {
+            true
+        }
+
+        fn merge(&mut self, other: &dyn QcModule) {
+            self.0 += other
+                .downcast_ref::<BaseCounter>()
+                .map_or(0, |other| other.0);
+        }
+
+        fn finish(&self) -> ModuleResult {
+            ModuleResult::new("Base counter", Status::Pass)
+                .with_columns(&["Bases"])
+                .with_row(&[self.0])
+        }
+    }
+    #[test]
+    fn test_process_reader() {
+        let fastq = fs::read("tests/resources/example.fastq").unwrap();
+        let qc = Qc::new(QcConfig::new().max_reads(10));
+        let report = qc
+            .process_reader(std::io::Cursor::new(fastq.clone()))
+            .unwrap();
+        let stats = report.basic_statistics();
+        assert_eq!(stats.total_sequences, 10);
+        assert_eq!(report.name(), "reader");
+        assert_eq!(report.modules()[0], ("Basic Statistics", Status::Pass));
+        assert_eq!(report.per_sequence_gc_content().len(), 101);
+        assert_eq!(report.duplication_levels().levels.len(), 16);
+        let duplication = report.duplication_levels();
+        assert_eq!(duplication.saturation.last(), Some(&(10, 10.0)));
+        assert!(duplication.library_size.is_none());
+        assert!(!report.per_base_quality().is_empty());
+        let report = report.with_name("sample");
+        assert_eq!(report.to_json()["files"][0]["filename"], "sample");
+        assert!(report.render_html().unwrap().contains("sample"));
+        assert!(qc.process_reader(&b""[..]).is_err());
+        let report = qc.process_bytes(&fastq).unwrap();
+        assert_eq!(report.basic_statistics().total_sequences, 10);
+    }
+    #[test]
+    fn test_sample_zero() {
+        // A sample of 0 reads analyzes all reads
+        let qc = Qc::new(QcConfig::new().sample(0));
+        let report = qc.process_file("tests/resources/example.fastq").unwrap();
+        let stats = report.basic_statistics();
+        assert_eq!(stats.total_sequences, 200);
+        assert_eq!(stats.sampled_from, None);
+    }
+    #[test]
+    fn test_quality_fractions() {
+        let qc = Qc::new(QcConfig::new());
+        let report = qc.process_file("tests/resources/example.fastq").unwrap();
+        let stats = report.basic_statistics();
+        assert_eq!(format!("{:.2}", stats.q20), "96.18");
+        assert_eq!(format!("{:.2}", stats.q30), "91.23");
+        let q30_bases = stats.q30_bases as f64 * 100.0 / stats.total_bases as f64;
+        assert_eq!(format!("{:.2}", q30_bases), "91.23");
+        assert_eq!(stats.q20_reads, 100.0);
+        assert_eq!(stats.q30_reads, 90.0);
+        let expected_errors = report.expected_errors();
+        assert_eq!(expected_errors[0], (0.01, 1));
+        let reads = expected_errors
+            .iter()
+            .map(|(_, count)| count)
+            .sum::<usize>();
+        assert_eq!(reads, 200);
+    }
+    #[test]
+    fn test_quality_bins() {
+        let qc = Qc::new(QcConfig::new());
+        let reads = (0..100)
+            .map(|i| {
+                format!(
+                    "@r{}\nACGTACGTAC\n+\n{}\n",
+                    i,
+                    &"FFFF88-#FFFF88-#"[i % 4..i % 4 + 10]
+                )
+            })
+            .collect::<String>();
+        let report = qc.process_bytes(reads.as_bytes()).unwrap();
+        assert_eq!(
+            report.basic_statistics().quality_bins,
+            Some(vec![2, 12, 23, 37])
+        );
+        let continuous = qc.process_file("tests/resources/example.fastq").unwrap();
+        assert_eq!(continuous.basic_statistics().quality_bins, None);
+    }
+    #[test]
+    fn test_fasta() {
+        let qc = Qc::new(QcConfig::new());
+        let report = qc
+            .process_bytes(b">r1\nACGTACGTGG\n>r2\nACGTTTGCAN\n")
+            .unwrap();
+        let stats = report.basic_statistics();
+        assert!(!stats.has_qualities);
+        assert_eq!(stats.total_bases, 20);
+        let modules = report.modules();
+        assert!(!modules.iter().any(|(name, _)| name.contains("quality")));
+        assert!(modules.iter().any(|(name, _)| *name == "Kmer Content"));
+        assert!(report.per_base_quality().is_empty());
+        // The metrics of the qualities are null instead of 0
+        let json = report.to_json();
+        let stats = &json["files"][0]["basic_statistics"];
+        for key in ["q20", "q30", "q30_bases", "q20_reads", "q30_reads"] {
+            assert!(stats[key].is_null(), "{} is not null", key);
+        }
+        assert!(
+            qc.process_file("tests/resources/example.fastq")
+                .unwrap()
+                .basic_statistics()
+                .has_qualities
+        );
+    }
+    #[test]
+    fn test_read_names() {
+        let qc = Qc::new(QcConfig::new());
+        let report = qc.process_file("tests/resources/example.fastq").unwrap();
+        let names = report.read_names();
+        assert_eq!(names.schemes, [("Illumina".to_string(), 200)]);
+        assert!(names.warnings.is_empty());
+        let reads = "@M1:7:FC1:1:1102:1:2 1:N:0:1\nACGT\n+\nIIII\n\
+                     @M1:7:FC1:1:1101:1:2 1:N:0:1\nACGT\n+\nIIII\n\
+                     @M2:9:FC2:1:1101:1:2 2:N:0:1\nACGT\n+\nIIII\n";
+        let names = qc.process_bytes(reads.as_bytes()).unwrap().read_names();
+        assert_eq!(names.mates, [0, 2, 1]);
+        assert_eq!(names.tile_restarts, Some(1));
+        assert_eq!(names.warnings.len(), 3);
+    }
+    #[test]
+    fn test_coverage() {
+        let example = "tests/resources/example.fastq";
+        let report = Qc::new(QcConfig::new()).process_file(example).unwrap();
+        assert_eq!(report.basic_statistics().coverage, None);
+        let stats = report.basic_statistics();
+        let qc = Qc::new(QcConfig::new().genome_size(stats.total_bases as u64 / 10));
+        let coverage = qc
+            .process_file(example)
+            .unwrap()
+            .basic_statistics()
+            .coverage;
+        let coverage = coverage.unwrap();
+        assert!((coverage.mean - 10.0).abs() < 0.01);
+        assert!(coverage.corrected > 0.0 && coverage.corrected < coverage.mean);
+    }
+    #[test]
+    fn test_base_skew() {
+        let qc = Qc::new(QcConfig::new());
+        let report = qc.process_file("tests/resources/example.fastq").unwrap();
+        for base in report.per_base_content() {
+            assert!((base.gc - base.g - base.c).abs() < 1e-9);
+            assert!((base.gc_skew - (base.g - base.c) / (base.g + base.c)).abs() < 1e-9);
+            assert!((base.at_skew - (base.a - base.t) / (base.a + base.t)).abs() < 1e-9);
+        }
+    }
+    #[test]
+    fn test_motif_bias() {
+        let qc = Qc::new(QcConfig::new());
+        let report = qc.process_file("tests/resources/example.fastq").unwrap();
+        let biases = report.motif_bias();
+        assert_eq!(biases.len(), 16 + 64);
+        let observed = biases
+            .iter()
+            .filter(|bias| bias.motif.len() == 2)
+            .map(|bias| bias.observed)
+            .sum::<usize>();
+        let expected = biases
+            .iter()
+            .filter(|bias| bias.motif.len() == 2)
+            .map(|bias| bias.expected)
+            .sum::<f64>();
+        assert!((observed as f64 - expected).abs() < 1e-6);
+        let bias = |ratio: f64| ratio.max(1.0 / ratio);
+        assert!(bias(biases[0].ratio) >= bias(biases[1].ratio));
+    }
+    #[test]
+    fn test_homopolymers() {
+        let qc = Qc::new(QcConfig::new());
+        let report = qc.process_file("tests/resources/example.fastq").unwrap();
+        let runs = report.homopolymers();
+        let bases = runs
+            .iter()
+            .map(|runs| runs.length * runs.count)
+            .sum::<usize>();
+        // All bases except for the 228 N are part of a run
+        assert_eq!(bases, report.basic_statistics().total_bases - 228);
+        for base in ['A', 'C', 'G', 'T'] {
+            let pct = runs
+                .iter()
+                .filter(|runs| runs.base == base)
+                .map(|runs| runs.percentage)
+                .sum::<f64>();
+            assert!((pct - 100.0).abs() < 1e-9);
+        }
+    }
+    #[test]
+    fn test_poly_tails() {
+        let mut fastq = Vec::new();
+        for tail in ["GGGGGGGGGGGG", "AAAAAAAAAA", "ACGT", "GGGGGGGGGGGG"] {
+            let seq = format!("ACGTTGCT{}", tail);
+            fastq.extend(format!("@read\n{}\n+\n{}\n", seq, "I".repeat(seq.len())).bytes());
+        }
+        let report = Qc::new(QcConfig::new()).process_bytes(&fastq).unwrap();
+        let tails = report.poly_tails();
+        assert_eq!(tails.poly_g, 50.0);
+        assert_eq!(tails.poly_a, 25.0);
+        assert_eq!(tails.lengths, [('G', 12, 2), ('A', 10, 1)]);
+        assert!(report
+            .modules()
+            .contains(&("Poly-G and poly-A tails", Status::Warn)));
+    }
+    #[test]
+    fn test_low_complexity() {
+        let mut fastq = Vec::new();
+        for seq in [
+            "GGGGGGGGGGGGGGGGGGGG",
+            "ACACACACACACACACACAC",
+            "GATTACAGCTTGCACGTAGG",
+        ] {
+            fastq.extend(format!("@read\n{}\n+\n{}\n", seq, "I".repeat(seq.len())).bytes());
+        }
+        let report = Qc::new(QcConfig::new()).process_bytes(&fastq).unwrap();
+        let low_complexity = report.low_complexity();
+        assert!((low_complexity.percentage - 200.0 / 3.0).abs() < 1e-9);
+        assert_eq!(low_complexity.composition[0], ('A', 25.0));
+        assert_eq!(low_complexity.composition[2], ('G', 50.0));
+        let reads = low_complexity.scores.iter().map(|(_, count)| count);
+        assert_eq!(reads.sum::<usize>(), 3);
+        assert!(report
+            .modules()
+            .contains(&("Low complexity reads", Status::Fail)));
+        let report = Qc::new(QcConfig::new())
+            .process_file("tests/resources/example.fastq")
+            .unwrap();
+        assert!(report.low_complexity().percentage < 5.0);
+    }
+    #[test]
+    fn test_quality_heatmap() {
+        let fastq = b"@r1\nACGT\n+\nII55\n@r2\nACGT\n+\nIIII\n";
+        let report = Qc::new(QcConfig::new()).process_bytes(fastq).unwrap();
+        assert!(report.quality_heatmap().is_empty());
+        let qc = Qc::new(QcConfig::new().quality_heatmap(true));
+        let heatmap = qc.process_bytes(fastq).unwrap().quality_heatmap();
+        assert_eq!(heatmap[..2], [(0, 40, 2), (1, 40, 2)]);
+        assert_eq!(
+            heatmap[2..],
+            [(2, 20, 1), (2, 40, 1), (3, 20, 1), (3, 40, 1)]
+        );
+    }
+    #[test]
+    fn test_filter_survival() {
+        let fastq = b"@r1\nACGT\n+\nIIII\n@r2\nACNN\n+\nII++\n@r3\nAC\n+\nII\n";
+        let qc = Qc::new(QcConfig::new().survival_filters(SurvivalFilters {
+            min_length: 3,
+            max_n: 1,
+            ..SurvivalFilters::default()
+        }));
+        let survival = qc.process_bytes(fastq).unwrap().filter_survival();
+        let reads = survival
+            .filters
+            .iter()
+            .map(|&(_, reads, _)| reads)
+            .collect::<Vec<_>>();
+        assert_eq!(reads, [2, 3, 2, 3, 1]);
+        assert_eq!(survival.filters[2].0, "N bases <= 1");
+        assert_eq!((survival.bases, survival.bases_percentage), (4, 40.0));
+    }
+    #[test]
+    fn test_lanes() {
+        let mut fastq = Vec::new();
+        for (lane, qualities) in [(1, "IIII"), (2, "5555"), (1, "II55")] {
+            let id = format!("A00123:8:HVWKDDSXX:{}:1101:1018:1000", lane);
+            fastq.extend(format!("@{}\nACGT\n+\n{}\n", id, qualities).bytes());
+        }
+        let qc = Qc::new(QcConfig::new().split_by_lane(true));
+        let lanes = qc.process_bytes(&fastq).unwrap().lanes().unwrap();
+        assert_eq!(lanes.len(), 2);
+        assert_eq!((lanes[0].lane, lanes[0].reads, lanes[0].bases), (1, 2, 8));
+        assert_eq!((lanes[0].q30, lanes[0].mean_quality), (75.0, 35.0));
+        assert_eq!(lanes[1].q30, 0.0);
+        assert_eq!(lanes[1].means.len(), 4);
+    }
+    #[test]
+    fn test_platform_modules() {
+        let qc = Qc::new(QcConfig::new().platform_modules(true));
+        let report = qc.process_file("tests/resources/example.fastq").unwrap();
+        let platform = report.basic_statistics().platform;
+        assert_eq!(platform.as_deref(), Some("Illumina HiSeq 2500"));
+        assert!(report.lanes().is_none());
+        let run = report.run_info().unwrap();
+        assert_eq!(
+            (run.runs, run.flowcells),
+            (vec!["723".to_string()], vec!["HG32CBCX2".to_string()])
+        );
+        assert_eq!((run.lanes, run.channels), (vec![1], 0));
+        let modules = report.modules();
+        assert!(modules
+            .iter()
+            .all(|(name, _)| !name.starts_with("Per tile")));
+        assert!(modules.iter().all(|(name, _)| !name.starts_with("Poly-G")));
+        let fastq = b"@A00123:8:HVWKDDSXX:1:1101:1018:1000 1:N:0:ACGT\nACGTGGGG\n+\nFFFFFFFF\n";
+        let report = qc.process_bytes(fastq).unwrap();
+        let modules = report.modules();
+        assert!(modules.iter().any(|(name, _)| name.starts_with("Per tile")));
+        assert!(modules.iter().any(|(name, _)| name.starts_with("Poly-G")));
+    }
+    #[test]
+    fn test_duplicate_ids() {
+        let mut fastq = Vec::new();
+        for id in [
+            "read1 1:N:0:ACGT",
+            "read2",
+            "read1 1:N:0:TTTT",
+            "read3",
+            "read2",
+        ] {
+            fastq.extend(format!("@{}\nACGT\n+\nIIII\n", id).bytes());
+        }
+        let report = Qc::new(QcConfig::new()).process_bytes(&fastq).unwrap();
+        let statistics = report.basic_statistics();
+        assert_eq!(statistics.duplicate_ids, 2);
+        assert!(!statistics.duplicate_ids_estimated);
+    }
+    #[test]
+    fn test_casava() {
+        let mut fastq = Vec::new();
+        for (i, flag) in ["N", "Y", "N", "N"].iter().enumerate() {
+            let id = format!("M1:7:FC:1:1101:{}:2 1:{}:0:ATCACG", i, flag);
+            fastq.extend(format!("@{}\nACGT\n+\nIIII\n", id).bytes());
+        }
+        let report = Qc::new(QcConfig::new()).process_bytes(&fastq).unwrap();
+        let statistics = report.basic_statistics();
+        assert_eq!(statistics.total_sequences, 4);
+        assert_eq!(statistics.filtered_reads, 1);
+        assert_eq!(statistics.filtered_pct, 25.0);
+        assert!(!statistics.filtered_excluded);
+        let config = QcConfig::new().casava(true);
+        let report = Qc::new(config).process_bytes(&fastq).unwrap();
+        let statistics = report.basic_statistics();
+        assert_eq!(statistics.total_sequences, 3);
+        assert_eq!(statistics.filtered_reads, 1);
+        assert!(statistics.filtered_excluded);
+    }
+    #[test]
+    fn test_umis() {
+        let mut fastq = Vec::new();
+        for umi in ["ACGTACGT", "ACGTACGT", "TTGCAGGA", "CCATGGTA"] {
+            fastq.extend(format!("@M1:7:FC:1:1101:1:2:{} 1:N:0:1\nACGT\n+\nIIII\n", umi).bytes());
+        }
+        let report = Qc::new(QcConfig::new()).process_bytes(&fastq).unwrap();
+        let umis = report.umis().unwrap();
+        assert_eq!((umis.reads, umis.distinct), (4, 3));
+        assert_eq!(umis.top[0], ("ACGTACGT".to_string(), 2, 50.0));
+        assert_eq!(umis.composition.len(), 8);
+        assert!(umis.diversity < 100.0);
+        let report = Qc::new(QcConfig::new().umi_length(2))
+            .process_bytes(&fastq)
+            .unwrap();
+        assert_eq!(report.umis().unwrap().top, [("AC".to_string(), 4, 100.0)]);
+        let report = Qc::new(QcConfig::new())
+            .process_file("tests/resources/example.fastq")
+            .unwrap();
+        assert!(report.umis().is_none());
+    }
+    #[test]
+    fn test_barcodes() {
+        let mut fastq = Vec::new();
+        for (read, index) in ["ATCACG", "ATCACG", "ATCACG", "CGATGT"].iter().enumerate() {
+            let header = format!("@M1:7:FC:1:1101:{}:2 1:N:0:{}", read, index);
+            fastq.extend(format!("{}\nACGT\n+\nIIII\n", header).bytes());
+        }
+        let report = Qc::new(QcConfig::new()).process_bytes(&fastq).unwrap();
+        let barcodes = report.barcodes().unwrap();
+        assert_eq!((barcodes.reads, barcodes.expected.as_str()), (4, "ATCACG"));
+        assert_eq!(
+            (barcodes.unexpected, barcodes.unexpected_percentage),
+            (1, 25.0)
+        );
+        assert_eq!(barcodes.top[1].barcode, "CGATGT");
+        assert_eq!(barcodes.top[1].mismatches, 6);
+        let report = Qc::new(QcConfig::new())
+            .process_file("tests/resources/example.fastq")
+            .unwrap();
+        assert!(report.barcodes().is_none());
+    }
+    #[test]
+    fn test_phix() {
+        let fastq = std::fs::read_to_string("tests/resources/example.fastq").unwrap();
+        let read = fastq.lines().nth(1).unwrap();
+        let fasta = format!(">phix\n{}\n", read);
+        let report = Qc::new(QcConfig::new().phix(&fasta).unwrap())
+            .process_file("tests/resources/example.fastq")
+            .unwrap();
+        let screen = report.screen();
+        assert_eq!(screen.len(), 1);
+        assert_eq!(screen[0].reference, "PhiX");
+        assert!(screen[0].count >= 1);
+        assert_eq!(screen[0].screened, 200);
+        assert_eq!(screen[0].percentage, screen[0].count as f64 / 2.0);
+        assert_eq!(screen[0].unique_percentage, screen[0].percentage);
+        assert!(QcConfig::new().phix(">phix\nACGT\n").is_err());
+        let report = Qc::new(QcConfig::new())
+            .process_file("tests/resources/example.fastq")
+            .unwrap();
+        assert!(report.screen().is_empty());
+    }
+    #[test]
+    fn test_kmer_spectrum() {
+        let report = Qc::new(QcConfig::new().kmer_spectrum(1))
+            .process_file("tests/resources/example.fastq")
+            .unwrap();
+        let spectrum = report.kmer_spectrum().unwrap();
+        assert!(spectrum.distinct > 0);
+        let histogram = spectrum.histogram.iter().enumerate();
+        let kmers = histogram.map(|(i, kmers)| (i + 1) * kmers).sum::<usize>();
+        assert!(kmers <= spectrum.kmers);
+        assert!(spectrum.quality_error_rate.unwrap() > 0.0);
+        let report = Qc::new(QcConfig::new())
+            .process_file("tests/resources/example.fastq")
+            .unwrap();
+        assert!(report.kmer_spectrum().is_none());
+    }
+    #[test]
+    fn test_optical_duplicates() {
+        let report = Qc::new(QcConfig::new().optical_duplicates(2500))
+            .process_file("tests/resources/example.fastq")
+            .unwrap();
+        let optical = report.optical_duplicates().unwrap();
+        assert_eq!(optical.distance, 2500);
+        assert!(optical.reads > 0);
+        assert!(optical.optical <= optical.duplicates);
+        assert!((optical.optical + optical.pcr - optical.duplicates).abs() < 1e-9);
+        let report = Qc::new(QcConfig::new())
+            .process_file("tests/resources/example.fastq")
+            .unwrap();
+        assert!(report.optical_duplicates().is_none());
+    }
+    #[test]
+    fn test_simulated_trimming() {
+        let report = Qc::new(QcConfig::new().simulate_trimming(&[30, 20]))
+            .process_file("tests/resources/example.fastq")
+            .unwrap();
+        let simulated = report.simulated_trimming();
+        let trimmings = simulated
+            .iter()
+            .map(|trimmed| (trimmed.method.as_str(), trimmed.cutoff))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            trimmings,
+            [
+                ("BWA", 20),
+                ("sliding window", 20),
+                ("BWA", 30),
+                ("sliding window", 30)
+            ]
+        );
+        let reads = report.basic_statistics().total_sequences;
+        for trimmed in &simulated {
+            assert!(trimmed.reads <= reads);
+            assert!(trimmed.bases_percentage <= 100.0);
+            let lengths = trimmed
+                .lengths
+                .iter()
+                .map(|(_, count)| count)
+                .sum::<usize>();
+            assert_eq!(lengths, reads);
+        }
+        // Higher cutoffs trim more
+        assert!(simulated[2].bases <= simulated[0].bases);
+        let report = Qc::new(QcConfig::new())
+            .process_file("tests/resources/example.fastq")
+            .unwrap();
+        assert!(report.simulated_trimming().is_empty());
+    }
+    #[test]
+    fn test_invalid_reads() {
+        let path = std::env::temp_dir().join("fastqc-rs-test-invalid.fastq");
+        let fastq = "@read1\nACGT\n+\nIIII\n@read2\nACGT\n+\nII\n\
+            read3\nACGT\n+\nIIII\n@read4\nACGT\n+\nIIII\n@read5\nACGT\n";
+        std::fs::write(&path, fastq).unwrap();
+        let report = Qc::new(QcConfig::new()).process_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(report.basic_statistics().total_sequences, 2);
+        let invalid = report.invalid_reads();
+        assert_eq!(invalid.count, 3);
+        assert_eq!(
+            invalid.kinds,
+            [
+                ("length mismatch".to_string(), 1),
+                ("missing @ header".to_string(), 1),
+                ("truncated record".to_string(), 1)
+            ]
+        );
+        assert_eq!(invalid.records, [2, 3, 5]);
+        assert_eq!(report.basic_statistics().invalid_reads, 3);
+    }
+    #[test]
+    fn test_suggested_trimming() {
+        let mut fastq = Vec::new();
+        for _ in 0..10 {
+            let seq = "ACGTTGCATGCAGTCAGTCATGCA";
+            let qual = format!("{}{}{}", "+".repeat(3), "I".repeat(16), "#".repeat(5));
+            fastq.extend(format!("@read\n{}\n+\n{}\n", seq, qual).bytes());
+        }
+        let report = Qc::new(QcConfig::new()).process_bytes(&fastq).unwrap();
+        let trimming = report.suggested_trimming();
+        assert_eq!((trimming.five_prime, trimming.three_prime), (3, 19));
+        assert_eq!(trimming.quality_cutoff, 20.0);
+    }
+    #[test]
+    fn test_adapter_starts() {
+        let qc = Qc::new(QcConfig::new().nogroup(true));
+        let report = qc.process_file("tests/resources/example.fastq").unwrap();
+        let starts = report.adapter_starts();
+        let positions = starts
+            .iter()
+            .map(|starts| starts.position)
+            .collect::<Vec<_>>();
+        assert_eq!(positions, [47, 56, 69, 78, 88]);
+        // All reads have the same length, so the adapter content adds up the starts
+        let content = report.adapter_content();
+        let last = content
+            .iter()
+            .rfind(|entry| entry.adapter == starts[0].adapter)
+            .unwrap();
+        let pct = starts.iter().map(|starts| starts.percentage).sum::<f64>();
+        assert!((last.percentage - pct).abs() < 1e-9);
+    }
+    #[test]
+    fn test_snapshots() {
+        let qc = Qc::new(QcConfig::new().threads(4));
+        let mut reads = Vec::new();
+        let report = qc
+            .process_file_with_snapshots("tests/resources/example.fastq", 75, |snapshot| {
+                reads.push(snapshot.basic_statistics().total_sequences)
+            })
+            .unwrap();
+        assert_eq!(reads, [75, 150]);
+        assert_eq!(report.basic_statistics().total_sequences, 200);
+    }
+    #[test]
+    fn test_contaminants() {
+        let list = "# Probes\nProbe A\tAAACATGTCAATGGCCAAAAAAAACAGACA\n";
+        let qc = Qc::new(QcConfig::new().contaminants(list).unwrap());
+        let report = qc.process_file("tests/resources/example.fastq").unwrap();
+        let hits = report.contaminant_hits();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].contaminant, "Probe A");
+        assert_eq!(hits[0].sequences, 1);
+        assert_eq!(hits[0].percentage, 0.5);
+        assert!(report.overrepresented_sequences()[0]
+            .source
+            .starts_with("Probe A"));
+        assert!(report.render_html().unwrap().contains("Probe A"));
+        assert!(QcConfig::new().contaminants("# Empty\n").is_err());
+    }
+    #[test]
+    fn test_adapters() {
+        let list = ">Kit adapter\nAAACATGTCAATGGCC\n";
+        let qc = Qc::new(
+            QcConfig::new()
+                .adapters(list)
+                .unwrap()
+                .adapter("CCCCCCCCCC"),
+        );
+        let report = qc.process_file("tests/resources/example.fastq").unwrap();
+        let content = report.adapter_content();
+        assert!(content
+            .iter()
+            .all(|entry| entry.adapter != "Illumina Universal Adapter"));
+        let kit = content
+            .iter()
+            .filter(|entry| entry.adapter == "Kit adapter")
+            .collect::<Vec<_>>();
+        assert_eq!(kit.last().unwrap().percentage, 0.5);
+        assert!(content.iter().any(|entry| entry.adapter == "CCCCCCCCCC"));
+        assert!(QcConfig::new().adapters(">Empty\n").is_err());
+    }
+    #[test]
+    fn test_nogroup() {
+        let path = "tests/resources/example.fastq";
+        let grouped = Qc::new(QcConfig::new()).process_file(path).unwrap();
+        assert_eq!(grouped.per_base_quality().len(), 55);
+        assert_eq!(grouped.per_base_quality()[9].position, 9);
+        assert_eq!(grouped.per_base_quality()[10].position, 11);
+        let grouped = Qc::new(QcConfig::new().long_reads(true));
+        assert!(grouped.process_file(path).unwrap().per_base_quality().len() < 100);
+        let exact = Qc::new(QcConfig::new().long_reads(true).nogroup(true));
+        assert_eq!(
+            exact.process_file(path).unwrap().per_base_quality().len(),
+            101
+        );
+    }
+    #[test]
+    fn test_custom_module() {
+        let qc = Qc::new(QcConfig::new().threads(2).module(|| ReadCounter(0)));
+        let report = qc.process_file("tests/resources/example.fastq").unwrap();
+        assert_eq!(report.custom_modules()[0].rows, [["200"]]);
+        assert_eq!(
+            report.modules().last(),
+            Some(&("Read counter", Status::Warn))
+        );
+        assert!(report.status() >= Status::Warn);
+        let json = report.to_json();
+        assert_eq!(
+            json["files"][0]["custom_modules"][0]["data"][0]["Reads"],
+            "200"
+        );
+        assert!(report.render_html().unwrap().contains("Read counter"));
+        let bases = |threads| {
+            let qc = Qc::new(QcConfig::new().threads(threads).module(|| BaseCounter(0)));
+            let report = qc.process_file("tests/resources/example.fastq").unwrap();
+            report.custom_modules()[0].rows.clone()
+        };
+        assert_eq!(bases(1), [["20200"]]);
+        assert_eq!(bases(4), bases(1));
+    }
+}
@@ -0,0 +1,136 @@
+use crate::metrics::Sample;
+use md5::{Digest, Md5};
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+/// Provenance of a report for workflow managers like Nextflow and Snakemake, written as
+/// `<name>.qc_status.yaml` next to it.
+pub(crate) struct QcStatus<'a> {
+    /// Options given on the command line with their values.
+    pub(crate) parameters: Vec<(String, Vec<String>)>,
+    /// Input files with their MD5 checksum, if they are local files.
+    pub(crate) inputs: Vec<(String, Option<String>)>,
+    /// The most severe module status of the files.
+    pub(crate) status: &'static str,
+    pub(crate) files: &'a [Sample],
+}
+
+/// Returns the MD5 checksum of a local file as hex digits like `md5sum`, or `None` for
+/// standard input, URLs and directories.
+pub(crate) fn md5(path: &str) -> io::Result<Option<String>> {
+    if crate::input::is_stdin(path) || crate::remote::is_url(path) || !Path::new(path).is_file() {
+        return Ok(None);
+    }
+    let mut hasher = Md5::new();
+    io::copy(&mut File::open(path)?, &mut hasher)?;
+    Ok(Some(
+        hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect(),
+    ))
+}
+
+/// Quotes a string as YAML scalar, which JSON strings are.
+fn quote(text: &str) -> String {
+    serde_json::to_string(text).unwrap()
+}
+
+impl QcStatus<'_> {
+    /// Renders the provenance as YAML with the version under `versions` like the
+    /// `versions.yml` of nf-core modules.
+    pub(crate) fn render(&self) -> String {
+        let mut yaml = String::new();
+        writeln!(yaml, "versions:").unwrap();
+        writeln!(yaml, "  fastqc-rs: {}", quote(env!("CARGO_PKG_VERSION"))).unwrap();
+        if self.parameters.is_empty() {
+            writeln!(yaml, "parameters: {{}}").unwrap();
+        } else {
+            writeln!(yaml, "parameters:").unwrap();
+        }
+        for (name, values) in &self.parameters {
+            match values.as_slice() {
+                [value] => writeln!(yaml, "  {}: {}", name, quote(value)).unwrap(),
+                values => {
+                    let values = values.iter().map(|value| quote(value)).collect::<Vec<_>>();
+                    writeln!(yaml, "  {}: [{}]", name, values.join(", ")).unwrap()
+                }
+            }
+        }
+        writeln!(yaml, "inputs:").unwrap();
+        for (path, md5) in &self.inputs {
+            writeln!(yaml, "  - path: {}", quote(path)).unwrap();
+            match md5 {
+                Some(md5) => writeln!(yaml, "    md5: {}", quote(md5)).unwrap(),
+                None => writeln!(yaml, "    md5: null").unwrap(),
+            }
+        }
+        writeln!(yaml, "status: {}", self.status).unwrap();
+        writeln!(yaml, "files:").unwrap();
+        for file in self.files {
+            writeln!(yaml, "  - file: {}", quote(&file.file)).unwrap();
+            writeln!(yaml, "    modules:").unwrap();
+            for (module, status) in &file.statuses {
+                writeln!(yaml, "      {}: {}", quote(module), status).unwrap();
+            }
+        }
+        yaml
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{md5, QcStatus};
+    use crate::metrics::Sample;
+    #[test]
+    fn test_qc_status() {
+        let path = std::env::temp_dir().join(format!("fqc-md5-{}.fastq", std::process::id()));
+        std::fs::write(&path, "@r1\nACGT\n+\nIIII\n").unwrap();
+        let path = path.to_str().unwrap();
+        let checksum = md5(path).unwrap();
+        assert_eq!(
+            checksum.as_deref(),
+            Some("54fbecfaa43146c14500b3fac0e8146e")
+        );
+        assert_eq!(md5("-").unwrap(), None);
+        let files = [Sample {
+            file: "reads.fastq".to_string(),
+            reads: 1,
+            bases: 4,
+            q30: Some(100.0),
+            duplication: None,
+            adapters: None,
+            statuses: vec![
+                ("Basic Statistics".to_string(), "pass"),
+                ("Kmer Content".to_string(), "warn"),
+            ],
+        }];
+        let status = QcStatus {
+            parameters: vec![
+                ("k".to_string(), vec!["5".to_string()]),
+                (
+                    "disable".to_string(),
+                    vec!["a".to_string(), "b".to_string()],
+                ),
+            ],
+            inputs: vec![("reads.fastq".to_string(), checksum)],
+            status: "warn",
+            files: &files,
+        };
+        let yaml = status.render();
+        assert!(yaml.starts_with(&format!(
+            "versions:\n  fastqc-rs: \"{}\"\nparameters:\n  k: \"5\"\n  disable: [\"a\", \"b\"]\n",
+            env!("CARGO_PKG_VERSION")
+        )));
+        assert!(yaml.contains(
+            "inputs:\n  - path: \"reads.fastq\"\n    md5: \"54fbecfaa43146c14500b3fac0e8146e\"\n"
+        ));
+        assert!(yaml.ends_with(
+            "status: warn\nfiles:\n  - file: \"reads.fastq\"\n    modules:\n      \"Basic Statistics\": pass\n      \"Kmer Content\": warn\n"
+        ));
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,294 @@
+use crate::bins::{group_counts, Binning};
+use crate::encoding::{char_index, index_char, PhredEncoding, QUALITY_CHARS};
+use crate::input::Record;
+use crate::modules::{Module, ModuleResult, QcModule, Threshold, Thresholds};
+use crate::process::{most_severe, quartiles};
+use crate::qc::Status;
+use crate::simd::count_qualities;
+
+/// Counts the quality characters of the bases at each position bin.
+pub(crate) struct BaseQualityCounter {
+    binning: Binning,
+    /// Number of bases per quality character at each position bin.
+    counts: Vec<[usize; QUALITY_CHARS]>,
+    /// Offset of the qualities, which is detected from the lowest quality unless given.
+    phred_offset: Option<u8>,
+    /// Median quality at any position at or below which the module warns or fails.
+    median: Threshold,
+    /// Lower quartile of the qualities at any position below which the module warns
+    /// or fails.
+    lower_quartile: Threshold,
+}
+
+impl Default for BaseQualityCounter {
+    fn default() -> Self {
+        BaseQualityCounter::new(Binning::default(), None, &Thresholds::default())
+    }
+}
+
+impl BaseQualityCounter {
+    pub(crate) fn new(binning: Binning, phred_offset: Option<u8>, thresholds: &Thresholds) -> Self {
+        BaseQualityCounter {
+            binning,
+            counts: Vec::new(),
+            phred_offset,
+            median: thresholds.base_quality_median,
+            lower_quartile: thresholds.base_quality_lower_quartile,
+        }
+    }
+
+    pub(crate) fn observe(&mut self, qualities: &[u8]) {
+        let bins = self.binning.bins(qualities.len());
+        if self.counts.len() < bins {
+            self.counts.resize(bins, [0; QUALITY_CHARS]);
+        }
+        if self.binning == Binning::Adaptive {
+            for (pos, &q) in qualities.iter().enumerate() {
+                self.counts[self.binning.bin(pos)][char_index(q)] += 1;
+            }
+        } else {
+            // Every position is its own bin
+            count_qualities(qualities, &mut self.counts);
+        }
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &BaseQualityCounter) {
+        if self.counts.len() < other.counts.len() {
+            self.counts.resize(other.counts.len(), [0; QUALITY_CHARS]);
+        }
+        for (counts, other) in self.counts.iter_mut().zip(&other.counts) {
+            for (count, other) in counts.iter_mut().zip(other) {
+                *count += other;
+            }
+        }
+    }
+
+    /// Returns the number of bases per quality character at each position bin.
+    pub(crate) fn counts(&self) -> &[[usize; QUALITY_CHARS]] {
+        &self.counts
+    }
+
+    /// Returns the number of bases per quality character over all positions.
+    pub(crate) fn totals(&self) -> [usize; QUALITY_CHARS] {
+        let mut totals = [0; QUALITY_CHARS];
+        for counts in &self.counts {
+            for (total, count) in totals.iter_mut().zip(counts) {
+                *total += count;
+            }
+        }
+        totals
+    }
+
+    /// Returns the encoding of the qualities, which is detected from the lowest quality
+    /// unless given.
+    pub(crate) fn encoding(&self) -> PhredEncoding {
+        let lowest = self
+            .counts
+            .iter()
+            .filter_map(|qualities| qualities.iter().position(|&count| count > 0))
+            .min();
+        encoding(self.phred_offset, lowest)
+    }
+}
+
+impl QcModule for BaseQualityCounter {
+    fn observe(&mut self, record: &Record) {
+        if let Some(qualities) = record.qual() {
+            self.observe(qualities);
+        }
+    }
+
+    fn is_mergeable() -> bool {
+        true
+    }
+
+    fn merge(&mut self, other: &dyn QcModule) {
+        if let Some(other) = other.downcast_ref::<BaseQualityCounter>() {
+            self.merge(other);
+        }
+    }
+
+    fn finish(&self) -> ModuleResult {
+        let encoding = self.encoding();
+        let groups = self.binning.groups_of(self.counts.len());
+        let mut status = "pass";
+        let mut rows = Vec::new();
+        for (bins, qualities) in group_counts(&self.counts, &groups) {
+            let scores = encoding.scores(&qualities);
+            let (sum, len) = scores
+                .iter()
+                .enumerate()
+                .fold((0_usize, 0_usize), |(s, l), (q, c)| (s + q * c, l + c));
+            let values = quartiles(&scores);
+            // Unlike the other thresholds, a median equal to the threshold counts
+            let median = values[2] as f64;
+            let median_status = if median <= self.median.fail {
+                "fail"
+            } else if median <= self.median.warn {
+                "warn"
+            } else {
+                "pass"
+            };
+            status = most_severe([
+                status,
+                median_status,
+                self.lower_quartile.below(values[1] as f64),
+            ]);
+            rows.push([
+                self.binning.label(&bins),
+                format!("{:.2}", sum as f64 / len as f64),
+                values[2].to_string(),
+                values[1].to_string(),
+                values[3].to_string(),
+            ]);
+        }
+        let mut result = ModuleResult::new(Module::PerBaseQuality.name(), Status::parse(status))
+            .with_columns(&["Base", "Mean", "Median", "Lower Quartile", "Upper Quartile"]);
+        for row in &rows {
+            result = result.with_row(row);
+        }
+        result
+    }
+}
+
+/// Counts the reads by their mean quality character.
+pub(crate) struct SequenceQualityCounter {
+    /// Number of reads per mean quality character.
+    counts: [usize; QUALITY_CHARS],
+    /// Index of the lowest quality character of any base, from which the encoding is
+    /// detected.
+    lowest: Option<usize>,
+    /// Offset of the qualities, which is detected from the lowest quality unless given.
+    phred_offset: Option<u8>,
+    /// Most frequent mean quality below which the module warns or fails.
+    threshold: Threshold,
+}
+
+impl Default for SequenceQualityCounter {
+    fn default() -> Self {
+        SequenceQualityCounter::new(None, Thresholds::default().sequence_quality)
+    }
+}
+
+impl SequenceQualityCounter {
+    pub(crate) fn new(phred_offset: Option<u8>, threshold: Threshold) -> Self {
+        SequenceQualityCounter {
+            counts: [0; QUALITY_CHARS],
+            lowest: None,
+            phred_offset,
+            threshold,
+        }
+    }
+
+    pub(crate) fn observe(&mut self, qualities: &[u8]) {
+        if let Some(&lowest) = qualities.iter().min() {
+            let lowest = char_index(lowest);
+            self.lowest = Some(self.lowest.map_or(lowest, |other| other.min(lowest)));
+            // Like FastQC, the mean quality of a read is truncated
+            let sum = qualities.iter().map(|&q| q as usize).sum::<usize>();
+            let mean = (sum / qualities.len()).min(u8::MAX as usize) as u8;
+            self.counts[char_index(mean)] += 1;
+        }
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &SequenceQualityCounter) {
+        for (count, other) in self.counts.iter_mut().zip(&other.counts) {
+            *count += other;
+        }
+        self.lowest = match (self.lowest, other.lowest) {
+            (Some(lowest), Some(other)) => Some(lowest.min(other)),
+            (lowest, other) => lowest.or(other),
+        };
+    }
+
+    /// Returns the number of reads per mean quality character.
+    pub(crate) fn counts(&self) -> &[usize; QUALITY_CHARS] {
+        &self.counts
+    }
+}
+
+impl QcModule for SequenceQualityCounter {
+    fn observe(&mut self, record: &Record) {
+        if let Some(qualities) = record.qual() {
+            self.observe(qualities);
+        }
+    }
+
+    fn is_mergeable() -> bool {
+        true
+    }
+
+    fn merge(&mut self, other: &dyn QcModule) {
+        if let Some(other) = other.downcast_ref::<SequenceQualityCounter>() {
+            self.merge(other);
+        }
+    }
+
+    fn finish(&self) -> ModuleResult {
+        let scores = encoding(self.phred_offset, self.lowest).scores(&self.counts);
+        let status = match scores
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, &count)| count)
+        {
+            Some((_, 0)) | None => "pass",
+            Some((quality, _)) => self.threshold.below(quality as f64),
+        };
+        let mut result =
+            ModuleResult::new(Module::PerSequenceQuality.name(), Status::parse(status))
+                .with_columns(&["Quality", "Count"]);
+        for (quality, &count) in scores.iter().enumerate().filter(|(_, &count)| count > 0) {
+            result = result.with_row(&[quality, count]);
+        }
+        result
+    }
+}
+
+/// Returns the encoding with the given offset, or the one detected from the index of
+/// the lowest quality character.
+fn encoding(phred_offset: Option<u8>, lowest: Option<usize>) -> PhredEncoding {
+    match phred_offset {
+        Some(offset) => PhredEncoding::from_offset(offset),
+        None => PhredEncoding::detect(lowest.map_or(b'!', index_char)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{BaseQualityCounter, SequenceQualityCounter};
+    use crate::modules::QcModule;
+    use crate::qc::Status;
+    #[test]
+    fn test_base_qualities() {
+        let mut counter = BaseQualityCounter::default();
+        counter.observe(b"II#");
+        let mut other = BaseQualityCounter::default();
+        other.observe(b"IIII");
+        counter.merge(&other);
+        assert_eq!(counter.counts().len(), 4);
+        assert_eq!(counter.totals()[b'I' as usize - 33], 6);
+        assert_eq!(counter.encoding().offset, 33);
+        let result = counter.finish();
+        // The third position has qualities of 2 and 40, so a median of 21
+        assert_eq!(result.status, Status::Warn);
+        assert_eq!(result.rows[0], ["1", "40.00", "40", "40", "40"]);
+        assert_eq!(result.rows[2], ["3", "21.00", "21", "11.5", "30.5"]);
+    }
+    #[test]
+    fn test_sequence_qualities() {
+        let mut counter = SequenceQualityCounter::default();
+        counter.observe(b"II#");
+        counter.observe(b"");
+        let mut other = SequenceQualityCounter::default();
+        other.observe(b"IIII");
+        other.observe(b"IIII");
+        counter.merge(&other);
+        assert_eq!(counter.counts().iter().sum::<usize>(), 3);
+        let result = counter.finish();
+        assert_eq!(result.status, Status::Pass);
+        assert_eq!(result.rows, [["27", "1"], ["40", "2"]]);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::sketch::{hash, HyperLogLog};
+use rustc_hash::FxHashSet as HashSet;
+
+/// Default memory for the hashes of the read IDs, in bytes.
+pub(crate) const DEFAULT_MEMORY: usize = 1 << 28;
+/// Approximate memory of a hash in the set of read IDs, in bytes.
+const ENTRY_SIZE: usize = 16;
+
+/// Counts the reads whose ID has been seen before, which hints at concatenated or
+/// repeated files. The IDs are hashed, and once the hashes exceed the memory budget,
+/// the number of distinct IDs is estimated.
+pub(crate) struct ReadIdCounter {
+    hashes: HashSet<u64>,
+    distinct: Option<HyperLogLog>,
+    /// Memory budget in bytes.
+    memory: usize,
+    reads: usize,
+}
+
+impl ReadIdCounter {
+    /// Creates a counter using about the given number of bytes.
+    pub(crate) fn new(memory: usize) -> Self {
+        ReadIdCounter {
+            hashes: HashSet::default(),
+            distinct: None,
+            memory,
+            reads: 0,
+        }
+    }
+
+    pub(crate) fn observe(&mut self, id: &[u8]) {
+        self.add(hash(read_name(id)));
+        self.reads += 1;
+    }
+
+    fn add(&mut self, hash: u64) {
+        match self.distinct.as_mut() {
+            Some(distinct) => distinct.add(hash),
+            None => {
+                self.hashes.insert(hash);
+                if self.hashes.len() * ENTRY_SIZE > self.memory {
+                    self.start_estimate();
+                }
+            }
+        }
+    }
+
+    /// Replaces the hashes by an estimate of their number.
+    fn start_estimate(&mut self) {
+        let mut distinct = HyperLogLog::new();
+        for hash in self.hashes.drain() {
+            distinct.add(hash);
+        }
+        self.hashes.shrink_to_fit();
+        self.distinct = Some(distinct);
+    }
+
+    /// Adds the IDs of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &ReadIdCounter) {
+        if other.distinct.is_some() && self.distinct.is_none() {
+            self.start_estimate();
+        }
+        for &hash in &other.hashes {
+            self.add(hash);
+        }
+        if let (Some(distinct), Some(other)) = (self.distinct.as_mut(), other.distinct.as_ref()) {
+            distinct.merge(other);
+        }
+        self.reads += other.reads;
+    }
+
+    /// Returns whether the number of duplicates is estimated.
+    pub(crate) fn is_estimated(&self) -> bool {
+        self.distinct.is_some()
+    }
+
+    /// Returns the number of reads with the ID of a previous read.
+    pub(crate) fn duplicates(&self) -> usize {
+        let distinct = match &self.distinct {
+            Some(distinct) => distinct.estimate().round() as usize,
+            None => self.hashes.len(),
+        };
+        self.reads.saturating_sub(distinct)
+    }
+}
+
+/// Returns the name of the read without the comment after the first whitespace and
+/// the `/1` or `/2` of older Illumina mates.
+fn read_name(id: &[u8]) -> &[u8] {
+    let name = id
+        .split(|byte| byte.is_ascii_whitespace())
+        .next()
+        .unwrap_or_default();
+    match name {
+        [name @ .., b'/', b'1' | b'2'] => name,
+        name => name,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ReadIdCounter;
+    #[test]
+    fn test_duplicates() {
+        let mut counter = ReadIdCounter::new(1 << 20);
+        counter.observe(b"read1 1:N:0:ACGT");
+        counter.observe(b"read2/1");
+        counter.observe(b"read1 1:N:0:TTTT");
+        let mut other = ReadIdCounter::new(1 << 20);
+        other.observe(b"read2/2");
+        other.observe(b"read3");
+        counter.merge(&other);
+        assert_eq!(counter.duplicates(), 2);
+        assert!(!counter.is_estimated());
+        let mut small = ReadIdCounter::new(1 << 10);
+        for i in 0..1000 {
+            small.observe(format!("read{}", i % 500).as_bytes());
+        }
+        assert!(small.is_estimated());
+        assert!((small.duplicates() as f64 - 500.0).abs() < 25.0);
+    }
+}
@@ -0,0 +1,247 @@
+use crate::illumina::{split_id, IlluminaHeader};
+use itertools::Itertools;
+use std::collections::BTreeMap;
+
+/// Number of distinct instruments, runs and flowcells counted, beyond which further
+/// ones are left out.
+const MAX_RUNS: usize = 32;
+
+/// Checks that the read names of a file follow a single scheme, from the same
+/// instrument, run and flowcell with tiles in order and the same mate numbers, as
+/// mixtures usually come from accidentally concatenated files.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ReadNameCounter {
+    /// Number of reads named by each scheme.
+    schemes: BTreeMap<&'static str, usize>,
+    /// Number of Illumina reads of each instrument, run and flowcell.
+    runs: BTreeMap<String, usize>,
+    /// Number of reads without a mate number, of the first and of the second mate.
+    mates: [usize; 3],
+    /// Lane and tile of the last Illumina read.
+    last_tile: Option<(u32, u32)>,
+    /// Number of Illumina reads from a lower lane or tile than the read before.
+    tile_restarts: usize,
+    /// Number of Illumina reads with a lane, tile or coordinate which is not a number.
+    implausible_coordinates: usize,
+}
+
+/// The consistency of the read names of a file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct ReadNames {
+    pub(crate) schemes: Vec<(&'static str, usize)>,
+    /// Number of reads of each instrument, run and flowcell, named like
+    /// `A00123:8:HVWKDDSXX`.
+    pub(crate) runs: Vec<(String, usize)>,
+    pub(crate) mates: [usize; 3],
+    /// Number of times the tiles started over, or `None` if the reads were sampled in
+    /// random order.
+    pub(crate) tile_restarts: Option<usize>,
+    pub(crate) implausible_coordinates: usize,
+}
+
+impl ReadNameCounter {
+    pub(crate) fn observe(&mut self, id: &[u8]) {
+        let Ok(id) = std::str::from_utf8(id) else {
+            return;
+        };
+        let Some((name, comment)) = split_id(id) else {
+            return;
+        };
+        let pacbio = name.starts_with('m') && name.split('/').count() >= 3;
+        let (name, mate) = match name.rsplit_once('/') {
+            Some((name, "1")) => (name, 1),
+            Some((name, "2")) => (name, 2),
+            _ => (name, 0),
+        };
+        // Casava 1.8+ headers give the mate in the comment, e.g. `1:N:0:ATCACG`
+        let mate = match comment.and_then(|comment| comment.split_once(':')) {
+            Some(("1", _)) if mate == 0 => 1,
+            Some(("2", _)) if mate == 0 => 2,
+            _ => mate,
+        };
+        self.mates[mate] += 1;
+        let scheme = if id.contains(" runid=") {
+            "nanopore"
+        } else if pacbio {
+            "PacBio"
+        } else if let Some(header) = IlluminaHeader::from_name(name) {
+            self.observe_position(&header);
+            match header.run {
+                Some((run, flowcell)) => {
+                    let run = format!("{}:{}:{}", header.instrument, run, flowcell);
+                    if self.runs.len() < MAX_RUNS || self.runs.contains_key(&run) {
+                        *self.runs.entry(run).or_default() += 1;
+                    }
+                    "Illumina"
+                }
+                None => "Illumina (before Casava 1.8)",
+            }
+        } else {
+            "other"
+        };
+        *self.schemes.entry(scheme).or_default() += 1;
+    }
+
+    /// Checks the lane, tile, x and y coordinate of an Illumina read.
+    fn observe_position(&mut self, header: &IlluminaHeader) {
+        let numbers = [header.lane, header.tile, header.x, header.y].map(str::parse::<u32>);
+        let [Ok(lane), Ok(tile), Ok(_), Ok(_)] = numbers else {
+            self.implausible_coordinates += 1;
+            return;
+        };
+        if lane == 0 || tile == 0 {
+            self.implausible_coordinates += 1;
+        }
+        if self.last_tile.is_some_and(|last| (lane, tile) < last) {
+            self.tile_restarts += 1;
+        }
+        self.last_tile = Some((lane, tile));
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread. Restarts of
+    /// the tiles between the reads of both are not counted, as they may be in any
+    /// order.
+    pub(crate) fn merge(&mut self, other: &ReadNameCounter) {
+        for (&scheme, &reads) in &other.schemes {
+            *self.schemes.entry(scheme).or_default() += reads;
+        }
+        for (run, &reads) in &other.runs {
+            if self.runs.len() < MAX_RUNS || self.runs.contains_key(run) {
+                *self.runs.entry(run.clone()).or_default() += reads;
+            }
+        }
+        for (mates, other) in self.mates.iter_mut().zip(other.mates) {
+            *mates += other;
+        }
+        self.last_tile = self.last_tile.or(other.last_tile);
+        self.tile_restarts += other.tile_restarts;
+        self.implausible_coordinates += other.implausible_coordinates;
+    }
+
+    /// Returns the consistency of the names, with the order of the tiles only checked
+    /// if the reads were observed in the order of the file.
+    pub(crate) fn read_names(&self, ordered: bool) -> ReadNames {
+        ReadNames {
+            schemes: self
+                .schemes
+                .iter()
+                .map(|(&scheme, &reads)| (scheme, reads))
+                .collect(),
+            runs: self
+                .runs
+                .iter()
+                .map(|(run, &reads)| (run.clone(), reads))
+                .collect(),
+            mates: self.mates,
+            tile_restarts: ordered.then_some(self.tile_restarts),
+            implausible_coordinates: self.implausible_coordinates,
+        }
+    }
+}
+
+impl ReadNames {
+    /// Returns a warning about every inconsistency of the names.
+    pub(crate) fn warnings(&self) -> Vec<String> {
+        let counts = |counts: &[(String, usize)]| {
+            counts
+                .iter()
+                .map(|(name, reads)| format!("{} {}", name, reads))
+                .join(", ")
+        };
+        let mut warnings = Vec::new();
+        if self.schemes.len() > 1 {
+            let schemes = self
+                .schemes
+                .iter()
+                .map(|(scheme, reads)| (scheme.to_string(), *reads))
+                .collect::<Vec<_>>();
+            warnings.push(format!(
+                "Read names of several schemes: {}",
+                counts(&schemes)
+            ));
+        }
+        if self.runs.len() > 1 {
+            warnings.push(format!(
+                "Reads of several instruments, runs or flowcells: {}",
+                counts(&self.runs)
+            ));
+        }
+        if self.mates.iter().filter(|&&reads| reads > 0).count() > 1 {
+            let mates = ["no mate", "mate 1", "mate 2"]
+                .iter()
+                .zip(self.mates)
+                .filter(|(_, reads)| *reads > 0)
+                .map(|(mate, reads)| (mate.to_string(), reads))
+                .collect::<Vec<_>>();
+            warnings.push(format!("Mixed mate numbers: {}", counts(&mates)));
+        }
+        if let Some(restarts) = self.tile_restarts.filter(|&restarts| restarts > 0) {
+            warnings.push(format!(
+                "The lanes or tiles start over at {} reads, as in concatenated files",
+                restarts
+            ));
+        }
+        if self.implausible_coordinates > 0 {
+            warnings.push(format!(
+                "{} Illumina read names with an invalid lane, tile or coordinate",
+                self.implausible_coordinates
+            ));
+        }
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ReadNameCounter;
+    #[test]
+    fn test_read_names() {
+        let mut counter = ReadNameCounter::default();
+        for id in [
+            "A00123:8:HVWKDDSXX:1:1101:1018:1000 1:N:0:ACGT",
+            "A00123:8:HVWKDDSXX:1:1102:1018:1000 1:N:0:ACGT",
+            "SRR9130495.1 A00123:8:HVWKDDSXX:2:1101:1330:1935/1",
+        ] {
+            counter.observe(id.as_bytes());
+        }
+        let names = counter.read_names(true);
+        assert_eq!(names.schemes, [("Illumina", 3)]);
+        assert_eq!(names.mates, [0, 3, 0]);
+        assert!(names.warnings().is_empty());
+        // A second file appended to the first starts over at the first tile
+        let mut other = ReadNameCounter::default();
+        for id in [
+            "D00236:723:HG32CBCX2:1:1101:1330:1935 2:N:0:1",
+            "D00236:723:HG32CBCX2:1:x:1330:1935 2:N:0:1",
+            "HWUSI-EAS100R:6:73:941:1973#0/1",
+            "read1",
+        ] {
+            counter.observe(id.as_bytes());
+            other.observe(id.as_bytes());
+        }
+        let names = counter.read_names(true);
+        assert_eq!(names.tile_restarts, Some(1));
+        assert_eq!(names.implausible_coordinates, 1);
+        assert_eq!(
+            names.warnings(),
+            [
+                "Read names of several schemes: Illumina 5, Illumina (before Casava 1.8) 1, other 1",
+                "Reads of several instruments, runs or flowcells: A00123:8:HVWKDDSXX 3, D00236:723:HG32CBCX2 2",
+                "Mixed mate numbers: no mate 1, mate 1 4, mate 2 2",
+                "The lanes or tiles start over at 1 reads, as in concatenated files",
+                "1 Illumina read names with an invalid lane, tile or coordinate",
+            ]
+        );
+        counter.merge(&other);
+        let names = counter.read_names(false);
+        assert_eq!(names.schemes[0], ("Illumina", 7));
+        assert_eq!(names.tile_restarts, None);
+        let mut nanopore = ReadNameCounter::default();
+        nanopore.observe(b"r1 runid=abc ch=1 flow_cell_id=FAK12345");
+        nanopore.observe(b"m64011_190830_220126/1/ccs");
+        assert_eq!(
+            nanopore.read_names(true).schemes,
+            [("PacBio", 1), ("nanopore", 1)]
+        );
+    }
+}
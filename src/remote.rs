@@ -0,0 +1,228 @@
+use crate::error::FastqcError;
+use std::io::Read;
+
+/// Returns whether the given input is an HTTP(S) or S3 URL.
+pub(crate) fn is_url(input: &str) -> bool {
+    ["http://", "https://", "s3://"]
+        .iter()
+        .any(|scheme| input.starts_with(scheme))
+}
+
+/// Returns the path of a URL without query and fragment, so that its file name and
+/// extension can be used like those of local files.
+pub(crate) fn url_path(url: &str) -> &str {
+    url.split(['?', '#'].as_ref()).next().unwrap_or(url)
+}
+
+/// Opens a URL for streaming its content while it is downloaded.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn open(url: &str) -> Result<Box<dyn Read + Send>, FastqcError> {
+    if let Some(location) = url.strip_prefix("s3://") {
+        return open_s3(location);
+    }
+    let response = reqwest::get(url)?.error_for_status()?;
+    Ok(Box::new(response))
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn open(_: &str) -> Result<Box<dyn Read + Send>, FastqcError> {
+    Err("URLs cannot be opened in WebAssembly builds.".into())
+}
+
+#[cfg(all(not(feature = "s3"), not(target_arch = "wasm32")))]
+fn open_s3(_: &str) -> Result<Box<dyn Read + Send>, FastqcError> {
+    Err("S3 URLs require fastqc-rs to be built with the `s3` feature.".into())
+}
+
+/// Opens an object given as `bucket/key`. Requests are signed with the credentials in
+/// `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY` and `AWS_SESSION_TOKEN` if present, so
+/// public buckets work without credentials. The region is taken from `AWS_REGION` or
+/// `AWS_DEFAULT_REGION` and S3 compatible services can be used with `AWS_ENDPOINT_URL`.
+#[cfg(all(feature = "s3", not(target_arch = "wasm32")))]
+fn open_s3(location: &str) -> Result<Box<dyn Read + Send>, FastqcError> {
+    use std::env::var;
+    let (bucket, key) = location
+        .split_once('/')
+        .ok_or_else(|| format!("Invalid S3 URL s3://{}", location))?;
+    let region = var("AWS_REGION")
+        .or_else(|_| var("AWS_DEFAULT_REGION"))
+        .unwrap_or_else(|_| "us-east-1".to_string());
+    let (host, path) = match var("AWS_ENDPOINT_URL") {
+        Ok(endpoint) => {
+            let host = endpoint
+                .trim_start_matches("https://")
+                .trim_start_matches("http://")
+                .trim_end_matches('/')
+                .to_string();
+            (host, format!("/{}/{}", bucket, s3::encode_path(key)))
+        }
+        Err(_) => (
+            format!("{}.s3.{}.amazonaws.com", bucket, region),
+            format!("/{}", s3::encode_path(key)),
+        ),
+    };
+    let scheme = match var("AWS_ENDPOINT_URL") {
+        Ok(endpoint) if endpoint.starts_with("http://") => "http",
+        _ => "https",
+    };
+    let mut request = reqwest::Client::new().get(&format!("{}://{}{}", scheme, host, path));
+    if let (Ok(access_key), Ok(secret_key)) =
+        (var("AWS_ACCESS_KEY_ID"), var("AWS_SECRET_ACCESS_KEY"))
+    {
+        let credentials = s3::Credentials {
+            access_key,
+            secret_key,
+            session_token: var("AWS_SESSION_TOKEN").ok(),
+        };
+        let time = chrono::Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        for (name, value) in s3::sign(&credentials, &region, &host, &path, &time) {
+            request = request.header(name, value);
+        }
+    }
+    let response = request.send()?.error_for_status()?;
+    Ok(Box::new(response))
+}
+
+#[cfg(all(feature = "s3", not(target_arch = "wasm32")))]
+mod s3 {
+    use hmac::{Hmac, Mac};
+    use sha2::{Digest, Sha256};
+
+    const UNSIGNED_PAYLOAD: &str = "UNSIGNED-PAYLOAD";
+
+    pub(super) struct Credentials {
+        pub(super) access_key: String,
+        pub(super) secret_key: String,
+        pub(super) session_token: Option<String>,
+    }
+
+    /// Percent-encodes an object key for use in a request path, keeping slashes.
+    pub(super) fn encode_path(key: &str) -> String {
+        key.bytes()
+            .map(|byte| match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                    (byte as char).to_string()
+                }
+                _ => format!("%{:02X}", byte),
+            })
+            .collect()
+    }
+
+    /// Returns the headers of a GET request signed with AWS Signature Version 4 at the
+    /// given time, e.g. `20130524T000000Z`.
+    pub(super) fn sign(
+        credentials: &Credentials,
+        region: &str,
+        host: &str,
+        path: &str,
+        time: &str,
+    ) -> Vec<(&'static str, String)> {
+        let mut headers = vec![
+            ("host", host.to_string()),
+            ("x-amz-content-sha256", UNSIGNED_PAYLOAD.to_string()),
+            ("x-amz-date", time.to_string()),
+        ];
+        if let Some(token) = &credentials.session_token {
+            headers.push(("x-amz-security-token", token.clone()));
+        }
+        let canonical_headers = headers
+            .iter()
+            .map(|(name, value)| format!("{}:{}\n", name, value.trim()))
+            .collect::<String>();
+        let signed_headers = headers
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(";");
+        let canonical_request = format!(
+            "GET\n{}\n\n{}\n{}\n{}",
+            path, canonical_headers, signed_headers, UNSIGNED_PAYLOAD
+        );
+        let date = &time[..8];
+        let scope = format!("{}/{}/s3/aws4_request", date, region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            time,
+            scope,
+            hex(&Sha256::digest(canonical_request.as_bytes()))
+        );
+        let key = signing_key(&credentials.secret_key, date, region, "s3");
+        let signature = hex(&hmac(&key, &string_to_sign));
+        headers.retain(|(name, _)| *name != "host");
+        headers.push((
+            "authorization",
+            format!(
+                "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+                credentials.access_key, scope, signed_headers, signature
+            ),
+        ));
+        headers
+    }
+
+    fn signing_key(secret_key: &str, date: &str, region: &str, service: &str) -> Vec<u8> {
+        let key = hmac(format!("AWS4{}", secret_key).as_bytes(), date);
+        let key = hmac(&key, region);
+        let key = hmac(&key, service);
+        hmac(&key, "aws4_request")
+    }
+
+    fn hmac(key: &[u8], data: &str) -> Vec<u8> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts any key length");
+        mac.update(data.as_bytes());
+        mac.finalize().into_bytes().to_vec()
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::{encode_path, hex, signing_key};
+        #[test]
+        fn test_signing_key() {
+            // Example from the AWS Signature Version 4 documentation
+            let key = signing_key(
+                "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+                "20120215",
+                "us-east-1",
+                "iam",
+            );
+            assert_eq!(
+                hex(&key),
+                "f4780e2d9f65fa895f9c67b32ce1baf0b0d8a43505a000a1a9e090d414db404d"
+            );
+        }
+        #[test]
+        fn test_encode_path() {
+            assert_eq!(encode_path("runs/a b+c.fq"), "runs/a%20b%2Bc.fq");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{is_url, open, url_path};
+    use crate::error::FastqcError;
+    #[test]
+    fn test_url() {
+        assert!(is_url("https://example.com/reads.fastq.gz"));
+        assert!(is_url("s3://bucket/reads.fastq.gz"));
+        assert!(!is_url("reads.fastq.gz"));
+        assert_eq!(
+            url_path("https://example.com/reads.fq.gz?token=abc"),
+            "https://example.com/reads.fq.gz"
+        );
+    }
+    #[test]
+    fn test_open_error() {
+        // Nothing listens on port 1, so the download fails
+        match open("http://127.0.0.1:1/reads.fastq") {
+            Err(FastqcError::Network { url, .. }) => {
+                assert!(url.starts_with("http://127.0.0.1:1/"))
+            }
+            Err(e) => panic!("Expected a network error, got {:?}", e),
+            Ok(_) => panic!("Expected a network error"),
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use crate::illumina::IlluminaHeader;
+use std::collections::BTreeSet;
+
+/// Number of distinct values kept of every field, beyond which further ones are left
+/// out, e.g. for reads merged from many runs.
+const MAX_VALUES: usize = 32;
+
+/// Collects the runs, flowcells and lanes of Illumina reads or the runs, flowcells and
+/// channels of nanopore reads from their headers, which trace a file back to its runs.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RunInfoCounter {
+    instruments: BTreeSet<String>,
+    runs: BTreeSet<String>,
+    flowcells: BTreeSet<String>,
+    lanes: BTreeSet<u32>,
+    /// Channels of the nanopore reads, of which only the number is reported.
+    channels: BTreeSet<u32>,
+}
+
+/// The runs a file was sequenced in.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct RunInfo {
+    pub(crate) instruments: Vec<String>,
+    pub(crate) runs: Vec<String>,
+    pub(crate) flowcells: Vec<String>,
+    pub(crate) lanes: Vec<u32>,
+    /// Number of channels of a nanopore flowcell which any read was sequenced in.
+    pub(crate) channels: usize,
+}
+
+impl RunInfoCounter {
+    pub(crate) fn observe(&mut self, id: &[u8]) {
+        let Ok(id) = std::str::from_utf8(id) else {
+            return;
+        };
+        if id.contains(" runid=") {
+            for field in id.split_whitespace().skip(1) {
+                match field.split_once('=') {
+                    Some(("runid", run)) => insert(&mut self.runs, run.to_string()),
+                    Some(("flow_cell_id", flowcell)) => {
+                        insert(&mut self.flowcells, flowcell.to_string())
+                    }
+                    Some(("ch", channel)) => {
+                        if let Ok(channel) = channel.parse() {
+                            self.channels.insert(channel);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            return;
+        }
+        let Some(header) = IlluminaHeader::parse(id.as_bytes()) else {
+            return;
+        };
+        if let Some(lane) = header.lane() {
+            insert(&mut self.instruments, header.instrument.to_string());
+            insert(&mut self.lanes, lane);
+            if let Some((run, flowcell)) = header.run {
+                insert(&mut self.runs, run.to_string());
+                insert(&mut self.flowcells, flowcell.to_string());
+            }
+        }
+    }
+
+    /// Adds the values of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &RunInfoCounter) {
+        for instrument in &other.instruments {
+            insert(&mut self.instruments, instrument.clone());
+        }
+        for run in &other.runs {
+            insert(&mut self.runs, run.clone());
+        }
+        for flowcell in &other.flowcells {
+            insert(&mut self.flowcells, flowcell.clone());
+        }
+        for &lane in &other.lanes {
+            insert(&mut self.lanes, lane);
+        }
+        self.channels.extend(&other.channels);
+    }
+
+    pub(crate) fn run_info(&self) -> RunInfo {
+        RunInfo {
+            instruments: self.instruments.iter().cloned().collect(),
+            runs: self.runs.iter().cloned().collect(),
+            flowcells: self.flowcells.iter().cloned().collect(),
+            lanes: self.lanes.iter().copied().collect(),
+            channels: self.channels.len(),
+        }
+    }
+}
+
+impl RunInfo {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.runs.is_empty() && self.flowcells.is_empty() && self.lanes.is_empty()
+    }
+}
+
+/// Adds a value to the set unless it already holds `MAX_VALUES` others.
+fn insert<T: Ord>(values: &mut BTreeSet<T>, value: T) {
+    if values.len() < MAX_VALUES {
+        values.insert(value);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{RunInfo, RunInfoCounter};
+    #[test]
+    fn test_run_info() {
+        let mut counter = RunInfoCounter::default();
+        counter.observe(b"SRR9130495.1 D00236:723:HG32CBCX2:1:1108:1330:1935/1");
+        counter.observe(b"A00123:8:HVWKDDSXX:2:1101:1018:1000 1:N:0:ACGT");
+        let mut other = RunInfoCounter::default();
+        other.observe(b"HWUSI-EAS100R:6:73:941:1973#0/1");
+        other.observe(b"read1");
+        counter.merge(&other);
+        let strings = |values: &[&str]| values.iter().map(|value| value.to_string()).collect();
+        assert_eq!(
+            counter.run_info(),
+            RunInfo {
+                instruments: strings(&["A00123", "D00236", "HWUSI-EAS100R"]),
+                runs: strings(&["723", "8"]),
+                flowcells: strings(&["HG32CBCX2", "HVWKDDSXX"]),
+                lanes: vec![1, 2, 6],
+                channels: 0,
+            }
+        );
+        let mut counter = RunInfoCounter::default();
+        for channel in [1, 2, 1] {
+            let id = format!(
+                "r1 runid=abc ch={} start_time=2020-01-01T00:00:00Z flow_cell_id=FAK12345",
+                channel
+            );
+            counter.observe(id.as_bytes());
+        }
+        let info = counter.run_info();
+        assert_eq!(
+            (info.runs, info.flowcells),
+            (strings(&["abc"]), strings(&["FAK12345"]))
+        );
+        assert_eq!((info.lanes.len(), info.channels), (0, 2));
+        assert!(RunInfoCounter::default().run_info().is_empty());
+    }
+}
@@ -0,0 +1,150 @@
+use crate::length_quality::mean_quality;
+use chrono::DateTime;
+use rustc_hash::FxHashMap as HashMap;
+
+/// Maximal number of intervals the run time is divided into.
+const MAX_INTERVALS: i64 = 200;
+
+/// Sums up yield and read qualities over the run time of nanopore reads, taken from
+/// the `start_time=` field in their headers.
+#[derive(Default)]
+pub(crate) struct RunTimeCounter {
+    /// Reads started in each minute since the epoch.
+    minutes: HashMap<i64, Reads>,
+}
+
+/// Number of reads and bases, and the mean read quality characters of the reads
+/// with qualities.
+#[derive(Default, Clone, Copy)]
+struct Reads {
+    reads: usize,
+    bases: usize,
+    with_qualities: usize,
+    quality_sum: u64,
+}
+
+impl Reads {
+    fn add(&mut self, other: &Reads) {
+        self.reads += other.reads;
+        self.bases += other.bases;
+        self.with_qualities += other.with_qualities;
+        self.quality_sum += other.quality_sum;
+    }
+}
+
+/// Yield and quality of the reads started in an interval of the run.
+pub(crate) struct RunInterval {
+    /// Start of the interval in hours since the start of the run.
+    pub(crate) hours: f64,
+    pub(crate) reads: usize,
+    pub(crate) bases: usize,
+    /// Bases of all reads started before the end of the interval.
+    pub(crate) cumulative_bases: usize,
+    /// Mean phred score of the reads, if they have qualities.
+    pub(crate) mean_quality: Option<f64>,
+}
+
+impl RunTimeCounter {
+    /// Adds a record if its header contains a start time.
+    pub(crate) fn observe(&mut self, id: &[u8], length: usize, qualities: Option<&[u8]>) {
+        let minute = match parse_start_time(id) {
+            Some(time) => time.div_euclid(60),
+            None => return,
+        };
+        let entry = self.minutes.entry(minute).or_default();
+        entry.reads += 1;
+        entry.bases += length;
+        if let Some(qualities) = qualities.filter(|qualities| !qualities.is_empty()) {
+            entry.with_qualities += 1;
+            entry.quality_sum += mean_quality(qualities) as u64;
+        }
+    }
+
+    /// Adds the reads of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &RunTimeCounter) {
+        for (&minute, reads) in &other.minutes {
+            self.minutes.entry(minute).or_default().add(reads);
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.minutes.is_empty()
+    }
+
+    /// Returns yield and mean phred score of the reads in consecutive intervals of the run.
+    pub(crate) fn timeline(&self, offset: u8) -> Vec<RunInterval> {
+        let (first, last) = match (self.minutes.keys().min(), self.minutes.keys().max()) {
+            (Some(&first), Some(&last)) => (first, last),
+            _ => return Vec::new(),
+        };
+        // Intervals are a whole number of minutes
+        let width = (last - first) / MAX_INTERVALS + 1;
+        let mut intervals = vec![Reads::default(); ((last - first) / width + 1) as usize];
+        for (&minute, reads) in &self.minutes {
+            intervals[((minute - first) / width) as usize].add(reads);
+        }
+        let mut cumulative_bases = 0;
+        intervals
+            .into_iter()
+            .enumerate()
+            .filter(|(_, interval)| interval.reads > 0)
+            .map(|(i, interval)| {
+                cumulative_bases += interval.bases;
+                RunInterval {
+                    hours: (i as i64 * width) as f64 / 60.0,
+                    reads: interval.reads,
+                    bases: interval.bases,
+                    cumulative_bases,
+                    mean_quality: match interval.with_qualities {
+                        0 => None,
+                        n => Some(interval.quality_sum as f64 / n as f64 - offset as f64),
+                    },
+                }
+            })
+            .collect()
+    }
+}
+
+/// Extracts the start time in seconds since the epoch from a nanopore read header,
+/// e.g. `@read_id runid=... start_time=2019-06-03T14:54:38Z flow_cell_id=...`.
+pub(crate) fn parse_start_time(id: &[u8]) -> Option<i64> {
+    let header = std::str::from_utf8(id).ok()?;
+    let value = header
+        .split_whitespace()
+        .find_map(|field| field.strip_prefix("start_time="))?;
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|time| time.timestamp())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_start_time, RunTimeCounter};
+    #[test]
+    fn test_parse_start_time() {
+        assert_eq!(
+            parse_start_time(b"r1 runid=abc start_time=1970-01-01T01:00:00Z ch=1"),
+            Some(3600)
+        );
+        assert_eq!(
+            parse_start_time(b"r1 start_time=1970-01-01T02:00:00+01:00"),
+            Some(3600)
+        );
+        assert_eq!(parse_start_time(b"r1 ch=1"), None);
+    }
+    #[test]
+    fn test_timeline() {
+        let mut counter = RunTimeCounter::default();
+        counter.observe(b"r1 start_time=2020-01-01T00:00:00Z", 100, Some(b"++"));
+        counter.observe(b"r2 start_time=2020-01-01T00:00:30Z", 50, Some(b"55"));
+        counter.observe(b"r3 start_time=2020-01-01T02:00:00Z", 10, None);
+        counter.observe(b"r4", 1000, None);
+        let timeline = counter.timeline(33);
+        assert_eq!(timeline.len(), 2);
+        assert_eq!(timeline[0].reads, 2);
+        assert_eq!(timeline[0].mean_quality, Some(15.0));
+        assert_eq!(timeline[1].hours, 2.0);
+        assert_eq!(timeline[1].mean_quality, None);
+        assert_eq!(timeline[1].cumulative_bases, 160);
+    }
+}
@@ -0,0 +1,136 @@
+/// Seed of the random number generator unless chosen, fixed so that reports are
+/// reproducible.
+pub(crate) const DEFAULT_SEED: u64 = 0;
+
+/// Keeps a uniform random sample of a fixed number of items from a stream of unknown
+/// length, using reservoir sampling with geometric skips (Li's algorithm L) so that
+/// skipped items are never copied.
+pub(crate) struct Reservoir<T> {
+    capacity: usize,
+    items: Vec<T>,
+    /// Number of items offered so far.
+    seen: usize,
+    /// Index of the next offered item that is taken into the sample.
+    next: usize,
+    weight: f64,
+    state: u64,
+}
+
+impl<T> Reservoir<T> {
+    /// Creates an empty sample of the given size, choosing the sampled items with a
+    /// generator seeded by `seed`, so that the same seed samples the same items.
+    pub(crate) fn new(capacity: usize, seed: u64) -> Self {
+        let mut reservoir = Reservoir {
+            capacity,
+            items: Vec::with_capacity(capacity),
+            seen: 0,
+            next: capacity,
+            weight: 1.0,
+            state: scramble(seed),
+        };
+        if capacity == 0 {
+            // An empty sample takes none of the items
+            reservoir.next = usize::MAX;
+        } else {
+            reservoir.weight = reservoir.draw_weight();
+            reservoir.next = reservoir.skip(capacity - 1);
+        }
+        reservoir
+    }
+
+    /// Offers the next item of the stream, creating it only if it is sampled.
+    pub(crate) fn offer(&mut self, item: impl FnOnce() -> T) {
+        if self.items.len() < self.capacity {
+            self.items.push(item());
+        } else if self.seen == self.next {
+            let slot = (self.random() * self.capacity as f64) as usize;
+            self.items[slot.min(self.capacity - 1)] = item();
+            self.weight *= self.draw_weight();
+            self.next = self.skip(self.seen);
+        }
+        self.seen += 1;
+    }
+
+    /// Returns the number of items offered so far.
+    pub(crate) fn seen(&self) -> usize {
+        self.seen
+    }
+
+    pub(crate) fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    /// Returns the index of the next sampled item after the given index.
+    fn skip(&mut self, index: usize) -> usize {
+        let skip = (self.random().ln() / (1.0 - self.weight).ln()).floor();
+        // Saturates for tiny weights, which only occur after a vast number of items
+        index.saturating_add(skip as usize).saturating_add(1)
+    }
+
+    fn draw_weight(&mut self) -> f64 {
+        (self.random().ln() / self.capacity as f64).exp()
+    }
+
+    /// Returns a uniformly distributed number in the open interval (0, 1) from a
+    /// xorshift64* generator.
+    fn random(&mut self) -> f64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        let value = self.state.wrapping_mul(0x2545_F491_4F6C_DD1D);
+        ((value >> 11) as f64 + 0.5) / (1_u64 << 53) as f64
+    }
+}
+
+/// Turns a seed into a nonzero state of the generator with the finalizer of SplitMix64,
+/// so that similar seeds like 1 and 2 give unrelated samples.
+fn scramble(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    (z ^ (z >> 31)).max(1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Reservoir, DEFAULT_SEED};
+    #[test]
+    fn test_reservoir_small() {
+        let mut reservoir = Reservoir::new(10, DEFAULT_SEED);
+        for i in 0..5 {
+            reservoir.offer(|| i);
+        }
+        assert_eq!(reservoir.seen(), 5);
+        assert_eq!(reservoir.into_items(), vec![0, 1, 2, 3, 4]);
+    }
+    #[test]
+    fn test_reservoir_empty() {
+        let mut reservoir = Reservoir::new(0, DEFAULT_SEED);
+        for i in 0..5 {
+            reservoir.offer(|| i);
+        }
+        assert_eq!(reservoir.seen(), 5);
+        assert!(reservoir.into_items().is_empty());
+    }
+    #[test]
+    fn test_reservoir_uniform() {
+        let sample = |seed: u64| {
+            let mut reservoir = Reservoir::new(1000, seed);
+            for i in 0..100_000 {
+                reservoir.offer(|| i);
+            }
+            reservoir.into_items()
+        };
+        let items = sample(1);
+        // The same seed samples the same items and another seed others
+        assert_eq!(sample(1), items);
+        assert_ne!(sample(DEFAULT_SEED), items);
+        assert_eq!(items.len(), 1000);
+        // Every tenth of the stream contributes about a tenth of the sample
+        let mut deciles = [0; 10];
+        for item in items {
+            deciles[item / 10_000] += 1;
+        }
+        assert!(deciles.iter().all(|&count| (70..130).contains(&count)));
+    }
+}
@@ -0,0 +1,299 @@
+use crate::adapters::Adapter;
+use crate::error::FastqcError;
+use crate::sketch::mix;
+use rustc_hash::FxHashSet as HashSet;
+use std::convert::TryInto;
+use std::fmt;
+use std::sync::Arc;
+
+/// Length of the k-mers of the references, long enough to be unique to a genome.
+pub(crate) const K: usize = 21;
+/// Share of the sampled k-mers of a read which have to be in a reference for the read
+/// to match it, allowing for sequencing errors.
+const MIN_SHARED: f64 = 0.5;
+/// First bytes of the index files written by `fqc index`.
+const MAGIC: &[u8; 8] = b"FQCSCRN1";
+
+/// The canonical k-mers of a reference genome such as PhiX, which reads are screened
+/// against. Indices of large genomes only keep the k-mers whose hash is among the
+/// lowest `1 / scale` of all hashes, and the same k-mers of the reads are compared.
+#[derive(Clone)]
+pub(crate) struct Reference {
+    pub(crate) name: String,
+    scale: u64,
+    kmers: Arc<HashSet<u64>>,
+}
+
+impl fmt::Debug for Reference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Reference({}, {} k-mers)", self.name, self.kmers.len())
+    }
+}
+
+/// Counts the reads matching each reference.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ScreenCounter {
+    counts: Vec<ScreenCounts>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct ScreenCounts {
+    /// Number of reads with sampled k-mers, which could be screened.
+    pub(crate) screened: usize,
+    pub(crate) matches: usize,
+    /// Number of matching reads which match no other reference.
+    pub(crate) unique: usize,
+}
+
+impl Reference {
+    /// Returns a reference without k-mers, which samples the k-mers of the added
+    /// sequences, or all of them for a scale of 1.
+    pub(crate) fn new(name: &str, scale: u64) -> Self {
+        Reference {
+            name: name.to_string(),
+            scale,
+            kmers: Arc::new(HashSet::default()),
+        }
+    }
+
+    /// Adds the sampled k-mers of a sequence of the reference.
+    pub(crate) fn add(&mut self, sequence: &[u8]) {
+        let scale = self.scale;
+        Arc::make_mut(&mut self.kmers)
+            .extend(canonical_kmers(sequence).filter(|&kmer| sampled(kmer, scale)));
+    }
+
+    /// Reads the reference from a FASTA file of all its sequences, keeping every
+    /// k-mer.
+    pub(crate) fn from_fasta(name: &str, fasta: &str) -> Result<Self, FastqcError> {
+        let mut reference = Reference::new(name, 1);
+        for (_, sequence) in Adapter::parse_file(fasta) {
+            reference.add(sequence.as_bytes());
+        }
+        reference.check()
+    }
+
+    /// Reads an index written by [`Reference::to_bytes`].
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self, FastqcError> {
+        let invalid = || FastqcError::parse("Not a screen index written by fqc index.");
+        let rest = bytes.strip_prefix(MAGIC).ok_or_else(invalid)?;
+        let field = |i: usize| -> Result<usize, FastqcError> {
+            let field = rest.get(i * 8..i * 8 + 8).ok_or_else(invalid)?;
+            Ok(u64::from_le_bytes(field.try_into().map_err(|_| invalid())?) as usize)
+        };
+        let [k, scale, name_length, count] = [field(0)?, field(1)?, field(2)?, field(3)?];
+        if k != K {
+            return Err(FastqcError::parse(format!(
+                "The screen index has {}-mers instead of {}-mers.",
+                k, K
+            )));
+        }
+        // Lengths which overflow cannot be those of the index
+        let name_end = name_length.checked_add(32).ok_or_else(invalid)?;
+        let name = rest.get(32..name_end).ok_or_else(invalid)?;
+        let kmers = &rest[name_end..];
+        if Some(kmers.len()) != count.checked_mul(8) {
+            return Err(invalid());
+        }
+        let kmers = kmers
+            .chunks_exact(8)
+            .map(|kmer| u64::from_le_bytes(kmer.try_into().unwrap()))
+            .collect();
+        let reference = Reference {
+            name: String::from_utf8(name.to_vec()).map_err(|_| invalid())?,
+            scale: scale as u64,
+            kmers: Arc::new(kmers),
+        };
+        reference.check()
+    }
+
+    /// Returns the index of the reference: the length of the k-mers, the scale, the
+    /// length of the name and the number of k-mers, followed by the name and the
+    /// sorted k-mers.
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+        let fields = [
+            K as u64,
+            self.scale,
+            self.name.len() as u64,
+            self.kmers.len() as u64,
+        ];
+        for field in fields {
+            bytes.extend(field.to_le_bytes());
+        }
+        bytes.extend(self.name.as_bytes());
+        let mut kmers = self.kmers.iter().collect::<Vec<_>>();
+        kmers.sort_unstable();
+        for kmer in kmers {
+            bytes.extend(kmer.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Returns the number of k-mers of the reference.
+    pub(crate) fn len(&self) -> usize {
+        self.kmers.len()
+    }
+
+    /// Fails for a reference without any k-mers, e.g. of sequences shorter than k.
+    pub(crate) fn check(self) -> Result<Self, FastqcError> {
+        if self.kmers.is_empty() {
+            return Err(FastqcError::parse(format!(
+                "The {} reference contains no sequences of {} bases.",
+                self.name, K
+            )));
+        }
+        Ok(self)
+    }
+
+    /// Returns whether most sampled k-mers of the sequence are in the reference, or
+    /// `None` if it has no sampled k-mers.
+    pub(crate) fn matches(&self, seq: &[u8]) -> Option<bool> {
+        let mut kmers = 0;
+        let mut shared = 0;
+        for kmer in canonical_kmers(seq) {
+            if sampled(kmer, self.scale) {
+                kmers += 1;
+                shared += self.kmers.contains(&kmer) as usize;
+            }
+        }
+        (kmers > 0).then_some(shared as f64 >= kmers as f64 * MIN_SHARED)
+    }
+}
+
+impl ScreenCounter {
+    pub(crate) fn observe(&mut self, references: &[Reference], seq: &[u8]) {
+        if references.is_empty() {
+            return;
+        }
+        self.counts
+            .resize(references.len(), ScreenCounts::default());
+        let matches = references
+            .iter()
+            .map(|reference| reference.matches(seq))
+            .collect::<Vec<_>>();
+        let unique = matches
+            .iter()
+            .filter(|&&matches| matches == Some(true))
+            .count()
+            == 1;
+        for (counts, matches) in self.counts.iter_mut().zip(matches) {
+            if let Some(matches) = matches {
+                counts.screened += 1;
+                counts.matches += matches as usize;
+                counts.unique += (matches && unique) as usize;
+            }
+        }
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &ScreenCounter) {
+        if self.counts.len() < other.counts.len() {
+            self.counts
+                .resize(other.counts.len(), ScreenCounts::default());
+        }
+        for (counts, other) in self.counts.iter_mut().zip(&other.counts) {
+            counts.screened += other.screened;
+            counts.matches += other.matches;
+            counts.unique += other.unique;
+        }
+    }
+
+    /// Returns the counts of each reference.
+    pub(crate) fn counts(&self) -> &[ScreenCounts] {
+        &self.counts
+    }
+}
+
+/// Returns whether the k-mer is sampled for the given scale.
+pub(crate) fn sampled(kmer: u64, scale: u64) -> bool {
+    scale <= 1 || mix(kmer) < u64::MAX / scale
+}
+
+/// Returns the canonical 2-bit encoding of every k-mer of the sequence without
+/// ambiguous bases, i.e. the smaller encoding of both strands.
+pub(crate) fn canonical_kmers(seq: &[u8]) -> impl Iterator<Item = u64> + '_ {
+    let mask = (1 << (2 * K)) - 1;
+    let mut forward = 0_u64;
+    let mut reverse = 0_u64;
+    let mut valid = 0;
+    seq.iter().filter_map(move |base| {
+        let code = match base.to_ascii_uppercase() {
+            b'A' => 0,
+            b'C' => 1,
+            b'G' => 2,
+            b'T' => 3,
+            _ => {
+                valid = 0;
+                return None;
+            }
+        };
+        forward = ((forward << 2) | code) & mask;
+        reverse = (reverse >> 2) | ((3 - code) << (2 * (K - 1)));
+        valid += 1;
+        (valid >= K).then(|| forward.min(reverse))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Reference, ScreenCounter, ScreenCounts, MAGIC};
+    use needletail::Sequence;
+    const GENOME: &[u8] =
+        b"GAGTTTTATCGCTTCCATGACGCAGAAGTTAACACTTTCGGATATTTCTGATGAGTCGAAAAATTATCTTGATAAAGCAGG";
+    #[test]
+    fn test_screen() {
+        let mut reference = Reference::new("test", 1);
+        reference.add(GENOME);
+        assert_eq!(reference.matches(&GENOME[10..70]), Some(true));
+        let reverse = GENOME[10..70].reverse_complement();
+        assert_eq!(reference.matches(&reverse), Some(true));
+        // One error leaves most of the k-mers intact
+        let mut read = GENOME[..42].to_vec();
+        read[5] = b'C';
+        assert_eq!(reference.matches(&read), Some(true));
+        read[25] = b'C';
+        assert_eq!(reference.matches(&read), Some(false));
+        assert_eq!(reference.matches(&GENOME[..20]), None);
+        assert_eq!(reference.matches(&b"ACGT".repeat(15)), Some(false));
+        let mut other = Reference::new("other", 1);
+        other.add(&GENOME[40..]);
+        let references = [reference, other];
+        let mut counter = ScreenCounter::default();
+        counter.observe(&references, &GENOME[..30]);
+        let mut other = ScreenCounter::default();
+        other.observe(&references, &GENOME[40..]);
+        other.observe(&references, b"ACGTACGTACGTACGTACGTACGT");
+        counter.merge(&other);
+        let counts = |screened, matches, unique| ScreenCounts {
+            screened,
+            matches,
+            unique,
+        };
+        assert_eq!(counter.counts(), [counts(3, 2, 1), counts(3, 1, 0)]);
+        assert!(Reference::from_fasta("test", ">test\nACGT\n").is_err());
+    }
+    #[test]
+    fn test_index() {
+        let mut reference = Reference::new("test", 4);
+        reference.add(GENOME);
+        assert!(reference.len() < GENOME.len() - 20);
+        let bytes = reference.to_bytes();
+        let index = Reference::from_bytes(&bytes).unwrap();
+        assert_eq!((index.name.as_str(), index.scale), ("test", 4));
+        assert_eq!(index.kmers, reference.kmers);
+        assert!(Reference::from_bytes(&bytes[..bytes.len() - 4]).is_err());
+        assert!(Reference::from_bytes(b"@read\nACGT\n+\nIIII\n").is_err());
+        // Lengths overflowing when added up are rejected instead of panicking
+        for field in [2, 3] {
+            let mut bytes = bytes.clone();
+            let start = MAGIC.len() + field * 8;
+            bytes[start..start + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+            let error = Reference::from_bytes(&bytes).unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                "Not a screen index written by fqc index."
+            );
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// Name of the configuration file looked up in the working directory and the
+/// configuration directory of the user.
+pub(crate) const FILE_NAME: &str = "fastqc-rs.toml";
+
+/// The value of a setting, either a flag or one or several values of an option.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+    Flag(bool),
+    Values(Vec<String>),
+}
+
+/// A setting of a configuration file, named by the long form of its option.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Setting {
+    /// The subcommand of the table the setting is in, or `None` for all subcommands.
+    pub(crate) subcommand: Option<String>,
+    pub(crate) key: String,
+    pub(crate) value: Value,
+}
+
+/// A configuration file, of which tables are the sections of subcommands.
+#[derive(Deserialize)]
+struct Config {
+    #[serde(flatten)]
+    entries: BTreeMap<String, Entry>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Entry {
+    Option(Item),
+    Section(BTreeMap<String, Item>),
+}
+
+/// The TOML value of an option, of which arrays list the values of options given
+/// several times.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Item {
+    Flag(bool),
+    Scalar(Scalar),
+    Array(Vec<Scalar>),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Scalar {
+    Integer(i64),
+    Float(f64),
+    String(String),
+}
+
+impl Scalar {
+    fn value(self, key: &str) -> Result<String, String> {
+        match self {
+            Scalar::Integer(value) => Ok(value.to_string()),
+            // TOML has inf and nan, which none of the options take
+            Scalar::Float(value) if !value.is_finite() => {
+                Err(format!("{} expects a finite number.", key))
+            }
+            Scalar::Float(value) => Ok(value.to_string()),
+            Scalar::String(value) => Ok(value),
+        }
+    }
+}
+
+impl Item {
+    fn value(self, key: &str) -> Result<Value, String> {
+        match self {
+            Item::Flag(flag) => Ok(Value::Flag(flag)),
+            Item::Scalar(scalar) => Ok(Value::Values(vec![scalar.value(key)?])),
+            Item::Array(scalars) => scalars
+                .into_iter()
+                .map(|scalar| scalar.value(key))
+                .collect::<Result<_, _>>()
+                .map(Value::Values),
+        }
+    }
+}
+
+/// Returns the configuration file, which is the given one or else `fastqc-rs.toml` in
+/// the working directory or in `fastqc-rs/` of the XDG configuration directory.
+pub(crate) fn find(config: Option<&Path>) -> Option<PathBuf> {
+    if let Some(config) = config {
+        return Some(config.to_path_buf());
+    }
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")));
+    std::iter::once(PathBuf::from(FILE_NAME))
+        .chain(config_dir.map(|dir| dir.join("fastqc-rs").join(FILE_NAME)))
+        .find(|path| path.is_file())
+}
+
+/// Parses a TOML configuration file of strings, numbers, booleans or arrays of strings
+/// and numbers, optionally in a table named after a subcommand, e.g.
+///
+/// ```toml
+/// threads = 4
+/// adapters = "/data/adapters.fa"
+/// disable = ["kmer_content"]
+///
+/// [aggregate]
+/// format = "json"
+/// ```
+///
+/// The settings of all subcommands come before those of the tables.
+pub(crate) fn parse(text: &str) -> Result<Vec<Setting>, String> {
+    let config = toml::from_str::<Config>(text).map_err(|e| e.to_string())?;
+    let mut settings = Vec::new();
+    let mut sections = Vec::new();
+    for (key, entry) in config.entries {
+        match entry {
+            Entry::Option(item) => settings.push(Setting {
+                subcommand: None,
+                value: item.value(&key)?,
+                key,
+            }),
+            Entry::Section(items) => {
+                for (name, item) in items {
+                    sections.push(Setting {
+                        subcommand: Some(key.clone()),
+                        value: item.value(&name)?,
+                        key: name,
+                    });
+                }
+            }
+        }
+    }
+    settings.append(&mut sections);
+    Ok(settings)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse, Setting, Value};
+    #[test]
+    fn test_parse() {
+        let text = "# Defaults of the facility\nthreads = 4\nadapters = \"/data/adapters.fa\" # FASTA\n\
+                    quiet = true\ndisable = [\n  'kmer_content', # slow\n  \"per_tile_quality\",\n]\n\n\
+                    [aggregate]\n\"format\" = \"json\"\nmax-reads = 1_000_000\nmin-length = -1.5e2\n";
+        let settings = parse(text).unwrap();
+        let setting = |subcommand: Option<&str>, key: &str, value: Value| Setting {
+            subcommand: subcommand.map(String::from),
+            key: key.to_string(),
+            value,
+        };
+        let values =
+            |values: &[&str]| Value::Values(values.iter().map(|v| v.to_string()).collect());
+        assert_eq!(
+            settings,
+            [
+                setting(None, "adapters", values(&["/data/adapters.fa"])),
+                setting(
+                    None,
+                    "disable",
+                    values(&["kmer_content", "per_tile_quality"])
+                ),
+                setting(None, "quiet", Value::Flag(true)),
+                setting(None, "threads", values(&["4"])),
+                setting(Some("aggregate"), "format", values(&["json"])),
+                setting(Some("aggregate"), "max-reads", values(&["1000000"])),
+                setting(Some("aggregate"), "min-length", values(&["-150"])),
+            ]
+        );
+        assert!(parse("threads = four")
+            .unwrap_err()
+            .contains("line 1, column 11"));
+        assert!(parse("disable = [\"kmer_content\"").is_err());
+        assert!(parse("[[report]]\nformat = \"json\"").is_err());
+        assert!(parse("adapter").is_err());
+        assert!(parse("modules = [[\"adapter_content\"]]").is_err());
+        for value in ["inf", "-nan"] {
+            assert_eq!(
+                parse(&format!("min-length = {}", value)).unwrap_err(),
+                "min-length expects a finite number."
+            );
+        }
+    }
+}
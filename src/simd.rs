@@ -0,0 +1,262 @@
+use crate::composition::{base_index, N};
+use crate::encoding::{char_index, QUALITY_CHARS};
+
+/// Adds one to `counts[i][pos]` for every A, C, G and T (`i` from 0 to 3 like in
+/// `BASES`) at position `pos` of the sequence, ignoring case, and returns the number
+/// of each of these bases in the sequence. Every vector in `counts` must be at least
+/// as long as the sequence.
+#[cfg(target_arch = "x86_64")]
+pub(crate) fn count_bases(seq: &[u8], counts: &mut [Vec<u32>; 4]) -> [usize; 4] {
+    assert!(counts.iter().all(|counts| counts.len() >= seq.len()));
+    if is_x86_feature_detected!("avx2") {
+        // Safety: AVX2 is supported by the CPU and the counts are long enough
+        unsafe { x86::count_bases_avx2(seq, counts) }
+    } else {
+        // Safety: SSE2 is supported by every x86_64 CPU and the counts are long enough
+        unsafe { x86::count_bases_sse2(seq, counts) }
+    }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+pub(crate) fn count_bases(seq: &[u8], counts: &mut [Vec<u32>; 4]) -> [usize; 4] {
+    count_scalar(seq, counts, 0, [0; 4])
+}
+
+/// Counts the bases from position `start` on one at a time, adding to the given totals.
+fn count_scalar(
+    seq: &[u8],
+    counts: &mut [Vec<u32>; 4],
+    start: usize,
+    mut totals: [usize; 4],
+) -> [usize; 4] {
+    for (pos, &base) in seq.iter().enumerate().skip(start) {
+        let index = base_index(base);
+        if index != N {
+            counts[index][pos] += 1;
+            totals[index] += 1;
+        }
+    }
+    totals
+}
+
+/// Adds one to `counts[pos][char_index(q)]` for the quality character `q` at every
+/// position `pos` of the qualities and returns the sum of the quality characters.
+/// `counts` must be at least as long as the qualities.
+#[cfg(target_arch = "x86_64")]
+pub(crate) fn count_qualities(qualities: &[u8], counts: &mut [[usize; QUALITY_CHARS]]) -> usize {
+    assert!(counts.len() >= qualities.len());
+    if is_x86_feature_detected!("avx2") {
+        // Safety: AVX2 is supported by the CPU
+        unsafe { x86::count_qualities_avx2(qualities, counts) }
+    } else {
+        // Safety: SSE2 is supported by every x86_64 CPU
+        unsafe { x86::count_qualities_sse2(qualities, counts) }
+    }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+pub(crate) fn count_qualities(qualities: &[u8], counts: &mut [[usize; QUALITY_CHARS]]) -> usize {
+    count_qualities_scalar(qualities, counts, 0, 0)
+}
+
+/// Counts the qualities from position `start` on one at a time, adding to the given sum.
+fn count_qualities_scalar(
+    qualities: &[u8],
+    counts: &mut [[usize; QUALITY_CHARS]],
+    start: usize,
+    mut sum: usize,
+) -> usize {
+    for (pos, &q) in qualities.iter().enumerate().skip(start) {
+        counts[pos][char_index(q)] += 1;
+        sum += q as usize;
+    }
+    sum
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use super::{count_qualities_scalar, count_scalar};
+    use crate::encoding::{LOWEST_CHAR, QUALITY_CHARS};
+    use std::arch::x86_64::*;
+
+    /// Lower case bases in the order of `BASES`, compared to every byte with the
+    /// case bit set.
+    const LOWER_BASES: [u8; 4] = [b'a', b'c', b'g', b't'];
+    const CASE_BIT: i8 = 0x20;
+
+    /// Counts 32 positions at once. Each comparison yields -1 for matching bytes, which
+    /// are sign-extended to 32 bits and subtracted from the counts.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn count_bases_avx2(seq: &[u8], counts: &mut [Vec<u32>; 4]) -> [usize; 4] {
+        let mut totals = [0; 4];
+        let chunks = seq.len() / 32;
+        for chunk in 0..chunks {
+            let pos = chunk * 32;
+            let bytes = _mm256_loadu_si256(seq.as_ptr().add(pos) as *const __m256i);
+            let bytes = _mm256_or_si256(bytes, _mm256_set1_epi8(CASE_BIT));
+            for (i, &base) in LOWER_BASES.iter().enumerate() {
+                let matches = _mm256_cmpeq_epi8(bytes, _mm256_set1_epi8(base as i8));
+                totals[i] += (_mm256_movemask_epi8(matches) as u32).count_ones() as usize;
+                let halves = [
+                    _mm256_castsi256_si128(matches),
+                    _mm256_extracti128_si256::<1>(matches),
+                ];
+                let out = counts[i].as_mut_ptr().add(pos) as *mut __m256i;
+                for (h, &half) in halves.iter().enumerate() {
+                    for (q, quarter) in [half, _mm_srli_si128::<8>(half)].iter().enumerate() {
+                        let ptr = out.add(h * 2 + q);
+                        let ones = _mm256_cvtepi8_epi32(*quarter);
+                        _mm256_storeu_si256(ptr, _mm256_sub_epi32(_mm256_loadu_si256(ptr), ones));
+                    }
+                }
+            }
+        }
+        count_scalar(seq, counts, chunks * 32, totals)
+    }
+
+    /// Counts 16 positions at once, widening the comparison results by unpacking them
+    /// with themselves.
+    pub(super) unsafe fn count_bases_sse2(seq: &[u8], counts: &mut [Vec<u32>; 4]) -> [usize; 4] {
+        let mut totals = [0; 4];
+        let chunks = seq.len() / 16;
+        for chunk in 0..chunks {
+            let pos = chunk * 16;
+            let bytes = _mm_loadu_si128(seq.as_ptr().add(pos) as *const __m128i);
+            let bytes = _mm_or_si128(bytes, _mm_set1_epi8(CASE_BIT));
+            for (i, &base) in LOWER_BASES.iter().enumerate() {
+                let matches = _mm_cmpeq_epi8(bytes, _mm_set1_epi8(base as i8));
+                totals[i] += (_mm_movemask_epi8(matches) as u32).count_ones() as usize;
+                let low = _mm_unpacklo_epi8(matches, matches);
+                let high = _mm_unpackhi_epi8(matches, matches);
+                let quarters = [
+                    _mm_unpacklo_epi16(low, low),
+                    _mm_unpackhi_epi16(low, low),
+                    _mm_unpacklo_epi16(high, high),
+                    _mm_unpackhi_epi16(high, high),
+                ];
+                let out = counts[i].as_mut_ptr().add(pos) as *mut __m128i;
+                for (q, &ones) in quarters.iter().enumerate() {
+                    let ptr = out.add(q);
+                    _mm_storeu_si128(ptr, _mm_sub_epi32(_mm_loadu_si128(ptr), ones));
+                }
+            }
+        }
+        count_scalar(seq, counts, chunks * 16, totals)
+    }
+
+    /// Counts 32 qualities at once. Their histogram indices are the characters minus
+    /// the lowest one with saturation, capped at the highest index, and their sums are
+    /// the absolute differences to zero summed in 64-bit lanes.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn count_qualities_avx2(
+        qualities: &[u8],
+        counts: &mut [[usize; QUALITY_CHARS]],
+    ) -> usize {
+        let lowest = _mm256_set1_epi8(LOWEST_CHAR as i8);
+        let highest = _mm256_set1_epi8((QUALITY_CHARS - 1) as i8);
+        let mut sums = _mm256_setzero_si256();
+        let mut indices = [0_u8; 32];
+        let chunks = qualities.len() / 32;
+        for chunk in 0..chunks {
+            let pos = chunk * 32;
+            let bytes = _mm256_loadu_si256(qualities.as_ptr().add(pos) as *const __m256i);
+            sums = _mm256_add_epi64(sums, _mm256_sad_epu8(bytes, _mm256_setzero_si256()));
+            let index = _mm256_min_epu8(_mm256_subs_epu8(bytes, lowest), highest);
+            _mm256_storeu_si256(indices.as_mut_ptr() as *mut __m256i, index);
+            for (counts, &index) in counts[pos..pos + 32].iter_mut().zip(&indices) {
+                counts[index as usize] += 1;
+            }
+        }
+        let mut lanes = [0_u64; 4];
+        _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, sums);
+        let sum = lanes.iter().sum::<u64>() as usize;
+        count_qualities_scalar(qualities, counts, chunks * 32, sum)
+    }
+
+    /// Counts 16 qualities at once like [`count_qualities_avx2`].
+    pub(super) unsafe fn count_qualities_sse2(
+        qualities: &[u8],
+        counts: &mut [[usize; QUALITY_CHARS]],
+    ) -> usize {
+        let lowest = _mm_set1_epi8(LOWEST_CHAR as i8);
+        let highest = _mm_set1_epi8((QUALITY_CHARS - 1) as i8);
+        let mut sums = _mm_setzero_si128();
+        let mut indices = [0_u8; 16];
+        let chunks = qualities.len() / 16;
+        for chunk in 0..chunks {
+            let pos = chunk * 16;
+            let bytes = _mm_loadu_si128(qualities.as_ptr().add(pos) as *const __m128i);
+            sums = _mm_add_epi64(sums, _mm_sad_epu8(bytes, _mm_setzero_si128()));
+            let index = _mm_min_epu8(_mm_subs_epu8(bytes, lowest), highest);
+            _mm_storeu_si128(indices.as_mut_ptr() as *mut __m128i, index);
+            for (counts, &index) in counts[pos..pos + 16].iter_mut().zip(&indices) {
+                counts[index as usize] += 1;
+            }
+        }
+        let mut lanes = [0_u64; 2];
+        _mm_storeu_si128(lanes.as_mut_ptr() as *mut __m128i, sums);
+        let sum = lanes.iter().sum::<u64>() as usize;
+        count_qualities_scalar(qualities, counts, chunks * 16, sum)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{count_bases, count_qualities, count_qualities_scalar, count_scalar};
+    use crate::encoding::QUALITY_CHARS;
+    #[test]
+    fn test_count_bases() {
+        // Covers full vector chunks, the remainder and mixed case
+        let seq = b"ACGTNacgtnAAAAccccGGGGttttNNNN-.ACGTACGTACGTACGTACGTACGTGGCCAATTaN"
+            .iter()
+            .cycle()
+            .take(203)
+            .copied()
+            .collect::<Vec<_>>();
+        let mut expected = [vec![0; 203], vec![0; 203], vec![0; 203], vec![0; 203]];
+        let expected_totals = count_scalar(&seq, &mut expected, 0, [0; 4]);
+        let mut counts = [vec![0; 210], vec![0; 210], vec![0; 210], vec![0; 210]];
+        assert_eq!(count_bases(&seq, &mut counts), expected_totals);
+        assert_eq!(count_bases(&seq[..7], &mut counts)[0], 2);
+        for (counts, expected) in counts.iter().zip(&expected) {
+            let twice = expected[..7]
+                .iter()
+                .map(|count| count * 2)
+                .collect::<Vec<_>>();
+            assert_eq!(&counts[..7], &twice[..]);
+            assert_eq!(&counts[7..203], &expected[7..]);
+            assert!(counts[203..].iter().all(|&count| count == 0));
+        }
+        #[cfg(target_arch = "x86_64")]
+        {
+            let mut counts = [vec![0; 203], vec![0; 203], vec![0; 203], vec![0; 203]];
+            let totals = unsafe { super::x86::count_bases_sse2(&seq, &mut counts) };
+            assert_eq!(totals, expected_totals);
+            assert_eq!(counts, expected);
+        }
+    }
+    #[test]
+    fn test_count_qualities() {
+        // Covers full vector chunks, the remainder and characters outside of the range
+        let qualities = (0..=255_u8)
+            .chain(b"#IIII?5".iter().copied())
+            .collect::<Vec<_>>();
+        let mut expected = vec![[0; QUALITY_CHARS]; qualities.len()];
+        let expected_sum = count_qualities_scalar(&qualities, &mut expected, 0, 0);
+        assert_eq!(
+            expected_sum,
+            qualities.iter().map(|&q| q as usize).sum::<usize>()
+        );
+        let mut counts = vec![[0; QUALITY_CHARS]; qualities.len() + 3];
+        assert_eq!(count_qualities(&qualities, &mut counts), expected_sum);
+        assert_eq!(&counts[..qualities.len()], &expected[..]);
+        assert_eq!(counts[qualities.len()], [0; QUALITY_CHARS]);
+        #[cfg(target_arch = "x86_64")]
+        {
+            let mut counts = vec![[0; QUALITY_CHARS]; qualities.len()];
+            let sum = unsafe { super::x86::count_qualities_sse2(&qualities, &mut counts) };
+            assert_eq!(sum, expected_sum);
+            assert_eq!(counts, expected);
+        }
+    }
+}
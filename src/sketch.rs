@@ -0,0 +1,158 @@
+use rustc_hash::FxHasher;
+use std::hash::Hasher;
+
+/// Number of index bits of the HyperLogLog registers, giving a standard error of 0.8%.
+const PRECISION: u32 = 14;
+/// Number of rows of the count-min sketch, each with an independent hash function.
+const DEPTH: usize = 4;
+
+/// Returns a well mixed 64-bit hash of the sequence.
+pub(crate) fn hash(seq: &[u8]) -> u64 {
+    let mut hasher = FxHasher::default();
+    hasher.write(seq);
+    mix(hasher.finish())
+}
+
+/// The finalizer of SplitMix64, spreading every input bit over all output bits.
+pub(crate) fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+/// Estimates the number of distinct hashes in constant memory.
+#[derive(Clone)]
+pub(crate) struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    pub(crate) fn new() -> Self {
+        HyperLogLog {
+            registers: vec![0; 1 << PRECISION],
+        }
+    }
+
+    pub(crate) fn add(&mut self, hash: u64) {
+        let index = (hash >> (64 - PRECISION)) as usize;
+        // The guard bit bounds the rank if all remaining bits are zero
+        let rest = (hash << PRECISION) | (1 << (PRECISION - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        self.registers[index] = self.registers[index].max(rank);
+    }
+
+    /// Adds the hashes of another estimator.
+    pub(crate) fn merge(&mut self, other: &HyperLogLog) {
+        for (register, &other) in self.registers.iter_mut().zip(&other.registers) {
+            *register = (*register).max(other);
+        }
+    }
+
+    pub(crate) fn estimate(&self) -> f64 {
+        let m = self.registers.len() as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum = self
+            .registers
+            .iter()
+            .map(|&register| 2_f64.powi(-(register as i32)))
+            .sum::<f64>();
+        let estimate = alpha * m * m / sum;
+        let zeros = self
+            .registers
+            .iter()
+            .filter(|&&register| register == 0)
+            .count();
+        // Linear counting is more accurate for small cardinalities
+        if estimate <= 2.5 * m && zeros > 0 {
+            m * (m / zeros as f64).ln()
+        } else {
+            estimate
+        }
+    }
+}
+
+/// Estimates how often every hash has been added, never underestimating.
+#[derive(Clone)]
+pub(crate) struct CountMinSketch {
+    /// Width of every row, a power of two.
+    width: usize,
+    counts: Vec<u32>,
+}
+
+impl CountMinSketch {
+    /// Creates a sketch taking about the given number of bytes.
+    pub(crate) fn with_memory(bytes: usize) -> Self {
+        let width = (bytes / (DEPTH * 4)).max(1).next_power_of_two();
+        CountMinSketch {
+            width,
+            counts: vec![0; width * DEPTH],
+        }
+    }
+
+    /// Adds the count to the hash with conservative updates, only raising the counters
+    /// to the new estimate, which reduces the overestimation of rare hashes.
+    pub(crate) fn add(&mut self, hash: u64, count: usize) {
+        let estimate = (self.estimate(hash) as u32).saturating_add(count as u32);
+        for index in self.indices(hash) {
+            self.counts[index] = self.counts[index].max(estimate);
+        }
+    }
+
+    /// Adds the counts of another sketch with the same width.
+    pub(crate) fn merge(&mut self, other: &CountMinSketch) {
+        for (count, &other) in self.counts.iter_mut().zip(&other.counts) {
+            *count = count.saturating_add(other);
+        }
+    }
+
+    pub(crate) fn estimate(&self, hash: u64) -> usize {
+        self.indices(hash)
+            .map(|index| self.counts[index])
+            .min()
+            .unwrap_or(0) as usize
+    }
+
+    /// Returns the counter of the hash in every row, derived from two halves of a
+    /// remixed hash, so that they are independent of the bits used for sampling.
+    fn indices(&self, hash: u64) -> impl Iterator<Item = usize> {
+        let hash = mix(hash ^ 0x9E37_79B9_7F4A_7C15);
+        let (h1, h2) = (hash as usize, (hash >> 32) as usize | 1);
+        let width = self.width;
+        (0..DEPTH).map(move |row| row * width + (h1.wrapping_add(row * h2) & (width - 1)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{hash, CountMinSketch, HyperLogLog};
+    #[test]
+    fn test_hyperloglog() {
+        let mut hll = HyperLogLog::new();
+        for i in 0_u32..100_000 {
+            hll.add(hash(&i.to_le_bytes()));
+            hll.add(hash(&i.to_le_bytes()));
+        }
+        assert!((hll.estimate() / 100_000.0 - 1.0).abs() < 0.03);
+        let mut small = HyperLogLog::new();
+        for i in 0_u32..100 {
+            small.add(hash(&i.to_le_bytes()));
+        }
+        assert!((small.estimate() - 100.0).abs() < 2.0);
+        small.merge(&hll);
+        assert!((small.estimate() / 100_000.0 - 1.0).abs() < 0.03);
+    }
+    #[test]
+    fn test_count_min_sketch() {
+        let mut sketch = CountMinSketch::with_memory(1 << 16);
+        for i in 0_u32..1000 {
+            sketch.add(hash(&i.to_le_bytes()), i as usize % 10 + 1);
+        }
+        for i in 0_u32..1000 {
+            assert!(sketch.estimate(hash(&i.to_le_bytes())) > i as usize % 10);
+        }
+        let exact = (0_u32..1000)
+            .filter(|i| sketch.estimate(hash(&i.to_le_bytes())) == *i as usize % 10 + 1)
+            .count();
+        assert!(exact > 990);
+    }
+}
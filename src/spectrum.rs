@@ -0,0 +1,318 @@
+use crate::screen::{canonical_kmers, sampled, K};
+use rustc_hash::FxHashMap as HashMap;
+
+/// Number of occurrences from which k-mers are counted together in the last bin of the
+/// spectrum, like in the histograms of Jellyfish used by GenomeScope.
+const MAX_COVERAGE: usize = 1000;
+/// Lowest coverage of the highest peak of the spectrum for a model to be fitted, below
+/// which the k-mers of the genome can not be told apart from erroneous ones.
+const MIN_PEAK: usize = 5;
+/// Highest heterozygosity of a fitted model, above which the peak of its heterozygous
+/// k-mers is rather taken for the one of a haploid genome.
+const MAX_HETEROZYGOSITY: f64 = 0.1;
+/// Ratios of the variance to the mean coverage of the k-mers tried for the model, which
+/// are above the 1 of a Poisson distribution for a biased coverage.
+const BIASES: [f64; 8] = [1.0, 1.1, 1.25, 1.5, 2.0, 3.0, 5.0, 8.0];
+
+/// Counts the occurrences of the canonical k-mers of the reads, only of those sampled
+/// by their hash for a scale above 1 like the indices of `fqc index`.
+pub(crate) struct SpectrumCounter {
+    scale: u64,
+    counts: HashMap<u64, u32>,
+}
+
+/// The k-mer spectrum, i.e. the number of distinct k-mers per number of occurrences.
+pub(crate) struct Spectrum {
+    pub(crate) scale: u64,
+    /// Number of sampled k-mers and of distinct ones.
+    pub(crate) kmers: usize,
+    pub(crate) distinct: usize,
+    /// Number of distinct k-mers occurring once, twice and so on up to `MAX_COVERAGE`
+    /// or more times.
+    pub(crate) histogram: Vec<usize>,
+    pub(crate) model: Option<GenomeModel>,
+    /// Percentage of the bases with sequencing errors estimated from the share of the
+    /// k-mers occurring once, if the genome's k-mers have a peak of enough coverage to
+    /// rarely occur once themselves.
+    pub(crate) singleton_error_rate: Option<f64>,
+}
+
+/// The model of a diploid genome fitted to the k-mer spectrum like by GenomeScope, with
+/// heterozygous k-mers at the coverage of one haplotype and homozygous ones at twice of
+/// it.
+pub(crate) struct GenomeModel {
+    /// Mean coverage of the heterozygous k-mers.
+    pub(crate) coverage: f64,
+    /// Estimated length of the haploid genome.
+    pub(crate) genome_size: f64,
+    /// Percentage of the bases which differ between the haplotypes.
+    pub(crate) heterozygosity: f64,
+    /// Percentage of the bases with sequencing errors, from the k-mers below the model.
+    pub(crate) error_rate: f64,
+    /// Number of distinct k-mers of the model per number of occurrences like the
+    /// histogram, up to the fitted range.
+    pub(crate) fit: Vec<f64>,
+}
+
+impl SpectrumCounter {
+    pub(crate) fn new(scale: u64) -> Self {
+        SpectrumCounter {
+            scale,
+            counts: HashMap::default(),
+        }
+    }
+
+    pub(crate) fn observe(&mut self, seq: &[u8]) {
+        let scale = self.scale;
+        for kmer in canonical_kmers(seq).filter(|&kmer| sampled(kmer, scale)) {
+            let count = self.counts.entry(kmer).or_insert(0);
+            *count = count.saturating_add(1);
+        }
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &SpectrumCounter) {
+        for (&kmer, &other) in &other.counts {
+            let count = self.counts.entry(kmer).or_insert(0);
+            *count = count.saturating_add(other);
+        }
+    }
+
+    pub(crate) fn spectrum(&self) -> Spectrum {
+        let mut histogram = vec![0; MAX_COVERAGE];
+        let mut kmers = 0;
+        for &count in self.counts.values() {
+            kmers += count as usize;
+            histogram[(count as usize).min(MAX_COVERAGE) - 1] += 1;
+        }
+        // Nearly every k-mer with an error occurs once at the coverage of the peak
+        let singleton_error_rate =
+            peak(&histogram).map(|_| error_rate(histogram[0] as f64 / kmers as f64));
+        Spectrum {
+            scale: self.scale,
+            kmers,
+            distinct: self.counts.len(),
+            model: fit(&histogram, kmers, self.scale),
+            histogram,
+            singleton_error_rate,
+        }
+    }
+}
+
+/// Returns the coverage of the first valley after the error k-mers and of the highest
+/// peak after it, or `None` without a peak of enough coverage.
+fn peak(histogram: &[usize]) -> Option<(usize, usize)> {
+    let count = |coverage: usize| histogram[coverage - 1];
+    let valley =
+        (1..histogram.len() - 1).find(|&coverage| count(coverage + 1) > count(coverage))?;
+    let peak = (valley..histogram.len())
+        .max_by_key(|&coverage| count(coverage))
+        .filter(|&peak| peak >= MIN_PEAK)?;
+    Some((valley, peak))
+}
+
+/// Fits the model of a diploid genome to the spectrum from the first valley after the
+/// error k-mers up to three times its highest peak.
+fn fit(histogram: &[usize], kmers: usize, scale: u64) -> Option<GenomeModel> {
+    let count = |coverage: usize| histogram[coverage - 1] as f64;
+    let (valley, peak) = peak(histogram)?;
+    let upper = (3 * peak).min(histogram.len() - 1);
+    let counts = (valley..=upper).map(count).collect::<Vec<_>>();
+    // The highest peak is either the one of the heterozygous or of the homozygous k-mers
+    let mut best = None;
+    let mut best_residual = f64::INFINITY;
+    for peak_coverage in [peak as f64 / 2.0, peak as f64] {
+        for step in -10..=10 {
+            let coverage = peak_coverage * (1.0 + step as f64 * 0.02);
+            if coverage < 1.0 {
+                continue;
+            }
+            for bias in BIASES {
+                let heterozygous = negative_binomial(coverage, bias, upper);
+                let homozygous = negative_binomial(2.0 * coverage, bias, upper);
+                let (a, b) = least_squares(&counts, &heterozygous[valley..], &homozygous[valley..]);
+                if b <= 0.0 || heterozygosity(a, b) > MAX_HETEROZYGOSITY {
+                    continue;
+                }
+                let residual = residual(
+                    &counts,
+                    &heterozygous[valley..],
+                    &homozygous[valley..],
+                    a,
+                    b,
+                );
+                if residual < best_residual {
+                    let fit = (1..=upper)
+                        .map(|coverage| a * heterozygous[coverage] + b * homozygous[coverage])
+                        .collect::<Vec<_>>();
+                    best = Some((coverage, heterozygosity(a, b), fit));
+                    best_residual = residual;
+                }
+            }
+        }
+    }
+    let (coverage, heterozygosity, fit) = best?;
+    // Erroneous k-mers make up the excess of the spectrum over the model below the valley
+    let errors = (1..valley)
+        .map(|coverage| (count(coverage) - fit[coverage - 1]).max(0.0) * coverage as f64)
+        .sum::<f64>();
+    Some(GenomeModel {
+        coverage,
+        genome_size: (kmers as f64 - errors) / (2.0 * coverage) * scale as f64,
+        heterozygosity: heterozygosity * 100.0,
+        error_rate: error_rate(errors / kmers as f64),
+        fit,
+    })
+}
+
+/// Returns the percentage of erroneous bases for the given share of k-mers with an
+/// error, i.e. with an erroneous base among their k bases.
+fn error_rate(erroneous_kmers: f64) -> f64 {
+    (1.0 - (1.0 - erroneous_kmers).powf(1.0 / K as f64)) * 100.0
+}
+
+/// Returns the heterozygosity per base of a diploid genome with the given numbers of
+/// distinct heterozygous and homozygous k-mers. Each haplotype has its own heterozygous
+/// k-mers, which are those with a heterozygous base among their k bases.
+fn heterozygosity(heterozygous: f64, homozygous: f64) -> f64 {
+    let homozygous_share = homozygous / (homozygous + heterozygous / 2.0);
+    1.0 - homozygous_share.powf(1.0 / K as f64)
+}
+
+/// Returns the probabilities of 0 to `max` occurrences for the negative binomial
+/// distribution with the given mean and ratio of the variance to the mean, or the Poisson
+/// distribution for a ratio of 1.
+fn negative_binomial(mean: f64, bias: f64, max: usize) -> Vec<f64> {
+    // The logarithms are summed up for the probabilities of high coverages not to underflow
+    let size = mean / (bias - 1.0);
+    let mut ln_probability = match bias > 1.0 {
+        true => -size * bias.ln(),
+        false => -mean,
+    };
+    let mut probabilities = Vec::with_capacity(max + 1);
+    for occurrences in 0..=max {
+        probabilities.push(ln_probability.exp());
+        let occurrences = occurrences as f64;
+        ln_probability += match bias > 1.0 {
+            true => ((occurrences + size) / (occurrences + 1.0) * (1.0 - 1.0 / bias)).ln(),
+            false => (mean / (occurrences + 1.0)).ln(),
+        };
+    }
+    probabilities
+}
+
+/// Returns the non-negative factors of the two curves best fitting the counts by least
+/// squares.
+fn least_squares(counts: &[f64], a: &[f64], b: &[f64]) -> (f64, f64) {
+    let dot = |x: &[f64], y: &[f64]| x.iter().zip(y).map(|(x, y)| x * y).sum::<f64>();
+    let (aa, ab, bb) = (dot(a, a), dot(a, b), dot(b, b));
+    let (ay, by) = (dot(a, counts), dot(b, counts));
+    let determinant = aa * bb - ab * ab;
+    if determinant > 0.0 {
+        let factors = (
+            (ay * bb - by * ab) / determinant,
+            (by * aa - ay * ab) / determinant,
+        );
+        if factors.0 >= 0.0 && factors.1 >= 0.0 {
+            return factors;
+        }
+    }
+    // Otherwise the best fit has only one of the curves
+    let only = |xy: f64, xx: f64| if xx > 0.0 { (xy / xx).max(0.0) } else { 0.0 };
+    let only_a = (only(ay, aa), 0.0);
+    let only_b = (0.0, only(by, bb));
+    match residual(counts, a, b, only_a.0, only_a.1) < residual(counts, a, b, only_b.0, only_b.1) {
+        true => only_a,
+        false => only_b,
+    }
+}
+
+/// Returns the sum of the squared differences between the counts and the curves.
+fn residual(counts: &[f64], a: &[f64], b: &[f64], factor_a: f64, factor_b: f64) -> f64 {
+    counts
+        .iter()
+        .zip(a.iter().zip(b))
+        .map(|(count, (a, b))| (count - factor_a * a - factor_b * b).powi(2))
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{fit, negative_binomial, SpectrumCounter, MAX_COVERAGE};
+    use crate::sketch::mix;
+    #[test]
+    fn test_negative_binomial() {
+        let poisson = negative_binomial(2.0, 1.0, 50);
+        assert!((poisson[0] - (-2.0_f64).exp()).abs() < 1e-12);
+        assert!((poisson.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        let biased = negative_binomial(500.0, 2.0, 1500);
+        let mean = biased
+            .iter()
+            .enumerate()
+            .map(|(x, p)| x as f64 * p)
+            .sum::<f64>();
+        assert!((mean - 500.0).abs() < 1e-6);
+    }
+    #[test]
+    fn test_fit() {
+        // A genome of a million bases with 1% heterozygosity sequenced at 25x per haplotype
+        let homozygous = 1e6 * 0.99_f64.powi(21);
+        let heterozygous = 2.0 * (1e6 - homozygous);
+        let het = negative_binomial(25.0, 1.5, MAX_COVERAGE);
+        let hom = negative_binomial(50.0, 1.5, MAX_COVERAGE);
+        let mut histogram = (1..=MAX_COVERAGE)
+            .map(|coverage| (heterozygous * het[coverage] + homozygous * hom[coverage]) as usize)
+            .collect::<Vec<_>>();
+        for (coverage, errors) in [(1, 3_000_000), (2, 400_000), (3, 50_000)] {
+            histogram[coverage - 1] += errors;
+        }
+        let kmers = (1..=MAX_COVERAGE)
+            .map(|coverage| coverage * histogram[coverage - 1])
+            .sum();
+        let model = fit(&histogram, kmers, 1).unwrap();
+        assert!((model.coverage - 25.0).abs() < 0.5);
+        assert!((model.heterozygosity - 1.0).abs() < 0.05);
+        assert!((model.genome_size - 1e6).abs() < 2e4);
+        // The errors make up 3.95 million of the 54 million k-mers
+        assert!((model.error_rate - 0.36).abs() < 0.02);
+        // A haploid genome has only the peak of the homozygous k-mers
+        let haploid = (1..=MAX_COVERAGE)
+            .map(|coverage| (1e6 * hom[coverage]) as usize)
+            .collect::<Vec<_>>();
+        let model = fit(&haploid, 50_000_000, 4).unwrap();
+        assert!((model.coverage - 25.0).abs() < 0.5);
+        assert!(model.heterozygosity < 0.01);
+        assert!((model.genome_size - 4e6).abs() < 1e5);
+        let mut counter = SpectrumCounter::new(1);
+        counter.observe(b"ACGTACGTACGTACGTACGTAC");
+        let mut other = SpectrumCounter::new(1);
+        other.observe(b"GTACGTACGTACGTACGTACGT");
+        counter.merge(&other);
+        let spectrum = counter.spectrum();
+        assert_eq!((spectrum.kmers, spectrum.distinct), (4, 2));
+        assert_eq!(spectrum.histogram[..3], [0, 2, 0]);
+        assert!(spectrum.model.is_none());
+        assert!(spectrum.singleton_error_rate.is_none());
+    }
+    #[test]
+    fn test_singleton_error_rate() {
+        let genome = (0..3000)
+            .map(|i| b"ACGT"[(mix(i) % 4) as usize])
+            .collect::<Vec<_>>();
+        let mut counter = SpectrumCounter::new(1);
+        let mut errors = 0;
+        for (i, start) in (0..2900).step_by(5).enumerate() {
+            let mut read = genome[start..start + 100].to_vec();
+            // Every tenth read has an error, which makes 21 k-mers occur once
+            if i % 10 == 0 {
+                read[50] = if read[50] == b'A' { b'C' } else { b'A' };
+                errors += 1;
+            }
+            counter.observe(&read);
+        }
+        let spectrum = counter.spectrum();
+        let expected = errors as f64 / spectrum.kmers as f64 * 100.0;
+        let estimated = spectrum.singleton_error_rate.unwrap();
+        assert!((estimated - expected).abs() < 0.01);
+    }
+}
@@ -0,0 +1,222 @@
+/// The thresholds of common read filters, of which the share of reads passing them is
+/// reported as an estimate of the usable data. The defaults follow `MINLEN:36` of
+/// Trimmomatic, `--n_base_limit` of fastp and `maxEE=2` of DADA2.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SurvivalFilters {
+    pub min_length: usize,
+    /// Lowest mean phred score of a read.
+    pub min_mean_quality: f64,
+    /// Highest number of N bases of a read.
+    pub max_n: usize,
+    /// Highest expected number of errors of a read, i.e. the sum of the error
+    /// probabilities of its bases.
+    pub max_expected_errors: f64,
+}
+
+impl Default for SurvivalFilters {
+    fn default() -> Self {
+        SurvivalFilters {
+            min_length: 36,
+            min_mean_quality: 20.0,
+            max_n: 5,
+            max_expected_errors: 2.0,
+        }
+    }
+}
+
+/// Counts the reads and bases passing each filter and all of them. As the qualities
+/// depend on the phred offset detected afterwards, the quality filters are evaluated
+/// for every possible offset.
+pub(crate) struct SurvivalCounter {
+    filters: SurvivalFilters,
+    offsets: Vec<u8>,
+    reads: usize,
+    bases: usize,
+    length: usize,
+    n: usize,
+    /// Per offset the reads passing the quality filters and the reads and bases passing
+    /// all filters.
+    counts: Vec<OffsetCounts>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct OffsetCounts {
+    quality: usize,
+    expected_errors: usize,
+    reads: usize,
+    bases: usize,
+}
+
+/// Number and percentage of the reads passing a filter.
+pub(crate) struct Survivors {
+    /// Filter with its threshold, e.g. `length >= 36`, or `all filters`.
+    pub(crate) filter: String,
+    pub(crate) reads: usize,
+    pub(crate) percentage: f64,
+}
+
+/// The reads and bases passing the filters.
+pub(crate) struct Survival {
+    pub(crate) filters: Vec<Survivors>,
+    /// Number and percentage of the bases of the reads passing all filters.
+    pub(crate) bases: usize,
+    pub(crate) bases_percentage: f64,
+}
+
+impl SurvivalCounter {
+    /// Returns a counter for the phred offset if given, or all offsets otherwise.
+    pub(crate) fn new(filters: SurvivalFilters, offset: Option<u8>) -> Self {
+        let offsets = offset.map_or_else(|| vec![33, 64], |offset| vec![offset]);
+        SurvivalCounter {
+            filters,
+            counts: vec![OffsetCounts::default(); offsets.len()],
+            offsets,
+            reads: 0,
+            bases: 0,
+            length: 0,
+            n: 0,
+        }
+    }
+
+    /// Adds a read with the given qualities and expected errors for a phred offset
+    /// of 0. Reads without qualities pass the quality filters.
+    pub(crate) fn observe(&mut self, seq: &[u8], qualities: Option<(&[u8], f64)>) {
+        self.reads += 1;
+        self.bases += seq.len();
+        let length = seq.len() >= self.filters.min_length;
+        let n = seq
+            .iter()
+            .filter(|&&base| base == b'N' || base == b'n')
+            .count()
+            <= self.filters.max_n;
+        self.length += length as usize;
+        self.n += n as usize;
+        let sum =
+            qualities.map(|(qualities, _)| qualities.iter().map(|&q| q as usize).sum::<usize>());
+        for (&offset, counts) in self.offsets.iter().zip(&mut self.counts) {
+            let (quality, expected_errors) = match (qualities, sum) {
+                (Some((qualities, errors)), Some(sum)) => {
+                    let mean = match qualities.len() {
+                        0 => 0.0,
+                        len => sum as f64 / len as f64 - offset as f64,
+                    };
+                    let errors = errors * 10_f64.powf(offset as f64 / 10.0);
+                    (
+                        mean >= self.filters.min_mean_quality,
+                        errors <= self.filters.max_expected_errors,
+                    )
+                }
+                _ => (true, true),
+            };
+            counts.quality += quality as usize;
+            counts.expected_errors += expected_errors as usize;
+            if length && n && quality && expected_errors {
+                counts.reads += 1;
+                counts.bases += seq.len();
+            }
+        }
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &SurvivalCounter) {
+        self.reads += other.reads;
+        self.bases += other.bases;
+        self.length += other.length;
+        self.n += other.n;
+        for (counts, other) in self.counts.iter_mut().zip(&other.counts) {
+            counts.quality += other.quality;
+            counts.expected_errors += other.expected_errors;
+            counts.reads += other.reads;
+            counts.bases += other.bases;
+        }
+    }
+
+    /// Returns the reads passing each filter and all of them for the given offset.
+    pub(crate) fn survival(&self, offset: u8) -> Survival {
+        let counts = self
+            .offsets
+            .iter()
+            .position(|&o| o == offset)
+            .map_or_else(OffsetCounts::default, |i| self.counts[i]);
+        let pct = |count: usize, total: usize| match total {
+            0 => 0.0,
+            total => count as f64 * 100.0 / total as f64,
+        };
+        let filters = &self.filters;
+        let filters = vec![
+            (format!("length >= {}", filters.min_length), self.length),
+            (
+                format!("mean quality >= {}", filters.min_mean_quality),
+                counts.quality,
+            ),
+            (format!("N bases <= {}", filters.max_n), self.n),
+            (
+                format!("expected errors <= {}", filters.max_expected_errors),
+                counts.expected_errors,
+            ),
+            ("all filters".to_string(), counts.reads),
+        ];
+        Survival {
+            filters: filters
+                .into_iter()
+                .map(|(filter, reads)| Survivors {
+                    filter,
+                    reads,
+                    percentage: pct(reads, self.reads),
+                })
+                .collect(),
+            bases: counts.bases,
+            bases_percentage: pct(counts.bases, self.bases),
+        }
+    }
+}
+
+impl Survival {
+    /// Returns the reads passing all filters.
+    pub(crate) fn all(&self) -> &Survivors {
+        self.filters.last().expect("filters are reported")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{SurvivalCounter, SurvivalFilters};
+    #[test]
+    fn test_survival() {
+        let filters = SurvivalFilters {
+            min_length: 4,
+            ..SurvivalFilters::default()
+        };
+        let mut counter = SurvivalCounter::new(filters, None);
+        // Q40 and Q3 bases with an offset of 33, errors for an offset of 0
+        fn errors(qualities: &[u8]) -> Option<(&[u8], f64)> {
+            let errors = qualities
+                .iter()
+                .map(|&q| 10_f64.powf(-(q as f64) / 10.0))
+                .sum();
+            Some((qualities, errors))
+        }
+        counter.observe(b"ACGT", errors(b"IIII"));
+        counter.observe(b"ACG", errors(b"III"));
+        counter.observe(b"ACGTACGT", errors(b"IIII$$$$"));
+        let mut other = SurvivalCounter::new(filters, None);
+        other.observe(b"NNNNNNNN", errors(b"IIIIIIII"));
+        other.observe(b"ACGT", None);
+        counter.merge(&other);
+        let survival = counter.survival(33);
+        let reads = survival
+            .filters
+            .iter()
+            .map(|survivors| survivors.reads)
+            .collect::<Vec<_>>();
+        assert_eq!(reads, [4, 5, 4, 4, 2]);
+        assert_eq!(survival.filters[0].filter, "length >= 4");
+        assert_eq!(survival.all().percentage, 40.0);
+        assert_eq!(
+            (survival.bases, survival.bases_percentage),
+            (8, 8.0 * 100.0 / 27.0)
+        );
+        // With an offset of 64, the Q40 bases of an offset of 33 have a quality of 9
+        assert_eq!(counter.survival(64).filters[4].reads, 1);
+    }
+}
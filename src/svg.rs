@@ -0,0 +1,259 @@
+use itertools::Itertools;
+use std::fmt::Write;
+
+const WIDTH: f64 = 800.0;
+const HEIGHT: f64 = 450.0;
+/// Space around the plot area for the title, axes and legend.
+const MARGIN: (f64, f64, f64, f64) = (40.0, 180.0, 50.0, 70.0);
+/// Colors of the series, the Tableau 10 scheme used by Vega-Lite.
+const COLORS: [&str; 10] = [
+    "#4c78a8", "#f58518", "#e45756", "#72b7b2", "#54a24b", "#eeca3b", "#b279a2", "#ff9da6",
+    "#9d755d", "#bab0ac",
+];
+
+/// A static line chart, optionally with a box per x value, e.g. of qualities per position.
+#[derive(Default)]
+pub(crate) struct Chart {
+    pub(crate) title: String,
+    pub(crate) x_label: String,
+    pub(crate) y_label: String,
+    pub(crate) series: Vec<Series>,
+    pub(crate) boxes: Vec<QualityBox>,
+    /// Labels of the x values 0, 1, 2 and so on, if they are categories rather than numbers.
+    pub(crate) categories: Vec<String>,
+    /// Lowest and highest value of the y axis, fitted to the data if `None`.
+    pub(crate) y_range: Option<(f64, f64)>,
+}
+
+/// A named line of points.
+pub(crate) struct Series {
+    pub(crate) name: String,
+    pub(crate) points: Vec<(f64, f64)>,
+}
+
+/// A box from the lower to the upper quartile with whiskers and a median line.
+pub(crate) struct QualityBox {
+    pub(crate) x: f64,
+    pub(crate) whiskers: (f64, f64),
+    pub(crate) quartiles: (f64, f64),
+    pub(crate) median: f64,
+}
+
+impl Chart {
+    /// Renders the chart as a standalone SVG document.
+    pub(crate) fn render(&self) -> String {
+        let (top, right, bottom, left) = MARGIN;
+        let (plot_width, plot_height) = (WIDTH - left - right, HEIGHT - top - bottom);
+        let xs = self
+            .series
+            .iter()
+            .flat_map(|series| series.points.iter().map(|&(x, _)| x))
+            .chain(self.boxes.iter().map(|b| b.x));
+        let ys = self
+            .series
+            .iter()
+            .flat_map(|series| series.points.iter().map(|&(_, y)| y))
+            .chain(self.boxes.iter().flat_map(|b| [b.whiskers.0, b.whiskers.1]));
+        let (x_min, x_max) = bounds(xs);
+        let (y_min, y_max) = match self.y_range {
+            Some(range) => range,
+            None => {
+                let (min, max) = bounds(ys);
+                (min.min(0.0), max)
+            }
+        };
+        // Coordinates are rounded to keep the files small
+        let round = |value: f64| (value * 100.0).round() / 100.0;
+        let x = |value: f64| round(left + (value - x_min) / (x_max - x_min) * plot_width);
+        let y =
+            |value: f64| round(top + plot_height - (value - y_min) / (y_max - y_min) * plot_height);
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" font-family="sans-serif" font-size="11">"#,
+            WIDTH, HEIGHT
+        );
+        let _ = writeln!(svg, r#"<rect width="100%" height="100%" fill="white"/>"#);
+        let _ = writeln!(
+            svg,
+            r#"<text x="{}" y="24" text-anchor="middle" font-size="14" font-weight="bold">{}</text>"#,
+            left + plot_width / 2.0,
+            escape(&self.title)
+        );
+
+        // Axes with grid lines at the ticks
+        for tick in ticks(y_min, y_max) {
+            let _ = writeln!(
+                svg,
+                r##"<line x1="{l}" x2="{r}" y1="{y}" y2="{y}" stroke="#ddd"/><text x="{t}" y="{y}" dy="4" text-anchor="end">{v}</text>"##,
+                l = left,
+                r = left + plot_width,
+                y = y(tick),
+                t = left - 6.0,
+                v = format_tick(tick)
+            );
+        }
+        let x_ticks = if self.categories.is_empty() {
+            ticks(x_min, x_max)
+                .into_iter()
+                .map(|tick| (tick, format_tick(tick)))
+                .collect()
+        } else {
+            self.categories
+                .iter()
+                .enumerate()
+                .map(|(i, label)| (i as f64, escape(label)))
+                .collect::<Vec<_>>()
+        };
+        for (tick, label) in x_ticks {
+            let _ = writeln!(
+                svg,
+                r##"<line x1="{x}" x2="{x}" y1="{b}" y2="{e}" stroke="#888"/><text x="{x}" y="{t}" text-anchor="middle">{v}</text>"##,
+                x = x(tick),
+                b = top + plot_height,
+                e = top + plot_height + 5.0,
+                t = top + plot_height + 18.0,
+                v = label
+            );
+        }
+        let _ = writeln!(
+            svg,
+            r##"<rect x="{}" y="{}" width="{}" height="{}" fill="none" stroke="#888"/>"##,
+            left, top, plot_width, plot_height
+        );
+        let _ = writeln!(
+            svg,
+            r#"<text x="{}" y="{}" text-anchor="middle">{}</text>"#,
+            left + plot_width / 2.0,
+            HEIGHT - 12.0,
+            escape(&self.x_label)
+        );
+        let _ = writeln!(
+            svg,
+            r#"<text transform="translate(18 {}) rotate(-90)" text-anchor="middle">{}</text>"#,
+            top + plot_height / 2.0,
+            escape(&self.y_label)
+        );
+
+        // Boxes are drawn below the lines
+        let box_width = (plot_width / self.boxes.len().max(1) as f64 * 0.7).min(20.0);
+        for b in &self.boxes {
+            let _ = writeln!(
+                svg,
+                r##"<line x1="{x}" x2="{x}" y1="{}" y2="{}" stroke="#333"/><rect x="{}" y="{}" width="{}" height="{}" fill="#fdd835" stroke="#333"/><line x1="{}" x2="{}" y1="{m}" y2="{m}" stroke="#c62828" stroke-width="2"/>"##,
+                y(b.whiskers.0),
+                y(b.whiskers.1),
+                x(b.x) - box_width / 2.0,
+                y(b.quartiles.1),
+                box_width,
+                y(b.quartiles.0) - y(b.quartiles.1),
+                x(b.x) - box_width / 2.0,
+                x(b.x) + box_width / 2.0,
+                x = x(b.x),
+                m = y(b.median)
+            );
+        }
+        for (i, series) in self.series.iter().enumerate() {
+            let color = COLORS[i % COLORS.len()];
+            let points = series
+                .points
+                .iter()
+                .map(|&(px, py)| format!("{},{}", x(px), y(py.clamp(y_min, y_max))))
+                .join(" ");
+            let _ = writeln!(
+                svg,
+                r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="1.5"/>"#,
+                points, color
+            );
+            if self.series.len() > 1 || !self.boxes.is_empty() {
+                let legend_y = top + 10.0 + i as f64 * 18.0;
+                let _ = writeln!(
+                    svg,
+                    r#"<rect x="{}" y="{}" width="12" height="12" fill="{}"/><text x="{}" y="{}">{}</text>"#,
+                    left + plot_width + 12.0,
+                    legend_y - 10.0,
+                    color,
+                    left + plot_width + 30.0,
+                    legend_y,
+                    escape(&series.name)
+                );
+            }
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// Returns the lowest and highest value, keeping the range from being empty.
+fn bounds(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    let (min, max) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+        (min.min(v), max.max(v))
+    });
+    match (min.is_finite(), max.is_finite()) {
+        (true, true) if max > min => (min, max),
+        (true, true) => (min, min + 1.0),
+        _ => (0.0, 1.0),
+    }
+}
+
+/// Returns evenly spaced tick values at round numbers between `min` and `max`.
+fn ticks(min: f64, max: f64) -> Vec<f64> {
+    let rough = (max - min) / 8.0;
+    let magnitude = 10_f64.powf(rough.log10().floor());
+    let step = [1.0, 2.0, 5.0, 10.0]
+        .iter()
+        .map(|factor| factor * magnitude)
+        .find(|&step| step >= rough)
+        .unwrap_or(rough);
+    let first = (min / step).ceil();
+    (0..)
+        .map(|i| (first + i as f64) * step)
+        .take_while(|&tick| tick <= max + step * 1e-9)
+        .collect()
+}
+
+fn format_tick(value: f64) -> String {
+    if value.fract().abs() < 1e-9 {
+        format!("{}", value.round())
+    } else {
+        format!("{:.2}", value)
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ticks, Chart, Series};
+    #[test]
+    fn test_ticks() {
+        assert_eq!(
+            ticks(0.0, 40.0),
+            vec![0.0, 5.0, 10.0, 15.0, 20.0, 25.0, 30.0, 35.0, 40.0]
+        );
+        assert_eq!(ticks(1.0, 2.0).len(), 6);
+    }
+    #[test]
+    fn test_render() {
+        let chart = Chart {
+            title: "GC <content>".to_string(),
+            series: vec![Series {
+                name: "count".to_string(),
+                points: vec![(0.0, 1.0), (100.0, 3.0)],
+            }],
+            ..Chart::default()
+        };
+        let svg = chart.render();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("GC &lt;content&gt;"));
+        assert_eq!(svg.matches("<polyline").count(), 1);
+    }
+}
@@ -0,0 +1,142 @@
+use crate::bins::Binning;
+use crate::illumina::IlluminaHeader;
+use rustc_hash::FxHashMap as HashMap;
+use std::ops::Range;
+
+/// Sums up base qualities per flowcell tile and position bin.
+#[derive(Default)]
+pub(crate) struct TileCounter {
+    binning: Binning,
+    /// Per tile the quality sum and number of bases at each position bin.
+    tiles: HashMap<usize, Vec<(u64, u64)>>,
+}
+
+/// The mean quality deviation of a tile at a specific range of position bins.
+pub(crate) struct TileDeviation {
+    pub(crate) tile: usize,
+    pub(crate) bins: Range<usize>,
+    pub(crate) deviation: f64,
+}
+
+impl TileCounter {
+    pub(crate) fn new(binning: Binning) -> Self {
+        TileCounter {
+            binning,
+            tiles: HashMap::default(),
+        }
+    }
+
+    /// Adds the qualities of a record if its read name contains tile information.
+    pub(crate) fn observe(&mut self, id: &[u8], qualities: &[u8]) {
+        let tile = match IlluminaHeader::parse(id).and_then(|header| header.tile()) {
+            Some(tile) => tile as usize,
+            None => return,
+        };
+        let sums = self.tiles.entry(tile).or_default();
+        let bins = self.binning.bins(qualities.len());
+        if sums.len() < bins {
+            sums.resize(bins, (0, 0));
+        }
+        for (pos, &q) in qualities.iter().enumerate() {
+            let sum = &mut sums[self.binning.bin(pos)];
+            sum.0 += q as u64;
+            sum.1 += 1;
+        }
+    }
+
+    /// Adds the sums of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &TileCounter) {
+        for (&tile, other) in &other.tiles {
+            let sums = self.tiles.entry(tile).or_default();
+            if sums.len() < other.len() {
+                sums.resize(other.len(), (0, 0));
+            }
+            for (sum, other) in sums.iter_mut().zip(other) {
+                sum.0 += other.0;
+                sum.1 += other.1;
+            }
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    /// Returns for every tile and group of position bins the difference between the mean
+    /// quality of the tile and the mean quality of all tiles, ordered by tile and
+    /// position. As only differences are reported, the phred offset of the qualities
+    /// cancels out.
+    pub(crate) fn deviations(&self, groups: &[Range<usize>]) -> Vec<TileDeviation> {
+        let positions = self.tiles.values().map(Vec::len).max().unwrap_or(0);
+        let mut totals = vec![(0_u64, 0_u64); positions];
+        for sums in self.tiles.values() {
+            for (total, sum) in totals.iter_mut().zip(sums) {
+                total.0 += sum.0;
+                total.1 += sum.1;
+            }
+        }
+        let grouped = |sums: &[(u64, u64)]| {
+            groups
+                .iter()
+                .map(|group| {
+                    let sums = &sums[group.start.min(sums.len())..group.end.min(sums.len())];
+                    let sum = sums
+                        .iter()
+                        .fold((0, 0), |(q, n), sum| (q + sum.0, n + sum.1));
+                    (group.clone(), sum)
+                })
+                .collect::<Vec<_>>()
+        };
+        let totals = grouped(&totals);
+        let mut tiles = self.tiles.iter().collect::<Vec<_>>();
+        tiles.sort_by_key(|(&tile, _)| tile);
+        let mut deviations = Vec::new();
+        for (&tile, sums) in tiles {
+            for ((bins, sum), (_, total)) in grouped(sums).into_iter().zip(&totals) {
+                if sum.1 == 0 {
+                    continue;
+                }
+                let mean = sum.0 as f64 / sum.1 as f64;
+                let overall = total.0 as f64 / total.1 as f64;
+                deviations.push(TileDeviation {
+                    tile,
+                    bins,
+                    deviation: mean - overall,
+                });
+            }
+        }
+        deviations
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TileCounter;
+    #[test]
+    fn test_deviations() {
+        let mut counter = TileCounter::default();
+        counter.observe(b"M:1:FC:1:1101:1:1", b"II");
+        counter.observe(b"M:1:FC:1:1102:1:1", b"5I");
+        let deviations = counter.deviations(&[0..1, 1..2]);
+        assert_eq!(deviations.len(), 4);
+        assert_eq!(deviations[0].tile, 1101);
+        assert_eq!(deviations[0].deviation, 10.0);
+        assert_eq!(deviations[2].deviation, -10.0);
+        assert_eq!(deviations[3].deviation, 0.0);
+        let deviations = counter.deviations(std::slice::from_ref(&(0..2)));
+        assert_eq!(deviations.len(), 2);
+        assert_eq!(deviations[0].bins, 0..2);
+        assert_eq!(deviations[1].deviation, -5.0);
+    }
+    #[test]
+    fn test_low_qualities() {
+        // Qualities below '!' must not underflow
+        let mut counter = TileCounter::default();
+        counter.observe(b"M:1:FC:1:1101:1:1", b" ");
+        counter.observe(b"M:1:FC:1:1102:1:1", b"\"");
+        assert_eq!(
+            counter.deviations(std::slice::from_ref(&(0..1)))[0].deviation,
+            -1.0
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use itertools::Itertools;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Runs the observation of a counter, adding the time it took to the timings under the
+/// given name if they are measured.
+macro_rules! timed {
+    ($timings:expr, $name:expr, $observe:expr) => {
+        if $timings.is_some() {
+            let start = std::time::Instant::now();
+            $observe;
+            if let Some(timings) = &mut $timings {
+                timings.add($name, start.elapsed());
+            }
+        } else {
+            $observe;
+        }
+    };
+}
+pub(crate) use timed;
+
+/// Time spent by the counters of the modules observing the records, which is only
+/// measured if debug messages are logged.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Timings(BTreeMap<&'static str, Duration>);
+
+impl Timings {
+    pub(crate) fn add(&mut self, name: &'static str, time: Duration) {
+        *self.0.entry(name).or_default() += time;
+    }
+
+    /// Adds the timings of another part of the same file, e.g. of another thread.
+    pub(crate) fn merge(&mut self, other: &Timings) {
+        for (&name, &time) in &other.0 {
+            self.add(name, time);
+        }
+    }
+
+    /// Lists the counters from the slowest to the fastest with their time in seconds.
+    pub(crate) fn summary(&self) -> String {
+        self.0
+            .iter()
+            .sorted_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)))
+            .map(|(name, time)| format!("{} {:.3}s", name, time.as_secs_f64()))
+            .join(", ")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Timings;
+    use std::time::Duration;
+    #[test]
+    fn test_timings() {
+        let mut timings = Some(Timings::default());
+        let mut observed = 0;
+        timed!(timings, "kmer_content", observed += 1);
+        let mut other = Timings::default();
+        other.add("kmer_content", Duration::from_millis(1500));
+        other.add("adapter_content", Duration::from_millis(250));
+        other.add("composition", Duration::from_millis(250));
+        let mut timings = timings.unwrap();
+        timings.merge(&other);
+        assert_eq!(observed, 1);
+        assert!(timings.summary().starts_with("kmer_content 1.5"));
+        assert!(timings
+            .summary()
+            .ends_with("adapter_content 0.250s, composition 0.250s"));
+        let mut unmeasured: Option<Timings> = None;
+        timed!(unmeasured, "kmer_content", observed += 1);
+        assert_eq!(observed, 2);
+        assert!(unmeasured.is_none());
+    }
+}
@@ -0,0 +1,268 @@
+use rustc_hash::FxHashMap as HashMap;
+
+/// Number of bases of the sliding window, like `SLIDINGWINDOW:4:<cutoff>` of
+/// Trimmomatic.
+const WINDOW: usize = 4;
+/// Highest quality cutoff that can be simulated.
+pub(crate) const MAX_CUTOFF: u8 = 63;
+
+/// Set of the quality cutoffs of which the trimming is simulated.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TrimCutoffs(u64);
+
+impl TrimCutoffs {
+    /// Returns the set of the given cutoffs, each up to `MAX_CUTOFF`.
+    pub(crate) fn new(cutoffs: &[u8]) -> Self {
+        TrimCutoffs(cutoffs.iter().fold(0, |set, &cutoff| set | 1 << cutoff))
+    }
+
+    pub(crate) fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the cutoffs in increasing order.
+    pub(crate) fn cutoffs(self) -> Vec<u8> {
+        (0..=MAX_CUTOFF)
+            .filter(|cutoff| self.0 & (1 << cutoff) != 0)
+            .collect()
+    }
+}
+
+/// Parses quality cutoffs given like `Q20,Q30` or `20,30`.
+pub(crate) fn parse_cutoffs(text: &str) -> Result<TrimCutoffs, String> {
+    let cutoffs = text
+        .split(',')
+        .map(|cutoff| {
+            let cutoff = cutoff.trim();
+            match cutoff
+                .strip_prefix(['Q', 'q'])
+                .unwrap_or(cutoff)
+                .parse::<u8>()
+            {
+                Ok(cutoff) if (1..=MAX_CUTOFF).contains(&cutoff) => Ok(cutoff),
+                _ => Err(format!(
+                    "Invalid quality cutoff {}, expected e.g. Q20 up to Q{}.",
+                    cutoff, MAX_CUTOFF
+                )),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(TrimCutoffs::new(&cutoffs))
+}
+
+/// Algorithm of the simulated quality trimming.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TrimMethod {
+    /// Trims the 3' end where the sum of the cutoff minus the qualities is largest,
+    /// like BWA and `-q` of cutadapt.
+    Bwa,
+    /// Cuts the read at the first window of bases with a mean quality below the cutoff,
+    /// like `SLIDINGWINDOW` of Trimmomatic.
+    SlidingWindow,
+}
+
+impl TrimMethod {
+    const ALL: [TrimMethod; 2] = [TrimMethod::Bwa, TrimMethod::SlidingWindow];
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            TrimMethod::Bwa => "BWA",
+            TrimMethod::SlidingWindow => "sliding window",
+        }
+    }
+
+    /// Returns the length of the read left after trimming the given qualities, which
+    /// are shifted by the phred offset like the cutoff.
+    fn trimmed_length(self, qualities: &[u8], cutoff: u8) -> usize {
+        match self {
+            TrimMethod::Bwa => {
+                let mut sum = 0_i64;
+                let mut max = 0;
+                let mut length = qualities.len();
+                for (i, &quality) in qualities.iter().enumerate().rev() {
+                    sum += cutoff as i64 - quality as i64;
+                    if sum < 0 {
+                        break;
+                    }
+                    if sum > max {
+                        max = sum;
+                        length = i;
+                    }
+                }
+                length
+            }
+            TrimMethod::SlidingWindow => {
+                let window = WINDOW.min(qualities.len()).max(1);
+                qualities
+                    .windows(window)
+                    .position(|bases| {
+                        let sum = bases.iter().map(|&q| q as usize).sum::<usize>();
+                        sum < cutoff as usize * window
+                    })
+                    .unwrap_or(qualities.len())
+            }
+        }
+    }
+}
+
+/// Counts the lengths the reads would have after quality trimming with every cutoff
+/// and method. As the qualities depend on the phred offset detected afterwards, the
+/// trimming is simulated for every possible offset.
+pub(crate) struct TrimSimulationCounter {
+    cutoffs: Vec<u8>,
+    /// Shortest trimmed read which is kept.
+    min_length: usize,
+    offsets: Vec<u8>,
+    reads: usize,
+    bases: usize,
+    /// Number of reads by their trimmed length per offset, cutoff and method.
+    lengths: Vec<HashMap<usize, usize>>,
+}
+
+/// Reads and bases kept after quality trimming with a cutoff and method.
+pub(crate) struct TrimmedReads {
+    pub(crate) method: TrimMethod,
+    pub(crate) cutoff: u8,
+    /// Number and percentage of the reads at least as long as the minimal length.
+    pub(crate) reads: usize,
+    pub(crate) reads_percentage: f64,
+    /// Number and percentage of the bases of these reads.
+    pub(crate) bases: usize,
+    pub(crate) bases_percentage: f64,
+    /// Number of all reads by their trimmed length, ordered by length.
+    pub(crate) lengths: Vec<(usize, usize)>,
+}
+
+impl TrimSimulationCounter {
+    /// Returns a counter for the phred offset if given, or all offsets otherwise.
+    pub(crate) fn new(cutoffs: TrimCutoffs, min_length: usize, offset: Option<u8>) -> Self {
+        let cutoffs = cutoffs.cutoffs();
+        let offsets = offset.map_or_else(|| vec![33, 64], |offset| vec![offset]);
+        TrimSimulationCounter {
+            lengths: vec![
+                HashMap::default();
+                offsets.len() * cutoffs.len() * TrimMethod::ALL.len()
+            ],
+            cutoffs,
+            min_length,
+            offsets,
+            reads: 0,
+            bases: 0,
+        }
+    }
+
+    /// Adds a read of the given length with its qualities. Reads without qualities are
+    /// not trimmed.
+    pub(crate) fn observe(&mut self, length: usize, qualities: Option<&[u8]>) {
+        self.reads += 1;
+        self.bases += length;
+        let mut lengths = self.lengths.iter_mut();
+        for &offset in &self.offsets {
+            for &cutoff in &self.cutoffs {
+                for method in TrimMethod::ALL {
+                    let trimmed = qualities.map_or(length, |qualities| {
+                        method.trimmed_length(qualities, cutoff + offset)
+                    });
+                    *lengths.next().unwrap().entry(trimmed).or_default() += 1;
+                }
+            }
+        }
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &TrimSimulationCounter) {
+        self.reads += other.reads;
+        self.bases += other.bases;
+        for (lengths, other) in self.lengths.iter_mut().zip(&other.lengths) {
+            for (&length, &reads) in other {
+                *lengths.entry(length).or_default() += reads;
+            }
+        }
+    }
+
+    /// Returns the reads kept with every cutoff and method for the given offset.
+    pub(crate) fn simulate(&self, offset: u8) -> Vec<TrimmedReads> {
+        let Some(i) = self.offsets.iter().position(|&o| o == offset) else {
+            return Vec::new();
+        };
+        let pct = |count: usize, total: usize| match total {
+            0 => 0.0,
+            total => count as f64 * 100.0 / total as f64,
+        };
+        let per_offset = self.cutoffs.len() * TrimMethod::ALL.len();
+        let mut lengths = self.lengths[i * per_offset..(i + 1) * per_offset].iter();
+        let mut simulated = Vec::new();
+        for &cutoff in &self.cutoffs {
+            for method in TrimMethod::ALL {
+                let mut lengths = lengths
+                    .next()
+                    .unwrap()
+                    .iter()
+                    .map(|(&length, &reads)| (length, reads))
+                    .collect::<Vec<_>>();
+                lengths.sort_unstable();
+                let kept = lengths
+                    .iter()
+                    .filter(|(length, _)| *length >= self.min_length);
+                let reads = kept.clone().map(|(_, reads)| reads).sum();
+                let bases = kept.map(|(length, reads)| length * reads).sum();
+                simulated.push(TrimmedReads {
+                    method,
+                    cutoff,
+                    reads,
+                    reads_percentage: pct(reads, self.reads),
+                    bases,
+                    bases_percentage: pct(bases, self.bases),
+                    lengths,
+                });
+            }
+        }
+        simulated
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_cutoffs, TrimCutoffs, TrimMethod, TrimSimulationCounter};
+    #[test]
+    fn test_trimmed_length() {
+        // Qualities with an offset of 0
+        let qualities = [30, 30, 30, 30, 30, 10, 30, 10, 10, 10];
+        assert_eq!(TrimMethod::Bwa.trimmed_length(&qualities, 20), 7);
+        assert_eq!(TrimMethod::SlidingWindow.trimmed_length(&qualities, 20), 5);
+        assert_eq!(TrimMethod::Bwa.trimmed_length(&[30; 5], 20), 5);
+        assert_eq!(TrimMethod::SlidingWindow.trimmed_length(&[30; 3], 20), 3);
+        assert_eq!(TrimMethod::SlidingWindow.trimmed_length(&[10; 3], 20), 0);
+        assert_eq!(TrimMethod::Bwa.trimmed_length(&[], 20), 0);
+    }
+    #[test]
+    fn test_simulate() {
+        let cutoffs = parse_cutoffs("Q20, 30").unwrap();
+        assert_eq!(cutoffs.cutoffs(), [20, 30]);
+        assert!(parse_cutoffs("Q20,Q").is_err());
+        assert!(parse_cutoffs("0").is_err());
+        assert!(TrimCutoffs::default().is_empty());
+        let mut counter = TrimSimulationCounter::new(cutoffs, 4, Some(33));
+        // Q40 bases followed by Q25 bases
+        counter.observe(8, Some(b"IIII::::"));
+        let mut other = TrimSimulationCounter::new(cutoffs, 4, Some(33));
+        other.observe(4, None);
+        counter.merge(&other);
+        let simulated = counter.simulate(33);
+        let kept = simulated
+            .iter()
+            .map(|trimmed| (trimmed.method, trimmed.cutoff, trimmed.reads, trimmed.bases))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kept,
+            [
+                (TrimMethod::Bwa, 20, 2, 12),
+                (TrimMethod::SlidingWindow, 20, 2, 12),
+                (TrimMethod::Bwa, 30, 2, 8),
+                (TrimMethod::SlidingWindow, 30, 1, 4),
+            ]
+        );
+        assert_eq!(simulated[3].lengths, [(3, 1), (4, 1)]);
+        assert!(counter.simulate(64).is_empty());
+    }
+}
@@ -0,0 +1,87 @@
+use std::fmt;
+use std::ops::Range;
+
+/// Mean quality below which positions at the ends of the reads are suggested to be
+/// trimmed, like the common `-q 20` of cutadapt.
+pub(crate) const QUALITY_CUTOFF: f64 = 20.0;
+
+/// Hard trimming suggested from the quality and adapter content per position, e.g.
+/// for the `HEADCROP` and `CROP` steps of Trimmomatic.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Trimming {
+    /// Number of bases to remove from the 5' end.
+    pub(crate) five_prime: usize,
+    /// Length to crop the reads to, removing the bases after it from the 3' end.
+    pub(crate) three_prime: usize,
+    pub(crate) quality_cutoff: f64,
+}
+
+impl Trimming {
+    /// Suggests to trim the positions at both ends whose mean quality is below the
+    /// cutoff, and the positions from where more reads than `max_adapter` percent
+    /// contain an adapter. Both are given by groups of positions, with the cumulative
+    /// adapter content of each adapter.
+    pub(crate) fn suggest(
+        qualities: &[(Range<usize>, f64)],
+        adapters: &[Vec<(Range<usize>, f64)>],
+        max_length: usize,
+        max_adapter: f64,
+    ) -> Self {
+        let low = |(_, mean): &&(Range<usize>, f64)| *mean < QUALITY_CUTOFF;
+        let five_prime = qualities
+            .iter()
+            .take_while(low)
+            .last()
+            .map_or(0, |(bins, _)| bins.end);
+        let mut three_prime = match qualities.iter().rev().find(|quality| !low(quality)) {
+            Some((bins, _)) => bins.end,
+            None if qualities.is_empty() => max_length,
+            None => five_prime,
+        };
+        for percentages in adapters {
+            if let Some((bins, _)) = percentages.iter().find(|(_, pct)| *pct > max_adapter) {
+                three_prime = three_prime.min(bins.start);
+            }
+        }
+        Trimming {
+            five_prime,
+            three_prime: three_prime.max(five_prime),
+            quality_cutoff: QUALITY_CUTOFF,
+        }
+    }
+}
+
+impl fmt::Display for Trimming {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} bp from 5', crop to {} bp, Q{}",
+            self.five_prime, self.three_prime, self.quality_cutoff
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Trimming;
+    #[test]
+    fn test_suggest() {
+        let qualities = [
+            (0..1, 15.0),
+            (1..2, 19.9),
+            (2..5, 35.0),
+            (5..10, 18.0),
+            (10..20, 30.0),
+            (20..30, 12.0),
+        ];
+        let trimming = Trimming::suggest(&qualities, &[], 30, 5.0);
+        assert_eq!((trimming.five_prime, trimming.three_prime), (2, 20));
+        let adapters = [vec![(0..10, 0.0), (10..20, 6.0)], vec![(0..20, 1.0)]];
+        let trimming = Trimming::suggest(&qualities, &adapters, 30, 5.0);
+        assert_eq!((trimming.five_prime, trimming.three_prime), (2, 10));
+        let trimming = Trimming::suggest(&[], &[], 30, 5.0);
+        assert_eq!((trimming.five_prime, trimming.three_prime), (0, 30));
+        let trimming = Trimming::suggest(&qualities[..2], &[], 30, 5.0);
+        assert_eq!((trimming.five_prime, trimming.three_prime), (2, 2));
+    }
+}
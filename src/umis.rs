@@ -0,0 +1,204 @@
+use crate::sketch::{hash, HyperLogLog};
+use rustc_hash::FxHashMap as HashMap;
+
+const BASES: [u8; 5] = *b"ACGTN";
+/// Minimum number of bases of the last field of a read name to be taken as UMI.
+const MIN_LENGTH: usize = 4;
+/// Number of distinct UMIs counted exactly, afterwards only UMIs already seen are
+/// counted and the number of distinct UMIs is estimated.
+const TRACKING_LIMIT: usize = 1 << 20;
+/// Number of most frequent UMIs reported.
+const TOP_UMIS: usize = 10;
+
+/// Counts the unique molecular identifiers of the reads, taken from the end of the read
+/// names like `@name:ACGTACGT` of bcl2fastq or `@name_ACGTACGT` of UMI-tools, or from
+/// the first bases of the reads.
+pub(crate) struct UmiCounter {
+    /// Number of bases at the start of the reads which make up the UMI, if not in the
+    /// read names.
+    length: Option<usize>,
+    counts: HashMap<Vec<u8>, usize>,
+    distinct: HyperLogLog,
+    /// Number of each base of `ACGTN` per position of the UMIs.
+    composition: Vec<[usize; 5]>,
+    reads: usize,
+    /// Number of reads with a UMI.
+    umis: usize,
+}
+
+/// Number, diversity and composition of the UMIs of the reads.
+pub(crate) struct Umis {
+    /// Number and percentage of reads with a UMI.
+    pub(crate) reads: usize,
+    pub(crate) percentage: f64,
+    /// Number of distinct UMIs, estimated for many of them.
+    pub(crate) distinct: usize,
+    /// Number of distinct UMIs expected for random UMIs of the most common length.
+    pub(crate) expected: f64,
+    /// Percentage of distinct among expected UMIs, which is low for UMIs with biased
+    /// or failed synthesis.
+    pub(crate) diversity: f64,
+    /// Percentage of each base of `ACGTN` per position.
+    pub(crate) composition: Vec<[f64; 5]>,
+    /// Most frequent UMIs with their number and percentage of the UMIs.
+    pub(crate) top: Vec<(String, usize, f64)>,
+}
+
+impl UmiCounter {
+    pub(crate) fn new(length: Option<usize>) -> Self {
+        UmiCounter {
+            length,
+            counts: HashMap::default(),
+            distinct: HyperLogLog::new(),
+            composition: Vec::new(),
+            reads: 0,
+            umis: 0,
+        }
+    }
+
+    pub(crate) fn observe(&mut self, id: &[u8], seq: &[u8]) {
+        self.reads += 1;
+        let umi = match self.length {
+            Some(length) => seq.get(..length),
+            None => umi_from_name(id),
+        };
+        let Some(umi) = umi else {
+            return;
+        };
+        self.umis += 1;
+        self.distinct.add(hash(umi));
+        if self.counts.len() < TRACKING_LIMIT {
+            *self.counts.entry(umi.to_vec()).or_insert(0) += 1;
+        } else if let Some(count) = self.counts.get_mut(umi) {
+            *count += 1;
+        }
+        // The separator of dual UMIs is left out of the positions
+        let bases = umi.iter().filter(|&&base| base != b'+');
+        for (position, base) in bases.enumerate() {
+            if self.composition.len() <= position {
+                self.composition.push([0; 5]);
+            }
+            let base = base.to_ascii_uppercase();
+            if let Some(index) = BASES.iter().position(|&b| b == base) {
+                self.composition[position][index] += 1;
+            }
+        }
+    }
+
+    /// Adds the counts of another counter, e.g. from a different thread.
+    pub(crate) fn merge(&mut self, other: &UmiCounter) {
+        for (umi, &count) in &other.counts {
+            if self.counts.len() < TRACKING_LIMIT || self.counts.contains_key(umi) {
+                *self.counts.entry(umi.clone()).or_insert(0) += count;
+            }
+        }
+        self.distinct.merge(&other.distinct);
+        if self.composition.len() < other.composition.len() {
+            self.composition.resize(other.composition.len(), [0; 5]);
+        }
+        for (counts, other) in self.composition.iter_mut().zip(&other.composition) {
+            for (count, other) in counts.iter_mut().zip(other) {
+                *count += other;
+            }
+        }
+        self.reads += other.reads;
+        self.umis += other.umis;
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.umis == 0
+    }
+
+    pub(crate) fn umis(&self) -> Umis {
+        let distinct = match self.counts.len() {
+            tracked if tracked < TRACKING_LIMIT => tracked,
+            _ => self.distinct.estimate().round() as usize,
+        };
+        // Positions covered by most UMIs make up the most common length
+        let length = self
+            .composition
+            .iter()
+            .filter(|counts| counts.iter().sum::<usize>() * 2 >= self.umis)
+            .count();
+        let possible = 4_f64.powi(length as i32);
+        let expected = -possible * (self.umis as f64 * (-1.0 / possible).ln_1p()).exp_m1();
+        let pct = |count: usize, total: usize| match total {
+            0 => 0.0,
+            total => count as f64 * 100.0 / total as f64,
+        };
+        let mut top = self.counts.iter().collect::<Vec<_>>();
+        top.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+        Umis {
+            reads: self.umis,
+            percentage: pct(self.umis, self.reads),
+            distinct,
+            expected,
+            diversity: match expected {
+                expected if expected > 0.0 => distinct as f64 / expected * 100.0,
+                _ => 0.0,
+            },
+            composition: self
+                .composition
+                .iter()
+                .map(|counts| {
+                    let total = counts.iter().sum();
+                    counts.map(|count| pct(count, total))
+                })
+                .collect(),
+            top: top
+                .into_iter()
+                .take(TOP_UMIS)
+                .map(|(umi, &count)| {
+                    let umi = String::from_utf8_lossy(umi).to_string();
+                    (umi, count, pct(count, self.umis))
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Returns the last field of the read name after a `:` or `_` if it consists of at least
+/// `MIN_LENGTH` bases, joined by `+` for dual UMIs.
+fn umi_from_name(id: &[u8]) -> Option<&[u8]> {
+    let name = id.split(|byte| byte.is_ascii_whitespace()).next()?;
+    let start = name
+        .iter()
+        .rposition(|&byte| byte == b':' || byte == b'_')?
+        + 1;
+    let umi = &name[start..];
+    let bases = umi.iter().filter(|&&base| base != b'+').count();
+    let valid = umi.iter().all(|base| BASES.contains(base) || *base == b'+');
+    (bases >= MIN_LENGTH && valid).then_some(umi)
+}
+
+#[cfg(test)]
+mod test {
+    use super::UmiCounter;
+    #[test]
+    fn test_umis() {
+        let mut counter = UmiCounter::new(None);
+        counter.observe(b"M1:7:FC:1:1101:1:2:ACGTAC 1:N:0:ATCACG", b"");
+        counter.observe(b"M1:7:FC:1:1101:1:3:ACGTAC 1:N:0:ATCACG", b"");
+        counter.observe(b"SRR1.2_TTGC+AGGA", b"");
+        let mut other = UmiCounter::new(None);
+        other.observe(b"M1:7:FC:1:1101:1:4 1:N:0:ATCACG", b"");
+        counter.merge(&other);
+        let umis = counter.umis();
+        assert_eq!((umis.reads, umis.distinct), (3, 2));
+        assert_eq!(umis.percentage, 75.0);
+        assert_eq!(umis.top[0], ("ACGTAC".to_string(), 2, 200.0 / 3.0));
+        assert_eq!(umis.composition.len(), 8);
+        assert_eq!(
+            umis.composition[0],
+            [200.0 / 3.0, 0.0, 0.0, 100.0 / 3.0, 0.0]
+        );
+        // Three random UMIs of six bases are most likely distinct
+        assert!((umis.expected - 3.0).abs() < 0.01);
+        let mut prefix = UmiCounter::new(Some(4));
+        prefix.observe(b"read1", b"ACGTTTTT");
+        prefix.observe(b"read2", b"ACG");
+        let umis = prefix.umis();
+        assert_eq!((umis.reads, umis.distinct), (1, 1));
+        assert_eq!(umis.top[0].0, "ACGT");
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{self, BufRead};
+
+/// Bases allowed in the sequence line: the IUPAC codes in either case.
+const BASES: &[u8] = b"ACGTUNRYKMSWBDHVacgtunrykmswbdhv";
+/// Range of the quality characters, from phred 0 with offset 33 to the last printable
+/// ASCII character.
+const QUALITIES: std::ops::RangeInclusive<u8> = b'!'..=b'~';
+
+/// A violation of the FASTQ format, found in a record.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Violation {
+    /// Number of the record, starting at 1.
+    pub(crate) record: usize,
+    /// Offset of the offending byte in the decompressed input.
+    pub(crate) offset: u64,
+    pub(crate) problem: Problem,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Problem {
+    /// The header line does not start with `@`.
+    Header,
+    /// The separator line does not start with `+`.
+    Separator,
+    /// The separator line repeats a name different from the header.
+    SeparatorName,
+    /// The quality line is not as long as the sequence line.
+    Length {
+        sequence: usize,
+        quality: usize,
+    },
+    Base(u8),
+    Quality(u8),
+    /// The input ends within the record.
+    Truncated,
+}
+
+impl Problem {
+    /// Returns the name of the kind of the problem, for counting them.
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            Problem::Header => "missing @ header",
+            Problem::Separator => "missing + separator",
+            Problem::SeparatorName => "mismatched separator name",
+            Problem::Length { .. } => "length mismatch",
+            Problem::Base(_) => "invalid base",
+            Problem::Quality(_) => "invalid quality",
+            Problem::Truncated => "truncated record",
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "record {} at byte {}: ", self.record, self.offset)?;
+        match &self.problem {
+            Problem::Header => write!(f, "header does not start with '@'"),
+            Problem::Separator => write!(f, "separator does not start with '+'"),
+            Problem::SeparatorName => write!(f, "separator does not repeat the header"),
+            Problem::Length { sequence, quality } => {
+                write!(f, "{} quality characters for {} bases", quality, sequence)
+            }
+            Problem::Base(base) => write!(f, "invalid base {:?}", *base as char),
+            Problem::Quality(quality) => write!(f, "invalid quality {:?}", *quality as char),
+            Problem::Truncated => write!(f, "input ends within the record"),
+        }
+    }
+}
+
+/// Records checked in an input and the number of violations of every kind.
+#[derive(Debug, Default)]
+pub(crate) struct Summary {
+    pub(crate) records: usize,
+    pub(crate) violations: BTreeMap<&'static str, usize>,
+}
+
+impl Summary {
+    pub(crate) fn is_valid(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// Checks every record of the FASTQ input for a header, sequence, separator and
+/// quality line, matching lengths and legal characters. Unlike the parser, it does not
+/// accept multi-line records, and it passes every violation to `report`.
+pub(crate) fn validate<R: BufRead>(
+    mut reader: R,
+    report: &mut dyn FnMut(&Violation) -> io::Result<()>,
+) -> io::Result<Summary> {
+    let mut summary = Summary::default();
+    let mut lines: [Vec<u8>; 4] = Default::default();
+    let mut starts = [0_u64; 4];
+    let mut offset = 0;
+    loop {
+        let mut read = 0;
+        for (line, start) in lines.iter_mut().zip(&mut starts) {
+            line.clear();
+            *start = offset;
+            let length = reader.read_until(b'\n', line)?;
+            if length == 0 {
+                break;
+            }
+            offset += length as u64;
+            if line.ends_with(b"\n") {
+                line.pop();
+                if line.ends_with(b"\r") {
+                    line.pop();
+                }
+            }
+            read += 1;
+        }
+        if read == 0 {
+            return Ok(summary);
+        }
+        summary.records += 1;
+        let mut violation = |problem: Problem, offset: u64| {
+            *summary.violations.entry(problem.kind()).or_insert(0) += 1;
+            report(&Violation {
+                record: summary.records,
+                offset,
+                problem,
+            })
+        };
+        if read < 4 {
+            violation(Problem::Truncated, offset)?;
+            return Ok(summary);
+        }
+        let [header, sequence, separator, quality] = &lines;
+        if !header.starts_with(b"@") {
+            violation(Problem::Header, starts[0])?;
+        }
+        if !separator.starts_with(b"+") {
+            violation(Problem::Separator, starts[2])?;
+        } else if separator.len() > 1 && separator[1..] != header[header.len().min(1)..] {
+            violation(Problem::SeparatorName, starts[2])?;
+        }
+        if let Some(i) = sequence.iter().position(|base| !BASES.contains(base)) {
+            violation(Problem::Base(sequence[i]), starts[1] + i as u64)?;
+        }
+        if let Some(i) = quality.iter().position(|q| !QUALITIES.contains(q)) {
+            violation(Problem::Quality(quality[i]), starts[3] + i as u64)?;
+        }
+        if sequence.len() != quality.len() {
+            let problem = Problem::Length {
+                sequence: sequence.len(),
+                quality: quality.len(),
+            };
+            violation(problem, starts[3])?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{validate, Problem, Violation};
+    #[test]
+    fn test_validate() {
+        let input = b"@read1\nACGTN\n+\nIIIII\n\
+            @read2\r\nACXT\r\n+read2\r\nII I\r\n\
+            read3\nACGT\n-\nIII\n\
+            @read4\nACGT\n+read5\nIIII\n\
+            @read5\nACGT\n";
+        let mut violations = Vec::new();
+        let summary = validate(&input[..], &mut |violation: &Violation| {
+            violations.push((
+                violation.record,
+                violation.offset,
+                violation.problem.clone(),
+            ));
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(
+            violations,
+            [
+                (2, 31, Problem::Base(b'X')),
+                (2, 45, Problem::Quality(b' ')),
+                (3, 49, Problem::Header),
+                (3, 60, Problem::Separator),
+                (
+                    3,
+                    62,
+                    Problem::Length {
+                        sequence: 4,
+                        quality: 3
+                    }
+                ),
+                (4, 78, Problem::SeparatorName),
+                (5, 102, Problem::Truncated),
+            ]
+        );
+        assert_eq!(summary.records, 5);
+        assert_eq!(summary.violations["truncated record"], 1);
+        assert!(!summary.is_valid());
+        let summary = validate(&b"@read\nACGT\n+\nIIII"[..], &mut |_| Ok(())).unwrap();
+        assert_eq!(summary.records, 1);
+        assert!(summary.is_valid());
+    }
+}
@@ -0,0 +1,31 @@
+use crate::qc::{Qc, QcConfig, QcReport};
+use wasm_bindgen::prelude::*;
+
+/// The results of all modules for a file analyzed in the browser.
+#[wasm_bindgen]
+pub struct Report(QcReport);
+
+/// Runs all quality control modules on the bytes of a plain or gzip compressed FASTQ
+/// or FASTA file, naming the report after the given file name.
+#[wasm_bindgen]
+pub fn run(bytes: &[u8], name: &str) -> Result<Report, JsError> {
+    let report = Qc::new(QcConfig::new())
+        .process_bytes(bytes)
+        .map_err(|e| JsError::new(&e.to_string()))?;
+    Ok(Report(report.with_name(name)))
+}
+
+#[wasm_bindgen]
+impl Report {
+    /// Returns the report in the format written by `fqc --format json`.
+    pub fn json(&self) -> String {
+        self.0.to_json().to_string()
+    }
+
+    /// Returns the HTML report of `fqc` with all assets embedded.
+    pub fn html(&self) -> Result<String, JsError> {
+        self.0
+            .render_html()
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+}
@@ -0,0 +1,92 @@
+use crate::input::scan_dir;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Finds the input files written into a directory by a running sequencer, e.g.
+/// `fastq_pass/` of MinKNOW, once they are complete.
+pub(crate) struct Watcher {
+    dir: PathBuf,
+    /// Sizes of the new files at the last poll. A file is complete once its size
+    /// stayed the same between two polls.
+    pending: HashMap<String, u64>,
+    /// Files which were already returned.
+    seen: HashSet<String>,
+}
+
+impl Watcher {
+    pub(crate) fn new(dir: &Path) -> Self {
+        Watcher {
+            dir: dir.to_path_buf(),
+            pending: HashMap::default(),
+            seen: HashSet::default(),
+        }
+    }
+
+    /// Returns the new files which did not grow since the last poll in lexicographic
+    /// order, or all new files, complete or not, if `all`.
+    pub(crate) fn poll(&mut self, all: bool) -> io::Result<Vec<String>> {
+        let mut complete = Vec::new();
+        for file in scan_dir(&self.dir)? {
+            if self.seen.contains(&file) {
+                continue;
+            }
+            // Files may vanish, e.g. if moved away, and are skipped then
+            let Ok(metadata) = fs::metadata(&file) else {
+                continue;
+            };
+            let size = metadata.len();
+            if all || (size > 0 && self.pending.get(&file) == Some(&size)) {
+                self.pending.remove(&file);
+                self.seen.insert(file.clone());
+                complete.push(file);
+            } else {
+                self.pending.insert(file, size);
+            }
+        }
+        Ok(complete)
+    }
+
+    /// Returns whether the run finished, as MinKNOW writes a `final_summary_*.txt`
+    /// file into the run directory containing `fastq_pass/` at the end of a run.
+    pub(crate) fn is_finished(&self) -> bool {
+        let summary = |dir: &Path| {
+            fs::read_dir(dir).is_ok_and(|entries| {
+                entries.filter_map(Result::ok).any(|entry| {
+                    let name = entry.file_name();
+                    let name = name.to_string_lossy();
+                    name.starts_with("final_summary") && name.ends_with(".txt")
+                })
+            })
+        };
+        summary(&self.dir) || self.dir.parent().is_some_and(summary)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Watcher;
+    use std::fs;
+    #[test]
+    fn test_watcher() {
+        let dir = std::env::temp_dir().join(format!("fqc-watch-{}", std::process::id()));
+        let fastq_pass = dir.join("fastq_pass");
+        fs::create_dir_all(&fastq_pass).unwrap();
+        let file = |name: &str| fastq_pass.join(name).to_string_lossy().into_owned();
+        fs::write(file("a.fastq"), "@r1\nACGT\n+\nIIII\n").unwrap();
+        fs::write(file("b.fastq"), "").unwrap();
+        fs::write(file("notes.txt"), "").unwrap();
+        let mut watcher = Watcher::new(&fastq_pass);
+        // Files are only complete once their size stayed the same
+        assert!(watcher.poll(false).unwrap().is_empty());
+        fs::write(file("b.fastq"), "@r2\n").unwrap();
+        assert_eq!(watcher.poll(false).unwrap(), [file("a.fastq")]);
+        assert!(!watcher.is_finished());
+        fs::write(dir.join("final_summary_FAL12345_abc.txt"), "").unwrap();
+        assert!(watcher.is_finished());
+        assert_eq!(watcher.poll(true).unwrap(), [file("b.fastq")]);
+        assert!(watcher.poll(true).unwrap().is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -26,3 +26,157 @@ fn test_report() {
         }
     }
 }
+
+#[test]
+fn test_report_without_subcommand() {
+    // An output named like a subcommand still writes a report like before subcommands
+    let dir = std::env::temp_dir().join("fastqc-rs-test-without-subcommand");
+    fs::create_dir_all(&dir).unwrap();
+    let input = fs::canonicalize("tests/resources/example.fastq").unwrap();
+    let status = Command::new(env!("CARGO_BIN_EXE_fqc"))
+        .args([
+            "-q".as_ref(),
+            input.as_os_str(),
+            "-o".as_ref(),
+            "report".as_ref(),
+        ])
+        .current_dir(&dir)
+        .status()
+        .unwrap();
+    assert!(status.success());
+    let report = fs::read_to_string(dir.join("report")).unwrap();
+    assert!(report.contains("example.fastq"));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_baseline() {
+    // Files are compared to the file of the same name in the baseline
+    let dir = std::env::temp_dir().join("fastqc-rs-test-baseline");
+    fs::create_dir_all(&dir).unwrap();
+    let fastq = fs::read_to_string("tests/resources/example.fastq").unwrap();
+    fs::write(dir.join("a.fastq"), &fastq).unwrap();
+    fs::write(dir.join("b.fastq"), &fastq).unwrap();
+    let fqc = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_fqc"))
+            .arg("aggregate")
+            .args(args)
+            .current_dir(&dir)
+            .output()
+            .unwrap()
+    };
+    let baseline = fqc(&["a.fastq", "b.fastq", "--format", "json", "-o", "base.json"]);
+    assert!(baseline.status.success());
+    // The qualities of b.fastq drop to 2
+    let worse = fastq
+        .lines()
+        .enumerate()
+        .map(|(i, line)| match i % 4 {
+            3 => "#".repeat(line.len()),
+            _ => line.to_string(),
+        })
+        .join("\n");
+    fs::write(dir.join("b.fastq"), worse).unwrap();
+    fs::write(dir.join("c.fastq"), &fastq).unwrap();
+    let compared = fqc(&[
+        "b.fastq",
+        "c.fastq",
+        "a.fastq",
+        "--format",
+        "json",
+        "-o",
+        "new.json",
+        "--baseline",
+        "base.json",
+    ]);
+    assert_eq!(compared.status.code(), Some(3));
+    let stderr = String::from_utf8(compared.stderr).unwrap();
+    assert!(stderr.contains("b.fastq: %Q30 bases changed from 91.23 in the baseline to 0.00"));
+    assert!(stderr.contains("c.fastq: Not in the baseline"));
+    assert!(!stderr.contains("a.fastq:"));
+    let unchanged = fqc(&[
+        "a.fastq",
+        "--format",
+        "json",
+        "-o",
+        "new.json",
+        "--baseline",
+        "base.json",
+    ]);
+    assert!(unchanged.status.success());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_live_json() {
+    // Snapshots are written to their own file, apart from the log on standard error
+    let dir = std::env::temp_dir().join("fastqc-rs-test-live-json");
+    fs::create_dir_all(&dir).unwrap();
+    let input = fs::canonicalize("tests/resources/example.fastq").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_fqc"))
+        .arg("-q")
+        .arg(input)
+        .args(["--live-json", "50", "--live-json-out", "live.jsonl"])
+        .args(["-o", "report.html"])
+        .current_dir(&dir)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(!String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("\"files\""));
+    let snapshots = fs::read_to_string(dir.join("live.jsonl")).unwrap();
+    assert_eq!(snapshots.lines().count(), 4);
+    assert!(snapshots
+        .lines()
+        .all(|line| line.starts_with("{\"files\":")));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_config() {
+    // The given configuration is used before the one in the working directory, which is
+    // used before the one in the XDG configuration directory
+    let dir = std::env::temp_dir().join("fastqc-rs-test-config");
+    let xdg = dir.join("xdg");
+    fs::create_dir_all(xdg.join("fastqc-rs")).unwrap();
+    let input = fs::canonicalize("tests/resources/example.fastq").unwrap();
+    let fqc = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_fqc"))
+            .arg("-q")
+            .arg(&input)
+            .args(args)
+            .env("XDG_CONFIG_HOME", &xdg)
+            .current_dir(&dir)
+            .output()
+            .unwrap()
+    };
+    let report = |args: &[&str]| {
+        let output = fqc(args);
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    };
+    fs::write(
+        xdg.join("fastqc-rs").join("fastqc-rs.toml"),
+        "# Defaults of the facility\nformat = \"json\"\n",
+    )
+    .unwrap();
+    assert!(report(&[]).starts_with('{'));
+    fs::write(
+        dir.join("fastqc-rs.toml"),
+        "format = \"json\"\n\n[report]\nformat = 'markdown'\n",
+    )
+    .unwrap();
+    assert!(report(&[]).starts_with("# fastqc-rs report"));
+    fs::write(dir.join("other.toml"), "disable = [\"kmer_content\"]\n").unwrap();
+    assert!(report(&["--config", "other.toml"]).starts_with("<!DOCTYPE html>"));
+    // Arguments take precedence over the configuration
+    assert!(report(&["--format", "json"]).starts_with('{'));
+    fs::write(dir.join("invalid.toml"), "format = \n").unwrap();
+    let invalid = fqc(&["--config", "invalid.toml"]);
+    assert!(!invalid.status.success());
+    assert!(String::from_utf8(invalid.stderr)
+        .unwrap()
+        .contains("invalid.toml: TOML parse error at line 1"));
+    fs::remove_dir_all(&dir).unwrap();
+}